@@ -0,0 +1,49 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+use rustis::resp::{RespBuf, Value};
+use serde::Deserialize;
+use std::time::Duration;
+
+fn bench_to<'de, T: Deserialize<'de>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    resp_buf: &'de RespBuf,
+) {
+    group.bench_function(name, |b| {
+        b.iter(|| black_box(resp_buf.to::<T>().unwrap()));
+    });
+}
+
+fn bench_resp_deserializer(c: &mut Criterion) {
+    let ok = RespBuf::from_slice(b"+OK\r\n");
+    let integer = RespBuf::from_slice(b":123456\r\n");
+    let small_bulk_string = RespBuf::from_slice(b"$5\r\nhello\r\n");
+    let bulk_string = RespBuf::from_slice(b"$12\r\nhello world!\r\n");
+    let array = RespBuf::from_slice(&(0..100).fold(b"*100\r\n".to_vec(), |mut buf, i| {
+        buf.extend_from_slice(format!("$9\r\nvalue{i:04}\r\n").as_bytes());
+        buf
+    }));
+
+    let mut group = c.benchmark_group("resp_deserializer");
+    group.measurement_time(Duration::from_secs(5));
+
+    bench_to::<()>(&mut group, "ok_to_unit", &ok);
+    bench_to::<bool>(&mut group, "ok_to_bool", &ok);
+    bench_to::<Value>(&mut group, "ok_to_value", &ok);
+    bench_to::<i64>(&mut group, "integer_to_i64", &integer);
+    bench_to::<Value>(&mut group, "integer_to_value", &integer);
+    bench_to::<String>(
+        &mut group,
+        "small_bulk_string_to_string",
+        &small_bulk_string,
+    );
+    bench_to::<String>(&mut group, "bulk_string_to_string", &bulk_string);
+    bench_to::<Value>(&mut group, "bulk_string_to_value", &bulk_string);
+    bench_to::<Vec<String>>(&mut group, "array_to_vec_string", &array);
+    bench_to::<Value>(&mut group, "array_to_value", &array);
+    group.finish();
+}
+
+criterion_group!(bench, bench_resp_deserializer);
+criterion_main!(bench);
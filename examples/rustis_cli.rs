@@ -0,0 +1,223 @@
+//! A minimal `redis-cli` clone built on the raw command API of rustis.
+//!
+//! ```text
+//! cargo run --example rustis-cli --features cli -- [-u redis://127.0.0.1:6379] [--pipe]
+//! ```
+//!
+//! In interactive mode, each line is parsed with [`Command::parse_inline`] and the reply
+//! is printed the way `redis-cli` does, including RESP3 maps and sets.
+//! `HISTORY` lists the previous commands and `!<n>` runs the n-th one again.
+//! The history is saved in `~/.rustis_cli_history`.
+//!
+//! With `--pipe`, the commands are read from the standard input, one per line,
+//! sent all at once, and only a summary of the replies is printed.
+
+use futures_util::future::join_all;
+use rustis::{
+    client::Client,
+    resp::{Command, Value},
+    Error, Result,
+};
+use std::{
+    env,
+    fmt::Write as _,
+    fs,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+};
+
+const HISTORY_FILE: &str = ".rustis_cli_history";
+const HISTORY_MAX_LEN: usize = 1000;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut url = "redis://127.0.0.1:6379".to_owned();
+    let mut pipe = false;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-u" => url = args.next().unwrap_or(url),
+            "--pipe" => pipe = true,
+            _ => {
+                eprintln!("Usage: rustis-cli [-u <url>] [--pipe]");
+                return Ok(());
+            }
+        }
+    }
+
+    let client = Client::connect(url.as_str()).await?;
+
+    if pipe {
+        run_pipe(&client).await
+    } else {
+        run_interactive(&client, &url).await
+    }
+}
+
+async fn run_pipe(client: &Client) -> Result<()> {
+    let mut commands = Vec::new();
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|e| Error::IO(e.to_string()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        commands.push(Command::parse_inline(&line)?);
+    }
+
+    // commands sent concurrently on the multiplexed connection are pipelined
+    let results = join_all(commands.into_iter().map(|command| send(client, command))).await;
+    let errors = results.iter().filter(|result| result.is_err()).count();
+
+    for error in results.iter().filter_map(|result| result.as_ref().err()) {
+        eprintln!("{error}");
+    }
+    println!("errors: {errors}, replies: {}", results.len());
+
+    Ok(())
+}
+
+async fn run_interactive(client: &Client, url: &str) -> Result<()> {
+    let history_path = env::var_os("HOME").map(|home| PathBuf::from(home).join(HISTORY_FILE));
+    let mut history: Vec<String> = history_path
+        .as_ref()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|history| history.lines().map(ToOwned::to_owned).collect())
+        .unwrap_or_default();
+
+    let prompt = url
+        .trim_start_matches("redis://")
+        .trim_start_matches("rediss://");
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        print!("{prompt}> ");
+        let _ = io::stdout().flush();
+
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        let mut line = line.trim().to_owned();
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.eq_ignore_ascii_case("quit") || line.eq_ignore_ascii_case("exit") {
+            break;
+        }
+
+        if line.eq_ignore_ascii_case("history") {
+            for (i, command) in history.iter().enumerate() {
+                println!("{:>5}  {command}", i + 1);
+            }
+            continue;
+        }
+
+        if let Some(index) = line.strip_prefix('!') {
+            match index
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| history.get(i.wrapping_sub(1)))
+            {
+                Some(command) => {
+                    println!("{command}");
+                    line = command.clone();
+                }
+                None => {
+                    println!("(error) no such entry in history: {index}");
+                    continue;
+                }
+            }
+        }
+
+        history.push(line.clone());
+
+        let command = match Command::parse_inline(&line) {
+            Ok(command) => command,
+            Err(e) => {
+                println!("(error) {e}");
+                continue;
+            }
+        };
+
+        if matches!(
+            command.name,
+            "SUBSCRIBE" | "PSUBSCRIBE" | "SSUBSCRIBE" | "MONITOR"
+        ) {
+            println!(
+                "(error) {} is not supported in interactive mode",
+                command.name
+            );
+            continue;
+        }
+
+        match send(client, command).await {
+            Ok(value) => print!("{}", format_value(&value, 0)),
+            Err(e) => println!("(error) {e}"),
+        }
+    }
+
+    if let Some(path) = history_path {
+        let start = history.len().saturating_sub(HISTORY_MAX_LEN);
+        let _ = fs::write(path, history[start..].join("\n"));
+    }
+
+    Ok(())
+}
+
+async fn send(client: &Client, command: Command) -> Result<Value> {
+    client.send(command, None).await?.to()
+}
+
+/// Format a reply the way `redis-cli` does, `indent` being the width of the parent prefixes
+fn format_value(value: &Value, indent: usize) -> String {
+    match value {
+        Value::SimpleString(s) => format!("{s}\n"),
+        Value::Integer(i) => format!("(integer) {i}\n"),
+        Value::Double(d) => format!("(double) {d}\n"),
+        Value::BulkString(bs) => format!("\"{}\"\n", bs.escape_ascii()),
+        Value::Boolean(b) => format!("({b})\n"),
+        Value::Error(e) => format!("(error) {e}\n"),
+        Value::Nil => "(nil)\n".to_owned(),
+        Value::Array(values) | Value::Push(values) => format_items(values, ")", indent, "array"),
+        Value::Set(values) => format_items(values, "~", indent, "set"),
+        Value::Map(map) => {
+            if map.is_empty() {
+                return "(empty hash)\n".to_owned();
+            }
+
+            let width = map.len().to_string().len();
+            let mut output = String::new();
+            for (i, (key, value)) in map.iter().enumerate() {
+                if i > 0 {
+                    output.push_str(&" ".repeat(indent));
+                }
+                let prefix = format!("{:>width$}# ", i + 1);
+                let key = format_value(key, indent + prefix.len());
+                let key = key.trim_end();
+                let _ = write!(output, "{prefix}{key} => ");
+                output.push_str(&format_value(value, indent + prefix.len() + key.len() + 3));
+            }
+            output
+        }
+    }
+}
+
+fn format_items(values: &[Value], marker: &str, indent: usize, kind: &str) -> String {
+    if values.is_empty() {
+        return format!("(empty {kind})\n");
+    }
+
+    let width = values.len().to_string().len();
+    let mut output = String::new();
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            output.push_str(&" ".repeat(indent));
+        }
+        let prefix = format!("{:>width$}{marker} ", i + 1);
+        output.push_str(&prefix);
+        output.push_str(&format_value(value, indent + prefix.len()));
+    }
+    output
+}
@@ -0,0 +1,113 @@
+use crate::{client::Config, resp::Command, Error, Result};
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, TryAcquireError};
+
+/// Limits of the commands sent on a connection and not yet answered,
+/// shared by all the clones of a [`Client`](crate::client::Client)
+///
+/// See [`Config::max_in_flight_commands`] and [`Config::max_pending_bytes`]
+pub(crate) struct Backpressure {
+    in_flight_commands: Option<(Arc<Semaphore>, u32)>,
+    pending_bytes: Option<(Arc<Semaphore>, u32)>,
+    fail_fast: bool,
+}
+
+/// Permits held while the commands of a message are in flight,
+/// released when dropped
+pub(crate) struct BackpressurePermit {
+    _in_flight_commands: Option<OwnedSemaphorePermit>,
+    _pending_bytes: Option<OwnedSemaphorePermit>,
+}
+
+impl Backpressure {
+    /// `None` if no limit is configured
+    pub fn from_config(config: &Config) -> Option<Arc<Self>> {
+        if config.max_in_flight_commands == 0 && config.max_pending_bytes == 0 {
+            return None;
+        }
+
+        Some(Arc::new(Self {
+            in_flight_commands: Self::semaphore(config.max_in_flight_commands),
+            pending_bytes: Self::semaphore(config.max_pending_bytes),
+            fail_fast: config.backpressure_fail_fast,
+        }))
+    }
+
+    fn semaphore(limit: usize) -> Option<(Arc<Semaphore>, u32)> {
+        if limit == 0 {
+            return None;
+        }
+
+        let limit = u32::try_from(limit.min(Semaphore::MAX_PERMITS)).unwrap_or(u32::MAX);
+        Some((Arc::new(Semaphore::new(limit as usize)), limit))
+    }
+
+    /// Wait until the commands fit within the limits, or fail right away in fail-fast mode.
+    ///
+    /// A message exceeding a limit on its own is let through once all the other
+    /// messages have been answered.
+    pub async fn acquire<'a>(
+        &self,
+        commands: impl IntoIterator<Item = &'a Command>,
+    ) -> Result<BackpressurePermit> {
+        let (num_commands, num_bytes) = commands
+            .into_iter()
+            .fold((0, 0), |(n, b), command| (n + 1, b + command_size(command)));
+
+        Ok(BackpressurePermit {
+            _in_flight_commands: self
+                .acquire_permits(
+                    self.in_flight_commands.as_ref(),
+                    num_commands,
+                    "in-flight commands",
+                )
+                .await?,
+            _pending_bytes: self
+                .acquire_permits(self.pending_bytes.as_ref(), num_bytes, "pending bytes")
+                .await?,
+        })
+    }
+
+    async fn acquire_permits(
+        &self,
+        semaphore: Option<&(Arc<Semaphore>, u32)>,
+        num_permits: usize,
+        limit_name: &str,
+    ) -> Result<Option<OwnedSemaphorePermit>> {
+        let Some((semaphore, limit)) = semaphore else {
+            return Ok(None);
+        };
+
+        let num_permits = u32::try_from(num_permits).unwrap_or(u32::MAX).min(*limit);
+
+        let permit = if self.fail_fast {
+            semaphore
+                .clone()
+                .try_acquire_many_owned(num_permits)
+                .map_err(|e| match e {
+                    TryAcquireError::NoPermits => Error::Overloaded(format!(
+                        "Maximum number of {limit_name} ({limit}) reached"
+                    )),
+                    TryAcquireError::Closed => {
+                        Error::Client("Backpressure semaphore closed".to_owned())
+                    }
+                })?
+        } else {
+            semaphore
+                .clone()
+                .acquire_many_owned(num_permits)
+                .await
+                .map_err(|_| Error::Client("Backpressure semaphore closed".to_owned()))?
+        };
+
+        Ok(Some(permit))
+    }
+}
+
+/// Approximate number of bytes of the command once written to the socket
+fn command_size(command: &Command) -> usize {
+    command
+        .args
+        .into_iter()
+        .fold(command.name.len(), |size, arg| size + arg.len())
+}
@@ -14,8 +14,12 @@ use crate::commands::{
 };
 use crate::{
     client::{
-        ClientState, ClientTrackingInvalidationStream, IntoConfig, Message, MonitorStream,
-        Pipeline, PreparedCommand, PubSubStream, Transaction,
+        is_write_command, Backpressure, ClientState, ClientTrackingInvalidationStream,
+        CommandLatency, Config, ConnectionEvent, ConnectionEventReceiver, ConnectionEventSender,
+        ConnectionEvents, ConnectionState, Interceptor, IntoConfig, KeyPrefix, LatencyRecorder,
+        Message, MonitorStream, Pipeline, PreparedCommand, PubSubStream, PushHandler,
+        PushMessageReceiver, PushMessageSender, ReadCoalescer, RedactKeys, ReplyStream,
+        RetryPolicy, ServerFeatures, Transaction,
     },
     commands::{
         BitmapCommands, BlockingCommands, ClusterCommands, ConnectionCommands, GenericCommands,
@@ -24,22 +28,30 @@ use crate::{
         SortedSetCommands, StreamCommands, StringCommands, TransactionCommands,
     },
     network::{
-        timeout, JoinHandle, MsgSender, NetworkHandler, PubSubReceiver, PubSubSender, PushReceiver,
-        PushSender, ReconnectReceiver, ReconnectSender, ResultReceiver, ResultSender,
-        ResultsReceiver, ResultsSender,
+        sleep, spawn, timeout, JoinHandle, MsgSender, NetworkHandler, PubSubReceiver, PubSubSender,
+        PushReceiver, PushSender, ReconnectReceiver, ReconnectSender, ResultReceiver, ResultSender,
+        ResultsReceiver, ResultsSender, StreamReceiver, StreamSender,
+    },
+    resp::{
+        cmd, Command, CommandArgs, RespBuf, RespSerializer, Response, SingleArg,
+        SingleArgCollection, Value,
     },
-    resp::{cmd, Command, CommandArgs, RespBuf, Response, SingleArg, SingleArgCollection},
     Error, Future, Result,
 };
 use futures_channel::{mpsc, oneshot};
 use futures_util::Stream;
-use log::trace;
-use serde::de::DeserializeOwned;
+use log::{debug, trace, warn};
+use serde::{de::DeserializeOwned, Serialize};
 use std::{
+    collections::BTreeMap,
     future::IntoFuture,
-    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
-    time::Duration,
+    sync::{
+        atomic::{AtomicI64, AtomicU8, AtomicUsize, Ordering},
+        Arc, RwLock, RwLockReadGuard, RwLockWriteGuard,
+    },
+    time::{Duration, Instant},
 };
+use tokio::sync::broadcast::error::RecvError;
 
 /// Client with a unique connection to a Redis server.
 #[derive(Clone)]
@@ -47,9 +59,22 @@ pub struct Client {
     msg_sender: Arc<Option<MsgSender>>,
     network_task_join_handle: Arc<Option<JoinHandle<()>>>,
     reconnect_sender: ReconnectSender,
+    connection_event_sender: ConnectionEventSender,
+    push_message_sender: PushMessageSender,
     client_state: Arc<RwLock<ClientState>>,
+    db_index: Arc<AtomicUsize>,
+    server_features: Arc<RwLock<Option<ServerFeatures>>>,
+    client_id: Arc<AtomicI64>,
+    connection_state: Arc<AtomicU8>,
     command_timeout: Duration,
     retry_on_error: bool,
+    retry_policy: Option<RetryPolicy>,
+    interceptors: Arc<Vec<Arc<dyn Interceptor>>>,
+    latency_recorder: Option<Arc<LatencyRecorder>>,
+    backpressure: Option<Arc<Backpressure>>,
+    read_coalescer: Option<Arc<ReadCoalescer>>,
+    /// Number of replicas and timeout of the `WAIT` appended to the write commands
+    write_concern: Option<(usize, Duration)>,
 }
 
 impl Drop for Client {
@@ -85,19 +110,247 @@ impl Client {
         let config = config.into_config()?;
         let command_timeout = config.command_timeout;
         let retry_on_error = config.retry_on_error;
-        let (msg_sender, network_task_join_handle, reconnect_sender) =
-            NetworkHandler::connect(config.into_config()?).await?;
+        let retry_policy = config.retry_policy.clone();
+        let interceptors = Self::config_interceptors(&config);
+        let backpressure = Backpressure::from_config(&config);
+        let db_index = Arc::new(AtomicUsize::new(config.database));
+        let server_features = Arc::new(RwLock::new(None));
+        let client_id = Arc::new(AtomicI64::new(0));
+        let connection_state = Arc::new(AtomicU8::new(ConnectionState::Disconnected.to_u8()));
+        let (
+            msg_sender,
+            network_task_join_handle,
+            reconnect_sender,
+            connection_event_sender,
+            push_message_sender,
+        ) = NetworkHandler::connect(
+            config.into_config()?,
+            db_index.clone(),
+            server_features.clone(),
+            client_id.clone(),
+            connection_state.clone(),
+        )
+        .await?;
 
         Ok(Self {
             msg_sender: Arc::new(Some(msg_sender)),
             network_task_join_handle: Arc::new(Some(network_task_join_handle)),
             reconnect_sender,
+            connection_event_sender,
+            push_message_sender,
             client_state: Arc::new(RwLock::new(ClientState::new())),
+            db_index,
+            server_features,
+            client_id,
+            connection_state,
             command_timeout,
             retry_on_error,
+            retry_policy,
+            interceptors,
+            latency_recorder: None,
+            backpressure,
+            read_coalescer: None,
+            write_concern: None,
         })
     }
 
+    /// Creates a client without connecting to the Redis server.
+    ///
+    /// The connection is established when the first command is sent.
+    /// If this first connection fails, the error is returned to the caller of the command
+    /// and the connection will be attempted again with the next command.
+    ///
+    /// This function must be called from within an async runtime.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while parsing the configuration
+    #[inline]
+    pub fn connect_lazy(config: impl IntoConfig) -> Result<Self> {
+        let config = config.into_config()?;
+        let command_timeout = config.command_timeout;
+        let retry_on_error = config.retry_on_error;
+        let retry_policy = config.retry_policy.clone();
+        let interceptors = Self::config_interceptors(&config);
+        let backpressure = Backpressure::from_config(&config);
+        let db_index = Arc::new(AtomicUsize::new(config.database));
+        let server_features = Arc::new(RwLock::new(None));
+        let client_id = Arc::new(AtomicI64::new(0));
+        let connection_state = Arc::new(AtomicU8::new(ConnectionState::Disconnected.to_u8()));
+        let (
+            msg_sender,
+            network_task_join_handle,
+            reconnect_sender,
+            connection_event_sender,
+            push_message_sender,
+        ) = NetworkHandler::connect_lazy(
+            config,
+            db_index.clone(),
+            server_features.clone(),
+            client_id.clone(),
+            connection_state.clone(),
+        );
+
+        Ok(Self {
+            msg_sender: Arc::new(Some(msg_sender)),
+            network_task_join_handle: Arc::new(Some(network_task_join_handle)),
+            reconnect_sender,
+            connection_event_sender,
+            push_message_sender,
+            client_state: Arc::new(RwLock::new(ClientState::new())),
+            db_index,
+            server_features,
+            client_id,
+            connection_state,
+            command_timeout,
+            retry_on_error,
+            retry_policy,
+            interceptors,
+            latency_recorder: None,
+            backpressure,
+            read_coalescer: None,
+            write_concern: None,
+        })
+    }
+
+    /// Checks that the connection is alive by sending a [`PING`](https://redis.io/commands/ping/)
+    /// to the Redis server.
+    ///
+    /// # Return
+    /// The round-trip latency of the `PING` command
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while sending the `PING` command
+    ///
+    /// # See Also
+    /// [`Config::health_check_interval`](crate::client::Config::health_check_interval)
+    /// to ping idle connections periodically in the background
+    pub async fn health_check(&self) -> Result<Duration> {
+        let start = Instant::now();
+        self.send(cmd("PING").high_priority(), Some(false))
+            .await?
+            .to::<()>()?;
+        Ok(start.elapsed())
+    }
+
+    fn config_interceptors(config: &Config) -> Arc<Vec<Arc<dyn Interceptor>>> {
+        let mut interceptors: Vec<Arc<dyn Interceptor>> = Vec::new();
+        // patterns match the keys before they are prefixed
+        if !config.redacted_key_patterns.is_empty() {
+            interceptors.push(Arc::new(RedactKeys::new(
+                config.redacted_key_patterns.iter().map(String::as_str),
+            )));
+        }
+        if let Some(key_prefix) = &config.key_prefix {
+            interceptors.push(Arc::new(KeyPrefix::new(key_prefix.as_str())));
+        }
+        Arc::new(interceptors)
+    }
+
+    /// Registers an [`Interceptor`] invoked before each command is sent
+    /// and after each reply is received.
+    ///
+    /// Interceptors are invoked in their registration order.
+    /// They are shared by the clones of this client created after the registration.
+    ///
+    /// # Example
+    /// See [`Interceptor`]
+    pub fn with_interceptor(mut self, interceptor: impl Interceptor + 'static) -> Self {
+        let mut interceptors = self.interceptors.as_ref().clone();
+        interceptors.push(Arc::new(interceptor));
+        self.interceptors = Arc::new(interceptors);
+        self
+    }
+
+    /// Attaches a [`LatencyRecorder`] which records the latency of each command sent by this client.
+    ///
+    /// The recorder is shared by the clones of this client created after the registration.
+    /// The recorded latencies are retrieved with [`latency_report`](Client::latency_report).
+    ///
+    /// # Example
+    /// See [`LatencyRecorder`]
+    pub fn with_latency_recorder(mut self, latency_recorder: LatencyRecorder) -> Self {
+        self.latency_recorder = Some(Arc::new(latency_recorder));
+        self
+    }
+
+    /// Enables the coalescing of concurrent [`GET`](https://redis.io/commands/get/) commands.
+    ///
+    /// When several tasks request the same key while a `GET` on this key is pending,
+    /// a single command is sent and its reply is shared by all the callers,
+    /// reducing the load on the Redis server during cache-miss storms.
+    ///
+    /// The coalescing is shared by the clones of this client created after the call,
+    /// as long as they have the same [interceptors](Client::with_interceptor).
+    ///
+    /// Because a caller can receive the reply of a `GET` sent before its own call,
+    /// a write completed concurrently by another task may not be observed:
+    /// only enable it for cache-like data.
+    pub fn with_read_coalescing(mut self) -> Self {
+        self.read_coalescer = Some(ReadCoalescer::new());
+        self
+    }
+
+    /// Sets a write concern: each write command sent by this client is followed,
+    /// in the same message, by a [`WAIT`](https://redis.io/commands/wait/) command,
+    /// and fails with a [`WriteConcern`](crate::Error::WriteConcern) error if fewer than
+    /// `num_replicas` replicas have acknowledged it within `timeout`.
+    ///
+    /// Pipelines and transactions containing a write command are followed by a single `WAIT`.
+    /// A zero `timeout` waits forever, so it should be combined with
+    /// [`Config::command_timeout`](crate::client::Config::command_timeout).
+    ///
+    /// The write concern only applies to the clones of this client created after the call.
+    /// A [`WriteConcern`](crate::Error::WriteConcern) error does not mean that the write has been rolled back:
+    /// it has been applied on the master, and may still reach the replicas later.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{client::Client, commands::StringCommands, Error, Result};
+    /// use std::time::Duration;
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let client = Client::connect("127.0.0.1:6379")
+    ///         .await?
+    ///         .with_write_concern(1, Duration::from_millis(100));
+    ///
+    ///     match client.set("key", "value").await {
+    ///         Ok(()) => println!("replicated"),
+    ///         Err(Error::WriteConcern { acknowledged, .. }) => {
+    ///             println!("only {acknowledged} replica(s) acknowledged the write")
+    ///         }
+    ///         Err(e) => return Err(e),
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_write_concern(mut self, num_replicas: usize, timeout: Duration) -> Self {
+        self.write_concern = Some((num_replicas, timeout));
+        self
+    }
+
+    /// Latency statistics of each command sent since the [`LatencyRecorder`] has been attached,
+    /// by command name.
+    ///
+    /// The report is empty if no recorder has been attached with
+    /// [`with_latency_recorder`](Client::with_latency_recorder).
+    #[must_use]
+    pub fn latency_report(&self) -> BTreeMap<&'static str, CommandLatency> {
+        self.latency_recorder
+            .as_ref()
+            .map(|latency_recorder| latency_recorder.report())
+            .unwrap_or_default()
+    }
+
+    /// Clears the latencies recorded by the attached [`LatencyRecorder`]
+    pub fn reset_latency_report(&self) {
+        if let Some(latency_recorder) = &self.latency_recorder {
+            latency_recorder.reset();
+        }
+    }
+
     /// if this client is the last client on the shared connection, the channel to send messages
     /// to the underlying network handler will be closed explicitely.
     ///
@@ -124,6 +377,43 @@ impl Client {
         Ok(())
     }
 
+    /// Gracefully shuts down the connection.
+    ///
+    /// * No command can be sent anymore, by this client or by any of its clones,
+    /// * the replies of the commands already sent are awaited,
+    /// * a [`QUIT`](https://redis.io/commands/quit/) command is sent,
+    /// * if this client is the last client on the shared connection,
+    ///   the network handler is awaited, until the socket is closed.
+    ///
+    /// # Errors
+    /// [`Error::Timeout`](crate::Error::Timeout) if the shutdown is not complete after `timeout`
+    pub async fn shutdown(self, timeout_duration: Duration) -> Result<()> {
+        let Some(msg_sender) = self.msg_sender.as_ref().clone() else {
+            return Ok(());
+        };
+
+        if msg_sender.is_closed() {
+            return self.close().await;
+        }
+
+        timeout(timeout_duration, async move {
+            // replies are received in order: the reply of QUIT comes after
+            // the replies of all the in-flight commands
+            let (result_sender, result_receiver): (ResultSender, ResultReceiver) =
+                oneshot::channel();
+            msg_sender.unbounded_send(Message::single(cmd("QUIT"), result_sender, false))?;
+            msg_sender.close_channel();
+
+            // the network handler may have dropped the QUIT command if it was disconnected
+            if let Ok(result) = result_receiver.await {
+                result?;
+            }
+
+            self.close().await
+        })
+        .await?
+    }
+
     /// Used to receive notifications when the client reconnects to the Redis server.
     ///
     /// To turn this receiver into a Stream, you can use the
@@ -132,6 +422,143 @@ impl Client {
         self.reconnect_sender.subscribe()
     }
 
+    /// Used to receive the [events](ConnectionEvent) of the connection of the client.
+    ///
+    /// To turn this receiver into a Stream, you can use the
+    /// [`BroadcastStream`](https://docs.rs/tokio-stream/latest/tokio_stream/wrappers/struct.BroadcastStream.html) wrapper.
+    pub fn on_connection_event(&self) -> ConnectionEventReceiver {
+        self.connection_event_sender.subscribe()
+    }
+
+    /// Invoke `handler` for each [event](ConnectionEvent) of the connection of the client.
+    ///
+    /// The handler is invoked from a background task,
+    /// which ends when the client, its clones and its connection are dropped.
+    ///
+    /// This function must be called from within an async runtime.
+    pub fn subscribe_connection_events(&self, handler: impl ConnectionEvents + 'static) {
+        let mut receiver = self.on_connection_event();
+
+        spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(ConnectionEvent::Connected) => handler.on_connect(),
+                    Ok(ConnectionEvent::Disconnected(reason)) => handler.on_disconnect(reason),
+                    Ok(ConnectionEvent::Reconnected) => handler.on_reconnect(),
+                    Ok(ConnectionEvent::Error(error)) => handler.on_error(&error),
+                    Err(RecvError::Lagged(_)) => (),
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    /// Used to receive the RESP3 [push messages](crate::client::PushMessage) sent by the server out of band,
+    /// e.g. the key invalidations of the [`client_tracking`](crate::commands::ConnectionCommands::client_tracking)
+    /// command, or the messages of modules.
+    ///
+    /// Push messages are routed by the network layer, apart from the replies of the commands:
+    /// a command future only ever receives its own reply, even when a push message is received
+    /// while the command is in flight.
+    /// Pub/sub messages are delivered to their [`PubSubStream`](crate::client::PubSubStream)
+    /// and not to this receiver.
+    ///
+    /// A receiver which does not keep up with the messages misses the oldest ones
+    /// ([`RecvError::Lagged`](tokio::sync::broadcast::error::RecvError::Lagged)).
+    ///
+    /// To turn this receiver into a Stream, you can use the
+    /// [`BroadcastStream`](https://docs.rs/tokio-stream/latest/tokio_stream/wrappers/struct.BroadcastStream.html) wrapper.
+    pub fn on_push_message(&self) -> PushMessageReceiver {
+        self.push_message_sender.subscribe()
+    }
+
+    /// Invoke `handler` for each [push message](crate::client::PushMessage) received by the client.
+    ///
+    /// See [`on_push_message`](Client::on_push_message).
+    ///
+    /// The handler is invoked from a background task,
+    /// which ends when the client, its clones and its connection are dropped.
+    ///
+    /// This function must be called from within an async runtime.
+    pub fn subscribe_push_messages(&self, handler: impl PushHandler + 'static) {
+        let mut receiver = self.on_push_message();
+
+        spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(message) => handler.on_push(&message),
+                    Err(RecvError::Lagged(num_messages)) => {
+                        warn!("Push handler lagged behind, {num_messages} push messages missed");
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    /// Index of the logical database currently selected on the connection
+    ///
+    /// The index is updated after each successful [`select`](crate::commands::ConnectionCommands::select)
+    /// or [`reset`](crate::commands::ConnectionCommands::reset) sent by this client or one of its clones,
+    /// and the database is selected again after a reconnection.
+    /// A `SELECT` sent inside a pipeline or a transaction is not tracked.
+    #[must_use]
+    pub fn db_index(&self) -> usize {
+        self.db_index.load(Ordering::Relaxed)
+    }
+
+    /// Version and modules of the connected Redis server
+    ///
+    /// The features are retrieved at connection time and refreshed after each reconnection.
+    /// Returns `None` for a [lazy client](Client::connect_lazy) which has not connected yet.
+    ///
+    /// Before sending a version-sensitive command
+    /// (`SINTERCARD`, or `EXPIRE` with the `NX`, `XX`, `GT` or `LT` options),
+    /// with [`send`](Client::send), [`send_batch`](Client::send_batch), a pipeline or a transaction,
+    /// the client checks the server version and returns an
+    /// [`UnsupportedByServer`](crate::Error::UnsupportedByServer) error
+    /// if the server is too old to execute it.
+    #[must_use]
+    pub fn server_features(&self) -> Option<ServerFeatures> {
+        self.server_features.read().unwrap().clone()
+    }
+
+    /// Id of the connection on the server, as returned by
+    /// [`client_id`](crate::commands::ConnectionCommands::client_id)
+    ///
+    /// The id is retrieved at connection time, without an extra round-trip,
+    /// and refreshed after each reconnection: it can be used with
+    /// [`client_kill`](crate::commands::ConnectionCommands::client_kill) or
+    /// [`client_tracking`](crate::commands::ConnectionCommands::client_tracking) redirections
+    /// without sending a [`CLIENT ID`](https://redis.io/commands/client-id/) command.
+    ///
+    /// Returns `None` for a [lazy client](Client::connect_lazy) which has not connected yet,
+    /// or for a cluster client, which holds a connection to each node.
+    #[must_use]
+    pub fn cached_client_id(&self) -> Option<i64> {
+        match self.client_id.load(Ordering::Relaxed) {
+            0 => None,
+            client_id => Some(client_id),
+        }
+    }
+
+    /// Current state of the connection, as seen by the network layer
+    ///
+    /// Commands which are incompatible with this state are rejected with a
+    /// [`Client`](crate::Error::Client) error before being sent to the server.
+    /// See [`ConnectionState`] for the list of rules.
+    #[must_use]
+    pub fn connection_state(&self) -> ConnectionState {
+        ConnectionState::from_u8(self.connection_state.load(Ordering::Relaxed))
+    }
+
+    fn check_server_support(&self, command: &Command) -> Result<()> {
+        match &*self.server_features.read().unwrap() {
+            Some(server_features) => server_features.check_command(command),
+            None => Ok(()),
+        }
+    }
+
     /// Give an immutable generic access to attach any state to a client instance
     pub fn get_client_state(&self) -> RwLockReadGuard<ClientState> {
         self.client_state.read().unwrap()
@@ -198,6 +625,125 @@ impl Client {
 
     #[inline]
     pub async fn send(&self, command: Command, retry_on_error: Option<bool>) -> Result<RespBuf> {
+        self.check_server_support(&command)?;
+        self.send_unchecked(command, retry_on_error).await
+    }
+
+    /// [`send`](Client::send) without checking the server version,
+    /// for commands which handle older servers themselves
+    pub(crate) async fn send_unchecked(
+        &self,
+        command: Command,
+        retry_on_error: Option<bool>,
+    ) -> Result<RespBuf> {
+        match &self.latency_recorder {
+            Some(latency_recorder) => {
+                let command_name = command.name;
+                let start = Instant::now();
+                let result = self.send_coalesced(command, retry_on_error).await;
+                latency_recorder.record(command_name, start.elapsed());
+                result
+            }
+            None => self.send_coalesced(command, retry_on_error).await,
+        }
+    }
+
+    async fn send_coalesced(
+        &self,
+        command: Command,
+        retry_on_error: Option<bool>,
+    ) -> Result<RespBuf> {
+        match &self.read_coalescer {
+            Some(read_coalescer) => {
+                // clones with different interceptor chains, e.g. key prefixes, must not share replies
+                let scope = Arc::as_ptr(&self.interceptors) as usize;
+                read_coalescer
+                    .send(scope, command, |command| {
+                        self.send_intercepted(command, retry_on_error)
+                    })
+                    .await
+            }
+            None => self.send_intercepted(command, retry_on_error).await,
+        }
+    }
+
+    async fn send_intercepted(
+        &self,
+        command: Command,
+        retry_on_error: Option<bool>,
+    ) -> Result<RespBuf> {
+        if self.interceptors.is_empty() {
+            return self.send_with_retries(command, retry_on_error).await;
+        }
+
+        let command = self.before_send(command);
+        let command_for_reply = command.clone();
+        let resp_buf = self.send_with_retries(command, retry_on_error).await?;
+        self.after_receive(&command_for_reply, resp_buf)
+    }
+
+    async fn send_with_retries(
+        &self,
+        command: Command,
+        retry_on_error: Option<bool>,
+    ) -> Result<RespBuf> {
+        match &self.retry_policy {
+            Some(retry_policy) if retry_policy.is_retryable(&command) => {
+                self.send_with_retry_policy(command, retry_policy).await
+            }
+            _ => self.send_once(command, retry_on_error).await,
+        }
+    }
+
+    /// idempotent commands are retried on network errors by the network handler,
+    /// and on transient server errors here, with a backoff between attempts
+    async fn send_with_retry_policy(
+        &self,
+        command: Command,
+        retry_policy: &RetryPolicy,
+    ) -> Result<RespBuf> {
+        let mut attempt = 1;
+
+        loop {
+            let error = match self.send_once(command.clone(), Some(true)).await {
+                Ok(resp_buf) if resp_buf.is_error() => match resp_buf.to::<()>() {
+                    Err(e) => e,
+                    Ok(()) => return Ok(resp_buf),
+                },
+                Ok(resp_buf) => return Ok(resp_buf),
+                Err(e) => e,
+            };
+
+            if attempt >= retry_policy.max_attempts || !retry_policy.is_transient(&error) {
+                return Err(error);
+            }
+
+            let backoff = retry_policy.backoff(attempt);
+            debug!(
+                "{:?}: attempt {attempt} failed ({error}), retrying in {backoff:?}",
+                command.name
+            );
+            sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
+    #[inline]
+    async fn send_once(&self, command: Command, retry_on_error: Option<bool>) -> Result<RespBuf> {
+        if let Some(wait) = self.wait_command([&command]) {
+            let mut results = self
+                .send_batch_with_wait(vec![command], wait, retry_on_error)
+                .await?;
+            return results
+                .pop()
+                .ok_or_else(|| Error::Client("Missing reply".to_owned()));
+        }
+
+        let _permit = match &self.backpressure {
+            Some(backpressure) => Some(backpressure.acquire([&command]).await?),
+            None => None,
+        };
+
         let (result_sender, result_receiver): (ResultSender, ResultReceiver) = oneshot::channel();
         let message = Message::single(
             command,
@@ -226,12 +772,95 @@ impl Client {
     /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
     #[inline]
     pub fn send_and_forget(&self, command: Command, retry_on_error: Option<bool>) -> Result<()> {
+        let command = self.before_send(command);
         let message =
             Message::single_forget(command, retry_on_error.unwrap_or(self.retry_on_error));
         self.send_message(message)?;
         Ok(())
     }
 
+    /// Send command to the Redis server without asking for its response.
+    ///
+    /// Unlike [`send_and_forget`](Client::send_and_forget), the command is preceded by
+    /// [`CLIENT REPLY SKIP`](https://redis.io/commands/client-reply/) so that the Redis server
+    /// does not send any reply back, saving bandwidth for high-volume best-effort writes.
+    /// As a consequence, errors returned by the Redis server are silently lost.
+    ///
+    /// # Arguments
+    /// * `command` - generic [`Command`](crate::resp::Command) meant to be sent to the Redis server.
+    /// * `retry_on_error` - retry to send the command on network error.
+    ///   * `None` - default behaviour defined in [`Config::retry_on_error`](crate::client::Config::retry_on_error)
+    ///   * `Some(true)` - retry sending command on network error
+    ///   * `Some(false)` - do not retry sending command on network error
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    #[inline]
+    pub fn send_and_skip_reply(
+        &self,
+        command: Command,
+        retry_on_error: Option<bool>,
+    ) -> Result<()> {
+        let command = self.before_send(command);
+        // both commands are sent in the same message, so that no other command
+        // can be inserted in between; no reply is expected for this message
+        let (results_sender, _): (ResultsSender, ResultsReceiver) = oneshot::channel();
+        let message = Message::batch(
+            vec![cmd("CLIENT").arg("REPLY").arg("SKIP"), command],
+            results_sender,
+            retry_on_error.unwrap_or(self.retry_on_error),
+        );
+        self.send_message(message)?;
+        Ok(())
+    }
+
+    /// Send command to the Redis server and stream the elements of its array reply.
+    ///
+    /// Elements are yielded as soon as they are received from the network,
+    /// instead of buffering the entire reply in memory.
+    /// This is useful for commands with huge array replies like
+    /// [`KEYS`](https://redis.io/commands/keys/), [`LRANGE`](https://redis.io/commands/lrange/)
+    /// or [`SMEMBERS`](https://redis.io/commands/smembers/) on big collections.
+    ///
+    /// The command is never retried on network error since elements may have already been yielded.
+    ///
+    /// # Arguments
+    /// * `command` - generic [`Command`](crate::resp::Command) meant to be sent to the Redis server.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{client::Client, commands::ListCommands, resp::cmd, Result};
+    /// use futures_util::StreamExt;
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let client = Client::connect("127.0.0.1:6379").await?;
+    ///     client.del("mylist").await?;
+    ///     client.rpush("mylist", ["element1", "element2", "element3"]).await?;
+    ///
+    ///     let mut stream = client.send_stream::<String>(
+    ///         cmd("LRANGE").arg("mylist").arg(0).arg(-1)
+    ///     )?;
+    ///
+    ///     while let Some(element) = stream.next().await {
+    ///         println!("{}", element?);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn send_stream<E>(&self, command: Command) -> Result<ReplyStream<E>> {
+        let command = self.before_send(command);
+        let (stream_sender, stream_receiver): (StreamSender, StreamReceiver) = mpsc::unbounded();
+        let message = Message::stream(command, stream_sender);
+        self.send_message(message)?;
+        Ok(ReplyStream::new(stream_receiver))
+    }
+
     /// Send a batch of commands to the Redis server.
     ///
     /// # Arguments
@@ -249,6 +878,101 @@ impl Client {
         commands: Vec<Command>,
         retry_on_error: Option<bool>,
     ) -> Result<Vec<RespBuf>> {
+        for command in &commands {
+            self.check_server_support(command)?;
+        }
+
+        if self.interceptors.is_empty() {
+            return self.send_batch_once(commands, retry_on_error).await;
+        }
+
+        let commands: Vec<Command> = commands
+            .into_iter()
+            .map(|command| self.before_send(command))
+            .collect();
+        let commands_for_replies = commands.clone();
+        let resp_bufs = self.send_batch_once(commands, retry_on_error).await?;
+
+        commands_for_replies
+            .iter()
+            .zip(resp_bufs)
+            .map(|(command, resp_buf)| self.after_receive(command, resp_buf))
+            .collect()
+    }
+
+    async fn send_batch_once(
+        &self,
+        commands: Vec<Command>,
+        retry_on_error: Option<bool>,
+    ) -> Result<Vec<RespBuf>> {
+        match self.wait_command(&commands) {
+            Some(wait) => {
+                self.send_batch_with_wait(commands, wait, retry_on_error)
+                    .await
+            }
+            None => self.send_batch_message(commands, retry_on_error).await,
+        }
+    }
+
+    /// `WAIT` command of the write concern, if any of the commands is a write
+    fn wait_command<'c>(&self, commands: impl IntoIterator<Item = &'c Command>) -> Option<Command> {
+        let (num_replicas, timeout) = self.write_concern?;
+        commands.into_iter().any(is_write_command).then(|| {
+            cmd("WAIT")
+                .arg(num_replicas)
+                .arg(timeout.as_millis() as u64)
+        })
+    }
+
+    /// Sends the commands followed by `WAIT` in the same message, then checks the write concern
+    async fn send_batch_with_wait(
+        &self,
+        mut commands: Vec<Command>,
+        wait: Command,
+        retry_on_error: Option<bool>,
+    ) -> Result<Vec<RespBuf>> {
+        let (num_replicas, _) = self.write_concern.unwrap_or_default();
+        let is_transaction = commands.first().is_some_and(|c| c.name == "MULTI");
+        let writes: Vec<bool> = commands.iter().map(is_write_command).collect();
+        commands.push(wait);
+        let mut results = self.send_batch_message(commands, retry_on_error).await?;
+
+        let acknowledged: usize = results
+            .pop()
+            .ok_or_else(|| Error::Client("Missing WAIT reply".to_owned()))?
+            .to()?;
+        // a failed write has not been applied, its error is more relevant
+        let write_applied = if is_transaction {
+            // queued commands only reply `QUEUED`, `EXEC` tells if they have been applied
+            results
+                .last()
+                .is_some_and(|exec| !exec.is_error() && !exec.is_nil())
+        } else {
+            writes
+                .iter()
+                .zip(&results)
+                .any(|(is_write, result)| *is_write && !result.is_error())
+        };
+        if acknowledged < num_replicas && write_applied {
+            return Err(Error::WriteConcern {
+                num_replicas,
+                acknowledged,
+            });
+        }
+
+        Ok(results)
+    }
+
+    async fn send_batch_message(
+        &self,
+        commands: Vec<Command>,
+        retry_on_error: Option<bool>,
+    ) -> Result<Vec<RespBuf>> {
+        let _permit = match &self.backpressure {
+            Some(backpressure) => Some(backpressure.acquire(&commands).await?),
+            None => None,
+        };
+
         let (results_sender, results_receiver): (ResultsSender, ResultsReceiver) =
             oneshot::channel();
         let message = Message::batch(
@@ -265,6 +989,44 @@ impl Client {
         }
     }
 
+    fn before_send(&self, mut command: Command) -> Command {
+        for interceptor in self.interceptors.iter() {
+            interceptor.before_send(&mut command);
+        }
+        command
+    }
+
+    /// the reply is only encoded again if an interceptor has modified it
+    fn after_receive(&self, command: &Command, resp_buf: RespBuf) -> Result<RespBuf> {
+        if !self
+            .interceptors
+            .iter()
+            .any(|interceptor| interceptor.intercepts_reply(command))
+        {
+            return Ok(resp_buf);
+        }
+
+        let original_value = match resp_buf.to::<Value>() {
+            Ok(value) => value,
+            Err(e) => Value::Error(e.into_redis_error()?),
+        };
+
+        let mut value = original_value.clone();
+        for interceptor in self.interceptors.iter() {
+            if interceptor.intercepts_reply(command) {
+                interceptor.after_receive(command, &mut value);
+            }
+        }
+
+        if value == original_value {
+            Ok(resp_buf)
+        } else {
+            let mut serializer = RespSerializer::new();
+            value.serialize(&mut serializer)?;
+            Ok(RespBuf::new(serializer.get_output().freeze()))
+        }
+    }
+
     #[inline]
     fn send_message(&self, message: Message) -> Result<()> {
         if let Some(msg_sender) = &self.msg_sender as &Option<MsgSender> {
@@ -284,6 +1046,90 @@ impl Client {
         Transaction::new(self.clone())
     }
 
+    /// Run an optimistic transaction with automatic retries
+    ///
+    /// `keys` are [watched](TransactionCommands::watch), then `f` is called with a new transaction:
+    /// it reads the current values through [`Transaction::client`], queues the writes
+    /// into the transaction and returns it. The transaction is then executed.
+    ///
+    /// If one of the watched keys has been modified by another client in the meantime,
+    /// the transaction is aborted by the server and the whole sequence is retried,
+    /// up to `max_attempts` times.
+    ///
+    /// Since `WATCH` applies to the whole connection,
+    /// the client should not be used concurrently for other transactions.
+    ///
+    /// # Errors
+    /// * [`Error::Aborted`] if the transaction has been aborted `max_attempts` times.
+    /// * Any error returned by `f`, in which case the keys are [unwatched](TransactionCommands::unwatch).
+    /// * Any Redis driver [`Error`] that occurs during the operation
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{
+    ///     client::{BatchPreparedCommand, Client},
+    ///     commands::StringCommands,
+    ///     Result,
+    /// };
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let client = Client::connect("127.0.0.1:6379").await?;
+    ///     client.set("balance", 100).await?;
+    ///
+    ///     // withdraw 30 if the balance allows it
+    ///     let balance: i64 = client
+    ///         .transaction("balance", 5, |mut transaction| async move {
+    ///             let balance: i64 = transaction.client().get("balance").await?;
+    ///             if balance >= 30 {
+    ///                 transaction.decrby("balance", 30).queue();
+    ///             }
+    ///             Ok(transaction)
+    ///         })
+    ///         .await?;
+    ///     assert_eq!(70, balance);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn transaction<K, KK, F, Fut, T>(
+        &self,
+        keys: KK,
+        max_attempts: usize,
+        mut f: F,
+    ) -> Result<T>
+    where
+        K: SingleArg,
+        KK: SingleArgCollection<K>,
+        F: FnMut(Transaction) -> Fut,
+        Fut: std::future::Future<Output = Result<Transaction>>,
+        T: DeserializeOwned,
+    {
+        let watch = cmd("WATCH").arg(keys);
+
+        for _ in 0..max_attempts {
+            self.send(watch.clone(), None).await?.to::<()>()?;
+
+            let transaction = match f(self.create_transaction()).await {
+                Ok(transaction) => transaction,
+                Err(e) => {
+                    self.unwatch().await?;
+                    return Err(e);
+                }
+            };
+
+            match transaction.execute().await {
+                Err(Error::Aborted) => {
+                    debug!("Transaction aborted, retrying...");
+                }
+                result => return result,
+            }
+        }
+
+        Err(Error::Aborted)
+    }
+
     /// Create a new pipeline
     #[inline]
     pub fn create_pipeline(&self) -> Pipeline {
@@ -384,6 +1230,14 @@ pub trait ClientPreparedCommand<'a, R> {
     /// # Errors
     /// Any Redis driver [`Error`](crate::Error) that occur during the send operation
     fn forget(self) -> Result<()>;
+
+    /// Send command and ask the Redis server not to send its response
+    ///
+    /// See [`Client::send_and_skip_reply`](crate::client::Client::send_and_skip_reply)
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occur during the send operation
+    fn skip_reply(self) -> Result<()>;
 }
 
 impl<'a, R: Response> ClientPreparedCommand<'a, R> for PreparedCommand<'a, &'a Client, R> {
@@ -395,6 +1249,15 @@ impl<'a, R: Response> ClientPreparedCommand<'a, R> for PreparedCommand<'a, &'a C
         self.executor
             .send_and_forget(self.command, self.retry_on_error)
     }
+
+    /// Send command and ask the Redis server not to send its response
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occur during the send operation
+    fn skip_reply(self) -> Result<()> {
+        self.executor
+            .send_and_skip_reply(self.command, self.retry_on_error)
+    }
 }
 
 impl<'a, R> IntoFuture for PreparedCommand<'a, &'a Client, R>
@@ -407,10 +1270,12 @@ where
     fn into_future(self) -> Self::IntoFuture {
         Box::pin(async move {
             if let Some(custom_converter) = self.custom_converter {
+                // custom converters handle server errors themselves,
+                // e.g. with a fallback for older server versions
                 let command_for_result = self.command.clone();
                 let result = self
                     .executor
-                    .send(self.command, self.retry_on_error)
+                    .send_unchecked(self.command, self.retry_on_error)
                     .await?;
                 custom_converter(result, command_for_result, self.executor).await
             } else {
@@ -0,0 +1,383 @@
+use crate::resp::{Command, CommandArgs};
+
+/// Positions of the key arguments of a command
+///
+/// Argument indexes do not include the command name
+pub(crate) enum KeySpec {
+    /// Keys from the argument `first` to the argument `last`, every `step` arguments.
+    ///
+    /// A negative `last` index is counted from the end of the arguments.
+    Range {
+        first: usize,
+        last: isize,
+        step: usize,
+    },
+    /// The number of keys is given by the argument at the index `numkeys`.
+    /// Keys immediately follow this argument.
+    NumKeys { numkeys: usize },
+    /// Keys follow the `STREAMS` keyword and are followed by as many stream ids
+    Streams,
+    /// The argument following one of the `keywords`, searched from the argument `start_from`, is a key.
+    Keyword {
+        keywords: &'static [&'static str],
+        start_from: usize,
+    },
+}
+
+const FIRST: &[KeySpec] = &[KeySpec::Range {
+    first: 0,
+    last: 0,
+    step: 1,
+}];
+const SECOND: &[KeySpec] = &[KeySpec::Range {
+    first: 1,
+    last: 1,
+    step: 1,
+}];
+const FIRST_TWO: &[KeySpec] = &[KeySpec::Range {
+    first: 0,
+    last: 1,
+    step: 1,
+}];
+const ALL: &[KeySpec] = &[KeySpec::Range {
+    first: 0,
+    last: -1,
+    step: 1,
+}];
+const ALL_BUT_LAST: &[KeySpec] = &[KeySpec::Range {
+    first: 0,
+    last: -2,
+    step: 1,
+}];
+const ALL_BUT_FIRST: &[KeySpec] = &[KeySpec::Range {
+    first: 1,
+    last: -1,
+    step: 1,
+}];
+const EVERY_OTHER: &[KeySpec] = &[KeySpec::Range {
+    first: 0,
+    last: -1,
+    step: 2,
+}];
+const NUMKEYS_FIRST: &[KeySpec] = &[KeySpec::NumKeys { numkeys: 0 }];
+const NUMKEYS_SECOND: &[KeySpec] = &[KeySpec::NumKeys { numkeys: 1 }];
+const DESTINATION_AND_NUMKEYS: &[KeySpec] = &[
+    KeySpec::Range {
+        first: 0,
+        last: 0,
+        step: 1,
+    },
+    KeySpec::NumKeys { numkeys: 1 },
+];
+const STREAMS: &[KeySpec] = &[KeySpec::Streams];
+const GEORADIUS: &[KeySpec] = &[
+    KeySpec::Range {
+        first: 0,
+        last: 0,
+        step: 1,
+    },
+    KeySpec::Keyword {
+        keywords: &["STORE", "STOREDIST"],
+        start_from: 5,
+    },
+];
+const GEORADIUSBYMEMBER: &[KeySpec] = &[
+    KeySpec::Range {
+        first: 0,
+        last: 0,
+        step: 1,
+    },
+    KeySpec::Keyword {
+        keywords: &["STORE", "STOREDIST"],
+        start_from: 4,
+    },
+];
+
+/// Commands flagged `readonly` by the Redis server:
+/// they never modify the dataset and can be sent to replicas or retried safely.
+///
+/// This list must stay sorted for the binary search in [`CommandMetadata::get`]
+const READ_ONLY_COMMANDS: &[&str] = &[
+    "BITCOUNT",
+    "BITPOS",
+    "DBSIZE",
+    "DUMP",
+    "EVALSHA_RO",
+    "EVAL_RO",
+    "EXISTS",
+    "EXPIRETIME",
+    "FCALL_RO",
+    "GEODIST",
+    "GEOHASH",
+    "GEOPOS",
+    "GEORADIUSBYMEMBER_RO",
+    "GEORADIUS_RO",
+    "GEOSEARCH",
+    "GET",
+    "GETBIT",
+    "GETRANGE",
+    "HEXISTS",
+    "HEXPIRETIME",
+    "HGET",
+    "HGETALL",
+    "HKEYS",
+    "HLEN",
+    "HMGET",
+    "HPEXPIRETIME",
+    "HPTTL",
+    "HRANDFIELD",
+    "HSCAN",
+    "HSTRLEN",
+    "HTTL",
+    "HVALS",
+    "KEYS",
+    "LCS",
+    "LINDEX",
+    "LLEN",
+    "LPOS",
+    "LRANGE",
+    "MGET",
+    "OBJECT",
+    "PEXPIRETIME",
+    "PFCOUNT",
+    "PTTL",
+    "RANDOMKEY",
+    "SCAN",
+    "SCARD",
+    "SDIFF",
+    "SINTER",
+    "SINTERCARD",
+    "SISMEMBER",
+    "SMEMBERS",
+    "SMISMEMBER",
+    "SRANDMEMBER",
+    "SSCAN",
+    "STRLEN",
+    "SUNION",
+    "TTL",
+    "TYPE",
+    "XINFO",
+    "XLEN",
+    "XPENDING",
+    "XRANGE",
+    "XREAD",
+    "XREVRANGE",
+    "ZCARD",
+    "ZCOUNT",
+    "ZDIFF",
+    "ZINTER",
+    "ZINTERCARD",
+    "ZLEXCOUNT",
+    "ZMSCORE",
+    "ZRANDMEMBER",
+    "ZRANGE",
+    "ZRANGEBYLEX",
+    "ZRANGEBYSCORE",
+    "ZRANK",
+    "ZREVRANGE",
+    "ZREVRANGEBYLEX",
+    "ZREVRANGEBYSCORE",
+    "ZREVRANK",
+    "ZSCAN",
+    "ZSCORE",
+    "ZUNION",
+];
+
+/// Commands which can block the connection until a timeout expires.
+///
+/// `XREAD` and `XREADGROUP` only block with the `BLOCK` option.
+///
+/// This list must stay sorted for the binary search in [`CommandMetadata::get`]
+const BLOCKING_COMMANDS: &[&str] = &[
+    "BLMOVE",
+    "BLMPOP",
+    "BLPOP",
+    "BRPOP",
+    "BRPOPLPUSH",
+    "BZMPOP",
+    "BZPOPMAX",
+    "BZPOPMIN",
+    "WAIT",
+    "WAITAOF",
+];
+
+/// Static metadata of the commands wrapped by **rustis**
+///
+/// This registry records the positions of the key arguments and the flags of a command,
+/// without any round trip to the server.
+/// It is the single source of truth used for key prefixing, replica routing, retry policies
+/// and as a fallback for cluster routing when the server does not describe a command.
+pub(crate) struct CommandMetadata {
+    /// Positions of the key arguments, empty if the command has no key
+    pub key_specs: &'static [KeySpec],
+    /// The command never modifies the dataset
+    pub read_only: bool,
+    /// The command can block the connection until a timeout expires
+    pub blocking: bool,
+}
+
+impl CommandMetadata {
+    /// Metadata of a command, `None` if the command is unknown to the registry
+    pub fn get(command_name: &str) -> Option<CommandMetadata> {
+        let key_specs = key_specs(command_name);
+        let read_only = READ_ONLY_COMMANDS.binary_search(&command_name).is_ok();
+        let blocking = BLOCKING_COMMANDS.binary_search(&command_name).is_ok();
+
+        if key_specs.is_empty() && !read_only && !blocking {
+            return None;
+        }
+
+        Some(CommandMetadata {
+            key_specs,
+            read_only,
+            blocking,
+        })
+    }
+
+    /// Returns a mask of the arguments which are keys
+    pub fn key_mask(&self, args: &CommandArgs) -> Vec<bool> {
+        let num_args = args.len();
+        let mut mask = vec![false; num_args];
+
+        for spec in self.key_specs {
+            match spec {
+                KeySpec::Range { first, last, step } => {
+                    let last = if *last < 0 {
+                        num_args as isize + last
+                    } else {
+                        *last
+                    };
+
+                    // e.g. `GET` without any argument
+                    if last >= 0 && *first < num_args {
+                        let last = (last as usize).min(num_args - 1);
+                        for index in (*first..=last).step_by(*step) {
+                            mask[index] = true;
+                        }
+                    }
+                }
+                KeySpec::NumKeys { numkeys } => {
+                    let num_keys = args
+                        .get(*numkeys)
+                        .and_then(|arg| std::str::from_utf8(arg).ok())
+                        .and_then(|arg| arg.parse::<usize>().ok())
+                        .unwrap_or_default();
+                    mask.iter_mut()
+                        .skip(numkeys + 1)
+                        .take(num_keys)
+                        .for_each(|is_key| *is_key = true);
+                }
+                KeySpec::Streams => {
+                    if let Some(position) = args
+                        .iter()
+                        .position(|arg| arg.eq_ignore_ascii_case(b"STREAMS"))
+                    {
+                        let num_keys = (num_args - position - 1) / 2;
+                        mask.iter_mut()
+                            .skip(position + 1)
+                            .take(num_keys)
+                            .for_each(|is_key| *is_key = true);
+                    }
+                }
+                KeySpec::Keyword {
+                    keywords,
+                    start_from,
+                } => {
+                    if let Some(position) = args.iter().skip(*start_from).position(|arg| {
+                        keywords
+                            .iter()
+                            .any(|keyword| arg.eq_ignore_ascii_case(keyword.as_bytes()))
+                    }) {
+                        if let Some(is_key) = mask.get_mut(start_from + position + 1) {
+                            *is_key = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        mask
+    }
+
+    /// Key arguments of a command, `None` if the command is unknown to the registry
+    pub fn keys(command: &Command) -> Option<Vec<&[u8]>> {
+        let metadata = CommandMetadata::get(command.name)?;
+        let mask = metadata.key_mask(&command.args);
+
+        Some(
+            command
+                .args
+                .iter()
+                .zip(mask)
+                .filter_map(|(arg, is_key)| is_key.then_some(arg.as_slice()))
+                .collect(),
+        )
+    }
+}
+
+fn key_specs(command_name: &str) -> &'static [KeySpec] {
+    match command_name {
+        "APPEND" | "BITCOUNT" | "BITFIELD" | "BITFIELD_RO" | "BITPOS" | "DECR" | "DECRBY"
+        | "DUMP" | "EXPIRE" | "EXPIREAT" | "EXPIRETIME" | "GEOADD" | "GEODIST" | "GEOHASH"
+        | "GEOPOS" | "GEOSEARCH" | "GET" | "GETBIT" | "GETDEL" | "GETEX" | "GETRANGE"
+        | "GETSET" | "HDEL" | "HEXISTS" | "HEXPIRE" | "HEXPIREAT" | "HEXPIRETIME" | "HGET"
+        | "HGETALL" | "HINCRBY" | "HINCRBYFLOAT" | "HKEYS" | "HLEN" | "HMGET" | "HMSET"
+        | "HPERSIST" | "HPEXPIRE" | "HPEXPIREAT" | "HPEXPIRETIME" | "HPTTL" | "HRANDFIELD"
+        | "HSCAN" | "HSET" | "HSETNX" | "HSTRLEN" | "HTTL" | "HVALS" | "INCR" | "INCRBY"
+        | "INCRBYFLOAT" | "LINDEX" | "LINSERT" | "LLEN" | "LPOP" | "LPOS" | "LPUSH" | "LPUSHX"
+        | "LRANGE" | "LREM" | "LSET" | "LTRIM" | "MOVE" | "PERSIST" | "PEXPIRE" | "PEXPIREAT"
+        | "PEXPIRETIME" | "PFADD" | "PSETEX" | "PTTL" | "RESTORE" | "RPOP" | "RPUSH" | "RPUSHX"
+        | "SADD" | "SCARD" | "SET" | "SETBIT" | "SETEX" | "SETNX" | "SETRANGE" | "SISMEMBER"
+        | "SMEMBERS" | "SMISMEMBER" | "SORT" | "SORT_RO" | "SPOP" | "SRANDMEMBER" | "SREM"
+        | "SSCAN" | "STRLEN" | "SUBSTR" | "TTL" | "TYPE" | "XACK" | "XADD" | "XAUTOCLAIM"
+        | "XCLAIM" | "XDEL" | "XLEN" | "XPENDING" | "XRANGE" | "XREVRANGE" | "XTRIM" | "ZADD"
+        | "ZCARD" | "ZCOUNT" | "ZINCRBY" | "ZLEXCOUNT" | "ZMSCORE" | "ZPOPMAX" | "ZPOPMIN"
+        | "ZRANDMEMBER" | "ZRANGE" | "ZRANGEBYLEX" | "ZRANGEBYSCORE" | "ZRANK" | "ZREM"
+        | "ZREMRANGEBYLEX" | "ZREMRANGEBYRANK" | "ZREMRANGEBYSCORE" | "ZREVRANGE"
+        | "ZREVRANGEBYLEX" | "ZREVRANGEBYSCORE" | "ZREVRANK" | "ZSCAN" | "ZSCORE" => FIRST,
+        "MEMORY" | "OBJECT" | "XGROUP" | "XINFO" => SECOND,
+        "BLMOVE" | "BRPOPLPUSH" | "COPY" | "GEOSEARCHSTORE" | "LCS" | "LMOVE" | "RENAME"
+        | "RENAMENX" | "RPOPLPUSH" | "SMOVE" | "ZRANGESTORE" => FIRST_TWO,
+        "DEL" | "EXISTS" | "MGET" | "PFCOUNT" | "PFMERGE" | "SDIFF" | "SDIFFSTORE" | "SINTER"
+        | "SINTERSTORE" | "SUNION" | "SUNIONSTORE" | "TOUCH" | "UNLINK" | "WATCH" => ALL,
+        "BLPOP" | "BRPOP" | "BZPOPMAX" | "BZPOPMIN" => ALL_BUT_LAST,
+        "BITOP" => ALL_BUT_FIRST,
+        "MSET" | "MSETNX" => EVERY_OTHER,
+        "LMPOP" | "SINTERCARD" | "ZDIFF" | "ZINTER" | "ZINTERCARD" | "ZMPOP" | "ZUNION" => {
+            NUMKEYS_FIRST
+        }
+        "BLMPOP" | "BZMPOP" | "EVAL" | "EVALSHA" | "EVALSHA_RO" | "EVAL_RO" | "FCALL"
+        | "FCALL_RO" => NUMKEYS_SECOND,
+        "ZDIFFSTORE" | "ZINTERSTORE" | "ZUNIONSTORE" => DESTINATION_AND_NUMKEYS,
+        "XREAD" | "XREADGROUP" => STREAMS,
+        "GEORADIUS" | "GEORADIUS_RO" => GEORADIUS,
+        "GEORADIUSBYMEMBER" | "GEORADIUSBYMEMBER_RO" => GEORADIUSBYMEMBER,
+        _ => &[],
+    }
+}
+
+/// Indicates if a command never modifies the dataset
+pub(crate) fn is_read_only_command(command: &Command) -> bool {
+    CommandMetadata::get(command.name).is_some_and(|metadata| metadata.read_only)
+}
+
+/// Indicates if a command modifies the dataset:
+/// a command with keys which is not read-only, or a flush of the databases
+pub(crate) fn is_write_command(command: &Command) -> bool {
+    match command.name {
+        "FLUSHALL" | "FLUSHDB" | "SWAPDB" => true,
+        _ => CommandMetadata::get(command.name)
+            .is_some_and(|metadata| !metadata.read_only && !metadata.key_specs.is_empty()),
+    }
+}
+
+/// Indicates if a command can block the connection until a timeout expires
+pub(crate) fn is_blocking_command(command: &Command) -> bool {
+    match command.name {
+        "XREAD" | "XREADGROUP" => command
+            .args
+            .iter()
+            .any(|arg| arg.eq_ignore_ascii_case(b"BLOCK")),
+        _ => CommandMetadata::get(command.name).is_some_and(|metadata| metadata.blocking),
+    }
+}
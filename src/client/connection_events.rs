@@ -0,0 +1,139 @@
+#[cfg(doc)]
+use crate::client::Client;
+use crate::Error;
+use tokio::sync::broadcast;
+
+pub(crate) type ConnectionEventSender = broadcast::Sender<ConnectionEvent>;
+pub(crate) type ConnectionEventReceiver = broadcast::Receiver<ConnectionEvent>;
+
+/// Reason of a [`Disconnected`](ConnectionEvent::Disconnected) event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisconnectReason {
+    /// The connection has been closed, by the server or by the network
+    ConnectionClosed,
+    /// The server did not answer a health check `PING` in time
+    ///
+    /// See [`Config::health_check_interval`](crate::client::Config::health_check_interval)
+    HealthCheckFailed,
+}
+
+/// State of the connection shared by a [`Client`] and its clones
+///
+/// Commands which cannot be mixed with the current state are rejected
+/// with a [`Client`](crate::Error::Client) error before being sent,
+/// instead of desynchronizing the protocol:
+/// * in the [`Monitoring`](ConnectionState::Monitoring) state, only `RESET` and `QUIT` are allowed,
+/// * in the [`Subscribed`](ConnectionState::Subscribed) state, `MONITOR` is rejected,
+/// * in the [`Transaction`](ConnectionState::Transaction) state, as well as between `MULTI` and `EXEC`
+///   in a batch of commands, `MULTI`, `WATCH`, `MONITOR` and the subscription commands are rejected.
+///
+/// Since the connection uses RESP3, regular commands can be sent on a subscribed connection.
+///
+/// See [`Client::connection_state`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// The connection is not established, commands are queued until it is
+    Disconnected,
+    /// Regular request/response mode
+    Normal,
+    /// The connection is subscribed to at least one channel, pattern or shard channel
+    Subscribed,
+    /// The connection streams the commands processed by the server, after a `MONITOR` command
+    Monitoring,
+    /// A `MULTI` command has been sent alone, the next commands are queued by the server until `EXEC` or `DISCARD`
+    Transaction,
+}
+
+impl ConnectionState {
+    pub(crate) fn from_u8(state: u8) -> Self {
+        match state {
+            1 => ConnectionState::Normal,
+            2 => ConnectionState::Subscribed,
+            3 => ConnectionState::Monitoring,
+            4 => ConnectionState::Transaction,
+            _ => ConnectionState::Disconnected,
+        }
+    }
+
+    pub(crate) fn to_u8(self) -> u8 {
+        match self {
+            ConnectionState::Disconnected => 0,
+            ConnectionState::Normal => 1,
+            ConnectionState::Subscribed => 2,
+            ConnectionState::Monitoring => 3,
+            ConnectionState::Transaction => 4,
+        }
+    }
+}
+
+/// Event in the life of the connection of a [`Client`]
+///
+/// See [`Client::on_connection_event`]
+#[derive(Debug, Clone)]
+pub enum ConnectionEvent {
+    /// The connection of a [lazy](Client::connect_lazy) client has been established
+    ///
+    /// For other clients, the connection is established before the client is returned,
+    /// so this event cannot be observed.
+    Connected,
+    /// The connection has been lost, a reconnection is in progress
+    Disconnected(DisconnectReason),
+    /// The connection has been reestablished
+    Reconnected,
+    /// A network error occurred, or an attempt to connect or to reconnect failed
+    Error(Error),
+}
+
+/// Handler of the [events](ConnectionEvent) of the connection of a [`Client`]
+///
+/// Registered with [`Client::subscribe_connection_events`],
+/// it can be used to update a health endpoint or to raise alerts when the connection flaps.
+///
+/// All methods have an empty default implementation
+/// so that a handler can implement only some of them.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::{Client, ConnectionEvents, DisconnectReason},
+///     Error, Result,
+/// };
+///
+/// struct Health;
+///
+/// impl ConnectionEvents for Health {
+///     fn on_disconnect(&self, reason: DisconnectReason) {
+///         println!("disconnected from Redis: {reason:?}");
+///     }
+///
+///     fn on_reconnect(&self) {
+///         println!("reconnected to Redis");
+///     }
+///
+///     fn on_error(&self, error: &Error) {
+///         println!("Redis connection error: {error}");
+///     }
+/// }
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     client.subscribe_connection_events(Health);
+///
+///     Ok(())
+/// }
+/// ```
+pub trait ConnectionEvents: Send + Sync {
+    /// See [`ConnectionEvent::Connected`]
+    fn on_connect(&self) {}
+
+    /// See [`ConnectionEvent::Disconnected`]
+    fn on_disconnect(&self, _reason: DisconnectReason) {}
+
+    /// See [`ConnectionEvent::Reconnected`]
+    fn on_reconnect(&self) {}
+
+    /// See [`ConnectionEvent::Error`]
+    fn on_error(&self, _error: &Error) {}
+}
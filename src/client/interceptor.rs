@@ -0,0 +1,66 @@
+use crate::resp::{Command, Value};
+
+/// Middleware invoked by a [`Client`](crate::client::Client) around each command
+///
+/// Interceptors are registered with [`Client::with_interceptor`](crate::client::Client::with_interceptor)
+/// and are invoked in their registration order,
+/// enabling cross-cutting features like audit logging, key prefixing or custom metrics.
+///
+/// Both methods have an empty default implementation
+/// so that an interceptor can implement only one of them.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::{Client, Interceptor},
+///     commands::StringCommands,
+///     resp::{Command, Value},
+///     Result,
+/// };
+///
+/// struct AuditLog;
+///
+/// impl Interceptor for AuditLog {
+///     fn before_send(&self, command: &mut Command) {
+///         println!("sending {}", command.name);
+///     }
+///
+///     fn after_receive(&self, command: &Command, value: &mut Value) {
+///         println!("received {value:?} for {}", command.name);
+///     }
+/// }
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379")
+///         .await?
+///         .with_interceptor(AuditLog);
+///
+///     client.set("key", "value").await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub trait Interceptor: Send + Sync {
+    /// Invoked before a command is sent to the Redis server
+    ///
+    /// The command can be modified before being sent.
+    fn before_send(&self, _command: &mut Command) {}
+
+    /// Indicates if [`after_receive`](Interceptor::after_receive) must be invoked
+    /// for the reply of this command.
+    ///
+    /// Replies are decoded only if at least one interceptor intercepts them.
+    /// The default implementation intercepts all the replies.
+    fn intercepts_reply(&self, _command: &Command) -> bool {
+        true
+    }
+
+    /// Invoked after a reply has been received from the Redis server,
+    /// before the reply is converted to the expected Rust type.
+    ///
+    /// Errors returned by the Redis server are passed as [`Value::Error`].
+    /// The reply can be modified before being converted.
+    fn after_receive(&self, _command: &Command, _value: &mut Value) {}
+}
@@ -0,0 +1,165 @@
+use crate::{
+    client::{CommandMetadata, Interceptor},
+    resp::{Command, CommandArgs, Value},
+};
+
+/// [`Interceptor`] which transparently prepends a namespace to all the key arguments of the commands
+///
+/// This allows multi-tenant applications to isolate their keyspaces without touching call sites.
+/// Key positions are known for the commands wrapped by **rustis**.
+/// Arguments of unknown commands are left untouched.
+///
+/// Patterns of the [`keys`](crate::commands::GenericCommands::keys) and
+/// [`scan`](crate::commands::GenericCommands::scan) commands are prefixed too,
+/// and the prefix is removed from the key names returned by these commands, by
+/// [`randomkey`](crate::commands::GenericCommands::randomkey) and by the list & sorted set pop commands.
+///
+/// This interceptor is registered automatically when [`Config::key_prefix`](crate::client::Config::key_prefix) is set.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::{Client, KeyPrefix},
+///     commands::StringCommands,
+///     Result,
+/// };
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379")
+///         .await?
+///         .with_interceptor(KeyPrefix::new("myapp:"));
+///
+///     // actually sets the key `myapp:key`
+///     client.set("key", "value").await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct KeyPrefix {
+    prefix: Vec<u8>,
+}
+
+impl KeyPrefix {
+    /// Creates a new interceptor with the namespace `prefix`
+    pub fn new(prefix: impl Into<Vec<u8>>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+
+    fn prefixed(&self, arg: &[u8]) -> Vec<u8> {
+        let mut prefixed = Vec::with_capacity(self.prefix.len() + arg.len());
+        prefixed.extend_from_slice(&self.prefix);
+        prefixed.extend_from_slice(arg);
+        prefixed
+    }
+
+    fn strip(&self, value: &mut Value) {
+        if let Value::BulkString(key) = value {
+            if key.starts_with(&self.prefix) {
+                key.drain(..self.prefix.len());
+            }
+        }
+    }
+}
+
+impl Interceptor for KeyPrefix {
+    fn before_send(&self, command: &mut Command) {
+        if self.prefix.is_empty() {
+            return;
+        }
+
+        let mut args = CommandArgs::default();
+
+        match command.name {
+            "KEYS" => {
+                for (index, arg) in command.args.iter().enumerate() {
+                    if index == 0 {
+                        args.write_arg(self.prefixed(arg));
+                    } else {
+                        args.write_arg(arg.as_slice());
+                    }
+                }
+            }
+            "SCAN" => {
+                let mut is_pattern = false;
+                let mut has_pattern = false;
+                for arg in command.args.iter() {
+                    if is_pattern {
+                        args.write_arg(self.prefixed(arg));
+                        is_pattern = false;
+                    } else {
+                        is_pattern = arg.eq_ignore_ascii_case(b"MATCH");
+                        has_pattern |= is_pattern;
+                        args.write_arg(arg.as_slice());
+                    }
+                }
+
+                if !has_pattern {
+                    args.write_arg(&b"MATCH"[..]);
+                    args.write_arg(self.prefixed(b"*"));
+                }
+            }
+            _ => {
+                let Some(metadata) = CommandMetadata::get(command.name) else {
+                    return;
+                };
+                if metadata.key_specs.is_empty() {
+                    return;
+                }
+
+                let mask = metadata.key_mask(&command.args);
+                for (arg, is_key) in command.args.iter().zip(mask) {
+                    if is_key {
+                        args.write_arg(self.prefixed(arg));
+                    } else {
+                        args.write_arg(arg.as_slice());
+                    }
+                }
+            }
+        }
+
+        command.args = args;
+    }
+
+    fn intercepts_reply(&self, command: &Command) -> bool {
+        matches!(
+            command.name,
+            "BLMPOP"
+                | "BLPOP"
+                | "BRPOP"
+                | "BZMPOP"
+                | "BZPOPMAX"
+                | "BZPOPMIN"
+                | "KEYS"
+                | "LMPOP"
+                | "RANDOMKEY"
+                | "SCAN"
+                | "ZMPOP"
+        )
+    }
+
+    fn after_receive(&self, command: &Command, value: &mut Value) {
+        if self.prefix.is_empty() {
+            return;
+        }
+
+        match (command.name, value) {
+            ("KEYS", Value::Array(keys)) => keys.iter_mut().for_each(|key| self.strip(key)),
+            ("SCAN", Value::Array(cursor_and_keys)) => {
+                if let Some(Value::Array(keys)) = cursor_and_keys.get_mut(1) {
+                    keys.iter_mut().for_each(|key| self.strip(key));
+                }
+            }
+            ("RANDOMKEY", key) => self.strip(key),
+            (_, Value::Array(values)) => {
+                if let Some(key) = values.first_mut() {
+                    self.strip(key);
+                }
+            }
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+    time::Duration,
+};
+
+/// Number of bits of the sub-buckets of a power of two,
+/// i.e. the latencies are recorded with a relative precision of 1/32 (about 3%)
+const SUB_BUCKET_BITS: u32 = 5;
+const SUB_BUCKET_COUNT: u64 = 1 << SUB_BUCKET_BITS;
+
+/// Histogram of the latencies of a command, with a precision of one microsecond
+///
+/// Like an [HDR histogram](http://hdrhistogram.org/), the values are recorded in buckets
+/// of exponentially growing width, so that the relative error of each percentile is bounded
+/// whatever the range of the recorded values.
+#[derive(Debug, Clone, Default)]
+pub struct LatencyHistogram {
+    counts: Vec<u64>,
+    count: u64,
+    max: u64,
+}
+
+impl LatencyHistogram {
+    /// Record a latency
+    pub fn record(&mut self, latency: Duration) {
+        let value = u64::try_from(latency.as_micros()).unwrap_or(u64::MAX);
+        let index = bucket_index(value);
+        if index >= self.counts.len() {
+            self.counts.resize(index + 1, 0);
+        }
+
+        self.counts[index] += 1;
+        self.count += 1;
+        self.max = self.max.max(value);
+    }
+
+    /// Number of recorded latencies
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Highest recorded latency
+    #[must_use]
+    pub fn max(&self) -> Duration {
+        Duration::from_micros(self.max)
+    }
+
+    /// Latency under which `percentile` percent of the recorded latencies are
+    ///
+    /// The returned value is the upper bound of the bucket of the percentile,
+    /// capped by the highest recorded latency.
+    #[must_use]
+    pub fn percentile(&self, percentile: f64) -> Duration {
+        if self.count == 0 {
+            return Duration::ZERO;
+        }
+
+        let rank = ((percentile.clamp(0., 100.) / 100. * self.count as f64).ceil() as u64).max(1);
+        let mut total = 0;
+
+        for (index, count) in self.counts.iter().enumerate() {
+            total += count;
+            if total >= rank {
+                return Duration::from_micros(bucket_upper_bound(index).min(self.max));
+            }
+        }
+
+        self.max()
+    }
+}
+
+fn bucket_index(value: u64) -> usize {
+    if value < SUB_BUCKET_COUNT {
+        return value as usize;
+    }
+
+    let shift = 63 - value.leading_zeros() - SUB_BUCKET_BITS;
+    let sub_bucket = (value >> shift) - SUB_BUCKET_COUNT;
+    ((u64::from(shift) + 1) * SUB_BUCKET_COUNT + sub_bucket) as usize
+}
+
+fn bucket_upper_bound(index: usize) -> u64 {
+    let index = index as u64;
+    if index < SUB_BUCKET_COUNT {
+        return index;
+    }
+
+    let shift = index / SUB_BUCKET_COUNT - 1;
+    let sub_bucket = index % SUB_BUCKET_COUNT + SUB_BUCKET_COUNT;
+    (sub_bucket << shift) + ((1 << shift) - 1)
+}
+
+/// Latency statistics of a command, as returned by
+/// [`Client::latency_report`](crate::client::Client::latency_report)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandLatency {
+    /// Number of commands sent
+    pub count: u64,
+    /// Median latency
+    pub p50: Duration,
+    /// 95th percentile latency
+    pub p95: Duration,
+    /// 99th percentile latency
+    pub p99: Duration,
+    /// Highest latency
+    pub max: Duration,
+}
+
+impl From<&LatencyHistogram> for CommandLatency {
+    fn from(histogram: &LatencyHistogram) -> Self {
+        Self {
+            count: histogram.count(),
+            p50: histogram.percentile(50.),
+            p95: histogram.percentile(95.),
+            p99: histogram.percentile(99.),
+            max: histogram.max(),
+        }
+    }
+}
+
+/// Opt-in recorder of the latency of the commands sent by a [`Client`](crate::client::Client)
+///
+/// The recorder is attached with [`Client::with_latency_recorder`](crate::client::Client::with_latency_recorder)
+/// and keeps one [`LatencyHistogram`] per command name.
+/// The latency of a command is measured from the moment it is sent, to the moment its reply
+/// is received, retries included.
+/// Commands sent in a [`Pipeline`](crate::client::Pipeline) or a
+/// [`Transaction`](crate::client::Transaction) are not recorded.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::{Client, LatencyRecorder},
+///     commands::StringCommands,
+///     Result,
+/// };
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379")
+///         .await?
+///         .with_latency_recorder(LatencyRecorder::new());
+///
+///     client.set("key", "value").await?;
+///     let _value: String = client.get("key").await?;
+///
+///     let report = client.latency_report();
+///     let get_latency = report["GET"];
+///     println!("GET: p50={:?} p99={:?} max={:?}", get_latency.p50, get_latency.p99, get_latency.max);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct LatencyRecorder {
+    histograms: Mutex<HashMap<&'static str, LatencyHistogram>>,
+}
+
+impl LatencyRecorder {
+    /// Create an empty recorder
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the latency of a command
+    pub fn record(&self, command_name: &'static str, latency: Duration) {
+        self.histograms
+            .lock()
+            .unwrap()
+            .entry(command_name)
+            .or_default()
+            .record(latency);
+    }
+
+    /// Latency statistics of each recorded command, by command name
+    #[must_use]
+    pub fn report(&self) -> BTreeMap<&'static str, CommandLatency> {
+        self.histograms
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, histogram)| (*name, histogram.into()))
+            .collect()
+    }
+
+    /// Clear all the recorded latencies
+    pub fn reset(&self) {
+        self.histograms.lock().unwrap().clear();
+    }
+}
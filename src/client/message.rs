@@ -1,6 +1,6 @@
 use smallvec::SmallVec;
 
-use crate::{resp::Command, PushSender, PubSubSender, RetryReason, network::{ResultSender, ResultsSender}};
+use crate::{resp::Command, PushSender, PubSubSender, RetryReason, network::{ResultSender, ResultsSender, StreamSender}};
 
 #[cfg(debug_assertions)]
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -24,6 +24,15 @@ impl Commands {
             Commands::Batch(commands, _) => commands.len(),
         }
     }
+
+    /// `true` if all the commands are flagged as high priority
+    pub fn is_high_priority(&self) -> bool {
+        match &self {
+            Commands::None => false,
+            Commands::Single(command, _) => command.high_priority,
+            Commands::Batch(commands, _) => commands.iter().all(|c| c.high_priority),
+        }
+    }
 }
 
 impl IntoIterator for Commands {
@@ -119,6 +128,7 @@ pub(crate) struct Message {
     pub commands: Commands,
     pub pub_sub_senders: Option<Vec<(Vec<u8>, PubSubSender)>>,
     pub push_sender: Option<PushSender>,
+    pub stream_sender: Option<StreamSender>,
     pub retry_reasons: Option<SmallVec<[RetryReason; 10]>>,
     pub retry_on_error: bool,
     #[cfg(debug_assertions)]
@@ -133,6 +143,7 @@ impl Message {
             commands: Commands::Single(command, Some(result_sender)),
             pub_sub_senders: None,
             push_sender: None,
+            stream_sender: None,
             retry_reasons: None,
             retry_on_error,
             #[cfg(debug_assertions)]
@@ -146,6 +157,7 @@ impl Message {
             commands: Commands::Single(command, None),
             pub_sub_senders: None,
             push_sender: None,
+            stream_sender: None,
             retry_reasons: None,
             retry_on_error,
             #[cfg(debug_assertions)]
@@ -159,6 +171,7 @@ impl Message {
             commands: Commands::Batch(commands, results_sender),
             pub_sub_senders: None,
             push_sender: None,
+            stream_sender: None,
             retry_reasons: None,
             retry_on_error,
             #[cfg(debug_assertions)]
@@ -176,6 +189,7 @@ impl Message {
             commands: Commands::Single(command, Some(result_sender)),
             pub_sub_senders: Some(pub_sub_senders),
             push_sender: None,
+            stream_sender: None,
             retry_reasons: None,
             retry_on_error: true,
             #[cfg(debug_assertions)]
@@ -193,6 +207,7 @@ impl Message {
             commands: Commands::Single(command, Some(result_sender)),
             pub_sub_senders: None,
             push_sender: Some(push_sender),
+            stream_sender: None,
             retry_reasons: None,
             retry_on_error: true,
             #[cfg(debug_assertions)]
@@ -200,12 +215,29 @@ impl Message {
         }
     }
 
+    /// Elements of the reply are sent one by one to `stream_sender`.
+    /// The message is never retried since elements may have already been sent.
+    #[inline(always)]
+    pub fn stream(command: Command, stream_sender: StreamSender) -> Self {
+        Message {
+            commands: Commands::Single(command, None),
+            pub_sub_senders: None,
+            push_sender: None,
+            stream_sender: Some(stream_sender),
+            retry_reasons: None,
+            retry_on_error: false,
+            #[cfg(debug_assertions)]
+            message_seq: MESSAGE_SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst),
+        }
+    }
+
     #[inline(always)]
     pub fn client_tracking_invalidation(push_sender: PushSender) -> Self {
         Message {
             commands: Commands::None,
             pub_sub_senders: None,
             push_sender: Some(push_sender),
+            stream_sender: None,
             retry_reasons: None,
             retry_on_error: false,
             #[cfg(debug_assertions)]
@@ -50,6 +50,15 @@ to be sent concurrently on the same underlying connection.
 The multiplexer mode is great because it offers much performance in a multi-threaded architecture, with only a single
 underlying connection. It should be the prefered mode for Web applications.
 
+### Auto-pipelining
+The commands sent concurrently through clones of the same [`Client`] are pipelined automatically:
+all the commands queued while the connection is busy writing or reading are coalesced
+and sent to the server in a single socket write, without having to build a [`Pipeline`] explicitly.
+The replies are then dispatched back to each caller in order.
+
+Commands flagged with [`high_priority`](crate::resp::Command::high_priority), like the health check `PING`s
+or the lock releases, are sent ahead of the other queued commands.
+
 ### Limitations
 Beware that using [`Client`] in a multiplexer mode, by cloning an instance across multiple threads,
 is not suitable for using [blocking commands](crate::commands::BlockingCommands)
@@ -121,6 +130,9 @@ The manager can be configured via [bb8](https://docs.rs/bb8/latest/bb8/) with a
 
 For you convenience, [bb8](https://docs.rs/bb8/latest/bb8/) is reexported from the **rustis** crate.
 
+The [`PoolExt::get_db`] extension checks out a client bound to a given logical database.
+The database of the configuration is selected again when the client is checked out the next time.
+
 ```
 #[cfg(feature = "pool")]
 use rustis::{
@@ -157,6 +169,7 @@ async fn main() -> Result<()> {
 A [`Client`] instance can be configured with the [`Config`] struct:
 * Authentication
 * [`TlsConfig`]
+* [`ProxyConfig`] (SOCKS5 or HTTP)
 * [`ServerConfig`] (Standalone, Sentinel or Cluster)
 
 [`IntoConfig`] is a convenient trait to convert more known types to a [`Config`] instance:
@@ -211,10 +224,34 @@ of the struct [`Config`] or its dependencies:
   [`monitor`](crate::commands::BlockingCommands::monitor) command will be resent automatically
 * [`connection_name`](Config::connection_name) - Set the name of the connection to make
   it easier to identity the connection in client list.
+* [`lib_name`](Config::lib_name) - Name of the client library sent with `CLIENT SETINFO` at connection.
+  If empty, the library name is not sent (default `rustis`).
+* [`lib_version`](Config::lib_version) - Version of the client library sent with `CLIENT SETINFO` at connection.
+  If empty, the library version is not sent (default: version of this crate).
 * [`keep_alive`](Config::keep_alive) - Enable/disable keep-alive functionality (default `None`)
 * [`no_delay`](Config::no_delay) - Enable/disable the use of Nagle's algorithm (default `true`)
 * [`max_command_attempts`](Config::max_command_attempts) - Maximum number of retry attempts to send a command to the Redis server (default `3`).
 * [`retry_on_error`](Config::retry_on_error) - Defines the default strategy for retries on network error (default `false`).
+* [`health_check_interval`](Config::health_check_interval) - Interval of inactivity after which the connection is checked
+  with a `PING` command. If set to 0, no health check is done (default `0` ms).
+* [`key_prefix`](Config::key_prefix) - An optional namespace transparently prepended to all the key arguments (default `None`).
+* [`redacted_key_patterns`](Config::redacted_key_patterns) - Comma-separated glob-style patterns of the keys
+  whose values are redacted from the logs (default empty).
+* [`readonly`](Config::readonly) - Enable read queries on a Redis Cluster replica node with the `READONLY` command (default `false`).
+* [`no_evict`](Config::no_evict) - Exclude the connection from the client eviction with the `CLIENT NO-EVICT` command (default `false`).
+* [`no_touch`](Config::no_touch) - Do not alter the LRU/LFU of the accessed keys, with the `CLIENT NO-TOUCH` command (default `false`).
+* [`max_in_flight_commands`](Config::max_in_flight_commands) - Maximum number of commands sent and not answered yet.
+  If set to 0, no limit is applied (default `0`).
+* [`max_pending_bytes`](Config::max_pending_bytes) - Maximum number of bytes of the commands sent and not answered yet.
+  If set to 0, no limit is applied (default `0`).
+* [`backpressure_fail_fast`](Config::backpressure_fail_fast) - Fail instead of waiting when one of the limits above is reached (default `false`).
+* [`max_bulk_string_size`](Config::max_bulk_string_size) - Maximum size in bytes of a bulk string received from the server.
+  If set to 0, no limit is applied (default `0`).
+* [`max_reply_depth`](Config::max_reply_depth) - Maximum nesting depth of the arrays, sets and maps of a reply.
+  If set to 0, no limit is applied (default `0`).
+* [`max_reply_size`](Config::max_reply_size) - Maximum total size in bytes of a reply.
+  If set to 0, no limit is applied (default `0`).
+* [`proxy`](Config::proxy) - An optional SOCKS5 or HTTP proxy URL, see [`ProxyConfig`] (default `None`).
 * [`wait_between_failures`](SentinelConfig::wait_between_failures) - (Sentinel only) Waiting time after
   failing before connecting to the next Sentinel instance (default `250` ms).
 * [`sentinel_username`](SentinelConfig::username) - (Sentinel only) Sentinel username
@@ -456,13 +493,26 @@ async fn main() -> Result<()> {
     Ok(())
 }
 ```
+
+### Reconnection
+
+When the connection is lost, the client reconnects and, if [`auto_resubscribe`](Config::auto_resubscribe) is `true`,
+reissues all the active subscriptions. Messages published during the outage are lost:
+to be notified of these gaps, convert the split stream with [`into_events`](PubSubSplitStream::into_events).
+The resulting [`PubSubEventStream`] yields a [`PubSubEvent::Reconnected`] event after each resubscription.
 */
 
+mod backpressure;
 #[allow(clippy::module_inception)]
 mod client;
 mod client_state;
 mod client_tracking_invalidation_stream;
+mod command_metadata;
 mod config;
+mod connection_events;
+mod interceptor;
+mod key_prefix;
+mod latency_recorder;
 mod message;
 mod monitor_stream;
 mod pipeline;
@@ -471,12 +521,25 @@ mod pipeline;
 mod pooled_client_manager;
 mod prepared_command;
 mod pub_sub_stream;
+mod push_messages;
+mod read_coalescer;
+mod redact_keys;
+mod replicated_client;
+mod reply_stream;
+mod retry_policy;
+mod server_features;
 mod transaction;
 
+pub(crate) use backpressure::*;
 pub use client::*;
 pub use client_state::*;
 pub(crate) use client_tracking_invalidation_stream::*;
+pub(crate) use command_metadata::*;
 pub use config::*;
+pub use connection_events::*;
+pub use interceptor::*;
+pub use key_prefix::*;
+pub use latency_recorder::*;
 pub(crate) use message::*;
 pub use monitor_stream::*;
 pub use pipeline::*;
@@ -485,4 +548,11 @@ pub use pipeline::*;
 pub use pooled_client_manager::*;
 pub use prepared_command::*;
 pub use pub_sub_stream::*;
+pub use push_messages::*;
+pub(crate) use read_coalescer::*;
+pub use redact_keys::*;
+pub use replicated_client::*;
+pub use reply_stream::*;
+pub use retry_policy::*;
+pub use server_features::*;
 pub use transaction::*;
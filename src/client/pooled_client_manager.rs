@@ -3,9 +3,12 @@ use crate::{
     commands::ConnectionCommands,
     Error, Future, Result,
 };
-use bb8::ManageConnection;
+use bb8::{ManageConnection, Pool, PooledConnection, RunError};
 
 /// An object which manages a pool of clients, based on [bb8](https://docs.rs/bb8/latest/bb8/)
+///
+/// When a client is checked out of the pool, the database of the configuration is selected again
+/// if a previous user of the client selected another one.
 pub struct PooledClientManager {
     config: Config,
 }
@@ -38,7 +41,12 @@ impl ManageConnection for PooledClientManager {
         Self: 'a,
     {
         Box::pin(async move {
-            client.ping(Default::default()).await?;
+            // a previous user of the client may have selected another database
+            if client.db_index() != self.config.database {
+                client.select(self.config.database).await?;
+            } else {
+                client.ping(Default::default()).await?;
+            }
             Ok(())
         })
     }
@@ -47,3 +55,38 @@ impl ManageConnection for PooledClientManager {
         false
     }
 }
+
+/// Extension of a [`Pool`] of clients to check out clients bound to a logical database
+pub trait PoolExt {
+    /// Check out a client of the pool and [`select`](ConnectionCommands::select) the given database.
+    ///
+    /// The selected database is tracked by the client (see [`Client::db_index`]),
+    /// so that the database of the configuration is selected again the next time
+    /// the client is checked out with [`Pool::get`], as long as
+    /// [`test_on_check_out`](bb8::Builder::test_on_check_out) is enabled (the default).
+    /// `SELECT` is only sent when the client is not already on the requested database.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`] that occurs while checking out the client
+    /// or selecting the database, or [`Error::Timeout`] if no client is available in time
+    fn get_db(&self, database: usize) -> Future<'_, PooledConnection<'_, PooledClientManager>>;
+}
+
+impl PoolExt for Pool<PooledClientManager> {
+    fn get_db(&self, database: usize) -> Future<'_, PooledConnection<'_, PooledClientManager>> {
+        Box::pin(async move {
+            let client = self.get().await.map_err(|e| match e {
+                RunError::User(e) => e,
+                RunError::TimedOut => {
+                    Error::Timeout("Timed out while waiting for a pooled client".to_owned())
+                }
+            })?;
+
+            if client.db_index() != database {
+                client.select(database).await?;
+            }
+
+            Ok(client)
+        })
+    }
+}
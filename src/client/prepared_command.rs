@@ -57,6 +57,14 @@ where
         self
     }
 
+    /// Schedule the command ahead of the commands already queued on the connection.
+    ///
+    /// See [`Command::high_priority`]
+    pub fn high_priority(mut self) -> Self {
+        self.command.high_priority = true;
+        self
+    }
+
     /// Get a reference to the command to send
     pub fn command(&self) -> &Command {
         &self.command
@@ -1,5 +1,5 @@
 use crate::{
-    client::{Client, ClientPreparedCommand}, commands::InternalPubSubCommands, network::PubSubSender, resp::{ByteBufSeed, CommandArgs, SingleArg, SingleArgCollection}, Error, PubSubReceiver, Result
+    client::{Client, ClientPreparedCommand}, commands::InternalPubSubCommands, network::{PubSubItem, PubSubSender}, resp::{ByteBufSeed, CommandArgs, SingleArg, SingleArgCollection}, Error, PubSubReceiver, Result
 };
 use futures_util::{Stream, StreamExt};
 use serde::{
@@ -80,6 +80,18 @@ impl<'de> Deserialize<'de> for PubSubMessage {
     }
 }
 
+/// Event that can be streamed from [`PubSubEventStream`](PubSubEventStream)
+#[derive(Debug)]
+pub enum PubSubEvent {
+    /// A message published on a channel or pattern subscribed to
+    Message(PubSubMessage),
+    /// The connection has been lost and the subscriptions have been reissued
+    /// after the reconnection.
+    ///
+    /// Messages published during the outage have not been received.
+    Reconnected,
+}
+
 /// A pub sub `Sink` part of the [`split`](PubSubStream::split) pair.
 /// It allows to subscribe/unsubscribe to/from channels or patterns
 pub struct PubSubSplitSink {
@@ -189,7 +201,7 @@ impl PubSubSplitSink {
         Ok(())
     }
 
-    /// Unsubscribe from the given patterns
+    /// Unsubscribe from the given shard channels
     pub async fn sunsubscribe<C, CC>(&mut self, shardchannels: CC) -> Result<()>
     where
         C: SingleArg + Send,
@@ -198,7 +210,7 @@ impl PubSubSplitSink {
         let shardchannels = CommandArgs::default().arg(shardchannels).build();
         self.shardchannels
             .retain(|shardchannel| shardchannels.iter().all(|sc: &Vec<u8>| sc != shardchannel));
-        self.client.punsubscribe(shardchannels).await?;
+        self.client.sunsubscribe(shardchannels).await?;
 
         Ok(())
     }
@@ -268,12 +280,87 @@ pub struct PubSubSplitStream {
     receiver: PubSubReceiver,
 }
 
+impl PubSubSplitStream {
+    /// Converts this stream into a stream of [`events`](PubSubEvent),
+    /// which also notifies of the reconnections during which messages may have been missed.
+    pub fn into_events(self) -> PubSubEventStream {
+        PubSubEventStream {
+            receiver: self.receiver,
+        }
+    }
+}
+
 impl Stream for PubSubSplitStream {
     type Item = Result<PubSubMessage>;
 
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let receiver = &mut self.get_mut().receiver;
+        loop {
+            match receiver.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(PubSubItem::Message(message)))) => {
+                    return Poll::Ready(Some(message.to()))
+                }
+                // reconnections are only reported by PubSubEventStream
+                Poll::Ready(Some(Ok(PubSubItem::Reconnected))) => continue,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// A pub sub `Stream` of [`events`](PubSubEvent), obtained with [`into_events`](PubSubSplitStream::into_events).
+///
+/// Along with the messages, it yields [`PubSubEvent::Reconnected`] each time
+/// the subscriptions have been reissued after a reconnection,
+/// so that consumers know that messages may have been missed during the outage.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::{Client, PubSubEvent},
+///     commands::PubSubCommands,
+///     Result,
+/// };
+/// use futures_util::StreamExt;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let pub_sub_client = Client::connect("127.0.0.1:6379").await?;
+///     let regular_client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let (sink, stream) = pub_sub_client.subscribe("mychannel").await?.split();
+///     let mut events = stream.into_events();
+///
+///     regular_client.publish("mychannel", "mymessage").await?;
+///
+///     match events.next().await.unwrap()? {
+///         PubSubEvent::Message(message) => assert_eq!(b"mymessage".to_vec(), message.payload),
+///         PubSubEvent::Reconnected => println!("messages may have been missed"),
+///     }
+///
+///     sink.close().await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct PubSubEventStream {
+    receiver: PubSubReceiver,
+}
+
+impl Stream for PubSubEventStream {
+    type Item = Result<PubSubEvent>;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
         match self.get_mut().receiver.poll_next_unpin(cx) {
-            Poll::Ready(Some(Ok(message))) => Poll::Ready(Some(message.to())),
+            Poll::Ready(Some(Ok(PubSubItem::Message(message)))) => {
+                Poll::Ready(Some(message.to().map(PubSubEvent::Message)))
+            }
+            Poll::Ready(Some(Ok(PubSubItem::Reconnected))) => {
+                Poll::Ready(Some(Ok(PubSubEvent::Reconnected)))
+            }
             Poll::Ready(None) => Poll::Ready(None),
             Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
             Poll::Pending => Poll::Pending,
@@ -441,7 +528,7 @@ impl PubSubStream {
         self.split_sink.punsubscribe(patterns).await
     }
 
-    /// Unsubscribe from the given patterns
+    /// Unsubscribe from the given shard channels
     pub async fn sunsubscribe<C, CC>(&mut self, shardchannels: CC) -> Result<()>
     where
         C: SingleArg + Send,
@@ -0,0 +1,142 @@
+#[cfg(doc)]
+use crate::client::Client;
+use crate::{
+    resp::{RespBuf, Value},
+    Error, Result,
+};
+use tokio::sync::broadcast;
+
+pub(crate) type PushMessageSender = broadcast::Sender<PushMessage>;
+pub(crate) type PushMessageReceiver = broadcast::Receiver<PushMessage>;
+
+/// Kind of a [`PushMessage`], given by its first element
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PushKind {
+    /// Invalidation of keys tracked for
+    /// [`server assisted client side caching`](https://redis.io/docs/manual/client-side-caching/)
+    Invalidate,
+    /// Message published on a subscribed channel
+    Message,
+    /// Message published on a channel matching a subscribed pattern
+    PMessage,
+    /// Message published on a subscribed shard channel
+    SMessage,
+    /// Confirmation of a channel subscription
+    Subscribe,
+    /// Confirmation of a pattern subscription
+    PSubscribe,
+    /// Confirmation of a shard channel subscription
+    SSubscribe,
+    /// Confirmation of a channel unsubscription
+    Unsubscribe,
+    /// Confirmation of a pattern unsubscription
+    PUnsubscribe,
+    /// Confirmation of a shard channel unsubscription
+    SUnsubscribe,
+    /// Any other kind of push message, e.g. sent by a module
+    Other(String),
+}
+
+impl From<&str> for PushKind {
+    fn from(kind: &str) -> Self {
+        match kind {
+            "invalidate" => PushKind::Invalidate,
+            "message" => PushKind::Message,
+            "pmessage" => PushKind::PMessage,
+            "smessage" => PushKind::SMessage,
+            "subscribe" => PushKind::Subscribe,
+            "psubscribe" => PushKind::PSubscribe,
+            "ssubscribe" => PushKind::SSubscribe,
+            "unsubscribe" => PushKind::Unsubscribe,
+            "punsubscribe" => PushKind::PUnsubscribe,
+            "sunsubscribe" => PushKind::SUnsubscribe,
+            kind => PushKind::Other(kind.to_owned()),
+        }
+    }
+}
+
+/// [RESP3 push](https://github.com/antirez/RESP3/blob/master/spec.md#push-type) message
+/// sent by the server out of band, i.e. not as the reply of a command
+///
+/// See [`Client::on_push_message`]
+#[derive(Debug, Clone)]
+pub struct PushMessage {
+    /// Kind of the message
+    pub kind: PushKind,
+    /// Elements of the message following its kind,
+    /// e.g. the invalidated keys for an [`Invalidate`](PushKind::Invalidate) message
+    pub data: Vec<Value>,
+}
+
+impl PushMessage {
+    /// Parses a RESP3 push frame
+    ///
+    /// # Errors
+    /// A [`Client`](crate::Error::Client) error if the frame is not a push frame
+    /// starting with its kind
+    pub fn from_resp(resp_buf: &RespBuf) -> Result<Self> {
+        let Value::Push(mut values) = resp_buf.to::<Value>()? else {
+            return Err(Error::Client(format!("Not a push message: {resp_buf}")));
+        };
+
+        if values.is_empty() {
+            return Err(Error::Client("Empty push message".to_owned()));
+        }
+
+        let kind = match values.remove(0) {
+            Value::BulkString(kind) => PushKind::from(String::from_utf8_lossy(&kind).as_ref()),
+            Value::SimpleString(kind) => PushKind::from(kind.as_str()),
+            kind => {
+                return Err(Error::Client(format!(
+                    "Invalid push message kind: {kind:?}"
+                )))
+            }
+        };
+
+        Ok(Self { kind, data: values })
+    }
+}
+
+/// Handler of the [push messages](PushMessage) received by a [`Client`]
+///
+/// Registered with [`Client::subscribe_push_messages`].
+/// It is implemented for closures taking a `&PushMessage`.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::{Client, PushKind, PushMessage},
+///     commands::{ClientTrackingOptions, ClientTrackingStatus, ConnectionCommands},
+///     Result,
+/// };
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     client.subscribe_push_messages(|message: &PushMessage| {
+///         if message.kind == PushKind::Invalidate {
+///             println!("invalidated keys: {:?}", message.data);
+///         }
+///     });
+///
+///     client
+///         .client_tracking(ClientTrackingStatus::On, ClientTrackingOptions::default())
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub trait PushHandler: Send + Sync {
+    /// Invoked for each push message
+    fn on_push(&self, message: &PushMessage);
+}
+
+impl<F> PushHandler for F
+where
+    F: Fn(&PushMessage) + Send + Sync,
+{
+    fn on_push(&self, message: &PushMessage) {
+        self(message)
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    resp::{Command, RespBuf},
+    Result,
+};
+use futures_channel::oneshot;
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+type Waiters = Vec<oneshot::Sender<Result<RespBuf>>>;
+/// Scope of the command and key of the `GET`
+type CoalescingKey = (usize, Vec<u8>);
+
+/// Shares the reply of a pending [`GET`](https://redis.io/commands/get/)
+/// between all the tasks requesting the same key concurrently
+///
+/// See [`Client::with_read_coalescing`](crate::client::Client::with_read_coalescing)
+#[derive(Default)]
+pub(crate) struct ReadCoalescer {
+    pending: Mutex<HashMap<CoalescingKey, Waiters>>,
+}
+
+/// Removes the pending entry of the key if the leader is dropped before receiving its reply,
+/// so that the waiters fall back to sending their own command
+struct PendingGuard<'a> {
+    coalescer: &'a ReadCoalescer,
+    key: Option<CoalescingKey>,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.coalescer.pending.lock().unwrap().remove(&key);
+        }
+    }
+}
+
+impl ReadCoalescer {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Key of the command if it can be coalesced
+    fn coalescing_key(command: &Command) -> Option<&[u8]> {
+        if command.name != "GET" || command.args.len() != 1 {
+            return None;
+        }
+
+        command.args.into_iter().next()
+    }
+
+    /// Send the command with `send`, unless the same `GET` is already pending in the same `scope`,
+    /// in which case its reply is awaited instead
+    ///
+    /// Commands are only coalesced within a scope, e.g. the interceptor chain of the clients,
+    /// since the same key can target different Redis keys once intercepted.
+    pub async fn send<F, Fut>(&self, scope: usize, command: Command, send: F) -> Result<RespBuf>
+    where
+        F: FnOnce(Command) -> Fut,
+        Fut: Future<Output = Result<RespBuf>>,
+    {
+        let Some(key) = Self::coalescing_key(&command).map(|key| (scope, key.to_vec())) else {
+            return send(command).await;
+        };
+
+        let receiver = {
+            let mut pending = self.pending.lock().unwrap();
+            match pending.get_mut(&key) {
+                Some(waiters) => {
+                    let (sender, receiver) = oneshot::channel();
+                    waiters.push(sender);
+                    Some(receiver)
+                }
+                None => {
+                    pending.insert(key.clone(), Vec::new());
+                    None
+                }
+            }
+        };
+
+        if let Some(receiver) = receiver {
+            return match receiver.await {
+                Ok(result) => result,
+                // the leader has been dropped before receiving its reply
+                Err(_) => send(command).await,
+            };
+        }
+
+        let mut guard = PendingGuard {
+            coalescer: self,
+            key: Some(key),
+        };
+
+        let result = send(command).await;
+
+        if let Some(key) = guard.key.take() {
+            let waiters = self.pending.lock().unwrap().remove(&key);
+            for waiter in waiters.into_iter().flatten() {
+                let _ = waiter.send(result.clone());
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    client::{CommandMetadata, Interceptor},
+    resp::Command,
+};
+
+/// [`Interceptor`] which redacts from the logs the values of the commands
+/// touching keys matching one of the given glob-style patterns
+///
+/// The arguments of these commands, except for the keys themselves, are flagged as sensitive
+/// and replaced by `"***"` in the [`Debug`](std::fmt::Debug) and [`Display`](std::fmt::Display)
+/// outputs of the [`Command`], see [`Command::is_arg_redacted`].
+/// Their replies are not logged either, see [`Command::is_reply_redacted`].
+/// The commands are sent unchanged to the server.
+///
+/// Patterns support `*` (any sequence of characters), `?` (any single character)
+/// and `\` to escape these special characters.
+/// Key positions are known for the commands wrapped by **rustis**.
+///
+/// This interceptor is registered automatically when
+/// [`Config::redacted_key_patterns`](crate::client::Config::redacted_key_patterns) is set.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::{Client, RedactKeys},
+///     commands::StringCommands,
+///     Result,
+/// };
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379")
+///         .await?
+///         .with_interceptor(RedactKeys::new(["token:*"]));
+///
+///     // logged as `"SET" "token:1" "***"`
+///     client.set("token:1", "secret").await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct RedactKeys {
+    patterns: Vec<Vec<u8>>,
+}
+
+impl RedactKeys {
+    /// Creates a new interceptor redacting the values of the keys matching `patterns`
+    pub fn new<P: Into<Vec<u8>>>(patterns: impl IntoIterator<Item = P>) -> Self {
+        Self {
+            patterns: patterns.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    fn is_redacted_key(&self, key: &[u8]) -> bool {
+        self.patterns.iter().any(|pattern| glob_match(pattern, key))
+    }
+}
+
+impl Interceptor for RedactKeys {
+    fn before_send(&self, command: &mut Command) {
+        let Some(metadata) = CommandMetadata::get(command.name) else {
+            return;
+        };
+
+        let mask = metadata.key_mask(&command.args);
+        let has_redacted_key = command
+            .args
+            .iter()
+            .zip(&mask)
+            .any(|(arg, is_key)| *is_key && self.is_redacted_key(arg));
+
+        if has_redacted_key {
+            command.redact_reply();
+            for (index, is_key) in mask.into_iter().enumerate() {
+                if !is_key {
+                    command.redact_arg(index);
+                }
+            }
+        }
+    }
+
+    fn intercepts_reply(&self, _command: &Command) -> bool {
+        false
+    }
+}
+
+/// Glob-style matching supporting `*`, `?` and `\` escapes
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    // position of the last `*` in the pattern and of the text it matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                backtrack = Some((p, t));
+                p += 1;
+                continue;
+            }
+            Some(b'?') => {
+                p += 1;
+                t += 1;
+                continue;
+            }
+            Some(b'\\') if pattern.get(p + 1) == Some(&text[t]) => {
+                p += 2;
+                t += 1;
+                continue;
+            }
+            Some(c) if *c != b'\\' && *c == text[t] => {
+                p += 1;
+                t += 1;
+                continue;
+            }
+            _ => (),
+        }
+
+        match backtrack {
+            Some((star, matched)) => {
+                p = star + 1;
+                t = matched + 1;
+                backtrack = Some((star, matched + 1));
+            }
+            None => return false,
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
@@ -0,0 +1,360 @@
+#[cfg(feature = "redis-graph")]
+use crate::commands::GraphCommands;
+#[cfg(feature = "redis-json")]
+use crate::commands::JsonCommands;
+#[cfg(feature = "redis-search")]
+use crate::commands::SearchCommands;
+#[cfg(feature = "redis-time-series")]
+use crate::commands::TimeSeriesCommands;
+#[cfg(feature = "redis-bloom")]
+use crate::commands::{
+    BloomCommands, CountMinSketchCommands, CuckooCommands, TDigestCommands, TopKCommands,
+};
+use crate::{
+    client::{is_read_only_command, Client, IntoConfig, PreparedCommand},
+    commands::{
+        BitmapCommands, ClusterCommands, ConnectionCommands, GenericCommands, GeoCommands,
+        HashCommands, HyperLogLogCommands, ListCommands, ScriptingCommands, ServerCommands,
+        SetCommands, SortedSetCommands, StreamCommands, StringCommands,
+    },
+    resp::{Command, RespBuf},
+    Error, Future, RedisErrorKind, Result,
+};
+use futures_util::future::join_all;
+use log::warn;
+use serde::de::DeserializeOwned;
+use std::{
+    future::IntoFuture,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// Strategy to select the replica receiving a read-only command
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReadFrom {
+    /// Read-only commands are sent to each replica in turn
+    #[default]
+    RoundRobin,
+    /// Read-only commands are sent to the replica with the lowest measured latency
+    LowestLatency,
+}
+
+/// Time during which a failed replica is not selected anymore
+const REPLICA_RETRY_AFTER: Duration = Duration::from_secs(5);
+
+struct Replica {
+    client: Client,
+    /// smoothed latency in microseconds, `u64::MAX` when the replica has failed
+    latency: AtomicU64,
+    /// instant of the last failure, reset once the replica answers again
+    failed_at: Mutex<Option<Instant>>,
+}
+
+impl Replica {
+    fn new(client: Client) -> Self {
+        Self {
+            client,
+            latency: AtomicU64::new(u64::MAX),
+            failed_at: Mutex::new(None),
+        }
+    }
+
+    fn is_available(&self, now: Instant) -> bool {
+        match *self.failed_at.lock().unwrap() {
+            Some(failed_at) => now.duration_since(failed_at) >= REPLICA_RETRY_AFTER,
+            None => true,
+        }
+    }
+
+    fn record_latency(&self, latency: Duration) {
+        *self.failed_at.lock().unwrap() = None;
+        let sample = latency.as_micros() as u64;
+        let previous = self.latency.load(Ordering::Relaxed);
+        let latency = if previous == u64::MAX {
+            sample
+        } else {
+            previous.saturating_mul(3).saturating_add(sample) / 4
+        };
+        self.latency.store(latency, Ordering::Relaxed);
+    }
+
+    fn record_failure(&self) {
+        self.latency.store(u64::MAX, Ordering::Relaxed);
+        *self.failed_at.lock().unwrap() = Some(Instant::now());
+    }
+}
+
+/// Client routing read-only commands to replicas and all the other commands to the master
+///
+/// Read-only commands (`GET`, `HGETALL`, `ZRANGE`, etc.) are sent to a replica selected
+/// with the [`ReadFrom`] strategy. If the replica fails to answer, because it is not reachable
+/// or because it is still loading its dataset or disconnected from its master,
+/// the command is sent to the master instead,
+/// and the replica is not selected anymore during 5 seconds.
+///
+/// Replicas are connected lazily: an unreachable replica does not prevent the client to be created.
+///
+/// For a Redis Cluster replica, enable [`Config::readonly`](crate::client::Config::readonly)
+/// in its configuration (e.g. `redis://replica:6380?readonly=true`).
+///
+/// Transactions, pub/sub and blocking commands must be sent through the [`master`](ReplicatedClient::master) client.
+///
+/// `SELECT` is refused, since it would only change the database of the master:
+/// the database must be set in the configurations of the master and the replicas.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::{ReadFrom, ReplicatedClient},
+///     commands::StringCommands,
+///     Result,
+/// };
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = ReplicatedClient::connect(
+///         "127.0.0.1:6379",
+///         ["127.0.0.1:6379"],
+///         ReadFrom::RoundRobin,
+///     )
+///     .await?;
+///
+///     // sent to the master
+///     client.set("key", "value").await?;
+///     // sent to a replica
+///     let value: String = client.get("key").await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ReplicatedClient {
+    master: Client,
+    replicas: Arc<Vec<Replica>>,
+    read_from: ReadFrom,
+    next_replica: Arc<AtomicUsize>,
+}
+
+impl ReplicatedClient {
+    /// Connects asynchronously to the master and the replicas.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the connection to the master
+    /// or while parsing the configurations of the replicas
+    pub async fn connect<C>(
+        master: impl IntoConfig,
+        replicas: impl IntoIterator<Item = C>,
+        read_from: ReadFrom,
+    ) -> Result<Self>
+    where
+        C: IntoConfig,
+    {
+        let master = Client::connect(master).await?;
+        let replicas = replicas
+            .into_iter()
+            .map(|config| Ok(Replica::new(Client::connect_lazy(config)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let client = Self {
+            master,
+            replicas: Arc::new(replicas),
+            read_from,
+            next_replica: Arc::new(AtomicUsize::new(0)),
+        };
+
+        if read_from == ReadFrom::LowestLatency {
+            client.refresh_latencies().await;
+        }
+
+        Ok(client)
+    }
+
+    /// Client connected to the master
+    pub fn master(&self) -> &Client {
+        &self.master
+    }
+
+    /// Clients connected to the replicas
+    pub fn replicas(&self) -> impl Iterator<Item = &Client> {
+        self.replicas.iter().map(|replica| &replica.client)
+    }
+
+    /// Measures the latency of each replica with a [`health_check`](Client::health_check)
+    ///
+    /// Failed replicas are not selected anymore during 5 seconds,
+    /// unless their latency is successfully measured again in the meantime.
+    pub async fn refresh_latencies(&self) {
+        let health_checks = self
+            .replicas
+            .iter()
+            .map(|replica| replica.client.health_check());
+
+        for (replica, result) in self.replicas.iter().zip(join_all(health_checks).await) {
+            match result {
+                Ok(latency) => {
+                    *replica.failed_at.lock().unwrap() = None;
+                    replica
+                        .latency
+                        .store(latency.as_micros() as u64, Ordering::Relaxed);
+                }
+                Err(e) => {
+                    warn!("Health check failed on replica: {e}");
+                    replica.record_failure();
+                }
+            }
+        }
+    }
+
+    /// Send an arbitrary command to a replica if the command is read-only, to the master otherwise.
+    ///
+    /// # Arguments
+    /// * `command` - generic [`Command`](crate::resp::Command) meant to be sent to the Redis server.
+    /// * `retry_on_error` - retry to send the command on network error.
+    ///
+    /// # Errors
+    /// * [`Error::Client`](crate::Error::Client) for a `SELECT` command
+    /// * Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    pub async fn send(&self, command: Command, retry_on_error: Option<bool>) -> Result<RespBuf> {
+        if command.name == "SELECT" {
+            return Err(Error::Client(
+                "SELECT is not supported by ReplicatedClient, \
+                set the database in the configurations instead"
+                    .to_owned(),
+            ));
+        }
+
+        if is_read_only_command(&command) {
+            if let Some(replica) = self.select_replica() {
+                let start = Instant::now();
+                match replica.client.send(command.clone(), retry_on_error).await {
+                    Ok(resp_buf) if !Self::is_replica_failure(&resp_buf) => {
+                        replica.record_latency(start.elapsed());
+                        return Ok(resp_buf);
+                    }
+                    Ok(resp_buf) => {
+                        warn!(
+                            "Replica cannot serve {}: {:?}, falling back to master",
+                            command.name,
+                            resp_buf.to::<()>()
+                        );
+                        replica.record_failure();
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Replica cannot serve {}: {e}, falling back to master",
+                            command.name
+                        );
+                        replica.record_failure();
+                    }
+                }
+            }
+        }
+
+        self.master.send(command, retry_on_error).await
+    }
+
+    /// `None` if there is no replica or if all the replicas have recently failed
+    fn select_replica(&self) -> Option<&Replica> {
+        if self.replicas.is_empty() {
+            return None;
+        }
+
+        let now = Instant::now();
+        match self.read_from {
+            ReadFrom::RoundRobin => {
+                let index = self.next_replica.fetch_add(1, Ordering::Relaxed);
+                (0..self.replicas.len())
+                    .map(|offset| &self.replicas[(index + offset) % self.replicas.len()])
+                    .find(|replica| replica.is_available(now))
+            }
+            ReadFrom::LowestLatency => self
+                .replicas
+                .iter()
+                .filter(|replica| replica.is_available(now))
+                .min_by_key(|replica| replica.latency.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// a replica still loading its dataset or without link to its master
+    fn is_replica_failure(resp_buf: &RespBuf) -> bool {
+        resp_buf.is_error()
+            && matches!(
+                resp_buf.to::<()>(),
+                Err(Error::Redis(e))
+                    if matches!(e.kind, RedisErrorKind::Loading | RedisErrorKind::MasterDown)
+            )
+    }
+}
+
+impl<'a, R> IntoFuture for PreparedCommand<'a, &'a ReplicatedClient, R>
+where
+    R: DeserializeOwned + Send + 'a,
+{
+    type Output = Result<R>;
+    type IntoFuture = Future<'a, R>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            if let Some(custom_converter) = self.custom_converter {
+                let command_for_result = self.command.clone();
+                let result = self
+                    .executor
+                    .send(self.command, self.retry_on_error)
+                    .await?;
+                custom_converter(result, command_for_result, &self.executor.master).await
+            } else {
+                let result = self
+                    .executor
+                    .send(self.command, self.retry_on_error)
+                    .await?;
+                result.to()
+            }
+        })
+    }
+}
+
+impl<'a> BitmapCommands<'a> for &'a ReplicatedClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a> BloomCommands<'a> for &'a ReplicatedClient {}
+impl<'a> ClusterCommands<'a> for &'a ReplicatedClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a> CountMinSketchCommands<'a> for &'a ReplicatedClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a> CuckooCommands<'a> for &'a ReplicatedClient {}
+impl<'a> ConnectionCommands<'a> for &'a ReplicatedClient {}
+impl<'a> GenericCommands<'a> for &'a ReplicatedClient {}
+impl<'a> GeoCommands<'a> for &'a ReplicatedClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-graph")))]
+#[cfg(feature = "redis-graph")]
+impl<'a> GraphCommands<'a> for &'a ReplicatedClient {}
+impl<'a> HashCommands<'a> for &'a ReplicatedClient {}
+impl<'a> HyperLogLogCommands<'a> for &'a ReplicatedClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-json")))]
+#[cfg(feature = "redis-json")]
+impl<'a> JsonCommands<'a> for &'a ReplicatedClient {}
+impl<'a> ListCommands<'a> for &'a ReplicatedClient {}
+impl<'a> ScriptingCommands<'a> for &'a ReplicatedClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[cfg(feature = "redis-search")]
+impl<'a> SearchCommands<'a> for &'a ReplicatedClient {}
+impl<'a> ServerCommands<'a> for &'a ReplicatedClient {}
+impl<'a> SetCommands<'a> for &'a ReplicatedClient {}
+impl<'a> SortedSetCommands<'a> for &'a ReplicatedClient {}
+impl<'a> StreamCommands<'a> for &'a ReplicatedClient {}
+impl<'a> StringCommands<'a> for &'a ReplicatedClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a> TDigestCommands<'a> for &'a ReplicatedClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-time-series")))]
+#[cfg(feature = "redis-time-series")]
+impl<'a> TimeSeriesCommands<'a> for &'a ReplicatedClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a> TopKCommands<'a> for &'a ReplicatedClient {}
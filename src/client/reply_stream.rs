@@ -0,0 +1,42 @@
+use crate::{network::StreamReceiver, Result};
+use futures_util::{Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Stream of the elements of an array reply, returned by [`Client::send_stream`](crate::client::Client::send_stream)
+///
+/// Each element is decoded as soon as it is received from the network,
+/// without waiting for the whole reply.
+///
+/// The stream ends after the last element of the reply.
+/// A nil reply or an empty array produce an empty stream.
+pub struct ReplyStream<E> {
+    receiver: StreamReceiver,
+    phantom: PhantomData<fn() -> E>,
+}
+
+impl<E> ReplyStream<E> {
+    pub(crate) fn new(receiver: StreamReceiver) -> Self {
+        Self {
+            receiver,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: DeserializeOwned> Stream for ReplyStream<E> {
+    type Item = Result<E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        match self.get_mut().receiver.poll_next_unpin(cx) {
+            Poll::Ready(Some(Ok(resp_buf))) => Poll::Ready(Some(resp_buf.to())),
+            Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    client::{is_blocking_command, is_read_only_command},
+    resp::Command,
+    Error, RedisErrorKind,
+};
+use std::time::Duration;
+
+const DEFAULT_MAX_ATTEMPTS: usize = 3;
+const DEFAULT_INITIAL_BACKOFF: u64 = 100;
+const DEFAULT_MAX_BACKOFF: u64 = 2_000;
+
+/// Retry policy applied to idempotent commands on transient errors
+///
+/// When a retry policy is set in [`Config::retry_policy`](crate::client::Config::retry_policy),
+/// idempotent commands (`GET`, `EXISTS`, `TTL`, etc.) are automatically sent again,
+/// with an exponential backoff, when:
+/// * the connection to the Redis server is lost,
+/// * the Redis server replies with a `LOADING`, `CLUSTERDOWN`, `TRYAGAIN` or `MASTERDOWN` error.
+///
+/// Other commands, including the blocking ones, are not retried and fail fast.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts to send an idempotent command (default `3`).
+    pub max_attempts: usize,
+    /// Waiting time before the first retry (default `100` ms).
+    ///
+    /// This time is doubled after each failed attempt.
+    pub initial_backoff: Duration,
+    /// Maximum waiting time between two attempts (default `2,000` ms).
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_backoff: Duration::from_millis(DEFAULT_INITIAL_BACKOFF),
+            max_backoff: Duration::from_millis(DEFAULT_MAX_BACKOFF),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Indicates if a command can be retried by this policy
+    pub fn is_retryable(&self, command: &Command) -> bool {
+        (is_read_only_command(command) && !is_blocking_command(command))
+            || matches!(command.name, "ECHO" | "PING")
+    }
+
+    /// Indicates if an error is transient and may disappear by sending the command again
+    pub fn is_transient(&self, error: &Error) -> bool {
+        match error {
+            Error::IO(_) | Error::EOF => true,
+            Error::Redis(e) => matches!(
+                e.kind,
+                RedisErrorKind::Loading
+                    | RedisErrorKind::ClusterDown
+                    | RedisErrorKind::TryAgain
+                    | RedisErrorKind::MasterDown
+            ),
+            _ => false,
+        }
+    }
+
+    /// Waiting time before the next attempt
+    ///
+    /// `attempt` starts at `1` after the first failure
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1) as u32);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
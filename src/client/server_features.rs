@@ -0,0 +1,92 @@
+use crate::{commands::ModuleInfo, network::Version, resp::Command, Error, Result};
+
+/// Version and modules of the Redis server to which a [`Client`](crate::client::Client) is connected.
+///
+/// Populated from the reply of the [`HELLO`](https://redis.io/commands/hello/) command
+/// sent at each connection and reconnection.
+/// For a cluster, the features are the ones of the first node.
+///
+/// See [`Client::server_features`](crate::client::Client::server_features)
+#[derive(Debug, Clone, Default)]
+pub struct ServerFeatures {
+    /// Server name, usually `redis`
+    pub server: String,
+    /// Version string, e.g. `7.2.4`
+    pub version: String,
+    /// Major version number, `0` if the version cannot be parsed
+    pub major: u8,
+    /// Minor version number
+    pub minor: u8,
+    /// Revision number
+    pub revision: u8,
+    /// Loaded modules
+    pub modules: Vec<ModuleInfo>,
+}
+
+impl ServerFeatures {
+    pub(crate) fn new(server: String, version: String, modules: Vec<ModuleInfo>) -> Self {
+        let (major, minor, revision) = match Version::try_from(version.as_str()) {
+            Ok(v) => (v.major, v.minor, v.revision),
+            Err(_) => (0, 0, 0),
+        };
+
+        Self {
+            server,
+            version,
+            major,
+            minor,
+            revision,
+            modules,
+        }
+    }
+
+    /// Returns `true` if the server version is greater than or equal to `major.minor`
+    #[must_use]
+    pub fn is_at_least(&self, major: u8, minor: u8) -> bool {
+        (self.major, self.minor) >= (major, minor)
+    }
+
+    /// Returns `true` if a module with this name (case insensitive) is loaded
+    #[must_use]
+    pub fn has_module(&self, name: &str) -> bool {
+        self.modules
+            .iter()
+            .any(|module| module.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns an [`Error::UnsupportedByServer`] error if the command, or one of its options,
+    /// requires a more recent server version
+    pub(crate) fn check_command(&self, command: &Command) -> Result<()> {
+        // unknown version (e.g. Redis compatible servers)
+        if self.major == 0 {
+            return Ok(());
+        }
+
+        let Some((major, minor, feature)) = required_version(command) else {
+            return Ok(());
+        };
+
+        if self.is_at_least(major, minor) {
+            Ok(())
+        } else {
+            Err(Error::UnsupportedByServer(format!(
+                "{feature} requires Redis {major}.{minor} or later, server version is {}",
+                self.version
+            )))
+        }
+    }
+}
+
+/// Minimum server version required by the version-sensitive commands
+///
+/// Commands older than `HELLO` (Redis 6.0), which is sent at each connection, are not listed
+fn required_version(command: &Command) -> Option<(u8, u8, &'static str)> {
+    match command.name {
+        "SINTERCARD" => Some((7, 0, "SINTERCARD")),
+        // EXPIRE key seconds [NX | XX | GT | LT]
+        "EXPIRE" | "PEXPIRE" | "EXPIREAT" | "PEXPIREAT" if command.args.len() > 2 => {
+            Some((7, 0, "Expiration options NX, XX, GT and LT"))
+        }
+        _ => None,
+    }
+}
@@ -45,6 +45,15 @@ impl Transaction {
         }
     }
 
+    /// Client on which the transaction will be executed
+    ///
+    /// Commands sent through the client are executed immediately, outside of the transaction,
+    /// e.g. to read the keys [watched](crate::commands::TransactionCommands::watch) by the transaction.
+    #[inline]
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
     /// Set a flag to override default `retry_on_error` behavior.
     ///
     /// See [Config::retry_on_error](crate::client::Config::retry_on_error)
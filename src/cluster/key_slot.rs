@@ -0,0 +1,107 @@
+/// Number of hash slots of a Redis Cluster
+pub const NUM_HASH_SLOTS: u16 = 16384;
+
+/// Returns the hash slot of a key, like [`CLUSTER KEYSLOT`](https://redis.io/commands/cluster-keyslot/).
+///
+/// The slot is the CRC16 of the key modulo 16384. When the key contains a hash tag,
+/// i.e. a non-empty substring between the first `{` and the next `}`, only the hash tag is hashed.
+///
+/// # Example
+/// ```
+/// use rustis::cluster::key_slot;
+///
+/// assert_eq!(11058, key_slot("somekey"));
+/// assert_eq!(key_slot("bar"), key_slot("{bar}:baz"));
+/// ```
+#[must_use]
+pub fn key_slot(key: impl AsRef<[u8]>) -> u16 {
+    let key = key.as_ref();
+    crc16::State::<crc16::XMODEM>::calculate(hash_tag_of(key)) % NUM_HASH_SLOTS
+}
+
+/// Returns `true` if all the keys belong to the same hash slot,
+/// and thus can be used in the same command, transaction or script on a cluster.
+///
+/// An empty set of keys is considered to be in the same slot.
+#[must_use]
+pub fn same_slot<K, KK>(keys: KK) -> bool
+where
+    K: AsRef<[u8]>,
+    KK: IntoIterator<Item = K>,
+{
+    let mut slots = keys.into_iter().map(key_slot);
+    match slots.next() {
+        Some(first) => slots.all(|slot| slot == first),
+        None => true,
+    }
+}
+
+/// Part of the key which is hashed: the hash tag if any, otherwise the whole key
+fn hash_tag_of(key: &[u8]) -> &[u8] {
+    if let Some(start) = key.iter().position(|b| *b == b'{') {
+        if let Some(len) = key[start + 1..].iter().position(|b| *b == b'}') {
+            if len > 0 {
+                return &key[start + 1..start + 1 + len];
+            }
+        }
+    }
+
+    key
+}
+
+/// Returns `true` if `tag` can be used between `{` and `}` as a hash tag:
+/// it must not be empty, and must not contain `}`.
+///
+/// Used by the [`hash_tag!`](crate::hash_tag) macro to check its tag at compile time.
+#[must_use]
+pub const fn is_valid_hash_tag(tag: &str) -> bool {
+    let bytes = tag.as_bytes();
+    if bytes.is_empty() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'}' {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Builds a key, or a key prefix, starting with a hash tag, from string literals.
+///
+/// `hash_tag!("user:42")` expands to `"{user:42}"`,
+/// and `hash_tag!("user:42", ":profile")` to `"{user:42}:profile"`.
+/// All the keys built with the same tag belong to the same hash slot of a cluster.
+///
+/// The tag is checked at compile time: it must not be empty and must not contain `}`,
+/// otherwise the whole key would be hashed.
+///
+/// # Example
+/// ```
+/// use rustis::{cluster::same_slot, hash_tag};
+///
+/// const CART: &str = hash_tag!("cart:17", ":items");
+/// const CART_TOTAL: &str = hash_tag!("cart:17", ":total");
+///
+/// assert_eq!("{cart:17}:items", CART);
+/// assert!(same_slot([CART, CART_TOTAL]));
+/// ```
+///
+/// An invalid tag does not compile:
+/// ```compile_fail
+/// let key = rustis::hash_tag!("cart}17");
+/// ```
+#[macro_export]
+macro_rules! hash_tag {
+    ($tag:literal $(, $suffix:literal)* $(,)?) => {{
+        const _: () = assert!(
+            $crate::cluster::is_valid_hash_tag(concat!($tag)),
+            "a hash tag must not be empty and must not contain `}}`"
+        );
+        concat!("{", $tag, "}" $(, $suffix)*)
+    }};
+}
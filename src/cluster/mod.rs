@@ -0,0 +1,30 @@
+/*!
+Helpers to design the keys of applications running on a [Redis Cluster](https://redis.io/docs/reference/cluster-spec/)
+
+A multi-key command, a transaction or a script can only be executed by a cluster
+if all its keys belong to the same hash slot.
+
+The [`key_slot`] function computes the hash slot of a key, like [`CLUSTER KEYSLOT`](https://redis.io/commands/cluster-keyslot/),
+and the [`same_slot`] function checks that a set of keys can be used together.
+
+When a key contains a hash tag, i.e. a non-empty substring between the first `{` and the next `}`,
+only the hash tag is hashed. The [`hash_tag!`](crate::hash_tag) macro builds such keys
+from string literals, and rejects empty or malformed tags at compile time.
+
+# Example
+```
+use rustis::{cluster::{key_slot, same_slot}, hash_tag};
+
+let profile = hash_tag!("user:42", ":profile");
+let orders = hash_tag!("user:42", ":orders");
+assert_eq!("{user:42}:profile", profile);
+
+assert_eq!(key_slot("user:42"), key_slot(profile));
+assert!(same_slot([profile, orders]));
+assert!(!same_slot(["user:42:profile", "user:42:orders"]));
+```
+*/
+
+mod key_slot;
+
+pub use key_slot::*;
@@ -4,6 +4,7 @@ use crate::{
         cmd, CommandArgs, MultipleArgsCollection, SingleArg, SingleArgCollection, ToArgs,
     },
 };
+use std::fmt;
 
 /// A group of Redis commands related to [`Bitmaps`](https://redis.io/docs/data-types/bitmaps/)
 /// & [`Bitfields`](https://redis.io/docs/data-types/bitfields/)
@@ -49,6 +50,55 @@ pub trait BitmapCommands<'a> {
         prepare_command(self, cmd("BITFIELD").arg(key).arg(sub_commands))
     }
 
+    /// Typed variant of the [`bitfield`](BitmapCommands::bitfield) command,
+    /// where field encodings and offsets are built with [`BitFieldEncoding`] and [`BitFieldOffset`].
+    ///
+    /// # Return
+    /// A collection with each entry being the corresponding result of the operation
+    /// given at the same position, `None` when an increment or a set operation
+    /// failed because of the [`Fail`](BitFieldOverflow::Fail) overflow behavior.
+    ///
+    /// # Example
+    /// ```
+    /// # use rustis::{
+    /// #    client::Client,
+    /// #    commands::{BitFieldEncoding, BitFieldOffset, BitFieldOperations, BitmapCommands},
+    /// #    Result,
+    /// # };
+    /// #
+    /// # #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// # #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// # async fn main() -> Result<()> {
+    /// #    let client = Client::connect("127.0.0.1:6379").await?;
+    /// // an array of unsigned bytes: SET u8 #3 200, GET u8 #3
+    /// let results = client
+    ///     .bitfield_typed(
+    ///         "packed",
+    ///         BitFieldOperations::default()
+    ///             .set(BitFieldEncoding::U8, BitFieldOffset::index(3), 200)
+    ///             .get(BitFieldEncoding::U8, BitFieldOffset::index(3)),
+    ///     )
+    ///     .await?;
+    /// assert_eq!(Some(200), results[1]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/bitfield/>](https://redis.io/commands/bitfield/)
+    #[must_use]
+    fn bitfield_typed<K>(
+        self,
+        key: K,
+        operations: BitFieldOperations,
+    ) -> PreparedCommand<'a, Self, Vec<Option<i64>>>
+    where
+        Self: Sized,
+        K: SingleArg,
+    {
+        prepare_command(self, cmd("BITFIELD").arg(key).arg(operations))
+    }
+
     /// Read-only variant of the BITFIELD command.
     /// It is like the original BITFIELD but only accepts GET subcommand
     /// and can safely be used in read-only replicas.
@@ -300,6 +350,171 @@ impl ToArgs for BitFieldOverflow {
     }
 }
 
+/// Integer encoding of a bit field: signed (`i`) or unsigned (`u`) and its width in bits
+///
+/// Signed encodings support up to 64 bits and unsigned encodings up to 63 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitFieldEncoding {
+    signed: bool,
+    bits: u8,
+}
+
+impl BitFieldEncoding {
+    pub const I8: Self = Self::signed(8);
+    pub const U8: Self = Self::unsigned(8);
+    pub const I16: Self = Self::signed(16);
+    pub const U16: Self = Self::unsigned(16);
+    pub const I32: Self = Self::signed(32);
+    pub const U32: Self = Self::unsigned(32);
+    pub const I64: Self = Self::signed(64);
+
+    /// Signed integer of `bits` bits (`i<bits>`)
+    #[must_use]
+    pub const fn signed(bits: u8) -> Self {
+        Self { signed: true, bits }
+    }
+
+    /// Unsigned integer of `bits` bits (`u<bits>`)
+    #[must_use]
+    pub const fn unsigned(bits: u8) -> Self {
+        Self {
+            signed: false,
+            bits,
+        }
+    }
+
+    /// Width of the encoding in bits
+    #[must_use]
+    pub const fn bits(&self) -> u8 {
+        self.bits
+    }
+}
+
+impl fmt::Display for BitFieldEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", if self.signed { 'i' } else { 'u' }, self.bits)
+    }
+}
+
+impl ToArgs for BitFieldEncoding {
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(self.to_string());
+    }
+}
+
+impl SingleArg for BitFieldEncoding {}
+
+/// Offset of a bit field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitFieldOffset {
+    /// Offset in bits from the start of the string
+    Bit(u64),
+    /// Index of the field in an array of fields of the same encoding (`#` prefix):
+    /// the offset is multiplied by the width of the encoding
+    Index(u64),
+}
+
+impl BitFieldOffset {
+    /// Offset in bits from the start of the string
+    #[must_use]
+    pub const fn bit(offset: u64) -> Self {
+        Self::Bit(offset)
+    }
+
+    /// Index of the field in an array of fields of the same encoding (`#index`)
+    #[must_use]
+    pub const fn index(index: u64) -> Self {
+        Self::Index(index)
+    }
+}
+
+impl fmt::Display for BitFieldOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitFieldOffset::Bit(offset) => write!(f, "{offset}"),
+            BitFieldOffset::Index(index) => write!(f, "#{index}"),
+        }
+    }
+}
+
+impl ToArgs for BitFieldOffset {
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(self.to_string());
+    }
+}
+
+impl SingleArg for BitFieldOffset {}
+
+/// Typed operations for the [`bitfield_typed`](BitmapCommands::bitfield_typed) command
+#[derive(Default)]
+pub struct BitFieldOperations {
+    command_args: CommandArgs,
+}
+
+impl BitFieldOperations {
+    /// Returns the specified bit field.
+    #[must_use]
+    pub fn get(mut self, encoding: BitFieldEncoding, offset: BitFieldOffset) -> Self {
+        Self {
+            command_args: self
+                .command_args
+                .arg("GET")
+                .arg(encoding)
+                .arg(offset)
+                .build(),
+        }
+    }
+
+    /// Set the specified bit field and returns its old value.
+    #[must_use]
+    pub fn set(mut self, encoding: BitFieldEncoding, offset: BitFieldOffset, value: i64) -> Self {
+        Self {
+            command_args: self
+                .command_args
+                .arg("SET")
+                .arg(encoding)
+                .arg(offset)
+                .arg(value)
+                .build(),
+        }
+    }
+
+    /// Increments or decrements (if a negative increment is given)
+    /// the specified bit field and returns the new value.
+    #[must_use]
+    pub fn incr_by(
+        mut self,
+        encoding: BitFieldEncoding,
+        offset: BitFieldOffset,
+        increment: i64,
+    ) -> Self {
+        Self {
+            command_args: self
+                .command_args
+                .arg("INCRBY")
+                .arg(encoding)
+                .arg(offset)
+                .arg(increment)
+                .build(),
+        }
+    }
+
+    /// Changes the overflow behavior of the following [`set`](BitFieldOperations::set)
+    /// and [`incr_by`](BitFieldOperations::incr_by) operations.
+    #[must_use]
+    pub fn overflow(mut self, overflow: BitFieldOverflow) -> Self {
+        Self {
+            command_args: self.command_args.arg("OVERFLOW").arg(overflow).build(),
+        }
+    }
+}
+
+impl ToArgs for BitFieldOperations {
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(&self.command_args);
+    }
+}
+
 /// Bit operation for the [`bitop`](BitmapCommands::bitop) command.
 pub enum BitOperation {
     And,
@@ -1,7 +1,10 @@
 use crate::{
     client::{prepare_command, MonitorStream, PreparedCommand},
     commands::{LMoveWhere, ZMPopResult, ZWhere},
-    resp::{cmd, deserialize_vec_of_triplets, PrimitiveResponse, SingleArg, SingleArgCollection},
+    resp::{
+        cmd, deserialize_vec_of_triplets, KeysWithCount, PrimitiveResponse, SingleArg,
+        SingleArgCollection,
+    },
     Result,
 };
 use serde::{
@@ -126,8 +129,7 @@ pub trait BlockingCommands<'a> {
             self,
             cmd("BLMPOP")
                 .arg(timeout)
-                .arg(keys.num_args())
-                .arg(keys)
+                .arg(KeysWithCount::new(keys))
                 .arg(where_)
                 .arg("COUNT")
                 .arg(count),
@@ -216,8 +218,7 @@ pub trait BlockingCommands<'a> {
             self,
             cmd("BZMPOP")
                 .arg(timeout)
-                .arg(keys.num_args())
-                .arg(keys)
+                .arg(KeysWithCount::new(keys))
                 .arg(where_)
                 .arg("COUNT")
                 .arg(count),
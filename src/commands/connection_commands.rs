@@ -8,7 +8,7 @@ use serde::{
     de::{self, DeserializeOwned},
     Deserialize, Deserializer,
 };
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 /// A group of Redis commands related to connection management
 ///
@@ -156,6 +156,23 @@ pub trait ConnectionCommands<'a> {
         )
     }
 
+    /// Controls whether commands sent by the client will alter the LRU/LFU of the keys they access.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/client-no-touch/>](https://redis.io/commands/client-no-touch/)
+    #[must_use]
+    fn client_no_touch(self, no_touch: bool) -> PreparedCommand<'a, Self, ()>
+    where
+        Self: Sized,
+    {
+        prepare_command(
+            self,
+            cmd("CLIENT")
+                .arg("NO-TOUCH")
+                .arg(if no_touch { "ON" } else { "OFF" }),
+        )
+    }
+
     /// Connections control command able to suspend all the Redis clients
     /// for the specified amount of time (in milliseconds).
     ///
@@ -194,6 +211,25 @@ pub trait ConnectionCommands<'a> {
         prepare_command(self, cmd("CLIENT").arg("SETNAME").arg(connection_name))
     }
 
+    /// Assigns various info attributes to the current connection,
+    /// which are displayed in the output of [`client_list`](ConnectionCommands::client_list)
+    /// and [`client_info`](ConnectionCommands::client_info).
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/client-setinfo/>](https://redis.io/commands/client-setinfo/)
+    #[must_use]
+    fn client_setinfo<V>(
+        self,
+        attribute: ClientInfoAttribute,
+        value: V,
+    ) -> PreparedCommand<'a, Self, ()>
+    where
+        Self: Sized,
+        V: SingleArg,
+    {
+        prepare_command(self, cmd("CLIENT").arg("SETINFO").arg(attribute).arg(value))
+    }
+
     /// This command enables the tracking feature of the Redis server,
     /// that is used for [`server assisted client side caching`](https://redis.io/topics/client-side-caching).
     ///
@@ -327,8 +363,12 @@ pub trait ConnectionCommands<'a> {
 
     /// Select the Redis logical database having the specified zero-based numeric index.
     ///
+    /// When sent with a [`Client`](crate::client::Client), the selected database is tracked
+    /// (see [`Client::db_index`](crate::client::Client::db_index))
+    /// and selected again after a reconnection.
+    ///
     /// # See Also
-    /// [<https://redis.io/commands/reset/>](https://redis.io/commands/reset/)
+    /// [<https://redis.io/commands/select/>](https://redis.io/commands/select/)
     #[must_use]
     fn select(self, index: usize) -> PreparedCommand<'a, Self, ()>
     where
@@ -440,6 +480,40 @@ pub struct ClientInfo {
 }
 
 impl ClientInfo {
+    /// Total duration of the connection
+    #[must_use]
+    pub fn connection_age(&self) -> Duration {
+        Duration::from_secs(self.age as u64)
+    }
+
+    /// Idle time of the connection
+    #[must_use]
+    pub fn idle_time(&self) -> Duration {
+        Duration::from_secs(self.idle as u64)
+    }
+
+    /// Parsed client flags
+    #[must_use]
+    pub fn client_flags(&self) -> Vec<ClientFlag> {
+        self.flags.chars().map(ClientFlag::from).collect()
+    }
+
+    /// Returns `true` if the client has the given flag
+    #[must_use]
+    pub fn has_flag(&self, flag: ClientFlag) -> bool {
+        self.flags.chars().any(|c| ClientFlag::from(c) == flag)
+    }
+
+    /// Last command played, or `None` if the client has not played any command yet
+    #[must_use]
+    pub fn last_command(&self) -> Option<&str> {
+        if self.cmd.is_empty() || self.cmd == "NULL" {
+            None
+        } else {
+            Some(&self.cmd)
+        }
+    }
+
     pub fn from_line(line: &str) -> Result<ClientInfo> {
         // Each line is composed of a succession of property=value fields separated by a space character.
         let mut values: HashMap<String, String> = line
@@ -554,11 +628,88 @@ impl<'de> Deserialize<'de> for ClientInfo {
     }
 }
 
-/// Client type options for the [`client_list`](ConnectionCommands::client_list) command.
+/// Flag of a client, as listed in the `flags` field of [`ClientInfo`]
+///
+/// # See Also
+/// [<https://redis.io/commands/client-list/>](https://redis.io/commands/client-list/)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientFlag {
+    /// connection to be closed ASAP (`A`)
+    CloseAsap,
+    /// the client is waiting in a blocking operation (`b`)
+    Blocked,
+    /// connection to be closed after writing entire reply (`c`)
+    CloseAfterReply,
+    /// a watched keys has been modified - EXEC will fail (`d`)
+    DirtyCas,
+    /// the client is excluded from the client eviction mechanism (`e`)
+    NoEvict,
+    /// the client is a master (`M`)
+    Master,
+    /// no specific flag set (`N`)
+    None,
+    /// the client is a replica in MONITOR mode (`O`)
+    Monitor,
+    /// the client is a Pub/Sub subscriber (`P`)
+    PubSub,
+    /// the client is in readonly mode against a cluster node (`r`)
+    ReadOnly,
+    /// the client is a replica node connection to this instance (`S`)
+    Replica,
+    /// the client is unblocked (`u`)
+    Unblocked,
+    /// the client is connected via a Unix domain socket (`U`)
+    UnixSocket,
+    /// the client is in a MULTI/EXEC context (`x`)
+    Multi,
+    /// the client enabled keys tracking in order to perform client side caching (`t`)
+    Tracking,
+    /// the client tracking target client is invalid (`R`)
+    TrackingBrokenRedirect,
+    /// the client enabled broadcast tracking mode (`B`)
+    BroadcastTracking,
+    /// the client does not alter the LRU/LFU of the keys it accesses (`T`)
+    NoTouch,
+    /// flag unknown to this version of the crate
+    Other(char),
+}
+
+impl From<char> for ClientFlag {
+    fn from(c: char) -> Self {
+        match c {
+            'A' => ClientFlag::CloseAsap,
+            'b' => ClientFlag::Blocked,
+            'c' => ClientFlag::CloseAfterReply,
+            'd' => ClientFlag::DirtyCas,
+            'e' => ClientFlag::NoEvict,
+            'M' => ClientFlag::Master,
+            'N' => ClientFlag::None,
+            'O' => ClientFlag::Monitor,
+            'P' => ClientFlag::PubSub,
+            'r' => ClientFlag::ReadOnly,
+            'S' => ClientFlag::Replica,
+            'u' => ClientFlag::Unblocked,
+            'U' => ClientFlag::UnixSocket,
+            'x' => ClientFlag::Multi,
+            't' => ClientFlag::Tracking,
+            'R' => ClientFlag::TrackingBrokenRedirect,
+            'B' => ClientFlag::BroadcastTracking,
+            'T' => ClientFlag::NoTouch,
+            c => ClientFlag::Other(c),
+        }
+    }
+}
+
+/// Client type options for the [`client_list`](ConnectionCommands::client_list)
+/// & [`client_kill`](ConnectionCommands::client_kill) commands.
 pub enum ClientType {
+    /// normal clients
     Normal,
+    /// connections of the replicas to their master, from the master side
     Master,
+    /// connections of the master to its replicas, from the replica side
     Replica,
+    /// Pub/Sub subscribers
     PubSub,
 }
 
@@ -593,6 +744,7 @@ impl ClientListOptions {
         }
     }
 
+    #[must_use]
     pub fn client_ids<II>(mut self, client_ids: II) -> Self
     where
         II: SingleArgCollection<i64>,
@@ -615,8 +767,11 @@ impl<'de> Deserialize<'de> for ClientListResult {
         D: Deserializer<'de>,
     {
         let lines = <&str>::deserialize(deserializer)?;
-        let client_infos: Result<Vec<ClientInfo>> =
-            lines.split('\n').map(ClientInfo::from_line).collect();
+        let client_infos: Result<Vec<ClientInfo>> = lines
+            .split('\n')
+            .filter(|line| !line.is_empty())
+            .map(ClientInfo::from_line)
+            .collect();
 
         Ok(Self {
             client_infos: client_infos.map_err(de::Error::custom)?,
@@ -624,7 +779,7 @@ impl<'de> Deserialize<'de> for ClientListResult {
     }
 }
 
-/// Options for the [`client-kill`](ConnectionCommands::client-kill) command.
+/// Options for the [`client_kill`](ConnectionCommands::client_kill) command.
 #[derive(Default)]
 pub struct ClientKillOptions {
     command_args: CommandArgs,
@@ -683,6 +838,16 @@ impl ClientKillOptions {
                 .build(),
         }
     }
+
+    /// Kill only the clients whose connection is older than `max_age` seconds.
+    ///
+    /// Added in Redis 7.4
+    #[must_use]
+    pub fn max_age(mut self, max_age: u64) -> Self {
+        Self {
+            command_args: self.command_args.arg("MAXAGE").arg(max_age).build(),
+        }
+    }
 }
 
 impl ToArgs for ClientKillOptions {
@@ -727,6 +892,23 @@ impl ToArgs for ClientReplyMode {
     }
 }
 
+/// Attributes for the [`client_setinfo`](ConnectionCommands::client_setinfo) command.
+pub enum ClientInfoAttribute {
+    /// Name of the client library
+    LibName,
+    /// Version of the client library
+    LibVer,
+}
+
+impl ToArgs for ClientInfoAttribute {
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(match self {
+            ClientInfoAttribute::LibName => "LIB-NAME",
+            ClientInfoAttribute::LibVer => "LIB-VER",
+        });
+    }
+}
+
 /// Status options for the [`client_tracking`](ConnectionCommands::client_tracking) command.
 pub enum ClientTrackingStatus {
     On,
@@ -1,6 +1,6 @@
 use crate::{
     client::{prepare_command, PreparedCommand},
-    resp::cmd,
+    resp::{cmd, CollectionResponse, SingleArg, SingleArgCollection},
 };
 use std::time::Duration;
 
@@ -73,4 +73,40 @@ pub trait DebugCommands<'a> {
     {
         prepare_command(self, cmd("DEBUG").arg("PANIC"))
     }
+
+    /// Get low level information about the internal representation of a key.
+    ///
+    /// # Return
+    /// A line of `field:value` pairs (encoding, refcount, serialized length, LRU, ...)
+    #[must_use]
+    fn debug_object<K>(self, key: K) -> PreparedCommand<'a, Self, String>
+    where
+        Self: Sized,
+        K: SingleArg,
+    {
+        prepare_command(self, cmd("DEBUG").arg("OBJECT").arg(key))
+    }
+
+    /// Output a hex signature representing the current dataset.
+    ///
+    /// Two datasets holding the same data have the same digest.
+    #[must_use]
+    fn debug_digest(self) -> PreparedCommand<'a, Self, String>
+    where
+        Self: Sized,
+    {
+        prepare_command(self, cmd("DEBUG").arg("DIGEST"))
+    }
+
+    /// Output a hex signature of the values of each of the given keys.
+    #[must_use]
+    fn debug_digest_value<K, KK, R>(self, keys: KK) -> PreparedCommand<'a, Self, R>
+    where
+        Self: Sized,
+        K: SingleArg,
+        KK: SingleArgCollection<K>,
+        R: CollectionResponse<String>,
+    {
+        prepare_command(self, cmd("DEBUG").arg("DIGEST-VALUE").arg(keys))
+    }
 }
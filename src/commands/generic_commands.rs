@@ -4,8 +4,13 @@ use crate::{
         cmd, deserialize_byte_buf, CollectionResponse, CommandArgs, PrimitiveResponse, SingleArg,
         SingleArgCollection, ToArgs,
     },
+    Error, Result,
 };
 use serde::{de::DeserializeOwned, Deserialize};
+use std::{
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 /// A group of generic Redis commands
 ///
@@ -62,12 +67,12 @@ pub trait GenericCommands<'a> {
     /// Serialize the value stored at key in a Redis-specific format and return it to the user.
     ///
     /// # Return
-    /// The serialized value.
+    /// The serialized value, or `None` if the key does not exist.
     ///
     /// # See Also
     /// [<https://redis.io/commands/dump/>](https://redis.io/commands/dump/)
     #[must_use]
-    fn dump<K>(self, key: K) -> PreparedCommand<'a, Self, DumpResult>
+    fn dump<K>(self, key: K) -> PreparedCommand<'a, Self, Option<DumpResult>>
     where
         Self: Sized,
         K: SingleArg,
@@ -143,6 +148,70 @@ pub trait GenericCommands<'a> {
         )
     }
 
+    /// Set a timeout on key from a [`Duration`](std::time::Duration)
+    ///
+    /// EXPIRE is sent when the duration is a whole number of seconds, PEXPIRE otherwise.
+    /// Sub-millisecond precision is rounded up to the next millisecond.
+    ///
+    /// # Return
+    /// * `true` - if the timeout was set.
+    /// * `false` - if the timeout was not set. e.g. key doesn't exist, or operation skipped due to the provided arguments.
+    ///
+    /// # See Also
+    /// * [<https://redis.io/commands/expire/>](https://redis.io/commands/expire/)
+    /// * [<https://redis.io/commands/pexpire/>](https://redis.io/commands/pexpire/)
+    #[must_use]
+    fn expire_in<K>(
+        self,
+        key: K,
+        timeout: Duration,
+        option: ExpireOption,
+    ) -> PreparedCommand<'a, Self, bool>
+    where
+        Self: Sized,
+        K: SingleArg,
+    {
+        let command = match whole_seconds(timeout) {
+            Some(seconds) => cmd("EXPIRE").arg(key).arg(seconds),
+            None => cmd("PEXPIRE").arg(key).arg(ceil_millis(timeout)),
+        };
+        prepare_command(self, command.arg(option))
+    }
+
+    /// Set the time at which key will expire from a [`SystemTime`](std::time::SystemTime)
+    ///
+    /// EXPIREAT is sent when the time is a whole number of seconds since the Unix epoch, PEXPIREAT otherwise.
+    /// Sub-millisecond precision is rounded up to the next millisecond.
+    ///
+    /// A time in the past will delete the key
+    ///
+    /// # Return
+    /// * `true` - if the timeout was set.
+    /// * `false` - if the timeout was not set. e.g. key doesn't exist, or operation skipped due to the provided arguments.
+    ///
+    /// # See Also
+    /// * [<https://redis.io/commands/expireat/>](https://redis.io/commands/expireat/)
+    /// * [<https://redis.io/commands/pexpireat/>](https://redis.io/commands/pexpireat/)
+    #[must_use]
+    fn expire_at<K>(
+        self,
+        key: K,
+        time: SystemTime,
+        option: ExpireOption,
+    ) -> PreparedCommand<'a, Self, bool>
+    where
+        Self: Sized,
+        K: SingleArg,
+    {
+        // times before the Unix epoch are in the past anyway
+        let unix_time = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let command = match whole_seconds(unix_time) {
+            Some(seconds) => cmd("EXPIREAT").arg(key).arg(seconds),
+            None => cmd("PEXPIREAT").arg(key).arg(ceil_millis(unix_time)),
+        };
+        prepare_command(self, command.arg(option))
+    }
+
     /// Returns the absolute Unix timestamp (since January 1, 1970) in seconds at which the given key will expire.
     ///
     /// # Return
@@ -163,6 +232,11 @@ pub trait GenericCommands<'a> {
 
     /// Returns all keys matching pattern.
     ///
+    /// # Warning
+    /// `KEYS` blocks the server while it scans the whole keyspace:
+    /// it should not be used on production datasets.
+    /// Prefer [`scan`](GenericCommands::scan) or the [`keys_safe`](crate::utils::keys_safe) helper.
+    ///
     /// # Return
     /// list of keys matching pattern.
     ///
@@ -251,12 +325,12 @@ pub trait GenericCommands<'a> {
     /// This command returns the logarithmic access frequency counter of a Redis object stored at `key`.
     ///
     /// # Return
-    /// The counter's value.
+    /// The counter's value, or `None` if the key does not exist.
     ///
     /// # See Also
     /// [<https://redis.io/commands/object-freq/>](https://redis.io/commands/object-freq/)
     #[must_use]
-    fn object_freq<K>(self, key: K) -> PreparedCommand<'a, Self, i64>
+    fn object_freq<K>(self, key: K) -> PreparedCommand<'a, Self, Option<i64>>
     where
         Self: Sized,
         K: SingleArg,
@@ -267,12 +341,12 @@ pub trait GenericCommands<'a> {
     /// This command returns the time in seconds since the last access to the value stored at `key`.
     ///
     /// # Return
-    /// The idle time in seconds.
+    /// The idle time in seconds, or `None` if the key does not exist.
     ///
     /// # See Also
     /// [<https://redis.io/commands/object-idletime/>](https://redis.io/commands/object-idletime/)
     #[must_use]
-    fn object_idle_time<K>(self, key: K) -> PreparedCommand<'a, Self, i64>
+    fn object_idle_time<K>(self, key: K) -> PreparedCommand<'a, Self, Option<i64>>
     where
         Self: Sized,
         K: SingleArg,
@@ -283,12 +357,12 @@ pub trait GenericCommands<'a> {
     /// This command returns the reference count of the stored at `key`.
     ///
     /// # Return
-    /// The number of references.
+    /// The number of references, or `None` if the key does not exist.
     ///
     /// # See Also
     /// [<https://redis.io/commands/object-refcount/>](https://redis.io/commands/object-refcount/)
     #[must_use]
-    fn object_refcount<K>(self, key: K) -> PreparedCommand<'a, Self, i64>
+    fn object_refcount<K>(self, key: K) -> PreparedCommand<'a, Self, Option<i64>>
     where
         Self: Sized,
         K: SingleArg,
@@ -653,15 +727,46 @@ pub trait GenericCommands<'a> {
     }
 }
 
-/// Options for the [`expire`](GenericCommands::expire) command
-#[derive(Default)]
+/// Returns the number of seconds of `duration` if it has no sub-second part
+pub(crate) fn whole_seconds(duration: Duration) -> Option<u64> {
+    if duration.subsec_nanos() == 0 {
+        Some(duration.as_secs())
+    } else {
+        None
+    }
+}
+
+/// Returns the number of milliseconds of `duration`, rounded up
+pub(crate) fn ceil_millis(duration: Duration) -> u64 {
+    let millis = duration.as_millis() as u64;
+    if Duration::from_millis(millis) < duration {
+        millis + 1
+    } else {
+        millis
+    }
+}
+
+/// Options for the [`expire`](GenericCommands::expire) and [`expire_in`](GenericCommands::expire_in) commands
+/// and for the [`hexpire`](crate::commands::HashCommands::hexpire) commands
+///
+/// The option can also be parsed from its name (`NX`, `XX`, `GT`, `LT`, case insensitive,
+/// or an empty string for no option), to be read from a configuration.
+///
+/// # Example
+/// ```
+/// use rustis::commands::ExpireOption;
+///
+/// let option: ExpireOption = "gt".parse().unwrap();
+/// assert_eq!(ExpireOption::Gt, option);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum ExpireOption {
     /// No option
     #[default]
     None,
     /// Set expiry only when the key has no expiry
     Nx,
-    /// Set expiry only when the key has no expiry    
+    /// Set expiry only when the key has an existing expiry
     Xx,
     /// Set expiry only when the new expiry is greater than current one
     Gt,
@@ -669,6 +774,23 @@ pub enum ExpireOption {
     Lt,
 }
 
+impl FromStr for ExpireOption {
+    type Err = Error;
+
+    fn from_str(str: &str) -> Result<Self> {
+        match str.to_ascii_uppercase().as_str() {
+            "" => Ok(ExpireOption::None),
+            "NX" => Ok(ExpireOption::Nx),
+            "XX" => Ok(ExpireOption::Xx),
+            "GT" => Ok(ExpireOption::Gt),
+            "LT" => Ok(ExpireOption::Lt),
+            _ => Err(Error::Client(format!(
+                "Cannot parse ExpireOption from `{str}`"
+            ))),
+        }
+    }
+}
+
 impl ToArgs for ExpireOption {
     fn write_args(&self, args: &mut CommandArgs) {
         match self {
@@ -859,7 +981,7 @@ impl ToArgs for SortOptions {
 pub struct DumpResult(#[serde(deserialize_with = "deserialize_byte_buf")] pub Vec<u8>);
 
 /// Options for the [`scan`](GenericCommands::scan) command
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct ScanOptions {
     command_args: CommandArgs,
 }
@@ -902,3 +1024,124 @@ pub enum MigrateResult {
     /// no keys were found in the source instance.
     NoKey,
 }
+
+/// Internal encoding of a Redis object, as returned by the [`object_encoding`](GenericCommands::object_encoding) command
+///
+/// # See Also
+/// [<https://redis.io/commands/object-encoding/>](https://redis.io/commands/object-encoding/)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectEncoding {
+    /// Normal string encoding
+    Raw,
+    /// String representing a 64 bit signed integer
+    Int,
+    /// Embedded string, for strings of up to 44 bytes
+    EmbStr,
+    /// Space efficient encoding for small lists, hashes and sorted sets (Redis 7.0 and later)
+    ListPack,
+    /// Space efficient encoding for small hashes and sorted sets, replaced by `listpack` in Redis 7.0
+    ZipList,
+    /// Legacy space efficient encoding for small hashes, before Redis 2.6
+    ZipMap,
+    /// Linked list of listpacks (or ziplists before Redis 7.0), for lists
+    QuickList,
+    /// Legacy encoding for lists, before Redis 3.2
+    LinkedList,
+    /// Space efficient encoding for small sets of integers
+    IntSet,
+    /// Regular hash table, for hashes and sets
+    HashTable,
+    /// Skip list, for sorted sets
+    SkipList,
+    /// Encoding of streams
+    Stream,
+    /// Any other encoding
+    Other(String),
+}
+
+impl ObjectEncoding {
+    /// Indicates if the encoding is one of the compact encodings used for small values
+    /// (`int`, `embstr`, `listpack`, `ziplist`, `zipmap` or `intset`).
+    ///
+    /// `quicklist` is not considered compact even if its nodes are compact.
+    #[must_use]
+    pub fn is_compact(&self) -> bool {
+        matches!(
+            self,
+            ObjectEncoding::Int
+                | ObjectEncoding::EmbStr
+                | ObjectEncoding::ListPack
+                | ObjectEncoding::ZipList
+                | ObjectEncoding::ZipMap
+                | ObjectEncoding::IntSet
+        )
+    }
+
+    /// Indicates if two encodings are the same across server versions.
+    ///
+    /// `listpack` replaced `ziplist` in Redis 7.0, so both are equivalent.
+    #[must_use]
+    pub fn is_equivalent(&self, other: &ObjectEncoding) -> bool {
+        match (self, other) {
+            (
+                ObjectEncoding::ListPack | ObjectEncoding::ZipList,
+                ObjectEncoding::ListPack | ObjectEncoding::ZipList,
+            ) => true,
+            _ => self == other,
+        }
+    }
+}
+
+impl std::fmt::Display for ObjectEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ObjectEncoding::Raw => "raw",
+            ObjectEncoding::Int => "int",
+            ObjectEncoding::EmbStr => "embstr",
+            ObjectEncoding::ListPack => "listpack",
+            ObjectEncoding::ZipList => "ziplist",
+            ObjectEncoding::ZipMap => "zipmap",
+            ObjectEncoding::QuickList => "quicklist",
+            ObjectEncoding::LinkedList => "linkedlist",
+            ObjectEncoding::IntSet => "intset",
+            ObjectEncoding::HashTable => "hashtable",
+            ObjectEncoding::SkipList => "skiplist",
+            ObjectEncoding::Stream => "stream",
+            ObjectEncoding::Other(encoding) => encoding,
+        })
+    }
+}
+
+impl FromStr for ObjectEncoding {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "raw" => ObjectEncoding::Raw,
+            "int" => ObjectEncoding::Int,
+            "embstr" => ObjectEncoding::EmbStr,
+            "listpack" => ObjectEncoding::ListPack,
+            "ziplist" => ObjectEncoding::ZipList,
+            "zipmap" => ObjectEncoding::ZipMap,
+            "quicklist" => ObjectEncoding::QuickList,
+            "linkedlist" => ObjectEncoding::LinkedList,
+            "intset" => ObjectEncoding::IntSet,
+            "hashtable" => ObjectEncoding::HashTable,
+            "skiplist" => ObjectEncoding::SkipList,
+            "stream" => ObjectEncoding::Stream,
+            _ => ObjectEncoding::Other(s.to_owned()),
+        })
+    }
+}
+
+impl PrimitiveResponse for ObjectEncoding {}
+
+impl<'de> Deserialize<'de> for ObjectEncoding {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let encoding = String::deserialize(deserializer)?;
+        encoding.parse().map_err(serde::de::Error::custom)
+    }
+}
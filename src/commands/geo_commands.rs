@@ -123,6 +123,267 @@ pub trait GeoCommands<'a> {
         prepare_command(self, cmd("GEOPOS").arg(key).arg(members))
     }
 
+    /// Return the members of a sorted set populated with geospatial information using [geoadd](GeoCommands::geoadd),
+    /// which are within the borders of the area specified with the center location and the maximum distance from the center (the radius).
+    ///
+    /// # Return
+    /// An array of members + additional information depending
+    /// on which `with_xyz` options have been selected
+    ///
+    /// # Legacy
+    /// `GEORADIUS` is deprecated since Redis 6.2, in favor of [`geosearch`](GeoCommands::geosearch)
+    /// with [`GeoSearchBy::ByRadius`].
+    /// It is kept for servers older than 6.2 and for forks which do not implement `GEOSEARCH`.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/georadius/>](https://redis.io/commands/georadius/)
+    #[must_use]
+    #[deprecated(note = "use `geosearch` on Redis 6.2 and later")]
+    fn georadius<K, M, A>(
+        self,
+        key: K,
+        longitude: f64,
+        latitude: f64,
+        radius: f64,
+        unit: GeoUnit,
+        options: GeoSearchOptions,
+    ) -> PreparedCommand<'a, Self, A>
+    where
+        Self: Sized,
+        K: SingleArg,
+        M: PrimitiveResponse + DeserializeOwned,
+        A: CollectionResponse<GeoSearchResult<M>> + DeserializeOwned,
+    {
+        prepare_command(
+            self,
+            cmd("GEORADIUS")
+                .arg(key)
+                .arg(longitude)
+                .arg(latitude)
+                .arg(radius)
+                .arg(unit)
+                .arg(options),
+        )
+    }
+
+    /// Read-only variant of [`georadius`](GeoCommands::georadius), which can be sent to replicas.
+    ///
+    /// # Return
+    /// An array of members + additional information depending
+    /// on which `with_xyz` options have been selected
+    ///
+    /// # Legacy
+    /// `GEORADIUS_RO` is deprecated since Redis 6.2, in favor of [`geosearch`](GeoCommands::geosearch)
+    /// with [`GeoSearchBy::ByRadius`].
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/georadius_ro/>](https://redis.io/commands/georadius_ro/)
+    #[must_use]
+    #[deprecated(note = "use `geosearch` on Redis 6.2 and later")]
+    fn georadius_ro<K, M, A>(
+        self,
+        key: K,
+        longitude: f64,
+        latitude: f64,
+        radius: f64,
+        unit: GeoUnit,
+        options: GeoSearchOptions,
+    ) -> PreparedCommand<'a, Self, A>
+    where
+        Self: Sized,
+        K: SingleArg,
+        M: PrimitiveResponse + DeserializeOwned,
+        A: CollectionResponse<GeoSearchResult<M>> + DeserializeOwned,
+    {
+        prepare_command(
+            self,
+            cmd("GEORADIUS_RO")
+                .arg(key)
+                .arg(longitude)
+                .arg(latitude)
+                .arg(radius)
+                .arg(unit)
+                .arg(options),
+        )
+    }
+
+    /// This command is like [`georadius`](GeoCommands::georadius),
+    /// but stores the result in destination key (`STORE` or `STOREDIST` option).
+    ///
+    /// # Return
+    /// the number of elements in the resulting set.
+    ///
+    /// # Legacy
+    /// `GEORADIUS` is deprecated since Redis 6.2, in favor of [`geosearchstore`](GeoCommands::geosearchstore)
+    /// with [`GeoSearchBy::ByRadius`].
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/georadius/>](https://redis.io/commands/georadius/)
+    #[must_use]
+    #[deprecated(note = "use `geosearchstore` on Redis 6.2 and later")]
+    #[allow(clippy::too_many_arguments)]
+    fn georadius_store<K, D>(
+        self,
+        key: K,
+        longitude: f64,
+        latitude: f64,
+        radius: f64,
+        unit: GeoUnit,
+        destination: D,
+        options: GeoRadiusStoreOptions,
+    ) -> PreparedCommand<'a, Self, usize>
+    where
+        Self: Sized,
+        K: SingleArg,
+        D: SingleArg,
+    {
+        prepare_command(
+            self,
+            cmd("GEORADIUS")
+                .arg(key)
+                .arg(longitude)
+                .arg(latitude)
+                .arg(radius)
+                .arg(unit)
+                .arg(options.command_args)
+                .arg(if options.store_dist {
+                    "STOREDIST"
+                } else {
+                    "STORE"
+                })
+                .arg(destination),
+        )
+    }
+
+    /// This command is exactly like [`georadius`](GeoCommands::georadius)
+    /// with the sole difference that instead of taking, as the center of the area to query,
+    /// a longitude and latitude value, it takes the name of a member already existing inside the geospatial index.
+    ///
+    /// # Return
+    /// An array of members + additional information depending
+    /// on which `with_xyz` options have been selected
+    ///
+    /// # Legacy
+    /// `GEORADIUSBYMEMBER` is deprecated since Redis 6.2, in favor of [`geosearch`](GeoCommands::geosearch)
+    /// with [`GeoSearchFrom::FromMember`] and [`GeoSearchBy::ByRadius`].
+    /// It is kept for servers older than 6.2 and for forks which do not implement `GEOSEARCH`.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/georadiusbymember/>](https://redis.io/commands/georadiusbymember/)
+    #[must_use]
+    #[deprecated(note = "use `geosearch` on Redis 6.2 and later")]
+    fn georadiusbymember<K, M1, M2, A>(
+        self,
+        key: K,
+        member: M1,
+        radius: f64,
+        unit: GeoUnit,
+        options: GeoSearchOptions,
+    ) -> PreparedCommand<'a, Self, A>
+    where
+        Self: Sized,
+        K: SingleArg,
+        M1: SingleArg,
+        M2: PrimitiveResponse + DeserializeOwned,
+        A: CollectionResponse<GeoSearchResult<M2>> + DeserializeOwned,
+    {
+        prepare_command(
+            self,
+            cmd("GEORADIUSBYMEMBER")
+                .arg(key)
+                .arg(member)
+                .arg(radius)
+                .arg(unit)
+                .arg(options),
+        )
+    }
+
+    /// Read-only variant of [`georadiusbymember`](GeoCommands::georadiusbymember), which can be sent to replicas.
+    ///
+    /// # Return
+    /// An array of members + additional information depending
+    /// on which `with_xyz` options have been selected
+    ///
+    /// # Legacy
+    /// `GEORADIUSBYMEMBER_RO` is deprecated since Redis 6.2, in favor of [`geosearch`](GeoCommands::geosearch)
+    /// with [`GeoSearchFrom::FromMember`] and [`GeoSearchBy::ByRadius`].
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/georadiusbymember_ro/>](https://redis.io/commands/georadiusbymember_ro/)
+    #[must_use]
+    #[deprecated(note = "use `geosearch` on Redis 6.2 and later")]
+    fn georadiusbymember_ro<K, M1, M2, A>(
+        self,
+        key: K,
+        member: M1,
+        radius: f64,
+        unit: GeoUnit,
+        options: GeoSearchOptions,
+    ) -> PreparedCommand<'a, Self, A>
+    where
+        Self: Sized,
+        K: SingleArg,
+        M1: SingleArg,
+        M2: PrimitiveResponse + DeserializeOwned,
+        A: CollectionResponse<GeoSearchResult<M2>> + DeserializeOwned,
+    {
+        prepare_command(
+            self,
+            cmd("GEORADIUSBYMEMBER_RO")
+                .arg(key)
+                .arg(member)
+                .arg(radius)
+                .arg(unit)
+                .arg(options),
+        )
+    }
+
+    /// This command is like [`georadiusbymember`](GeoCommands::georadiusbymember),
+    /// but stores the result in destination key (`STORE` or `STOREDIST` option).
+    ///
+    /// # Return
+    /// the number of elements in the resulting set.
+    ///
+    /// # Legacy
+    /// `GEORADIUSBYMEMBER` is deprecated since Redis 6.2, in favor of [`geosearchstore`](GeoCommands::geosearchstore)
+    /// with [`GeoSearchFrom::FromMember`] and [`GeoSearchBy::ByRadius`].
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/georadiusbymember/>](https://redis.io/commands/georadiusbymember/)
+    #[must_use]
+    #[deprecated(note = "use `geosearchstore` on Redis 6.2 and later")]
+    fn georadiusbymember_store<K, M, D>(
+        self,
+        key: K,
+        member: M,
+        radius: f64,
+        unit: GeoUnit,
+        destination: D,
+        options: GeoRadiusStoreOptions,
+    ) -> PreparedCommand<'a, Self, usize>
+    where
+        Self: Sized,
+        K: SingleArg,
+        M: SingleArg,
+        D: SingleArg,
+    {
+        prepare_command(
+            self,
+            cmd("GEORADIUSBYMEMBER")
+                .arg(key)
+                .arg(member)
+                .arg(radius)
+                .arg(unit)
+                .arg(options.command_args)
+                .arg(if options.store_dist {
+                    "STOREDIST"
+                } else {
+                    "STORE"
+                })
+                .arg(destination),
+        )
+    }
+
     /// Return the members of a sorted set populated with geospatial information using [geoadd](GeoCommands::geoadd),
     /// which are within the borders of the area specified by a given shape.
     ///
@@ -308,6 +569,7 @@ impl ToArgs for GeoSearchOrder {
 }
 
 /// Options for the [`geosearch`](GeoCommands::geosearch) command
+/// and the legacy [`georadius`](GeoCommands::georadius) and [`georadiusbymember`](GeoCommands::georadiusbymember) commands
 #[derive(Default)]
 pub struct GeoSearchOptions {
     command_args: CommandArgs,
@@ -550,3 +812,44 @@ impl ToArgs for GeoSearchStoreOptions {
         args.arg(&self.command_args);
     }
 }
+
+/// Options for the legacy [`georadius_store`](GeoCommands::georadius_store)
+/// and [`georadiusbymember_store`](GeoCommands::georadiusbymember_store) commands
+#[derive(Default)]
+pub struct GeoRadiusStoreOptions {
+    command_args: CommandArgs,
+    store_dist: bool,
+}
+
+impl GeoRadiusStoreOptions {
+    #[must_use]
+    pub fn order(mut self, order: GeoSearchOrder) -> Self {
+        Self {
+            command_args: self.command_args.arg(order).build(),
+            store_dist: self.store_dist,
+        }
+    }
+
+    #[must_use]
+    pub fn count(mut self, count: usize, any: bool) -> Self {
+        Self {
+            command_args: self
+                .command_args
+                .arg("COUNT")
+                .arg(count)
+                .arg_if(any, "ANY")
+                .build(),
+            store_dist: self.store_dist,
+        }
+    }
+
+    /// Store the distances of the members from the center (`STOREDIST`)
+    /// instead of their geohashes (`STORE`)
+    #[must_use]
+    pub fn store_dist(self, store_dist: bool) -> Self {
+        Self {
+            command_args: self.command_args,
+            store_dist,
+        }
+    }
+}
@@ -1,11 +1,15 @@
 use crate::{
     client::{prepare_command, PreparedCommand},
+    commands::ExpireOption,
     resp::{
         cmd, deserialize_vec_of_pairs, CollectionResponse, CommandArgs, KeyValueArgsCollection,
         KeyValueCollectionResponse, PrimitiveResponse, SingleArg, SingleArgCollection, ToArgs,
     },
 };
-use serde::{de::DeserializeOwned, Deserialize};
+use serde::{
+    de::{self, DeserializeOwned, Unexpected},
+    Deserialize,
+};
 
 /// A group of Redis commands related to [`Hashes`](https://redis.io/docs/data-types/hashes/)
 ///
@@ -48,6 +52,105 @@ pub trait HashCommands<'a> {
         prepare_command(self, cmd("HEXISTS").arg(key).arg(field))
     }
 
+    /// Set a timeout in seconds on one or more fields of the hash stored at key.
+    ///
+    /// Once a field expires, it is deleted from the hash.
+    ///
+    /// # Return
+    /// The result of the operation for each field, in the same order as they are requested.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hexpire/>](https://redis.io/commands/hexpire/)
+    #[must_use]
+    fn hexpire<K, F, C>(
+        self,
+        key: K,
+        seconds: u64,
+        option: ExpireOption,
+        fields: C,
+    ) -> PreparedCommand<'a, Self, Vec<HashFieldExpireResult>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HEXPIRE")
+                .arg(key)
+                .arg(seconds)
+                .arg(option)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
+    /// Set an expiration for one or more fields of the hash stored at key,
+    /// as an absolute Unix timestamp in seconds.
+    ///
+    /// Once a field expires, it is deleted from the hash.
+    ///
+    /// # Return
+    /// The result of the operation for each field, in the same order as they are requested.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hexpireat/>](https://redis.io/commands/hexpireat/)
+    #[must_use]
+    fn hexpireat<K, F, C>(
+        self,
+        key: K,
+        unix_time_seconds: u64,
+        option: ExpireOption,
+        fields: C,
+    ) -> PreparedCommand<'a, Self, Vec<HashFieldExpireResult>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HEXPIREAT")
+                .arg(key)
+                .arg(unix_time_seconds)
+                .arg(option)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
+    /// Returns the absolute Unix timestamp in seconds at which the given fields of the hash will expire.
+    ///
+    /// # Return
+    /// For each field, in the same order as they are requested:
+    /// the expiration Unix timestamp in seconds, or a negative value:
+    /// * `-1` if the field exists but has no associated expiration.
+    /// * `-2` if the field or the key does not exist.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hexpiretime/>](https://redis.io/commands/hexpiretime/)
+    #[must_use]
+    fn hexpiretime<K, F, C>(self, key: K, fields: C) -> PreparedCommand<'a, Self, Vec<i64>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HEXPIRETIME")
+                .arg(key)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
     /// Returns the value associated with field in the hash stored at key.
     ///
     /// # Return
@@ -174,6 +277,163 @@ pub trait HashCommands<'a> {
         prepare_command(self, cmd("HMGET").arg(key).arg(fields))
     }
 
+    /// Remove the expiration of the given fields of the hash stored at key,
+    /// turning them from volatile to persistent.
+    ///
+    /// # Return
+    /// The result of the operation for each field, in the same order as they are requested.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hpersist/>](https://redis.io/commands/hpersist/)
+    #[must_use]
+    fn hpersist<K, F, C>(
+        self,
+        key: K,
+        fields: C,
+    ) -> PreparedCommand<'a, Self, Vec<HashFieldPersistResult>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HPERSIST")
+                .arg(key)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
+    /// Set a timeout in milliseconds on one or more fields of the hash stored at key.
+    ///
+    /// Once a field expires, it is deleted from the hash.
+    ///
+    /// # Return
+    /// The result of the operation for each field, in the same order as they are requested.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hpexpire/>](https://redis.io/commands/hpexpire/)
+    #[must_use]
+    fn hpexpire<K, F, C>(
+        self,
+        key: K,
+        milliseconds: u64,
+        option: ExpireOption,
+        fields: C,
+    ) -> PreparedCommand<'a, Self, Vec<HashFieldExpireResult>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HPEXPIRE")
+                .arg(key)
+                .arg(milliseconds)
+                .arg(option)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
+    /// Set an expiration for one or more fields of the hash stored at key,
+    /// as an absolute Unix timestamp in milliseconds.
+    ///
+    /// Once a field expires, it is deleted from the hash.
+    ///
+    /// # Return
+    /// The result of the operation for each field, in the same order as they are requested.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hpexpireat/>](https://redis.io/commands/hpexpireat/)
+    #[must_use]
+    fn hpexpireat<K, F, C>(
+        self,
+        key: K,
+        unix_time_milliseconds: u64,
+        option: ExpireOption,
+        fields: C,
+    ) -> PreparedCommand<'a, Self, Vec<HashFieldExpireResult>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HPEXPIREAT")
+                .arg(key)
+                .arg(unix_time_milliseconds)
+                .arg(option)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
+    /// Returns the absolute Unix timestamp in milliseconds at which the given fields of the hash will expire.
+    ///
+    /// # Return
+    /// For each field, in the same order as they are requested:
+    /// the expiration Unix timestamp in milliseconds, or a negative value:
+    /// * `-1` if the field exists but has no associated expiration.
+    /// * `-2` if the field or the key does not exist.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hpexpiretime/>](https://redis.io/commands/hpexpiretime/)
+    #[must_use]
+    fn hpexpiretime<K, F, C>(self, key: K, fields: C) -> PreparedCommand<'a, Self, Vec<i64>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HPEXPIRETIME")
+                .arg(key)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
+    /// Returns the remaining time to live in milliseconds of the given fields of the hash stored at key.
+    ///
+    /// # Return
+    /// For each field, in the same order as they are requested:
+    /// the TTL in milliseconds, or a negative value:
+    /// * `-1` if the field exists but has no associated expiration.
+    /// * `-2` if the field or the key does not exist.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hpttl/>](https://redis.io/commands/hpttl/)
+    #[must_use]
+    fn hpttl<K, F, C>(self, key: K, fields: C) -> PreparedCommand<'a, Self, Vec<i64>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HPTTL")
+                .arg(key)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
     /// return random fields from the hash value stored at key.
     ///
     /// # Return
@@ -266,6 +526,37 @@ pub trait HashCommands<'a> {
         prepare_command(self, cmd("HSCAN").arg(key).arg(cursor).arg(options))
     }
 
+    /// Iterates fields of Hash types, without their associated values.
+    ///
+    /// Requires Redis 7.4 or later.
+    ///
+    /// # Return
+    /// the next cursor and the fields returned by this iteration.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hscan/>](https://redis.io/commands/hscan/)
+    #[must_use]
+    fn hscan_novalues<K, F>(
+        self,
+        key: K,
+        cursor: u64,
+        options: HScanOptions,
+    ) -> PreparedCommand<'a, Self, (u64, Vec<F>)>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: PrimitiveResponse + DeserializeOwned,
+    {
+        prepare_command(
+            self,
+            cmd("HSCAN")
+                .arg(key)
+                .arg(cursor)
+                .arg(options)
+                .arg("NOVALUES"),
+        )
+    }
+
     /// Sets field in the hash stored at key to value.
     ///
     /// # Return
@@ -322,6 +613,34 @@ pub trait HashCommands<'a> {
         prepare_command(self, cmd("HSTRLEN").arg(key).arg(field))
     }
 
+    /// Returns the remaining time to live in seconds of the given fields of the hash stored at key.
+    ///
+    /// # Return
+    /// For each field, in the same order as they are requested:
+    /// the TTL in seconds, or a negative value:
+    /// * `-1` if the field exists but has no associated expiration.
+    /// * `-2` if the field or the key does not exist.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/httl/>](https://redis.io/commands/httl/)
+    #[must_use]
+    fn httl<K, F, C>(self, key: K, fields: C) -> PreparedCommand<'a, Self, Vec<i64>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HTTL")
+                .arg(key)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
     /// list of values in the hash, or an empty list when key does not exist.
     ///
     /// # Return
@@ -380,3 +699,64 @@ where
     #[serde(deserialize_with = "deserialize_vec_of_pairs")]
     pub elements: Vec<(F, V)>,
 }
+
+/// Result of the [`hexpire`](HashCommands::hexpire) command and its variants, for one field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashFieldExpireResult {
+    /// The field does not exist, or the key does not exist
+    NoSuchField,
+    /// The expiration was not set because the condition of the [`ExpireOption`] was not met
+    ConditionNotMet,
+    /// The expiration was set or updated
+    Set,
+    /// The field was deleted because the expiration is in the past or set to `0`
+    Deleted,
+}
+
+impl<'de> Deserialize<'de> for HashFieldExpireResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let result = i64::deserialize(deserializer)?;
+        match result {
+            -2 => Ok(HashFieldExpireResult::NoSuchField),
+            0 => Ok(HashFieldExpireResult::ConditionNotMet),
+            1 => Ok(HashFieldExpireResult::Set),
+            2 => Ok(HashFieldExpireResult::Deleted),
+            _ => Err(de::Error::invalid_value(
+                Unexpected::Signed(result),
+                &"-2, 0, 1 or 2",
+            )),
+        }
+    }
+}
+
+/// Result of the [`hpersist`](HashCommands::hpersist) command, for one field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashFieldPersistResult {
+    /// The field does not exist, or the key does not exist
+    NoSuchField,
+    /// The field exists but has no associated expiration
+    NoExpiration,
+    /// The expiration was removed
+    Persisted,
+}
+
+impl<'de> Deserialize<'de> for HashFieldPersistResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let result = i64::deserialize(deserializer)?;
+        match result {
+            -2 => Ok(HashFieldPersistResult::NoSuchField),
+            -1 => Ok(HashFieldPersistResult::NoExpiration),
+            1 => Ok(HashFieldPersistResult::Persisted),
+            _ => Err(de::Error::invalid_value(
+                Unexpected::Signed(result),
+                &"-2, -1 or 1",
+            )),
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{
     client::{prepare_command, PreparedCommand},
     resp::{
-        cmd, CollectionResponse, CommandArgs, PrimitiveResponse, SingleArg, SingleArgCollection,
-        ToArgs,
+        cmd, CollectionResponse, CommandArgs, KeysWithCount, PrimitiveResponse, SingleArg,
+        SingleArgCollection, ToArgs,
     },
 };
 use serde::de::DeserializeOwned;
@@ -107,7 +107,8 @@ pub trait ListCommands<'a> {
     /// Pops one or more elements from the first non-empty list key from the list of provided key names.
     ///
     /// # Return
-    /// Tuple composed by the name of the key from which elements were popped and the list of popped element
+    /// Tuple composed by the name of the key from which elements were popped and the list of popped element,
+    /// or `None` if no element could be popped.
     ///
     /// # See Also
     /// [<https://redis.io/commands/lmpop/>](https://redis.io/commands/lmpop/)
@@ -117,7 +118,7 @@ pub trait ListCommands<'a> {
         keys: C,
         where_: LMoveWhere,
         count: usize,
-    ) -> PreparedCommand<'a, Self, (String, Vec<E>)>
+    ) -> PreparedCommand<'a, Self, Option<(String, Vec<E>)>>
     where
         Self: Sized,
         K: SingleArg,
@@ -127,8 +128,7 @@ pub trait ListCommands<'a> {
         prepare_command(
             self,
             cmd("LMPOP")
-                .arg(keys.num_args())
-                .arg(keys)
+                .arg(KeysWithCount::new(keys))
                 .arg(where_)
                 .arg("COUNT")
                 .arg(count),
@@ -114,6 +114,8 @@ pub trait PubSubCommands<'a> {
     /// # Return
     /// A collection of channels and number of subscribers for every channel.
     ///
+    /// Collect it into a `Vec<(String, usize)>` to keep the order of the requested channels.
+    ///
     /// # See Also
     /// [<https://redis.io/commands/pubsub-numsub/>](https://redis.io/commands/pubsub-numsub/)
     fn pub_sub_numsub<C, CC, R, RR>(self, channels: CC) -> PreparedCommand<'a, Self, RR>
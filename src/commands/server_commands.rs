@@ -2,7 +2,7 @@ use crate::{
     client::{prepare_command, PreparedCommand},
     resp::{
         cmd, CollectionResponse, CommandArgs, KeyValueArgsCollection, KeyValueCollectionResponse,
-        PrimitiveResponse, SingleArg, SingleArgCollection, ToArgs, Value,
+        PrimitiveResponse, Response, SingleArg, SingleArgCollection, ToArgs, Value,
     },
     Error, Result,
 };
@@ -353,6 +353,37 @@ pub trait ServerCommands<'a> {
         prepare_command(self, cmd("COMMAND").arg("LIST").arg(options))
     }
 
+    /// Send any server command, with its subcommand and arguments, as is.
+    ///
+    /// This passthrough gives access to server commands or subcommands
+    /// which are not wrapped yet by this crate.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{client::Client, commands::ServerCommands, resp::Value, Result};
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let client = Client::connect("127.0.0.1:6379").await?;
+    ///
+    ///     let count: usize = client.command_raw("COMMAND", "COUNT").await?;
+    ///     assert!(count > 0);
+    ///     let _value: Value = client.command_raw("MEMORY", ["USAGE", "key"]).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    fn command_raw<A, R>(self, name: &'static str, args: A) -> PreparedCommand<'a, Self, R>
+    where
+        Self: Sized,
+        A: ToArgs,
+        R: Response,
+    {
+        prepare_command(self, cmd(name).arg(args))
+    }
+
     /// Used to read the configuration parameters of a running Redis server.
     ///
     /// For every key that does not hold a string value or does not exist,
@@ -1782,7 +1813,7 @@ impl ToArgs for MemoryUsageOptions {
 }
 
 /// Module information result for the [`module_list`](ServerCommands::module_list) command.
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ModuleInfo {
     /// Name of the module
     pub name: String,
@@ -2085,7 +2116,7 @@ impl ToArgs for SlowLogOptions {
 }
 
 /// Result [`slowlog_get`](ServerCommands::slowlog_get) for the command.
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct SlowLogEntry {
     /// A unique progressive identifier for every slow log entry.
     pub id: i64,
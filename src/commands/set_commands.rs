@@ -1,8 +1,8 @@
 use crate::{
     client::{prepare_command, PreparedCommand},
     resp::{
-        cmd, CollectionResponse, CommandArgs, PrimitiveResponse, SingleArg, SingleArgCollection,
-        ToArgs,
+        cmd, CollectionResponse, CommandArgs, KeysWithCount, PrimitiveResponse, SingleArg,
+        SingleArgCollection, ToArgs, Value,
     },
 };
 use serde::de::DeserializeOwned;
@@ -122,13 +122,64 @@ pub trait SetCommands<'a> {
         prepare_command(
             self,
             cmd("SINTERCARD")
-                .arg(keys.num_args())
-                .arg(keys)
+                .arg(KeysWithCount::new(keys))
                 .arg("LIMIT")
                 .arg(limit),
         )
     }
 
+    /// Returns the cardinality of the intersection of the given sets,
+    /// stopping the count as soon as it reaches `limit` (`0` for no limit).
+    ///
+    /// [`SINTERCARD`](https://redis.io/commands/sintercard/) is sent first.
+    /// If the server rejects it and its version, as reported by
+    /// [`Client::server_features`](crate::client::Client::server_features), is older than 7.0,
+    /// the intersection is retrieved with [`SINTER`](https://redis.io/commands/sinter/) and counted client-side.
+    /// The fallback is only available when the command is sent directly through a [`Client`](crate::client::Client),
+    /// not within a pipeline or a transaction.
+    ///
+    /// # Return
+    /// The number of elements in the resulting intersection, capped to `limit`.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/sintercard/>](https://redis.io/commands/sintercard/)
+    #[must_use]
+    fn intersection_size<K, C>(self, keys: C, limit: usize) -> PreparedCommand<'a, Self, usize>
+    where
+        Self: Sized,
+        K: SingleArg,
+        C: SingleArgCollection<K>,
+    {
+        prepare_command(
+            self,
+            cmd("SINTERCARD")
+                .arg(KeysWithCount::new(keys))
+                .arg("LIMIT")
+                .arg(limit),
+        )
+        .custom_converter(Box::new(move |resp_buffer, command, client| {
+            Box::pin(async move {
+                if resp_buffer.is_error() {
+                    let server_features = client.server_features().unwrap_or_default();
+                    if server_features.major != 0 && !server_features.is_at_least(7, 0) {
+                        // SINTERCARD numkeys key [key ...] LIMIT limit
+                        let mut args = (&command.args).into_iter();
+                        let num_keys = args.next().and_then(atoi::atoi::<usize>).unwrap_or(0);
+                        let sinter = cmd("SINTER").extend_from_iter(args.take(num_keys));
+                        let members: Vec<Value> = client.send(sinter, None).await?.to()?;
+                        return Ok(if limit == 0 {
+                            members.len()
+                        } else {
+                            members.len().min(limit)
+                        });
+                    }
+                }
+
+                resp_buffer.to()
+            })
+        }))
+    }
+
     /// This command is equal to [sinter](SetCommands::sinter), but instead of returning the resulting set,
     /// it is stored in destination.
     ///
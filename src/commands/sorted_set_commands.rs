@@ -1,8 +1,8 @@
 use crate::{
     client::{prepare_command, PreparedCommand},
     resp::{
-        cmd, deserialize_vec_of_pairs, CommandArgs, MultipleArgsCollection, PrimitiveResponse,
-        SingleArg, SingleArgCollection, ToArgs,
+        cmd, deserialize_vec_of_pairs, CommandArgs, KeysWithCount, MultipleArgsCollection,
+        PrimitiveResponse, SingleArg, SingleArgCollection, ToArgs,
     },
 };
 use serde::{de::DeserializeOwned, Deserialize};
@@ -40,9 +40,13 @@ pub trait SortedSetCommands<'a> {
     /// In this mode ZADD acts like ZINCRBY.
     /// Only one score-element pair can be specified in this mode.
     ///
+    /// Unlike [`zadd`](SortedSetCommands::zadd) which returns a number of elements,
+    /// the new score of the member is returned.
+    ///
     /// # Return
     /// The new score of member (a double precision floating point number),
-    /// or nil if the operation was aborted (when called with either the XX or the NX option).
+    /// or `None` if the operation was aborted because of the `condition` (`NX` or `XX`)
+    /// or of the `comparison` (`GT` or `LT`).
     ///
     /// # See Also
     /// [<https://redis.io/commands/zadd/>](https://redis.io/commands/zadd/)
@@ -92,6 +96,8 @@ pub trait SortedSetCommands<'a> {
 
     /// Returns the number of elements in the sorted set at key with a score between min and max.
     ///
+    /// The boundaries can be expressed with [`ScoreBound`].
+    ///
     /// # Return
     /// The number of elements in the specified score range.
     ///
@@ -124,7 +130,7 @@ pub trait SortedSetCommands<'a> {
         C: SingleArgCollection<K>,
         E: PrimitiveResponse + DeserializeOwned,
     {
-        prepare_command(self, cmd("ZDIFF").arg(keys.num_args()).arg(keys))
+        prepare_command(self, cmd("ZDIFF").arg(KeysWithCount::new(keys)))
     }
 
     /// This command is similar to [zdiffstore](SortedSetCommands::zdiffstore), but instead of storing the resulting sorted set,
@@ -145,10 +151,7 @@ pub trait SortedSetCommands<'a> {
     {
         prepare_command(
             self,
-            cmd("ZDIFF")
-                .arg(keys.num_args())
-                .arg(keys)
-                .arg("WITHSCORES"),
+            cmd("ZDIFF").arg(KeysWithCount::new(keys)).arg("WITHSCORES"),
         )
     }
 
@@ -172,8 +175,7 @@ pub trait SortedSetCommands<'a> {
             self,
             cmd("ZDIFFSTORE")
                 .arg(destination)
-                .arg(keys.num_args())
-                .arg(keys),
+                .arg(KeysWithCount::new(keys)),
         )
     }
 
@@ -219,8 +221,7 @@ pub trait SortedSetCommands<'a> {
         prepare_command(
             self,
             cmd("ZINTER")
-                .arg(keys.num_args())
-                .arg(keys)
+                .arg(KeysWithCount::new(keys))
                 .arg(weights.map(|w| ("WEIGHTS", w)))
                 .arg(aggregate),
         )
@@ -251,8 +252,7 @@ pub trait SortedSetCommands<'a> {
         prepare_command(
             self,
             cmd("ZINTER")
-                .arg(keys.num_args())
-                .arg(keys)
+                .arg(KeysWithCount::new(keys))
                 .arg(weights.map(|w| ("WEIGHTS", w)))
                 .arg(aggregate)
                 .arg("WITHSCORES"),
@@ -277,8 +277,7 @@ pub trait SortedSetCommands<'a> {
         prepare_command(
             self,
             cmd("ZINTERCARD")
-                .arg(keys.num_args())
-                .arg(keys)
+                .arg(KeysWithCount::new(keys))
                 .arg("LIMIT")
                 .arg(limit),
         )
@@ -311,8 +310,7 @@ pub trait SortedSetCommands<'a> {
             self,
             cmd("ZINTERSTORE")
                 .arg(destination)
-                .arg(keys.num_args())
-                .arg(keys)
+                .arg(KeysWithCount::new(keys))
                 .arg(weights.map(|w| ("WEIGHTS", w)))
                 .arg(aggregate),
         )
@@ -322,6 +320,8 @@ pub trait SortedSetCommands<'a> {
     /// in order to force lexicographical ordering, this command returns the number
     /// of elements in the sorted set at key with a value between min and max.
     ///
+    /// The boundaries can be expressed with [`LexBound`].
+    ///
     /// # Return
     /// the number of elements in the specified score range.
     ///
@@ -365,8 +365,7 @@ pub trait SortedSetCommands<'a> {
         prepare_command(
             self,
             cmd("ZMPOP")
-                .arg(keys.num_args())
-                .arg(keys)
+                .arg(KeysWithCount::new(keys))
                 .arg(where_)
                 .arg("COUNT")
                 .arg(count),
@@ -628,6 +627,8 @@ pub trait SortedSetCommands<'a> {
     /// this command removes all elements in the sorted set stored at key
     /// between the lexicographical range specified by min and max.
     ///
+    /// The boundaries can be expressed with [`LexBound`].
+    ///
     /// # Return
     /// the number of elements removed.
     ///
@@ -666,6 +667,8 @@ pub trait SortedSetCommands<'a> {
 
     /// Removes all elements in the sorted set stored at key with a score between min and max (inclusive).
     ///
+    /// The boundaries can be expressed with [`ScoreBound`].
+    ///
     /// # Return
     /// the number of elements removed.
     ///
@@ -765,8 +768,7 @@ pub trait SortedSetCommands<'a> {
         prepare_command(
             self,
             cmd("ZUNION")
-                .arg(keys.num_args())
-                .arg(keys)
+                .arg(KeysWithCount::new(keys))
                 .arg(weights.map(|w| ("WEIGHTS", w)))
                 .arg(aggregate),
         )
@@ -797,8 +799,7 @@ pub trait SortedSetCommands<'a> {
         prepare_command(
             self,
             cmd("ZUNION")
-                .arg(keys.num_args())
-                .arg(keys)
+                .arg(KeysWithCount::new(keys))
                 .arg(weights.map(|w| ("WEIGHTS", w)))
                 .arg(aggregate)
                 .arg("WITHSCORES"),
@@ -832,8 +833,7 @@ pub trait SortedSetCommands<'a> {
             self,
             cmd("ZUNIONSTORE")
                 .arg(destination)
-                .arg(keys.num_args())
-                .arg(keys)
+                .arg(KeysWithCount::new(keys))
                 .arg(weights.map(|w| ("WEIGHTS", w)))
                 .arg(aggregate),
         )
@@ -846,9 +846,9 @@ pub enum ZAddCondition {
     /// No condition
     #[default]
     None,
-    /// Only update elements that already exist. Don't add new elements.
-    NX,
     /// Only add new elements. Don't update already existing elements.
+    NX,
+    /// Only update elements that already exist. Don't add new elements.
     XX,
 }
 
@@ -896,6 +896,137 @@ impl ToArgs for ZAddComparison {
     }
 }
 
+/// Score boundary of a range of the sorted set commands,
+/// like [`zcount`](SortedSetCommands::zcount), [`zremrangebyscore`](SortedSetCommands::zremrangebyscore),
+/// or [`zrange`](SortedSetCommands::zrange) with [`ZRangeSortBy::ByScore`]
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::Client,
+///     commands::{ScoreBound, SortedSetCommands, ZAddOptions},
+///     Result,
+/// };
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     client.zadd("key", [(1., "one"), (2., "two"), (3., "three")], ZAddOptions::default()).await?;
+///
+///     // scores in ]1, +inf[
+///     let count = client.zcount("key", ScoreBound::Exclusive(1.), ScoreBound::PosInfinity).await?;
+///     assert_eq!(2, count);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScoreBound {
+    /// The score is included in the range
+    Inclusive(f64),
+    /// The score is excluded from the range
+    Exclusive(f64),
+    /// `-inf`
+    NegInfinity,
+    /// `+inf`
+    PosInfinity,
+}
+
+impl From<f64> for ScoreBound {
+    fn from(score: f64) -> Self {
+        ScoreBound::Inclusive(score)
+    }
+}
+
+impl SingleArg for ScoreBound {}
+
+impl ToArgs for ScoreBound {
+    fn write_args(&self, args: &mut CommandArgs) {
+        fn format_score(score: f64) -> String {
+            if score == f64::INFINITY {
+                "+inf".to_owned()
+            } else if score == f64::NEG_INFINITY {
+                "-inf".to_owned()
+            } else {
+                score.to_string()
+            }
+        }
+
+        match self {
+            ScoreBound::Inclusive(score) => {
+                args.arg(format_score(*score));
+            }
+            ScoreBound::Exclusive(score) => {
+                args.arg(format!("({}", format_score(*score)));
+            }
+            ScoreBound::NegInfinity => {
+                args.arg("-inf");
+            }
+            ScoreBound::PosInfinity => {
+                args.arg("+inf");
+            }
+        }
+    }
+}
+
+/// Lexicographical boundary of a range of the sorted set commands,
+/// like [`zlexcount`](SortedSetCommands::zlexcount), [`zremrangebylex`](SortedSetCommands::zremrangebylex),
+/// or [`zrange`](SortedSetCommands::zrange) with [`ZRangeSortBy::ByLex`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LexBound {
+    /// The member is included in the range
+    Inclusive(Vec<u8>),
+    /// The member is excluded from the range
+    Exclusive(Vec<u8>),
+    /// Lower than any member (`-`)
+    NegInfinity,
+    /// Greater than any member (`+`)
+    PosInfinity,
+}
+
+impl LexBound {
+    /// Build an inclusive boundary
+    #[must_use]
+    pub fn inclusive(member: impl Into<Vec<u8>>) -> Self {
+        LexBound::Inclusive(member.into())
+    }
+
+    /// Build an exclusive boundary
+    #[must_use]
+    pub fn exclusive(member: impl Into<Vec<u8>>) -> Self {
+        LexBound::Exclusive(member.into())
+    }
+}
+
+impl SingleArg for LexBound {}
+
+impl ToArgs for LexBound {
+    fn write_args(&self, args: &mut CommandArgs) {
+        fn prefixed(prefix: u8, member: &[u8]) -> Vec<u8> {
+            let mut arg = Vec::with_capacity(member.len() + 1);
+            arg.push(prefix);
+            arg.extend_from_slice(member);
+            arg
+        }
+
+        match self {
+            LexBound::Inclusive(member) => {
+                args.arg(prefixed(b'[', member));
+            }
+            LexBound::Exclusive(member) => {
+                args.arg(prefixed(b'(', member));
+            }
+            LexBound::NegInfinity => {
+                args.arg("-");
+            }
+            LexBound::PosInfinity => {
+                args.arg("+");
+            }
+        }
+    }
+}
+
 /// sort by option of the [`zrange`](SortedSetCommands::zrange) command
 #[derive(Default)]
 pub enum ZRangeSortBy {
@@ -4,9 +4,13 @@ use crate::{
         cmd, CommandArgs, KeyValueArgsCollection, KeyValueCollectionResponse, PrimitiveResponse,
         SingleArg, SingleArgCollection, ToArgs,
     },
+    Error, Result,
 };
-use serde::{de::DeserializeOwned, Deserialize};
-use std::collections::HashMap;
+use serde::{
+    de::{self, DeserializeOwned, Unexpected},
+    Deserialize,
+};
+use std::{collections::HashMap, str::FromStr};
 
 /// A group of Redis commands related to [`Streams`](https://redis.io/docs/data-types/streams/)
 /// # See Also
@@ -44,6 +48,8 @@ pub trait StreamCommands<'a> {
     ///
     /// The command returns a Null reply when used with create_stream=false and the key doesn't exist.
     ///
+    /// The ID can be received as a [`StreamId`] to be compared or to compute the next one.
+    ///
     /// # See Also
     /// [<https://redis.io/commands/xadd/>](https://redis.io/commands/xadd/)
     fn xadd<K, I, F, V, FFVV, R>(
@@ -339,8 +345,11 @@ pub trait StreamCommands<'a> {
 
     /// This command returns information about the stream stored at `key`.
     ///
+    /// Use [`xinfo_stream_full`](StreamCommands::xinfo_stream_full)
+    /// to get the verbose reply of the `FULL` modifier.
+    ///
     /// # Return
-    /// A collection of XGroupInfo.
+    /// The stream info.
     ///
     /// # See Also
     /// [<https://redis.io/commands/xinfo-stream/>](https://redis.io/commands/xinfo-stream/)
@@ -356,6 +365,36 @@ pub trait StreamCommands<'a> {
         prepare_command(self, cmd("XINFO").arg("STREAM").arg(key).arg(options))
     }
 
+    /// This command returns the verbose information about the stream stored at `key`,
+    /// including its entries, its consumer groups, their consumers and pending entries lists.
+    ///
+    /// `count` limits the number of stream and PEL entries that are returned.
+    /// When `None`, the server default (10) is used. `Some(0)` returns all entries.
+    ///
+    /// # Return
+    /// The full stream info.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/xinfo-stream/>](https://redis.io/commands/xinfo-stream/)
+    fn xinfo_stream_full<K>(
+        self,
+        key: K,
+        count: Option<usize>,
+    ) -> PreparedCommand<'a, Self, XStreamFullInfo>
+    where
+        Self: Sized,
+        K: SingleArg,
+    {
+        prepare_command(
+            self,
+            cmd("XINFO")
+                .arg("STREAM")
+                .arg(key)
+                .arg("FULL")
+                .arg(count.map(|c| ("COUNT", c))),
+        )
+    }
+
     /// Returns the number of entries inside a stream.
     ///
     /// # Return
@@ -681,6 +720,162 @@ impl ToArgs for XAutoClaimOptions {
     }
 }
 
+/// ID of a stream entry, made of a milliseconds time part and a sequence number
+///
+/// A stream ID can be parsed from its textual form `<ms>-<seq>`,
+/// received from the commands replying with IDs, like [`xadd`](StreamCommands::xadd),
+/// or passed to any command expecting an ID.
+///
+/// # Example
+/// ```
+/// use rustis::commands::StreamId;
+///
+/// let id: StreamId = "1526919030474-55".parse().unwrap();
+/// assert_eq!(StreamId::new(1526919030474, 55), id);
+/// assert_eq!("1526919030474-56", id.next().unwrap().to_string());
+/// assert!(id < id.next().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StreamId {
+    /// Milliseconds time part
+    pub ms: u64,
+    /// Sequence number of the entry within the same millisecond
+    pub seq: u64,
+}
+
+impl StreamId {
+    /// Smallest possible ID `0-0`
+    pub const MIN: StreamId = StreamId { ms: 0, seq: 0 };
+    /// Greatest possible ID
+    pub const MAX: StreamId = StreamId {
+        ms: u64::MAX,
+        seq: u64::MAX,
+    };
+
+    /// Build an ID from its parts
+    #[must_use]
+    pub const fn new(ms: u64, seq: u64) -> Self {
+        Self { ms, seq }
+    }
+
+    /// Smallest ID greater than this one, `None` for [`StreamId::MAX`]
+    ///
+    /// Useful to iterate over a stream with [`xrange`](StreamCommands::xrange)
+    /// without receiving the last entry of the previous page again.
+    #[must_use]
+    pub fn next(&self) -> Option<StreamId> {
+        match self.seq.checked_add(1) {
+            Some(seq) => Some(StreamId::new(self.ms, seq)),
+            None => Some(StreamId::new(self.ms.checked_add(1)?, 0)),
+        }
+    }
+
+    /// Greatest ID smaller than this one, `None` for [`StreamId::MIN`]
+    ///
+    /// Useful to iterate backward with [`xrevrange`](StreamCommands::xrevrange).
+    #[must_use]
+    pub fn prev(&self) -> Option<StreamId> {
+        match self.seq.checked_sub(1) {
+            Some(seq) => Some(StreamId::new(self.ms, seq)),
+            None => Some(StreamId::new(self.ms.checked_sub(1)?, u64::MAX)),
+        }
+    }
+}
+
+impl std::fmt::Display for StreamId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.ms, self.seq)
+    }
+}
+
+impl FromStr for StreamId {
+    type Err = Error;
+
+    /// Parse an ID in the format `<ms>-<seq>`, or `<ms>` with a sequence number of `0`
+    fn from_str(s: &str) -> Result<Self> {
+        let parse_part = |part: &str| {
+            part.parse::<u64>()
+                .map_err(|_| Error::Client(format!("Cannot parse stream id `{s}`")))
+        };
+
+        match s.split_once('-') {
+            Some((ms, seq)) => Ok(StreamId::new(parse_part(ms)?, parse_part(seq)?)),
+            None => Ok(StreamId::new(parse_part(s)?, 0)),
+        }
+    }
+}
+
+impl SingleArg for StreamId {}
+
+impl ToArgs for StreamId {
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(self.to_string());
+    }
+}
+
+impl PrimitiveResponse for StreamId {}
+
+impl<'de> Deserialize<'de> for StreamId {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let id = String::deserialize(deserializer)?;
+        id.parse()
+            .map_err(|_| de::Error::invalid_value(Unexpected::Str(&id), &"<ms>-<seq>"))
+    }
+}
+
+/// Position in a stream, either an explicit [`StreamId`] or one of the special IDs
+/// understood by the stream commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamPosition {
+    /// An explicit ID
+    Id(StreamId),
+    /// `-`: the smallest ID of the stream, for [`xrange`](StreamCommands::xrange)
+    /// and [`xrevrange`](StreamCommands::xrevrange)
+    Min,
+    /// `+`: the greatest ID of the stream, for [`xrange`](StreamCommands::xrange)
+    /// and [`xrevrange`](StreamCommands::xrevrange)
+    Max,
+    /// `$`: the last ID of the stream, for [`xread`](StreamCommands::xread)
+    /// and [`xgroup_create`](StreamCommands::xgroup_create)
+    Last,
+    /// `>`: the messages never delivered to other consumers,
+    /// for [`xreadgroup`](StreamCommands::xreadgroup)
+    New,
+}
+
+impl From<StreamId> for StreamPosition {
+    fn from(id: StreamId) -> Self {
+        StreamPosition::Id(id)
+    }
+}
+
+impl SingleArg for StreamPosition {}
+
+impl ToArgs for StreamPosition {
+    fn write_args(&self, args: &mut CommandArgs) {
+        match self {
+            StreamPosition::Id(id) => {
+                args.arg(*id);
+            }
+            StreamPosition::Min => {
+                args.arg("-");
+            }
+            StreamPosition::Max => {
+                args.arg("+");
+            }
+            StreamPosition::Last => {
+                args.arg("$");
+            }
+            StreamPosition::New => {
+                args.arg(">");
+            }
+        }
+    }
+}
+
 /// Result for the [`xrange`](StreamCommands::xrange) and other associated commands.
 #[derive(Deserialize)]
 pub struct StreamEntry<V>
@@ -826,6 +1021,11 @@ pub struct XConsumerInfo {
     /// since the consumer last interacted with the server
     #[serde(rename = "idle")]
     pub idle_millis: u64,
+
+    /// the number of milliseconds that have passed since the consumer's last successful interaction,
+    /// or `-1` if the consumer never had one (available since Redis 7.2)
+    #[serde(rename = "inactive")]
+    pub inactive_millis: Option<i64>,
 }
 
 /// Result entry for the [`xinfo_groups`](StreamCommands::xinfo_groups) command.
@@ -915,9 +1115,116 @@ pub struct XStreamInfo {
     /// the ID and field-value tuples of the last entry in the stream
     pub last_entry: StreamEntry<String>,
 
+    /// the ID of the first entry recorded in the stream
     pub recorded_first_entry_id: String,
 }
 
+/// Stream info returned by the [`xinfo_stream_full`](StreamCommands::xinfo_stream_full) command.
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct XStreamFullInfo {
+    /// the number of entries in the stream (see [`xlen`](StreamCommands::xlen))
+    pub length: usize,
+
+    /// the number of keys in the underlying radix data structure
+    pub radix_tree_keys: usize,
+
+    /// the number of nodes in the underlying radix data structure
+    pub radix_tree_nodes: usize,
+
+    /// the ID of the least-recently entry that was added to the stream
+    pub last_generated_id: String,
+
+    /// the maximal entry ID that was deleted from the stream (available since Redis 7.0)
+    pub max_deleted_entry_id: Option<String>,
+
+    /// the count of all entries added to the stream during its lifetime (available since Redis 7.0)
+    pub entries_added: Option<usize>,
+
+    /// the ID of the first entry recorded in the stream (available since Redis 7.0)
+    pub recorded_first_entry_id: Option<String>,
+
+    /// the entries of the stream, in ascending ID order
+    pub entries: Vec<StreamEntry<String>>,
+
+    /// the consumer groups of the stream
+    pub groups: Vec<XGroupFullInfo>,
+}
+
+/// Consumer group info of the [`xinfo_stream_full`](StreamCommands::xinfo_stream_full) command.
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct XGroupFullInfo {
+    /// the consumer group's name
+    pub name: String,
+
+    /// the ID of the last entry delivered the group's consumers
+    pub last_delivered_id: String,
+
+    /// the logical "read counter" of the last entry delivered to group's consumers
+    pub entries_read: Option<usize>,
+
+    /// the number of entries in the stream that are still waiting to be delivered to the group's consumers,
+    /// or a NULL when that number can't be determined.
+    pub lag: Option<usize>,
+
+    /// the length of the group's pending entries list (PEL)
+    pub pel_count: usize,
+
+    /// the pending entries of the group
+    pub pel: Vec<XGroupPendingEntry>,
+
+    /// the consumers of the group
+    pub consumers: Vec<XConsumerFullInfo>,
+}
+
+/// Pending entry of a consumer group, in the [`xinfo_stream_full`](StreamCommands::xinfo_stream_full) command.
+#[derive(Deserialize)]
+pub struct XGroupPendingEntry {
+    /// The id of the message
+    pub id: String,
+    /// The name of the consumer that fetched the message and has still to acknowledge it
+    pub consumer: String,
+    /// The UNIX timestamp in milliseconds of the last delivery of the message
+    pub delivery_time_millis: u64,
+    /// The number of times this message was delivered
+    pub delivery_count: usize,
+}
+
+/// Consumer info of the [`xinfo_stream_full`](StreamCommands::xinfo_stream_full) command.
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct XConsumerFullInfo {
+    /// the consumer's name
+    pub name: String,
+
+    /// the UNIX timestamp in milliseconds of the last attempted interaction of the consumer
+    #[serde(rename = "seen-time")]
+    pub seen_time_millis: u64,
+
+    /// the UNIX timestamp in milliseconds of the last successful interaction of the consumer,
+    /// or `-1` if the consumer never had one (available since Redis 7.2)
+    #[serde(rename = "active-time")]
+    pub active_time_millis: Option<i64>,
+
+    /// the number of pending messages for the consumer
+    pub pel_count: usize,
+
+    /// the pending entries of the consumer
+    pub pel: Vec<XConsumerPendingEntry>,
+}
+
+/// Pending entry of a consumer, in the [`xinfo_stream_full`](StreamCommands::xinfo_stream_full) command.
+#[derive(Deserialize)]
+pub struct XConsumerPendingEntry {
+    /// The id of the message
+    pub id: String,
+    /// The UNIX timestamp in milliseconds of the last delivery of the message
+    pub delivery_time_millis: u64,
+    /// The number of times this message was delivered
+    pub delivery_count: usize,
+}
+
 /// Options for the [`xread`](StreamCommands::xread) command
 #[derive(Default)]
 pub struct XReadOptions {
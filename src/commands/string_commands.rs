@@ -1,15 +1,21 @@
 use crate::{
     client::{prepare_command, PreparedCommand},
+    commands::{ceil_millis, whole_seconds},
     resp::{
         cmd, CollectionResponse, CommandArgs, KeyValueArgsCollection, PrimitiveResponse, SingleArg,
         SingleArgCollection, ToArgs,
     },
 };
 use serde::{
-    de::{self, SeqAccess, Visitor},
+    de::{self, DeserializeOwned, SeqAccess, Visitor},
     Deserialize, Deserializer,
 };
-use std::fmt;
+use std::{
+    collections::HashMap,
+    fmt,
+    hash::{BuildHasher, Hash},
+    time::Duration,
+};
 
 /// A group of Redis commands related to [`Strings`](https://redis.io/docs/data-types/strings/)
 /// # See Also
@@ -227,9 +233,15 @@ pub trait StringCommands<'a> {
     /// # Return
     /// the old value stored at key, or nil when key did not exist.
     ///
+    /// # Legacy
+    /// `GETSET` is deprecated since Redis 6.2, in favor of the `GET` option of `SET`,
+    /// see [`set_get_with_options`](StringCommands::set_get_with_options).
+    /// It is kept for servers older than 6.2.
+    ///
     /// # See Also
     /// [<https://redis.io/commands/getset/>](https://redis.io/commands/getset/)
     #[must_use]
+    #[deprecated(note = "use `set_get_with_options` on Redis 6.2 and later")]
     fn getset<K, V, R>(self, key: K, value: V) -> PreparedCommand<'a, Self, R>
     where
         Self: Sized,
@@ -324,6 +336,26 @@ pub trait StringCommands<'a> {
         prepare_command(self, cmd("INCRBYFLOAT").arg(key).arg(increment))
     }
 
+    /// Increment the floating point number stored at `key` by `increment`,
+    /// like [`incrbyfloat`](StringCommands::incrbyfloat).
+    ///
+    /// The reply is decoded as a `f64` whether the server sends it as a string (RESP2)
+    /// or as a double (RESP3).
+    ///
+    /// # Return
+    /// the value of key after the increment
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/incrbyfloat/>](https://redis.io/commands/incrbyfloat/)
+    #[must_use]
+    fn incr_by_float<K>(self, key: K, increment: f64) -> PreparedCommand<'a, Self, f64>
+    where
+        Self: Sized,
+        K: SingleArg,
+    {
+        self.incrbyfloat(key, increment)
+    }
+
     /// The LCS command implements the longest common subsequence algorithm
     ///
     /// # Return
@@ -411,6 +443,66 @@ pub trait StringCommands<'a> {
         prepare_command(self, cmd("MGET").arg(keys))
     }
 
+    /// Returns the values of all specified keys, associated with their key.
+    ///
+    /// This is the same command as [`mget`](StringCommands::mget), without having to
+    /// correlate the positional results with the requested keys.
+    /// The key type must be owned, e.g. `String` rather than `&str`.
+    ///
+    /// The association is only available when the command is sent directly through a [`Client`](crate::client::Client),
+    /// not within a pipeline or a transaction.
+    ///
+    /// # Return
+    /// A map of the requested keys to their value, `None` for every key that does not hold a string value or does not exist.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{
+    ///     client::Client,
+    ///     commands::{GenericCommands, StringCommands},
+    ///     Result,
+    /// };
+    /// use std::collections::HashMap;
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let client = Client::connect("127.0.0.1:6379").await?;
+    ///     client.set("key1", "value1").await?;
+    ///     client.del("key2").await?;
+    ///
+    ///     let values: HashMap<String, Option<String>> = client
+    ///         .mget_map(["key1".to_owned(), "key2".to_owned()])
+    ///         .await?;
+    ///     assert_eq!(Some(&Some("value1".to_owned())), values.get("key1"));
+    ///     assert_eq!(Some(&None), values.get("key2"));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/mget/>](https://redis.io/commands/mget/)
+    #[must_use]
+    fn mget_map<K, KK, V>(self, keys: KK) -> PreparedCommand<'a, Self, HashMap<K, Option<V>>>
+    where
+        Self: Sized,
+        K: SingleArg + DeserializeOwned + Eq + Hash + Clone + Send + Sync + 'static,
+        KK: IntoIterator<Item = K>,
+        V: PrimitiveResponse + DeserializeOwned + Send + 'a,
+    {
+        let keys: Vec<K> = keys.into_iter().collect();
+        prepare_command(self, cmd("MGET").arg(keys.clone())).custom_converter(Box::new(
+            move |resp_buffer, _command, _client| {
+                let keys = keys.clone();
+                Box::pin(async move {
+                    let values: Vec<Option<V>> = resp_buffer.to()?;
+                    Ok(keys.into_iter().zip(values).collect())
+                })
+            },
+        ))
+    }
+
     /// Sets the given keys to their respective values.
     ///
     /// # Return
@@ -429,6 +521,27 @@ pub trait StringCommands<'a> {
         prepare_command(self, cmd("MSET").arg(items))
     }
 
+    /// Sets the keys of the map to their respective values.
+    ///
+    /// This is the same command as [`mset`](StringCommands::mset), named after
+    /// [`mget_map`](StringCommands::mget_map) to make the round trip explicit.
+    ///
+    /// # Return
+    /// always OK since MSET can't fail.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/mset/>](https://redis.io/commands/mset/)
+    #[must_use]
+    fn mset_map<K, V, S>(self, items: HashMap<K, V, S>) -> PreparedCommand<'a, Self, ()>
+    where
+        Self: Sized,
+        K: SingleArg,
+        V: SingleArg,
+        S: BuildHasher,
+    {
+        prepare_command(self, cmd("MSET").arg(items))
+    }
+
     /// Sets the given keys to their respective values.
     /// MSETNX will not perform any operation at all even if just a single key already exists.
     ///
@@ -463,9 +576,14 @@ pub trait StringCommands<'a> {
     /// If key already holds a value, it is overwritten, regardless of its type.
     /// Any previous time to live associated with the key is discarded on successful SET operation.
     ///
+    /// # Legacy
+    /// `PSETEX` is deprecated since Redis 2.6.12, in favor of the `PX` option of `SET`,
+    /// see [`set_with_expiry`](StringCommands::set_with_expiry).
+    ///
     /// # See Also
     /// [<https://redis.io/commands/psetex/>](https://redis.io/commands/psetex/)
     #[must_use]
+    #[deprecated(note = "use `set_with_expiry` or `set_with_options` instead")]
     fn psetex<K, V>(self, key: K, milliseconds: u64, value: V) -> PreparedCommand<'a, Self, ()>
     where
         Self: Sized,
@@ -558,11 +676,43 @@ pub trait StringCommands<'a> {
         )
     }
 
+    /// Set key to hold the string value and set key to timeout after a given [`Duration`](std::time::Duration).
+    ///
+    /// The `EX` option is sent when the duration is a whole number of seconds, `PX` otherwise.
+    /// Sub-millisecond precision is rounded up to the next millisecond.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/set/>](https://redis.io/commands/set/)
+    #[must_use]
+    fn set_with_expiry<K, V>(
+        self,
+        key: K,
+        value: V,
+        expiry: Duration,
+    ) -> PreparedCommand<'a, Self, ()>
+    where
+        Self: Sized,
+        K: SingleArg,
+        V: SingleArg,
+    {
+        let command = cmd("SET").arg(key).arg(value);
+        let command = match whole_seconds(expiry) {
+            Some(seconds) => command.arg("EX").arg(seconds),
+            None => command.arg("PX").arg(ceil_millis(expiry)),
+        };
+        prepare_command(self, command)
+    }
+
     /// Set key to hold the string value and set key to timeout after a given number of seconds.
     ///
+    /// # Legacy
+    /// `SETEX` is deprecated since Redis 2.6.12, in favor of the `EX` option of `SET`,
+    /// see [`set_with_expiry`](StringCommands::set_with_expiry).
+    ///
     /// # See Also
     /// [<https://redis.io/commands/setex/>](https://redis.io/commands/setex/)
     #[must_use]
+    #[deprecated(note = "use `set_with_expiry` or `set_with_options` instead")]
     fn setex<K, V>(self, key: K, seconds: u64, value: V) -> PreparedCommand<'a, Self, ()>
     where
         Self: Sized,
@@ -583,9 +733,14 @@ pub trait StringCommands<'a> {
     /// * `true` - if the key was set
     /// * `false` - if the key was not set
     ///
+    /// # Legacy
+    /// `SETNX` is deprecated since Redis 2.6.12, in favor of the `NX` option of `SET`,
+    /// see [`set_with_options`](StringCommands::set_with_options) with [`SetCondition::NX`].
+    ///
     /// # See Also
     /// [<https://redis.io/commands/setnx/>](https://redis.io/commands/setnx/)
     #[must_use]
+    #[deprecated(note = "use `set_with_options` with `SetCondition::NX` instead")]
     fn setnx<K, V>(self, key: K, value: V) -> PreparedCommand<'a, Self, bool>
     where
         Self: Sized,
@@ -659,10 +814,34 @@ impl ToArgs for GetExOptions {
     }
 }
 
-/// Part of the result for the [`lcs`](StringCommands::lcs) command
+/// Part of the result for the [`lcs_idx`](StringCommands::lcs_idx) command
+///
+/// Holds the inclusive range of the match in the first key,
+/// the inclusive range of the match in the second key,
+/// and the length of the match when `WITHMATCHLEN` is requested.
 #[derive(Debug, PartialEq, Eq)]
 pub struct LcsMatch(pub (usize, usize), pub (usize, usize), pub Option<usize>);
 
+impl LcsMatch {
+    /// Inclusive range of the match in the first key
+    #[must_use]
+    pub fn first_range(&self) -> (usize, usize) {
+        self.0
+    }
+
+    /// Inclusive range of the match in the second key
+    #[must_use]
+    pub fn second_range(&self) -> (usize, usize) {
+        self.1
+    }
+
+    /// Length of the match, if `WITHMATCHLEN` has been requested
+    #[must_use]
+    pub fn match_len(&self) -> Option<usize> {
+        self.2
+    }
+}
+
 impl<'de> Deserialize<'de> for LcsMatch {
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -699,10 +878,12 @@ impl<'de> Deserialize<'de> for LcsMatch {
     }
 }
 
-/// Result for the [`lcs`](StringCommands::lcs) command
+/// Result for the [`lcs_idx`](StringCommands::lcs_idx) command
 #[derive(Debug, Deserialize)]
 pub struct LcsResult {
+    /// Matching ranges, from the last to the first one
     pub matches: Vec<LcsMatch>,
+    /// Length of the longest common subsequence
     pub len: usize,
 }
 
@@ -42,6 +42,30 @@ pub enum Error {
     Sentinel(String),
     /// Error returned by the Redis sercer
     Redis(RedisError),
+    /// The hash slot of the key is served by another node of the cluster
+    ///
+    /// Returned by the Redis server with a `MOVED` error,
+    /// when a command is sent to the wrong node of a cluster.
+    /// Cluster clients follow the redirection transparently.
+    Moved {
+        /// Hash slot of the key
+        hash_slot: u16,
+        /// Host and port of the node now serving the hash slot
+        address: (String, u16),
+    },
+    /// The hash slot of the key is being migrated to another node of the cluster
+    ///
+    /// Returned by the Redis server with an `ASK` error,
+    /// the command must be sent again to the target node, preceded by `ASKING`.
+    /// Cluster clients follow the redirection transparently.
+    Ask {
+        /// Hash slot of the key
+        hash_slot: u16,
+        /// Host and port of the node importing the hash slot
+        address: (String, u16),
+    },
+    /// Malformed RESP data received from the Redis server
+    Protocol(String),
     /// IO error when connecting the Redis server
     IO(String),
     #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
@@ -50,6 +74,38 @@ pub enum Error {
     Tls(String),
     /// The I/O operation’s timeout expired
     Timeout(String),
+    /// The command, or one of its options, is not supported by the version of the Redis server
+    ///
+    /// See [`ServerFeatures`](crate::client::ServerFeatures)
+    UnsupportedByServer(String),
+    /// The payload of a [`KeyDump`](crate::utils::KeyDump) is corrupted
+    /// or cannot be restored by the server
+    InvalidDump(String),
+    /// The limits of in-flight commands of the connection are reached
+    /// and [`Config::backpressure_fail_fast`](crate::client::Config::backpressure_fail_fast) is set
+    Overloaded(String),
+    /// A write has been applied on the master but acknowledged by fewer replicas than required
+    /// by the write concern of the client
+    ///
+    /// See [`Client::with_write_concern`](crate::client::Client::with_write_concern)
+    WriteConcern {
+        /// Number of replicas required to acknowledge the write
+        num_replicas: usize,
+        /// Number of replicas which acknowledged the write before the timeout
+        acknowledged: usize,
+    },
+    /// A reply received from the server exceeds one of the response limits of the
+    /// [`Config`](crate::client::Config), e.g. [`max_reply_size`](crate::client::Config::max_reply_size)
+    ///
+    /// The connection is closed, since the rest of the reply cannot be skipped safely.
+    ResponseLimitExceeded {
+        /// Name of the exceeded limit in the [`Config`](crate::client::Config)
+        limit: &'static str,
+        /// Configured maximum
+        max: usize,
+        /// Value announced or received from the server
+        actual: usize,
+    },
     /// Internal error to trigger retry sending the command
     #[doc(hidden)]
     Retry(SmallVec<[RetryReason; 1]>),
@@ -66,16 +122,127 @@ impl std::fmt::Display for Error {
             Error::Aborted => f.write_fmt(format_args!("Transaction aborted")),
             Error::Sentinel(e) => f.write_fmt(format_args!("Sentinel error: {}", e)),
             Error::Redis(e) => f.write_fmt(format_args!("Redis error: {}", e)),
+            Error::Moved {
+                hash_slot,
+                address: (host, port),
+            } => f.write_fmt(format_args!(
+                "Redis error: hash slot {hash_slot} moved to {host}:{port}"
+            )),
+            Error::Ask {
+                hash_slot,
+                address: (host, port),
+            } => f.write_fmt(format_args!(
+                "Redis error: hash slot {hash_slot} migrating to {host}:{port}"
+            )),
+            Error::Protocol(e) => f.write_fmt(format_args!("Protocol error: {}", e)),
             Error::IO(e) => f.write_fmt(format_args!("IO error: {}", e)),
             #[cfg(feature = "tls")]
             Error::Tls(e) => f.write_fmt(format_args!("Tls error: {}", e)),
             Error::Retry(r) => f.write_fmt(format_args!("Retry: {:?}", r)),
             Error::Timeout(e) => f.write_fmt(format_args!("Timeout error: {}", e)),
+            Error::UnsupportedByServer(e) => {
+                f.write_fmt(format_args!("Unsupported by server: {}", e))
+            }
+            Error::InvalidDump(e) => f.write_fmt(format_args!("Invalid dump: {}", e)),
+            Error::Overloaded(e) => f.write_fmt(format_args!("Overloaded: {}", e)),
+            Error::WriteConcern {
+                num_replicas,
+                acknowledged,
+            } => f.write_fmt(format_args!(
+                "Write concern error: {acknowledged} replica(s) acknowledged the write, {num_replicas} required"
+            )),
+            Error::ResponseLimitExceeded { limit, max, actual } => f.write_fmt(format_args!(
+                "Response limit exceeded: {limit} is {max}, received {actual}"
+            )),
             Error::EOF => f.write_str("EOF error"),
         }
     }
 }
 
+impl Error {
+    /// Returns the kind of the error returned by the Redis server,
+    /// including the [`Moved`](Error::Moved) and [`Ask`](Error::Ask) redirections,
+    /// or `None` if the error has been raised by the driver
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{Error, RedisError, RedisErrorKind};
+    /// use std::str::FromStr;
+    ///
+    /// // converted to `Error::Moved`
+    /// let error = Error::from(RedisError::from_str("MOVED 3999 127.0.0.1:6381").unwrap());
+    /// assert!(matches!(
+    ///     error.redis_kind(),
+    ///     Some(RedisErrorKind::Moved { hash_slot: 3999, .. })
+    /// ));
+    /// ```
+    #[must_use]
+    pub fn redis_kind(&self) -> Option<RedisErrorKind> {
+        match self {
+            Error::Redis(e) => Some(e.kind.clone()),
+            Error::Moved { hash_slot, address } => Some(RedisErrorKind::Moved {
+                hash_slot: *hash_slot,
+                address: address.clone(),
+            }),
+            Error::Ask { hash_slot, address } => Some(RedisErrorKind::Ask {
+                hash_slot: *hash_slot,
+                address: address.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the command has been rejected by the Redis server
+    /// because its memory usage has reached the `maxmemory` limit
+    /// (error kind [`OutOfMemory`](RedisErrorKind::OutOfMemory)).
+    ///
+    /// Unlike network errors, such errors are not transient on the scale of a retry:
+    /// callers are expected to shed their write load until the server frees memory.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{Error, RedisError};
+    /// use std::str::FromStr;
+    ///
+    /// let error = Error::Redis(
+    ///     RedisError::from_str("OOM command not allowed when used memory > 'maxmemory'.").unwrap(),
+    /// );
+    /// assert!(error.is_out_of_memory());
+    /// ```
+    #[must_use]
+    pub fn is_out_of_memory(&self) -> bool {
+        matches!(self.redis_kind(), Some(RedisErrorKind::OutOfMemory))
+    }
+
+    /// Convert an error returned by the Redis server, including redirections,
+    /// back to a [`RedisError`]
+    pub(crate) fn into_redis_error(self) -> std::result::Result<RedisError, Error> {
+        match self {
+            Error::Redis(e) => Ok(e),
+            Error::Moved { hash_slot, address } => Ok(RedisError {
+                kind: RedisErrorKind::Moved { hash_slot, address },
+                description: String::new(),
+            }),
+            Error::Ask { hash_slot, address } => Ok(RedisError {
+                kind: RedisErrorKind::Ask { hash_slot, address },
+                description: String::new(),
+            }),
+            e => Err(e),
+        }
+    }
+}
+
+impl From<RedisError> for Error {
+    /// `MOVED` and `ASK` redirections are converted to [`Error::Moved`] and [`Error::Ask`]
+    fn from(e: RedisError) -> Self {
+        match e.kind {
+            RedisErrorKind::Moved { hash_slot, address } => Error::Moved { hash_slot, address },
+            RedisErrorKind::Ask { hash_slot, address } => Error::Ask { hash_slot, address },
+            _ => Error::Redis(e),
+        }
+    }
+}
+
 impl serde::de::Error for Error {
     fn custom<T>(msg: T) -> Self
     where
@@ -164,12 +331,16 @@ pub enum RedisErrorKind {
         hash_slot: u16,
         address: (String, u16),
     },
+    Busy,
     BusyGroup,
+    BusyKey,
     ClusterDown,
     CrossSlot,
     Err,
+    ExecAbort,
     InProg,
     IoErr,
+    Loading,
     MasterDown,
     MisConf,
     Moved {
@@ -182,8 +353,12 @@ pub enum RedisErrorKind {
     NoPerm,
     NoProto,
     NoQuorum,
+    NoReplicas,
     NotBusy,
     NoScript,
+    /// Command rejected because the memory usage of the server has reached the `maxmemory` limit
+    ///
+    /// See [`Error::is_out_of_memory`]
     OutOfMemory,
     Readonly,
     TryAgain,
@@ -214,12 +389,16 @@ impl FromStr for RedisErrorKind {
 
     fn from_str(str: &str) -> Result<Self> {
         match str {
+            "BUSY" => Ok(Self::Busy),
             "BUSYGROUP" => Ok(Self::BusyGroup),
+            "BUSYKEY" => Ok(Self::BusyKey),
             "CLUSTERDOWN" => Ok(Self::ClusterDown),
             "CROSSSLOT" => Ok(Self::CrossSlot),
             "ERR" => Ok(Self::Err),
+            "EXECABORT" => Ok(Self::ExecAbort),
             "INPROG" => Ok(Self::InProg),
             "IOERR" => Ok(Self::IoErr),
+            "LOADING" => Ok(Self::Loading),
             "MASTERDOWN" => Ok(Self::MasterDown),
             "MISCONF" => Ok(Self::MisConf),
             "NOAUTH" => Ok(Self::NoAuth),
@@ -228,6 +407,7 @@ impl FromStr for RedisErrorKind {
             "NOPERM" => Ok(Self::NoPerm),
             "NOPROTO" => Ok(Self::NoProto),
             "NOQUORUM" => Ok(Self::NoQuorum),
+            "NOREPLICAS" => Ok(Self::NoReplicas),
             "NOTBUSY" => Ok(Self::NotBusy),
             "NOSCRIPT" => Ok(Self::NoScript),
             "OOM" => Ok(Self::OutOfMemory),
@@ -262,12 +442,16 @@ impl Display for RedisErrorKind {
                 hash_slot,
                 address: (host, port),
             } => f.write_fmt(format_args!("ASK {} {}:{}", *hash_slot, *host, *port)),
+            RedisErrorKind::Busy => f.write_str("BUSY"),
             RedisErrorKind::BusyGroup => f.write_str("BUSYGROUP"),
+            RedisErrorKind::BusyKey => f.write_str("BUSYKEY"),
             RedisErrorKind::ClusterDown => f.write_str("CLUSTERDOWN"),
             RedisErrorKind::CrossSlot => f.write_str("CROSSSLOT"),
             RedisErrorKind::Err => f.write_str("ERR"),
+            RedisErrorKind::ExecAbort => f.write_str("EXECABORT"),
             RedisErrorKind::InProg => f.write_str("INPROG"),
             RedisErrorKind::IoErr => f.write_str("IOERR"),
+            RedisErrorKind::Loading => f.write_str("LOADING"),
             RedisErrorKind::MasterDown => f.write_str("MASTERDOWN"),
             RedisErrorKind::MisConf => f.write_str("MISCONF"),
             RedisErrorKind::Moved {
@@ -280,6 +464,7 @@ impl Display for RedisErrorKind {
             RedisErrorKind::NoPerm => f.write_str("NOPERM"),
             RedisErrorKind::NoProto => f.write_str("NOPROTO"),
             RedisErrorKind::NoQuorum => f.write_str("NOQUORUM"),
+            RedisErrorKind::NoReplicas => f.write_str("NOREPLICAS"),
             RedisErrorKind::NotBusy => f.write_str("NOTBUSY"),
             RedisErrorKind::NoScript => f.write_str("NOSCRIPT"),
             RedisErrorKind::OutOfMemory => f.write_str("OOM"),
@@ -39,6 +39,16 @@ rustis is a Redis client for Rust.
 | `tokio-tls` | Tokio TLS support (optional) |
 | `async-std-tls` | async-std TLS support (optional) |
 | `pool` | Pooled client manager (optional) |
+| `locks` | [Distributed locks](locks) (optional) |
+| `sessions` | [Session store](sessions) for web frameworks (optional) |
+| `test-server` | Temporary `redis-server` process for [tests](testing) (optional) |
+| `cli` | `rustis-cli` example, an interactive prompt similar to `redis-cli` (optional) |
+| `cache-json` | JSON serialization for the [cache](utils::Cache) (optional) |
+| `cache-msgpack` | MessagePack serialization for the [cache](utils::Cache) (optional) |
+| `cache-bincode` | bincode serialization for the [cache](utils::Cache) (optional) |
+| `cache-compression` | zlib compression of the [cache](utils::Cache) values (optional) |
+| `export` | JSON and CSV [export](utils::export_keys) of the keyspace (optional) |
+| `import` | [Replay](utils::import_commands) of command files, for seeding test environments (optional) |
 | `redis-json` | [RedisJSON v2.4](https://redis.io/docs/stack/json/) support (optional) |
 | `redis-search` | [RedisSearch v2.6](https://redis.io/docs/stack/search/) support (optional) |
 | `redis-graph` | [RedisGraph v2.10](https://redis.io/docs/stack/graph/) support (optional) |
@@ -155,10 +165,19 @@ async fn main() -> Result<()> {
 */
 
 pub mod client;
+pub mod cluster;
 pub mod commands;
 mod error;
+#[cfg_attr(docsrs, doc(cfg(feature = "locks")))]
+#[cfg(feature = "locks")]
+pub mod locks;
 mod network;
 pub mod resp;
+#[cfg_attr(docsrs, doc(cfg(feature = "sessions")))]
+#[cfg(feature = "sessions")]
+pub mod sessions;
+pub mod testing;
+pub mod utils;
 
 #[cfg(feature = "pool")]
 pub use bb8;
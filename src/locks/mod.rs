@@ -0,0 +1,91 @@
+/*!
+Distributed locks built on Redis
+
+# Single instance lock
+
+[`RedisLock`] implements the [single instance locking pattern](https://redis.io/docs/manual/patterns/distributed-locks/#correct-implementation-with-a-single-instance):
+* the lock is acquired with `SET key token NX PX ttl`, where `token` is a random value unique to the lock owner,
+* the lock is released or extended with a Lua script which checks the token first,
+  so a client can never release a lock acquired by another client.
+
+```
+use rustis::{client::Client, locks::RedisLock, Result};
+use std::time::Duration;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::main)]
+#[cfg_attr(feature = "async-std-runtime", async_std::main)]
+async fn main() -> Result<()> {
+    let client = Client::connect("127.0.0.1:6379").await?;
+
+    let lock = RedisLock::acquire(&client, "my_resource", Duration::from_secs(10)).await?;
+    let Some(lock) = lock else {
+        // the resource is already locked by another client
+        return Ok(());
+    };
+
+    // work on the resource...
+
+    lock.release().await?;
+
+    Ok(())
+}
+```
+
+# Redlock
+
+[`Redlock`] implements the [Redlock algorithm](https://redis.io/docs/manual/patterns/distributed-locks/#the-redlock-algorithm)
+over several independent Redis instances: the lock is acquired when a majority of instances
+have been locked within the validity time of the lock.
+*/
+
+mod redis_lock;
+mod redlock;
+
+pub use redis_lock::*;
+pub use redlock::*;
+
+use crate::{
+    client::Client,
+    commands::{SetCondition, SetExpiration, StringCommands},
+    utils::Script,
+    Result,
+};
+use rand::Rng;
+use std::{sync::OnceLock, time::Duration};
+
+const EXTEND_LOCK: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+return 0
+"#;
+
+static EXTEND_LOCK_SCRIPT: OnceLock<Script> = OnceLock::new();
+
+fn generate_token() -> String {
+    let bytes: [u8; 20] = rand::thread_rng().gen();
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+async fn try_lock(client: &Client, key: &str, token: &str, ttl: Duration) -> Result<bool> {
+    client
+        .set_with_options(
+            key,
+            token,
+            SetCondition::NX,
+            SetExpiration::Px(ttl.as_millis() as u64),
+            false,
+        )
+        .await
+}
+
+async fn extend_lock(client: &Client, key: &str, token: &str, ttl: Duration) -> Result<bool> {
+    EXTEND_LOCK_SCRIPT
+        .get_or_init(|| Script::new(EXTEND_LOCK))
+        .call(
+            client,
+            key,
+            [token.to_owned(), (ttl.as_millis() as u64).to_string()],
+        )
+        .await
+}
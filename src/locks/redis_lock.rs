@@ -0,0 +1,115 @@
+use crate::{
+    client::Client,
+    locks::{extend_lock, generate_token, try_lock},
+    sleep,
+    utils::AtomicCommands,
+    Result,
+};
+use std::time::Duration;
+
+/// A lock held on a single Redis instance.
+///
+/// The lock is identified by a key and a random token.
+/// It expires automatically after its time to live,
+/// unless it is [extended](RedisLock::extend) or [released](RedisLock::release) before.
+///
+/// # See Also
+/// [Distributed Locks with Redis](https://redis.io/docs/manual/patterns/distributed-locks/)
+pub struct RedisLock {
+    client: Client,
+    key: String,
+    token: String,
+}
+
+impl RedisLock {
+    /// Try to acquire the lock once.
+    ///
+    /// # Return
+    /// The acquired lock or `None` if the lock is already held by another owner.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn acquire(
+        client: &Client,
+        key: impl Into<String>,
+        ttl: Duration,
+    ) -> Result<Option<RedisLock>> {
+        Self::acquire_with_retry(client, key, ttl, 0, Duration::ZERO).await
+    }
+
+    /// Try to acquire the lock, retrying up to `retry_count` times
+    /// with a delay of `retry_delay` between each attempt.
+    ///
+    /// # Return
+    /// The acquired lock or `None` if the lock could not be acquired after all the attempts.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn acquire_with_retry(
+        client: &Client,
+        key: impl Into<String>,
+        ttl: Duration,
+        retry_count: usize,
+        retry_delay: Duration,
+    ) -> Result<Option<RedisLock>> {
+        let key = key.into();
+        let token = generate_token();
+
+        for attempt in 0..=retry_count {
+            if attempt > 0 {
+                sleep(retry_delay).await;
+            }
+
+            if try_lock(client, &key, &token, ttl).await? {
+                return Ok(Some(RedisLock {
+                    client: client.clone(),
+                    key,
+                    token,
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Key of the lock
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Random token identifying the owner of the lock
+    #[must_use]
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Reset the time to live of the lock, if it is still held by this owner.
+    ///
+    /// # Return
+    /// `true` if the lock has been extended, `false` if the lock has expired
+    /// or has been acquired by another owner in the meantime.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn extend(&self, ttl: Duration) -> Result<bool> {
+        extend_lock(&self.client, &self.key, &self.token, ttl).await
+    }
+
+    /// Release the lock, if it is still held by this owner.
+    ///
+    /// The release is sent as a [high priority](crate::resp::Command::high_priority) command.
+    ///
+    /// # Return
+    /// `true` if the lock has been released, `false` if the lock has expired
+    /// or has been acquired by another owner in the meantime.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn release(self) -> Result<bool> {
+        self.client
+            .compare_and_delete(self.key.as_str(), self.token.as_str())
+            .high_priority()
+            .await
+    }
+}
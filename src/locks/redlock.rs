@@ -0,0 +1,253 @@
+use crate::{
+    client::Client,
+    locks::{extend_lock, generate_token, try_lock},
+    sleep,
+    utils::AtomicCommands,
+    Result,
+};
+use futures_util::future::join_all;
+use log::debug;
+use rand::Rng;
+use std::{
+    future::IntoFuture,
+    time::{Duration, Instant},
+};
+
+/// Implementation of the [Redlock algorithm](https://redis.io/docs/manual/patterns/distributed-locks/#the-redlock-algorithm)
+/// over several independent Redis instances.
+///
+/// Each [`Client`] must be connected to a different, independent, Redis master.
+/// A lock is acquired when it has been set on a majority of the instances
+/// and when its remaining validity time is positive.
+///
+/// Errors returned by an instance are not propagated: the instance is simply not counted
+/// in the majority.
+///
+/// # Example
+/// ```no_run
+/// use rustis::{client::Client, locks::Redlock, Result};
+/// use std::time::Duration;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let redlock = Redlock::new(vec![
+///         Client::connect("redis1:6379").await?,
+///         Client::connect("redis2:6379").await?,
+///         Client::connect("redis3:6379").await?,
+///     ]);
+///
+///     if let Some(guard) = redlock.lock("my_resource", Duration::from_secs(10)).await? {
+///         // work on the resource for less than guard.validity()...
+///         guard.release().await;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub struct Redlock {
+    clients: Vec<Client>,
+    retry_count: usize,
+    retry_delay: Duration,
+    clock_drift_factor: f64,
+}
+
+impl Redlock {
+    /// Default number of retries when the lock cannot be acquired.
+    pub const DEFAULT_RETRY_COUNT: usize = 3;
+    /// Default maximum delay between two attempts.
+    pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(200);
+    /// Default clock drift factor, relative to the time to live of the lock.
+    pub const DEFAULT_CLOCK_DRIFT_FACTOR: f64 = 0.01;
+
+    /// Create a new Redlock manager from clients connected to independent Redis instances
+    #[must_use]
+    pub fn new(clients: Vec<Client>) -> Self {
+        Self {
+            clients,
+            retry_count: Self::DEFAULT_RETRY_COUNT,
+            retry_delay: Self::DEFAULT_RETRY_DELAY,
+            clock_drift_factor: Self::DEFAULT_CLOCK_DRIFT_FACTOR,
+        }
+    }
+
+    /// Number of retries when the lock cannot be acquired
+    #[must_use]
+    pub fn retry_count(mut self, retry_count: usize) -> Self {
+        self.retry_count = retry_count;
+        self
+    }
+
+    /// Maximum delay between two attempts.
+    ///
+    /// The actual delay is randomized between zero and this value
+    /// to desynchronize clients competing for the same lock.
+    #[must_use]
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Clock drift factor, relative to the time to live of the lock
+    ///
+    /// # Panics
+    /// If the factor is not in the range `0.0..1.0`
+    #[must_use]
+    pub fn clock_drift_factor(mut self, clock_drift_factor: f64) -> Self {
+        assert!(
+            (0.0..1.0).contains(&clock_drift_factor),
+            "clock drift factor must be in the range 0.0..1.0, got {clock_drift_factor}"
+        );
+        self.clock_drift_factor = clock_drift_factor;
+        self
+    }
+
+    /// Try to acquire the lock on a majority of instances.
+    ///
+    /// # Return
+    /// A guard on the acquired lock or `None` if the lock could not be acquired after all the retries.
+    ///
+    /// # Errors
+    /// None: an instance which returns an error is not counted in the majority
+    pub async fn lock(
+        &self,
+        key: impl Into<String>,
+        ttl: Duration,
+    ) -> Result<Option<RedlockGuard>> {
+        let key = key.into();
+        let token = generate_token();
+
+        for attempt in 0..=self.retry_count {
+            if attempt > 0 {
+                let delay = rand::thread_rng().gen_range(0..=self.retry_delay.as_millis() as u64);
+                sleep(Duration::from_millis(delay)).await;
+            }
+
+            let start = Instant::now();
+            let num_locked = join_all(
+                self.clients
+                    .iter()
+                    .map(|client| try_lock(client, &key, &token, ttl)),
+            )
+            .await
+            .into_iter()
+            .filter(|result| match result {
+                Ok(locked) => *locked,
+                Err(e) => {
+                    debug!("[{key}] Cannot lock instance: {e}");
+                    false
+                }
+            })
+            .count();
+
+            let elapsed = start.elapsed() + drift(ttl, self.clock_drift_factor);
+            if has_quorum(num_locked, self.clients.len()) && elapsed < ttl {
+                return Ok(Some(RedlockGuard {
+                    clients: self.clients.clone(),
+                    key,
+                    token,
+                    validity: ttl - elapsed,
+                    clock_drift_factor: self.clock_drift_factor,
+                }));
+            }
+
+            unlock_instances(&self.clients, &key, &token).await;
+        }
+
+        Ok(None)
+    }
+}
+
+/// A lock acquired with [`Redlock::lock`]
+pub struct RedlockGuard {
+    clients: Vec<Client>,
+    key: String,
+    token: String,
+    validity: Duration,
+    clock_drift_factor: f64,
+}
+
+impl RedlockGuard {
+    /// Key of the lock
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Random token identifying the owner of the lock
+    #[must_use]
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Time during which the lock is considered valid,
+    /// computed when the lock was acquired or extended for the last time.
+    #[must_use]
+    pub fn validity(&self) -> Duration {
+        self.validity
+    }
+
+    /// Reset the time to live of the lock on all instances.
+    ///
+    /// # Return
+    /// `true` if the lock has been extended on a majority of instances,
+    /// in which case [`validity`](RedlockGuard::validity) is updated.
+    ///
+    /// # Errors
+    /// None: an instance which returns an error is not counted in the majority
+    pub async fn extend(&mut self, ttl: Duration) -> Result<bool> {
+        let start = Instant::now();
+        let num_extended = join_all(
+            self.clients
+                .iter()
+                .map(|client| extend_lock(client, &self.key, &self.token, ttl)),
+        )
+        .await
+        .into_iter()
+        .filter(|result| match result {
+            Ok(extended) => *extended,
+            Err(e) => {
+                debug!("[{}] Cannot extend lock on instance: {e}", self.key);
+                false
+            }
+        })
+        .count();
+
+        let elapsed = start.elapsed() + drift(ttl, self.clock_drift_factor);
+        if has_quorum(num_extended, self.clients.len()) && elapsed < ttl {
+            self.validity = ttl - elapsed;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Release the lock on all instances
+    pub async fn release(self) {
+        unlock_instances(&self.clients, &self.key, &self.token).await;
+    }
+}
+
+fn drift(ttl: Duration, clock_drift_factor: f64) -> Duration {
+    // add 2 milliseconds to the drift to account for Redis expires precision
+    ttl.mul_f64(clock_drift_factor) + Duration::from_millis(2)
+}
+
+fn has_quorum(num_instances: usize, total_instances: usize) -> bool {
+    num_instances > total_instances / 2
+}
+
+async fn unlock_instances(clients: &[Client], key: &str, token: &str) {
+    for result in join_all(clients.iter().map(|client| {
+        client
+            .compare_and_delete(key, token)
+            .high_priority()
+            .into_future()
+    }))
+    .await
+    {
+        if let Err(e) = result {
+            debug!("[{key}] Cannot unlock instance: {e}");
+        }
+    }
+}
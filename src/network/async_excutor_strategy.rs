@@ -1,15 +1,33 @@
 #[cfg(feature = "tls")]
 use crate::client::TlsConfig;
-use crate::{client::Config, Error, Result};
-use futures_util::{Future, FutureExt};
+use crate::{
+    client::{Config, ProxyConfig},
+    network::proxy_handshake,
+    Error, Result,
+};
+use futures_util::{
+    future::{self, Either},
+    stream::FuturesUnordered,
+    Future, FutureExt, StreamExt,
+};
 use log::{debug, info};
 use socket2::{SockRef, TcpKeepalive};
 use std::{
+    net::SocketAddr,
     pin::Pin,
     task::{Context, Poll},
     time::Duration,
 };
 
+#[cfg(feature = "tokio-runtime")]
+type TcpStream = tokio::net::TcpStream;
+#[cfg(feature = "async-std-runtime")]
+type TcpStream = async_std::net::TcpStream;
+
+/// Delay after which a connection attempt to the next address is started
+/// while the previous attempts are still in progress (see [RFC 8305](https://www.rfc-editor.org/rfc/rfc8305))
+const CONNECTION_ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
 #[cfg(feature = "tokio-runtime")]
 pub(crate) type TcpStreamReader = tokio::io::ReadHalf<tokio::net::TcpStream>;
 #[cfg(feature = "tokio-runtime")]
@@ -53,7 +71,7 @@ pub(crate) async fn tcp_connect(
     {
         let stream = timeout(
             config.connect_timeout,
-            tokio::net::TcpStream::connect((host, port)),
+            tcp_stream_connect(host, port, config.proxy.as_ref()),
         )
         .await??;
 
@@ -74,7 +92,7 @@ pub(crate) async fn tcp_connect(
 
         let stream = timeout(
             config.connect_timeout,
-            async_std::net::TcpStream::connect((host, port)),
+            tcp_stream_connect(host, port, config.proxy.as_ref()),
         )
         .await??;
 
@@ -101,9 +119,12 @@ pub(crate) async fn tcp_tls_connect(
     host: &str,
     port: u16,
     tls_config: &TlsConfig,
-    connect_timeout: Duration,
+    config: &Config,
 ) -> Result<(TcpTlsStreamReader, TcpTlsStreamWriter)> {
-    debug!("Connecting to {host}:{port} with timeout {connect_timeout:?}...");
+    debug!(
+        "Connecting to {host}:{port} with timeout {:?}...",
+        config.connect_timeout
+    );
 
     let reader: TcpTlsStreamReader;
     let writer: TcpTlsStreamWriter;
@@ -113,8 +134,8 @@ pub(crate) async fn tcp_tls_connect(
     #[cfg(feature = "tokio-tls")]
     {
         let stream = timeout(
-            connect_timeout,
-            tokio::net::TcpStream::connect((host, port)),
+            config.connect_timeout,
+            tcp_stream_connect(host, port, config.proxy.as_ref()),
         )
         .await??;
         let tls_connector: native_tls::TlsConnector = builder.build()?;
@@ -129,8 +150,8 @@ pub(crate) async fn tcp_tls_connect(
         use tokio_util::compat::{FuturesAsyncReadCompatExt, FuturesAsyncWriteCompatExt};
 
         let stream = timeout(
-            connect_timeout,
-            async_std::net::TcpStream::connect((host, port)),
+            config.connect_timeout,
+            tcp_stream_connect(host, port, config.proxy.as_ref()),
         )
         .await??;
         let tls_connector: async_native_tls::TlsConnector = builder.into();
@@ -145,6 +166,123 @@ pub(crate) async fn tcp_tls_connect(
     Ok((reader, writer))
 }
 
+/// Connect to `host`:`port`, directly or through a tunnel opened by `proxy`
+async fn tcp_stream_connect(
+    host: &str,
+    port: u16,
+    proxy: Option<&ProxyConfig>,
+) -> Result<TcpStream> {
+    let Some(proxy) = proxy else {
+        return connect_any_addr(host, port).await;
+    };
+
+    let stream = connect_any_addr(&proxy.host, proxy.port).await?;
+
+    #[cfg(feature = "tokio-runtime")]
+    {
+        let mut stream = stream;
+        proxy_handshake(&mut stream, proxy, host, port).await?;
+        Ok(stream)
+    }
+    #[cfg(feature = "async-std-runtime")]
+    {
+        use tokio_util::compat::FuturesAsyncReadCompatExt;
+        proxy_handshake(&mut (&stream).compat(), proxy, host, port).await?;
+        Ok(stream)
+    }
+}
+
+/// Resolve all the addresses of `host` and connect to the first one which answers.
+///
+/// Resolution happens on each call, so that a reconnection picks up DNS changes.
+/// IPv6 and IPv4 addresses are tried alternately, a new attempt being started
+/// every [`CONNECTION_ATTEMPT_DELAY`] or as soon as the previous attempt fails.
+async fn connect_any_addr(host: &str, port: u16) -> Result<TcpStream> {
+    #[cfg(feature = "tokio-runtime")]
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port)).await?.collect();
+    #[cfg(feature = "async-std-runtime")]
+    let addrs: Vec<SocketAddr> = {
+        use async_std::net::ToSocketAddrs;
+        (host, port).to_socket_addrs().await?.collect()
+    };
+
+    if addrs.is_empty() {
+        return Err(Error::Client(format!(
+            "Cannot resolve any address for {host}:{port}"
+        )));
+    }
+
+    let mut addrs = interleave_address_families(addrs).into_iter();
+    let mut attempts = FuturesUnordered::new();
+    let mut last_error = None;
+
+    loop {
+        if attempts.is_empty() {
+            match addrs.next() {
+                Some(addr) => attempts.push(connect_addr(addr)),
+                None => {
+                    return Err(last_error.unwrap_or_else(|| {
+                        Error::Client(format!("Cannot connect to {host}:{port}"))
+                    }))
+                }
+            }
+        }
+
+        let result = match future::select(
+            attempts.next(),
+            Box::pin(sleep(CONNECTION_ATTEMPT_DELAY)),
+        )
+        .await
+        {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => None,
+        };
+
+        match result {
+            Some(Ok(stream)) => return Ok(stream),
+            Some(Err(e)) => {
+                debug!("{e}");
+                last_error = Some(e);
+                if let Some(addr) = addrs.next() {
+                    attempts.push(connect_addr(addr));
+                }
+            }
+            None => {
+                if let Some(addr) = addrs.next() {
+                    attempts.push(connect_addr(addr));
+                }
+            }
+        }
+    }
+}
+
+async fn connect_addr(addr: SocketAddr) -> Result<TcpStream> {
+    debug!("Connecting to {addr}...");
+    TcpStream::connect(addr)
+        .await
+        .map_err(|e| Error::IO(format!("Cannot connect to {addr}: {e}")))
+}
+
+/// Alternate IPv6 and IPv4 addresses, starting with the family of the first resolved address
+fn interleave_address_families(addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
+    let first_is_ipv6 = addrs[0].is_ipv6();
+    let (mut first, mut second): (Vec<_>, Vec<_>) = addrs
+        .into_iter()
+        .partition(|addr| addr.is_ipv6() == first_is_ipv6);
+    let mut interleaved = Vec::with_capacity(first.len() + second.len());
+    let mut first = first.drain(..);
+    let mut second = second.drain(..);
+
+    loop {
+        match (first.next(), second.next()) {
+            (None, None) => break,
+            (a, b) => interleaved.extend(a.into_iter().chain(b)),
+        }
+    }
+
+    interleaved
+}
+
 pub enum JoinHandle<T> {
     #[cfg(feature = "tokio-runtime")]
     Tokio(tokio::task::JoinHandle<T>),
@@ -1,12 +1,13 @@
 use crate::{
-    client::{ClusterConfig, Config},
+    client::{ClusterConfig, Config, ServerFeatures},
+    cluster::key_slot,
     commands::{
         ClusterCommands, ClusterHealthStatus, ClusterNodeResult, ClusterShardResult, CommandTip,
         LegacyClusterShardResult, RequestPolicy, ResponsePolicy,
     },
     network::{CommandInfoManager, Version},
     resp::{Command, RespBuf, RespDeserializer, RespSerializer},
-    Error, RedisError, RedisErrorKind, Result, RetryReason, StandaloneConnection,
+    Error, Result, RetryReason, StandaloneConnection,
 };
 use futures_util::{future, FutureExt};
 use log::{debug, info, trace, warn};
@@ -154,6 +155,18 @@ impl ClusterConnection {
 
         let command_name = command_info.name.clone();
 
+        if let Some(hash_slot) = command.hash_slot {
+            return self
+                .no_request_policy(
+                    command,
+                    command_name,
+                    SmallVec::new(),
+                    smallvec![hash_slot],
+                    ask_reasons,
+                )
+                .await;
+        }
+
         let node_idx = self.get_random_node_index();
         let keys = self
             .command_info_manager
@@ -560,20 +573,12 @@ impl ClusterConnection {
             if let Some(result) = result {
                 match &result {
                     Ok(resp_buf) if resp_buf.is_error() => match resp_buf.to::<()>() {
-                        Err(Error::Redis(RedisError {
-                            kind: RedisErrorKind::Ask { hash_slot, address },
-                            description: _,
-                        })) => retry_reasons.push(RetryReason::Ask {
-                            hash_slot,
-                            address: address.clone(),
-                        }),
-                        Err(Error::Redis(RedisError {
-                            kind: RedisErrorKind::Moved { hash_slot, address },
-                            description: _,
-                        })) => retry_reasons.push(RetryReason::Moved {
-                            hash_slot,
-                            address: address.clone(),
-                        }),
+                        Err(Error::Ask { hash_slot, address }) => {
+                            retry_reasons.push(RetryReason::Ask { hash_slot, address })
+                        }
+                        Err(Error::Moved { hash_slot, address }) => {
+                            retry_reasons.push(RetryReason::Moved { hash_slot, address })
+                        }
                         _ => sub_results.push(result),
                     },
                     _ => sub_results.push(result),
@@ -1176,30 +1181,7 @@ impl ClusterConnection {
     }
 
     fn hash_slots(keys: &[String]) -> SmallVec<[u16; 10]> {
-        keys.iter().map(|k| Self::hash_slot(k)).collect()
-    }
-
-    /// Implement hash_slot algorithm
-    /// see. https://redis.io/docs/reference/cluster-spec/#hash-tags
-    fn hash_slot(key: &str) -> u16 {
-        let mut key = key;
-
-        // { found
-        if let Some(s) = key.find('{') {
-            // } found
-            if let Some(e) = key[s + 1..].find('}') {
-                // hash tag non empty
-                if e != 0 {
-                    key = &key[s + 1..s + 1 + e];
-                }
-            }
-        }
-
-        Self::crc16(key) % 16384
-    }
-
-    fn crc16(str: &str) -> u16 {
-        crc16::State::<crc16::XMODEM>::calculate(str.as_bytes())
+        keys.iter().map(key_slot).collect()
     }
 
     pub(crate) fn convert_from_legacy_shard_description(
@@ -1244,6 +1226,13 @@ impl ClusterConnection {
         shards
     }
 
+    pub fn server_features(&self) -> ServerFeatures {
+        self.nodes
+            .first()
+            .map(|node| node.connection.server_features().clone())
+            .unwrap_or_default()
+    }
+
     pub(crate) fn tag(&self) -> &str {
         &self.tag
     }
@@ -1,4 +1,5 @@
 use crate::{
+    client::CommandMetadata,
     commands::{BeginSearch, CommandInfo, FindKeys, ServerCommands},
     network::Version,
     resp::{cmd, Command, CommandArgs},
@@ -62,6 +63,15 @@ impl CommandInfoManager {
         command_info
     }
 
+    /// Keys of a command known to the static [`CommandMetadata`] registry
+    fn extract_keys_from_metadata(command: &Command) -> Option<SmallVec<[String; 10]>> {
+        CommandMetadata::keys(command).map(|keys| {
+            keys.into_iter()
+                .filter_map(|key| String::from_utf8(key.to_vec()).ok())
+                .collect()
+        })
+    }
+
     /// see <https://redis.io/docs/reference/key-specs/>
     pub async fn extract_keys(
         &self,
@@ -70,6 +80,8 @@ impl CommandInfoManager {
     ) -> Result<SmallVec<[String; 10]>> {
         let command_info = if let Some(command_info) = self.command_info_map.get(command.name) {
             command_info
+        } else if let Some(keys) = Self::extract_keys_from_metadata(command) {
+            return Ok(keys);
         } else {
             return Err(Error::Client(format!("Unknown command {}", command.name)));
         };
@@ -78,6 +90,11 @@ impl CommandInfoManager {
             if command_info.first_key == 0 || command_info.last_key == 0 {
                 return Ok(SmallVec::new());
             } else if command_info.flags.iter().any(|f| f == "movablekeys") {
+                // avoid a round trip for the commands wrapped by rustis
+                if let Some(keys) = Self::extract_keys_from_metadata(command) {
+                    return Ok(keys);
+                }
+
                 let args = Self::prepare_command_getkeys_args(command);
                 let keys: SmallVec<[String; 10]> = connection.command_getkeys(args).await?;
                 return Ok(keys);
@@ -1,5 +1,5 @@
 use crate::{
-    client::{Config, PreparedCommand, ServerConfig},
+    client::{Config, PreparedCommand, ServerConfig, ServerFeatures},
     commands::InternalPubSubCommands,
     resp::{Command, RespBuf},
     ClusterConnection, Error, Future, Result, RetryReason, SentinelConnection,
@@ -68,6 +68,34 @@ impl Connection {
         }
     }
 
+    /// Arrays elements are decoded one by one after their header.
+    ///
+    /// Not supported by cluster connections, which always decode whole replies.
+    #[inline]
+    pub fn set_stream_array_header(&mut self, stream_array_header: bool) {
+        match self {
+            Connection::Standalone(connection) => {
+                connection.set_stream_array_header(stream_array_header)
+            }
+            Connection::Sentinel(connection) => {
+                connection.set_stream_array_header(stream_array_header)
+            }
+            Connection::Cluster(_) => (),
+        }
+    }
+
+    /// Database to select after a reconnection
+    ///
+    /// Cluster connections only support the database `0`.
+    #[inline]
+    pub fn set_database(&mut self, database: usize) {
+        match self {
+            Connection::Standalone(connection) => connection.set_database(database),
+            Connection::Sentinel(connection) => connection.set_database(database),
+            Connection::Cluster(_) => (),
+        }
+    }
+
     #[inline]
     pub async fn reconnect(&mut self) -> Result<()> {
         match self {
@@ -85,6 +113,29 @@ impl Connection {
             .ok_or_else(|| Error::Client("Disconnected by peer".to_owned()))?
     }
 
+    /// Features of the server, or of the first node of a cluster
+    pub fn server_features(&self) -> ServerFeatures {
+        match self {
+            Connection::Standalone(connection) => connection.server_features().clone(),
+            Connection::Sentinel(connection) => {
+                connection.inner_connection.server_features().clone()
+            }
+            Connection::Cluster(connection) => connection.server_features(),
+        }
+    }
+
+    /// Id of the connection on the server, `None` for a cluster,
+    /// which holds a connection to each node
+    pub fn cached_client_id(&self) -> Option<i64> {
+        match self {
+            Connection::Standalone(connection) => Some(connection.cached_client_id()),
+            Connection::Sentinel(connection) => {
+                Some(connection.inner_connection.cached_client_id())
+            }
+            Connection::Cluster(_) => None,
+        }
+    }
+
     pub(crate) fn tag(&self) -> &str {
         match self {
             Connection::Standalone(connection) => connection.tag(),
@@ -3,6 +3,7 @@ mod cluster_connection;
 mod command_info_manager;
 mod connection;
 mod network_handler;
+mod proxy;
 mod sentinel_connection;
 mod standalone_connection;
 mod util;
@@ -13,6 +14,7 @@ pub(crate) use cluster_connection::*;
 pub(crate) use command_info_manager::*;
 pub(crate) use connection::*;
 pub(crate) use network_handler::*;
+pub(crate) use proxy::*;
 pub(crate) use sentinel_connection::*;
 pub(crate) use standalone_connection::*;
 pub(crate) use version::*;
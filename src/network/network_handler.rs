@@ -1,15 +1,27 @@
 use super::util::RefPubSubMessage;
 use crate::{
-    client::{Commands, Config, Message},
+    client::{
+        is_blocking_command, Commands, Config, ConnectionEvent, ConnectionEventReceiver,
+        ConnectionEventSender, ConnectionState, DisconnectReason, Message, PushMessage,
+        PushMessageReceiver, PushMessageSender, ServerFeatures,
+    },
     commands::InternalPubSubCommands,
-    resp::{cmd, Command, RespBuf},
-    spawn, Connection, Error, JoinHandle, Result, RetryReason,
+    resp::{cmd, parse_array_header, Command, RespBuf},
+    sleep, spawn, Connection, Error, JoinHandle, Result, RetryReason,
 };
 use futures_channel::{mpsc, oneshot};
-use futures_util::{select, FutureExt, SinkExt, StreamExt};
+use futures_util::{future, select, FutureExt, SinkExt, StreamExt};
 use log::{debug, error, info, log_enabled, trace, warn, Level};
 use smallvec::SmallVec;
-use std::collections::{HashMap, VecDeque};
+use std::{
+    collections::{HashMap, VecDeque},
+    str,
+    sync::{
+        atomic::{AtomicI64, AtomicU8, AtomicUsize, Ordering},
+        Arc, RwLock,
+    },
+    time::Duration,
+};
 use tokio::sync::broadcast;
 
 pub(crate) type MsgSender = mpsc::UnboundedSender<Message>;
@@ -18,12 +30,33 @@ pub(crate) type ResultSender = oneshot::Sender<Result<RespBuf>>;
 pub(crate) type ResultReceiver = oneshot::Receiver<Result<RespBuf>>;
 pub(crate) type ResultsSender = oneshot::Sender<Result<Vec<RespBuf>>>;
 pub(crate) type ResultsReceiver = oneshot::Receiver<Result<Vec<RespBuf>>>;
-pub(crate) type PubSubSender = mpsc::UnboundedSender<Result<RespBuf>>;
-pub(crate) type PubSubReceiver = mpsc::UnboundedReceiver<Result<RespBuf>>;
+pub(crate) type PubSubSender = mpsc::UnboundedSender<Result<PubSubItem>>;
+pub(crate) type PubSubReceiver = mpsc::UnboundedReceiver<Result<PubSubItem>>;
 pub(crate) type PushSender = mpsc::UnboundedSender<Result<RespBuf>>;
 pub(crate) type PushReceiver = mpsc::UnboundedReceiver<Result<RespBuf>>;
+pub(crate) type StreamSender = mpsc::UnboundedSender<Result<RespBuf>>;
+pub(crate) type StreamReceiver = mpsc::UnboundedReceiver<Result<RespBuf>>;
 pub(crate) type ReconnectSender = broadcast::Sender<()>;
 pub(crate) type ReconnectReceiver = broadcast::Receiver<()>;
+/// Push messages can come in bursts, e.g. the invalidations of a `FLUSHALL` in broadcasting mode
+const PUSH_MESSAGE_CHANNEL_CAPACITY: usize = 1024;
+
+pub(crate) type NetworkHandlerSenders = (
+    MsgSender,
+    JoinHandle<()>,
+    ReconnectSender,
+    ConnectionEventSender,
+    PushMessageSender,
+);
+
+/// Item sent by the network handler to a pub/sub stream
+pub(crate) enum PubSubItem {
+    /// A `message`, `smessage` or `pmessage` push
+    Message(RespBuf),
+    /// The subscriptions have been reissued after a reconnection,
+    /// messages published during the outage have been lost
+    Reconnected,
+}
 
 #[derive(Clone, Copy, Debug)]
 enum Status {
@@ -93,31 +126,179 @@ pub(crate) struct NetworkHandler {
     pending_unsubscriptions: VecDeque<HashMap<Vec<u8>, SubscriptionType>>,
     subscriptions: HashMap<Vec<u8>, (SubscriptionType, PubSubSender)>,
     is_reply_on: bool,
+    skip_next_reply: bool,
     push_sender: Option<PushSender>,
     pending_replies: Option<Vec<RespBuf>>,
     reconnect_sender: ReconnectSender,
+    connection_event_sender: ConnectionEventSender,
+    /// out-of-band dispatcher of the push messages to the handlers registered by the clients
+    push_message_sender: PushMessageSender,
     auto_resubscribe: bool,
     auto_remonitor: bool,
     max_command_attempts: usize,
+    health_check_interval: Duration,
+    pending_health_check: Option<ResultReceiver>,
+    /// number of array elements still expected for the streamed reply
+    stream_remaining: usize,
+    /// index of the currently selected database, shared with the clients
+    db_index: Arc<AtomicUsize>,
+    /// features of the connected server, shared with the clients
+    server_features: Arc<RwLock<Option<ServerFeatures>>>,
+    /// id of the connection on the server, shared with the clients (`0` if unknown)
+    client_id: Arc<AtomicI64>,
+    /// a `MULTI` command has been sent alone, without its `EXEC`
+    in_transaction: bool,
+    /// [`ConnectionState`] shared with the clients
+    connection_state: Arc<AtomicU8>,
     tag: String,
 }
 
 impl NetworkHandler {
-    pub async fn connect(config: Config) -> Result<(MsgSender, JoinHandle<()>, ReconnectSender)> {
-        // options
-        let auto_resubscribe = config.auto_resubscribe;
-        let auto_remonitor = config.auto_remonitor;
-        let max_command_attempts = config.max_command_attempts;
-
-        let connection = Connection::connect(config).await?;
+    pub async fn connect(
+        config: Config,
+        db_index: Arc<AtomicUsize>,
+        server_features: Arc<RwLock<Option<ServerFeatures>>>,
+        client_id: Arc<AtomicI64>,
+        connection_state: Arc<AtomicU8>,
+    ) -> Result<NetworkHandlerSenders> {
+        let connection = Connection::connect(config.clone()).await?;
         let (msg_sender, msg_receiver): (MsgSender, MsgReceiver) = mpsc::unbounded();
         let (reconnect_sender, _): (ReconnectSender, ReconnectReceiver) = broadcast::channel(32);
-        let tag = connection.tag().to_owned();
+        let (connection_event_sender, _): (ConnectionEventSender, ConnectionEventReceiver) =
+            broadcast::channel(32);
+        let (push_message_sender, _): (PushMessageSender, PushMessageReceiver) =
+            broadcast::channel(PUSH_MESSAGE_CHANNEL_CAPACITY);
+
+        let mut network_handler = NetworkHandler::new(
+            connection,
+            &config,
+            msg_sender.clone(),
+            msg_receiver,
+            reconnect_sender.clone(),
+            connection_event_sender.clone(),
+            push_message_sender.clone(),
+            db_index,
+            server_features,
+            client_id,
+            connection_state,
+        );
+
+        let join_handle = spawn(async move {
+            if let Err(e) = network_handler.network_loop().await {
+                error!("[{}] network loop ended in error: {e}", network_handler.tag);
+            }
+        });
+
+        Ok((
+            msg_sender,
+            join_handle,
+            reconnect_sender,
+            connection_event_sender,
+            push_message_sender,
+        ))
+    }
+
+    /// Same as [`connect`](NetworkHandler::connect) but the connection is only established
+    /// when the first message is received.
+    ///
+    /// If the connection fails, the error is sent back to the caller of this first message
+    /// and the connection will be attempted again with the next message.
+    pub fn connect_lazy(
+        config: Config,
+        db_index: Arc<AtomicUsize>,
+        server_features: Arc<RwLock<Option<ServerFeatures>>>,
+        client_id: Arc<AtomicI64>,
+        connection_state: Arc<AtomicU8>,
+    ) -> NetworkHandlerSenders {
+        let (msg_sender, mut msg_receiver): (MsgSender, MsgReceiver) = mpsc::unbounded();
+        let (reconnect_sender, _): (ReconnectSender, ReconnectReceiver) = broadcast::channel(32);
+        let (connection_event_sender, _): (ConnectionEventSender, ConnectionEventReceiver) =
+            broadcast::channel(32);
+        let (push_message_sender, _): (PushMessageSender, PushMessageReceiver) =
+            broadcast::channel(PUSH_MESSAGE_CHANNEL_CAPACITY);
+
+        let handler_msg_sender = msg_sender.clone();
+        let handler_reconnect_sender = reconnect_sender.clone();
+        let handler_connection_event_sender = connection_event_sender.clone();
+        let handler_push_message_sender = push_message_sender.clone();
 
-        let mut network_handler = NetworkHandler {
+        let join_handle = spawn(async move {
+            while let Some(msg) = msg_receiver.next().await {
+                match Connection::connect(config.clone()).await {
+                    Ok(connection) => {
+                        let mut network_handler = NetworkHandler::new(
+                            connection,
+                            &config,
+                            handler_msg_sender,
+                            msg_receiver,
+                            handler_reconnect_sender,
+                            handler_connection_event_sender,
+                            handler_push_message_sender,
+                            db_index,
+                            server_features,
+                            client_id,
+                            connection_state,
+                        );
+                        network_handler.send_connection_event(ConnectionEvent::Connected);
+
+                        if network_handler.handle_message(Some(msg)).await {
+                            if let Err(e) = network_handler.network_loop().await {
+                                error!(
+                                    "[{}] network loop ended in error: {e}",
+                                    network_handler.tag
+                                );
+                            }
+                        }
+
+                        return;
+                    }
+                    Err(e) => {
+                        error!("Failed to connect lazily: {e}");
+                        let _ =
+                            handler_connection_event_sender.send(ConnectionEvent::Error(e.clone()));
+                        Self::fail_message(msg, e);
+                    }
+                }
+            }
+
+            debug!("end of network loop before any connection");
+        });
+
+        (
+            msg_sender,
+            join_handle,
+            reconnect_sender,
+            connection_event_sender,
+            push_message_sender,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        connection: Connection,
+        config: &Config,
+        msg_sender: MsgSender,
+        msg_receiver: MsgReceiver,
+        reconnect_sender: ReconnectSender,
+        connection_event_sender: ConnectionEventSender,
+        push_message_sender: PushMessageSender,
+        db_index: Arc<AtomicUsize>,
+        server_features: Arc<RwLock<Option<ServerFeatures>>>,
+        client_id: Arc<AtomicI64>,
+        connection_state: Arc<AtomicU8>,
+    ) -> Self {
+        let tag = connection.tag().to_owned();
+        *server_features.write().unwrap() = Some(connection.server_features());
+        client_id.store(
+            connection.cached_client_id().unwrap_or_default(),
+            Ordering::Relaxed,
+        );
+        connection_state.store(ConnectionState::Normal.to_u8(), Ordering::Relaxed);
+
+        NetworkHandler {
             status: Status::Connected,
             connection,
-            msg_sender: msg_sender.clone(),
+            msg_sender,
             msg_receiver,
             messages_to_send: VecDeque::new(),
             messages_to_receive: VecDeque::new(),
@@ -125,26 +306,59 @@ impl NetworkHandler {
             pending_unsubscriptions: VecDeque::new(),
             subscriptions: HashMap::new(),
             is_reply_on: true,
+            skip_next_reply: false,
             push_sender: None,
             pending_replies: None,
-            reconnect_sender: reconnect_sender.clone(),
-            auto_resubscribe,
-            auto_remonitor,
-            max_command_attempts,
+            reconnect_sender,
+            connection_event_sender,
+            push_message_sender,
+            auto_resubscribe: config.auto_resubscribe,
+            auto_remonitor: config.auto_remonitor,
+            max_command_attempts: config.max_command_attempts,
+            health_check_interval: config.health_check_interval,
+            pending_health_check: None,
+            stream_remaining: 0,
+            db_index,
+            server_features,
+            client_id,
+            in_transaction: false,
+            connection_state,
             tag,
-        };
+        }
+    }
 
-        let join_handle = spawn(async move {
-            if let Err(e) = network_handler.network_loop().await {
-                error!("[{}] network loop ended in error: {e}", network_handler.tag);
+    fn fail_message(msg: Message, e: Error) {
+        if let Some(stream_sender) = &msg.stream_sender {
+            if let Err(e) = stream_sender.unbounded_send(Err(e.clone())) {
+                warn!("Cannot send value to caller because receiver is not there anymore: {e:?}");
             }
-        });
+        }
 
-        Ok((msg_sender, join_handle, reconnect_sender))
+        match msg.commands {
+            Commands::Single(_, Some(result_sender)) => {
+                if let Err(e) = result_sender.send(Err(e)) {
+                    warn!(
+                        "Cannot send value to caller because receiver is not there anymore: {e:?}"
+                    );
+                }
+            }
+            Commands::Batch(_, results_sender) => {
+                if let Err(e) = results_sender.send(Err(e)) {
+                    warn!(
+                        "Cannot send value to caller because receiver is not there anymore: {e:?}"
+                    );
+                }
+            }
+            _ => (),
+        }
     }
 
     async fn network_loop(&mut self) -> Result<()> {
         loop {
+            let stream_array_header = self.stream_remaining == 0
+                && matches!(self.messages_to_receive.front(), Some(m) if m.message.stream_sender.is_some());
+            self.connection.set_stream_array_header(stream_array_header);
+
             select! {
                 msg = self.msg_receiver.next().fuse() => {
                     if !self.handle_message(msg).await { break; }
@@ -152,108 +366,320 @@ impl NetworkHandler {
                 value = self.connection.read().fuse() => {
                     self.handle_result(value).await;
                 }
+                _ = Self::idle_timer(self.health_check_interval).fuse() => {
+                    if !self.health_check().await { break; }
+                }
             }
         }
 
+        if self.is_quitting() {
+            self.drain_replies().await;
+        }
+
         debug!("[{}] end of network loop", self.tag);
         Ok(())
     }
 
-    async fn handle_message(&mut self, mut msg: Option<Message>) -> bool {
-        let is_channel_closed: bool;
+    /// A graceful shutdown has been requested:
+    /// the channel has been closed right after sending a `QUIT` command
+    fn is_quitting(&self) -> bool {
+        matches!(
+            self.messages_to_receive.back(),
+            Some(MessageToReceive {
+                message: Message {
+                    commands: Commands::Single(command, _),
+                    ..
+                },
+                ..
+            }) if command.name == "QUIT"
+        )
+    }
 
-        loop {
-            if let Some(mut msg) = msg {
-                trace!("[{}] Will handle message: {msg:?}", self.tag);
-                let pub_sub_senders = msg.pub_sub_senders.take();
-                if let Some(pub_sub_senders) = pub_sub_senders {
-                    let subscription_type = match &msg.commands {
-                        Commands::Single(command, _) => match command.name {
-                            "SUBSCRIBE" => SubscriptionType::Channel,
-                            "PSUBSCRIBE" => SubscriptionType::Pattern,
-                            "SSUBSCRIBE" => SubscriptionType::ShardChannel,
-                            _ => unreachable!(),
-                        },
-                        _ => unreachable!(),
-                    };
-
-                    let num_pending_subscriptions = pub_sub_senders.len();
-                    let pending_subscriptions = pub_sub_senders.into_iter().enumerate().map(
-                        |(index, (channel_or_pattern, sender))| PendingSubscription {
-                            channel_or_pattern,
-                            subscription_type,
-                            sender,
-                            more_to_come: index < num_pending_subscriptions - 1,
-                        },
-                    );
+    /// Wait for the replies of the in-flight commands, up to the reply of `QUIT`,
+    /// without reconnecting if the connection is closed
+    async fn drain_replies(&mut self) {
+        debug!(
+            "[{}] draining {} in-flight messages...",
+            self.tag,
+            self.messages_to_receive.len()
+        );
+
+        while !self.messages_to_receive.is_empty() {
+            match self.connection.read().await {
+                Some(result) => self.handle_result(Some(result)).await,
+                None => break,
+            }
+        }
+    }
 
-                    self.pending_subscriptions.extend(pending_subscriptions);
-                }
+    /// Resolves after `interval` without any network activity,
+    /// never resolves if health checks are disabled
+    async fn idle_timer(interval: Duration) {
+        if interval.is_zero() {
+            future::pending::<()>().await;
+        } else {
+            sleep(interval).await;
+        }
+    }
+
+    /// The next reply belongs to a command whose reply must not be logged
+    fn is_reply_redacted(&self) -> bool {
+        self.messages_to_receive
+            .front()
+            .is_some_and(|message_to_receive| {
+                (&message_to_receive.message.commands)
+                    .into_iter()
+                    .any(Command::is_reply_redacted)
+            })
+    }
 
-                let push_sender = msg.push_sender.take();
-                if let Some(push_sender) = push_sender {
-                    debug!("[{}] Registering push_sender", self.tag);
-                    self.push_sender = Some(push_sender);
+    /// A blocking command is waiting for its reply:
+    /// the server will not answer a `PING` before the command returns
+    fn is_blocked(&self) -> bool {
+        self.messages_to_receive.iter().any(|message_to_receive| {
+            (&message_to_receive.message.commands)
+                .into_iter()
+                .any(is_blocking_command)
+        })
+    }
+
+    /// Pings an idle connection and proactively reconnects
+    /// if the previous ping has not been answered in time
+    async fn health_check(&mut self) -> bool {
+        match self.status {
+            Status::Disconnected => {
+                debug!("[{}] health check: disconnected, reconnecting...", self.tag);
+                self.reconnect(DisconnectReason::ConnectionClosed).await;
+                true
+            }
+            Status::Connected if self.is_blocked() => {
+                trace!(
+                    "[{}] health check: skipped during a blocking command",
+                    self.tag
+                );
+                true
+            }
+            Status::Connected if self.in_transaction || self.skip_next_reply => {
+                // a PING would be queued in the transaction or its reply would be skipped
+                trace!(
+                    "[{}] health check: skipped during a transaction or a skipped reply",
+                    self.tag
+                );
+                true
+            }
+            Status::Connected if self.is_reply_on => {
+                if let Some(mut pending_health_check) = self.pending_health_check.take() {
+                    if !matches!(pending_health_check.try_recv(), Ok(Some(Ok(_)))) {
+                        warn!(
+                            "[{}] health check: no answer to PING, reconnecting...",
+                            self.tag
+                        );
+                        self.reconnect(DisconnectReason::HealthCheckFailed).await;
+                        return true;
+                    }
                 }
 
-                match &self.status {
-                    Status::Connected => {
-                        for command in &msg.commands {
-                            match command.name {
-                                "SUBSCRIBE" | "PSUBSCRIBE" | "SSUBSCRIBE" => {
-                                    self.status = Status::Subscribing;
-                                }
-                                "MONITOR" => {
-                                    self.status = Status::EnteringMonitor;
-                                }
-                                _ => (),
-                            }
+                trace!("[{}] health check: sending PING", self.tag);
+                let (result_sender, result_receiver): (ResultSender, ResultReceiver) =
+                    oneshot::channel();
+                self.pending_health_check = Some(result_receiver);
+                self.handle_message(Some(Message::single(
+                    cmd("PING").high_priority(),
+                    result_sender,
+                    false,
+                )))
+                .await
+            }
+            _ => true,
+        }
+    }
+
+    fn queue_message(&mut self, mut msg: Message) {
+        trace!("[{}] Will handle message: {msg:?}", self.tag);
+        let pub_sub_senders = msg.pub_sub_senders.take();
+        if let Some(pub_sub_senders) = pub_sub_senders {
+            let subscription_type = match &msg.commands {
+                Commands::Single(command, _) => match command.name {
+                    "SUBSCRIBE" => SubscriptionType::Channel,
+                    "PSUBSCRIBE" => SubscriptionType::Pattern,
+                    "SSUBSCRIBE" => SubscriptionType::ShardChannel,
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+
+            let num_pending_subscriptions = pub_sub_senders.len();
+            let pending_subscriptions = pub_sub_senders.into_iter().enumerate().map(
+                |(index, (channel_or_pattern, sender))| PendingSubscription {
+                    channel_or_pattern,
+                    subscription_type,
+                    sender,
+                    more_to_come: index < num_pending_subscriptions - 1,
+                },
+            );
+
+            self.pending_subscriptions.extend(pending_subscriptions);
+        }
+
+        let push_sender = msg.push_sender.take();
+        if let Some(push_sender) = push_sender {
+            debug!("[{}] Registering push_sender", self.tag);
+            self.push_sender = Some(push_sender);
+        }
+
+        for command in &msg.commands {
+            match command.name {
+                "MULTI" => self.in_transaction = true,
+                "EXEC" | "DISCARD" | "RESET" => self.in_transaction = false,
+                _ => (),
+            }
+        }
+
+        match &self.status {
+            Status::Connected => {
+                for command in &msg.commands {
+                    match command.name {
+                        "SUBSCRIBE" | "PSUBSCRIBE" | "SSUBSCRIBE" => {
+                            self.status = Status::Subscribing;
                         }
-                        self.messages_to_send.push_back(MessageToSend::new(msg));
-                    }
-                    Status::Subscribing => {
-                        self.messages_to_send.push_back(MessageToSend::new(msg));
-                    }
-                    Status::Subscribed => {
-                        for command in &msg.commands {
-                            if let "UNSUBSCRIBE" | "PUNSUBSCRIBE" | "SUNSUBSCRIBE" = command.name {
-                                let subscription_type = match command.name {
-                                    "UNSUBSCRIBE" => SubscriptionType::Channel,
-                                    "PUNSUBSCRIBE" => SubscriptionType::Pattern,
-                                    "SUNSUBSCRIBE" => SubscriptionType::ShardChannel,
-                                    _ => unreachable!(),
-                                };
-                                self.pending_unsubscriptions.push_back(
-                                    command
-                                        .args
-                                        .into_iter()
-                                        .map(|a| (a.to_vec(), subscription_type))
-                                        .collect(),
-                                );
-                            }
+                        "MONITOR" => {
+                            self.status = Status::EnteringMonitor;
                         }
-                        self.messages_to_send.push_back(MessageToSend::new(msg));
+                        _ => (),
                     }
-                    Status::Disconnected => {
-                        debug!(
-                            "[{}] network disconnected, queuing command: {:?}",
-                            self.tag, msg.commands
+                }
+                self.push_message_to_send(msg);
+            }
+            Status::Subscribing => {
+                self.messages_to_send.push_back(MessageToSend::new(msg));
+            }
+            Status::Subscribed => {
+                for command in &msg.commands {
+                    if let "UNSUBSCRIBE" | "PUNSUBSCRIBE" | "SUNSUBSCRIBE" = command.name {
+                        let subscription_type = match command.name {
+                            "UNSUBSCRIBE" => SubscriptionType::Channel,
+                            "PUNSUBSCRIBE" => SubscriptionType::Pattern,
+                            "SUNSUBSCRIBE" => SubscriptionType::ShardChannel,
+                            _ => unreachable!(),
+                        };
+                        self.pending_unsubscriptions.push_back(
+                            command
+                                .args
+                                .into_iter()
+                                .map(|a| (a.to_vec(), subscription_type))
+                                .collect(),
                         );
-                        self.messages_to_send.push_back(MessageToSend::new(msg));
                     }
-                    Status::EnteringMonitor => {
-                        self.messages_to_send.push_back(MessageToSend::new(msg))
-                    }
-                    Status::Monitor => {
-                        for command in &msg.commands {
-                            if command.name == "RESET" {
-                                self.status = Status::LeavingMonitor;
-                            }
-                        }
-                        self.messages_to_send.push_back(MessageToSend::new(msg));
+                }
+                self.messages_to_send.push_back(MessageToSend::new(msg));
+            }
+            Status::Disconnected => {
+                debug!(
+                    "[{}] network disconnected, queuing command: {:?}",
+                    self.tag, msg.commands
+                );
+                self.push_message_to_send(msg);
+            }
+            Status::EnteringMonitor => self.messages_to_send.push_back(MessageToSend::new(msg)),
+            Status::Monitor => {
+                for command in &msg.commands {
+                    if command.name == "RESET" {
+                        self.status = Status::LeavingMonitor;
                     }
-                    Status::LeavingMonitor => {
-                        self.messages_to_send.push_back(MessageToSend::new(msg));
+                }
+                self.messages_to_send.push_back(MessageToSend::new(msg));
+            }
+            Status::LeavingMonitor => {
+                self.messages_to_send.push_back(MessageToSend::new(msg));
+            }
+        }
+    }
+
+    /// High priority messages are queued after the other high priority messages,
+    /// but ahead of the regular ones
+    fn push_message_to_send(&mut self, msg: Message) {
+        if msg.commands.is_high_priority() {
+            let index = self
+                .messages_to_send
+                .iter()
+                .position(|m| !m.message.commands.is_high_priority())
+                .unwrap_or(self.messages_to_send.len());
+            self.messages_to_send.insert(index, MessageToSend::new(msg));
+        } else {
+            self.messages_to_send.push_back(MessageToSend::new(msg));
+        }
+    }
+
+    /// Reject the commands which cannot be mixed with the current state of the connection
+    fn check_message(&self, msg: &Message) -> Result<()> {
+        let mut in_transaction = self.in_transaction;
+
+        for command in &msg.commands {
+            let name = command.name;
+
+            if in_transaction
+                && matches!(
+                    name,
+                    "MULTI" | "WATCH" | "MONITOR" | "SUBSCRIBE" | "PSUBSCRIBE" | "SSUBSCRIBE"
+                )
+            {
+                return Err(Error::Client(format!(
+                    "{name} cannot be sent inside a transaction"
+                )));
+            }
+
+            match self.status {
+                Status::EnteringMonitor | Status::Monitor if !matches!(name, "RESET" | "QUIT") => {
+                    return Err(Error::Client(format!(
+                        "{name} cannot be sent on a connection in monitoring mode, only RESET and QUIT are allowed"
+                    )));
+                }
+                Status::Subscribing | Status::Subscribed if name == "MONITOR" => {
+                    return Err(Error::Client(
+                        "MONITOR cannot be sent on a subscribed connection".to_owned(),
+                    ));
+                }
+                _ => (),
+            }
+
+            match name {
+                "MULTI" => in_transaction = true,
+                "EXEC" | "DISCARD" | "RESET" => in_transaction = false,
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn connection_state(&self) -> ConnectionState {
+        match self.status {
+            Status::Disconnected => ConnectionState::Disconnected,
+            Status::Connected if self.in_transaction => ConnectionState::Transaction,
+            Status::Connected => ConnectionState::Normal,
+            Status::Subscribing | Status::Subscribed => ConnectionState::Subscribed,
+            Status::EnteringMonitor | Status::Monitor | Status::LeavingMonitor => {
+                ConnectionState::Monitoring
+            }
+        }
+    }
+
+    /// Share the connection state with the clients
+    fn publish_connection_state(&self) {
+        self.connection_state
+            .store(self.connection_state().to_u8(), Ordering::Relaxed);
+    }
+
+    async fn handle_message(&mut self, mut msg: Option<Message>) -> bool {
+        let is_channel_closed: bool;
+
+        loop {
+            if let Some(msg) = msg {
+                match self.check_message(&msg) {
+                    Ok(()) => self.queue_message(msg),
+                    Err(e) => {
+                        warn!("[{}] {e}", self.tag);
+                        Self::fail_message(msg, e);
                     }
                 }
             } else {
@@ -271,6 +697,8 @@ impl NetworkHandler {
             }
         }
 
+        self.publish_connection_state();
+
         if let Status::Disconnected = self.status {
         } else {
             self.send_messages().await
@@ -300,18 +728,28 @@ impl NetworkHandler {
             let mut num_commands_to_receive: usize = 0;
 
             for command in commands.into_iter() {
+                let mut is_reply_expected = !self.skip_next_reply;
+                self.skip_next_reply = false;
+
                 if command.name == "CLIENT" {
                     let mut args = command.args.into_iter();
 
                     match (args.next(), args.next()) {
                         (Some(b"REPLY"), Some(b"OFF")) => self.is_reply_on = false,
-                        (Some(b"REPLY"), Some(b"SKIP")) => self.is_reply_on = false,
-                        (Some(b"REPLY"), Some(b"ON")) => self.is_reply_on = true,
+                        (Some(b"REPLY"), Some(b"SKIP")) => {
+                            // CLIENT REPLY SKIP has no reply, neither the next command
+                            self.skip_next_reply = self.is_reply_on;
+                            is_reply_expected = false;
+                        }
+                        (Some(b"REPLY"), Some(b"ON")) => {
+                            self.is_reply_on = true;
+                            is_reply_expected = true;
+                        }
                         _ => (),
                     }
                 }
 
-                if self.is_reply_on {
+                if self.is_reply_on && is_reply_expected {
                     num_commands_to_receive += 1;
                 }
 
@@ -336,27 +774,7 @@ impl NetworkHandler {
             let mut idx: usize = 0;
             while let Some(msg) = self.messages_to_send.pop_front() {
                 if commands_to_receive[idx] > 0 {
-                    match msg.message.commands {
-                        Commands::Single(_, Some(result_sender)) => {
-                            if let Err(e) = result_sender.send(Err(e.clone())) {
-                                warn!(
-                                "[{}] Cannot send value to caller because receiver is not there anymore: {:?}",
-                                self.tag,
-                                e
-                            );
-                            }
-                        }
-                        Commands::Batch(_, results_sender) => {
-                            if let Err(e) = results_sender.send(Err(e.clone())) {
-                                warn!(
-                                "[{}] Cannot send value to caller because receiver is not there anymore: {:?}",
-                                self.tag,
-                                e
-                            );
-                            }
-                        }
-                        _ => (),
-                    }
+                    Self::fail_message(msg.message, e.clone());
                 }
                 idx += 1;
             }
@@ -376,21 +794,29 @@ impl NetworkHandler {
     }
 
     async fn handle_result(&mut self, result: Option<Result<RespBuf>>) {
+        if log_enabled!(Level::Debug) {
+            if let Some(Ok(resp_buf)) = &result {
+                if self.is_reply_redacted() {
+                    debug!("[{}] Received result ***", self.tag);
+                } else {
+                    debug!("[{}] Received result {resp_buf}", self.tag);
+                }
+            }
+        }
+
+        if let Some(Err(e @ (Error::IO(_) | Error::Client(_)))) = &result {
+            self.send_connection_event(ConnectionEvent::Error(e.clone()));
+        }
+
         match result {
             Some(result) => match self.status {
                 Status::Disconnected => (),
-                Status::Connected => match &result {
-                    Ok(resp_buf) if resp_buf.is_push_message() => match &mut self.push_sender {
-                        Some(push_sender) => {
-                            if let Err(e) = push_sender.send(result).await {
-                                warn!("[{}] Cannot send monitor result to caller: {e}", self.tag);
-                            }
-                        }
-                        None => {
-                            warn!("[{}] Received a push message with no sender configured: {resp_buf}", self.tag)
-                        }
-                    },
-                    _ => {
+                Status::Connected => match result {
+                    // elements of a streamed array cannot be push messages
+                    Ok(resp_buf) if self.stream_remaining == 0 && resp_buf.is_push_message() => {
+                        self.handle_push_message(resp_buf).await;
+                    }
+                    result => {
                         self.receive_result(result);
                     }
                 },
@@ -433,17 +859,65 @@ impl NetworkHandler {
                         }
                     }
                     _ => {
-                        self.receive_result(result);
+                        // publish the new state before the caller gets the result
                         self.status = Status::Connected;
+                        self.publish_connection_state();
+                        self.receive_result(result);
                     }
                 },
             },
             // disconnection
-            None => self.reconnect().await,
+            None => self.reconnect(DisconnectReason::ConnectionClosed).await,
+        }
+
+        self.publish_connection_state();
+    }
+
+    /// Routes a push message out of band, to the registered push sender
+    /// and to the push handlers of the clients, never to the callers of the pending commands
+    async fn handle_push_message(&mut self, resp_buf: RespBuf) {
+        let dispatched = resp_buf.is_push_frame() && self.dispatch_push_message(&resp_buf);
+
+        match &mut self.push_sender {
+            Some(push_sender) => {
+                if let Err(e) = push_sender.send(Ok(resp_buf)).await {
+                    warn!("[{}] Cannot send push message to caller: {e}", self.tag);
+                }
+            }
+            None if dispatched => (),
+            None => {
+                warn!(
+                    "[{}] Received a push message with no sender configured: {resp_buf}",
+                    self.tag
+                )
+            }
+        }
+    }
+
+    /// Returns `true` if the message has been dispatched to at least one push handler
+    fn dispatch_push_message(&self, resp_buf: &RespBuf) -> bool {
+        if self.push_message_sender.receiver_count() == 0 {
+            return false;
+        }
+
+        match PushMessage::from_resp(resp_buf) {
+            Ok(push_message) => self.push_message_sender.send(push_message).is_ok(),
+            Err(e) => {
+                warn!("[{}] Cannot parse push message: {e}", self.tag);
+                false
+            }
         }
     }
 
     fn receive_result(&mut self, result: Result<RespBuf>) {
+        if self.stream_remaining > 0
+            || (matches!(self.messages_to_receive.front(), Some(m) if m.message.stream_sender.is_some())
+                && !matches!(result, Err(Error::Retry(_))))
+        {
+            self.receive_stream_result(result);
+            return;
+        }
+
         match self.messages_to_receive.front_mut() {
             Some(message_to_receive) => {
                 if message_to_receive.num_commands == 1 || result.is_err() {
@@ -474,6 +948,14 @@ impl NetworkHandler {
                                 error!("[{}] Cannot retry message: {e}", self.tag);
                             }
                         } else {
+                            if let (Commands::Single(command, _), Ok(resp_buf)) =
+                                (&message_to_receive.message.commands, &result)
+                            {
+                                if !resp_buf.is_error() {
+                                    self.track_selected_database(command);
+                                }
+                            }
+
                             trace!(
                                 "[{}] Will respond to: {:?}",
                                 self.tag,
@@ -508,7 +990,7 @@ impl NetworkHandler {
                                     }
                                 },
                                 Commands::None | Commands::Single(_, None) => {
-                                    debug!("[{}] forget value {result:?}", self.tag)
+                                    debug!("[{}] forget value", self.tag)
                                     // fire & forget
                                 }
                             }
@@ -551,6 +1033,79 @@ impl NetworkHandler {
         }
     }
 
+    /// Keeps track of the selected database after a successful `SELECT` or `RESET`,
+    /// so that it can be selected again after a reconnection
+    fn track_selected_database(&mut self, command: &Command) {
+        let db_index = match command.name {
+            "SELECT" => command
+                .args
+                .into_iter()
+                .next()
+                .and_then(|arg| str::from_utf8(arg).ok())
+                .and_then(|arg| arg.parse::<usize>().ok()),
+            "RESET" => Some(0),
+            _ => None,
+        };
+
+        if let Some(db_index) = db_index {
+            debug!("[{}] database {db_index} selected", self.tag);
+            self.connection.set_database(db_index);
+            self.db_index.store(db_index, Ordering::Relaxed);
+        }
+    }
+
+    /// Sends the elements of an array reply one by one to the stream sender of the message
+    fn receive_stream_result(&mut self, result: Result<RespBuf>) {
+        let stream_sender = match self
+            .messages_to_receive
+            .front()
+            .and_then(|m| m.message.stream_sender.as_ref())
+        {
+            Some(stream_sender) => stream_sender,
+            None => return,
+        };
+
+        let send = |result: Result<RespBuf>| {
+            if stream_sender.unbounded_send(result).is_err() {
+                trace!("[{}] Stream receiver is not there anymore", self.tag);
+            }
+        };
+
+        let is_complete = if self.stream_remaining > 0 {
+            send(result);
+            self.stream_remaining -= 1;
+            self.stream_remaining == 0
+        } else {
+            match result {
+                Ok(resp_buf) if resp_buf.is_array() => match parse_array_header(&resp_buf) {
+                    // header alone: elements will follow
+                    Some((len, header_len)) if len > 0 && header_len == resp_buf.len() => {
+                        self.stream_remaining = len as usize;
+                        false
+                    }
+                    // whole array (e.g. cluster connection)
+                    _ => {
+                        match resp_buf.array_elements() {
+                            Ok(elements) => elements.into_iter().for_each(|e| send(Ok(e))),
+                            Err(e) => send(Err(e)),
+                        }
+                        true
+                    }
+                },
+                Ok(resp_buf) if resp_buf.is_nil() => true,
+                result => {
+                    send(result);
+                    true
+                }
+            }
+        };
+
+        if is_complete {
+            // dropping the stream sender ends the stream
+            self.messages_to_receive.pop_front();
+        }
+    }
+
     async fn try_match_pubsub_message(
         &mut self,
         value: Result<RespBuf>,
@@ -562,7 +1117,9 @@ impl NetworkHandler {
                     | RefPubSubMessage::SMessage(channel_or_pattern, _) => {
                         match self.subscriptions.get_mut(channel_or_pattern) {
                             Some((_subscription_type, pub_sub_sender)) => {
-                                if let Err(e) = pub_sub_sender.send(value).await {
+                                if let Err(e) =
+                                    pub_sub_sender.send(value.map(PubSubItem::Message)).await
+                                {
                                     warn!(
                                         "[{}] Cannot send pub/sub message to caller: {e}",
                                         self.tag
@@ -584,8 +1141,10 @@ impl NetworkHandler {
                     | RefPubSubMessage::SSubscribe(channel_or_pattern) => {
                         if let Some(pending_sub) = self.pending_subscriptions.pop_front() {
                             if pending_sub.channel_or_pattern == channel_or_pattern {
-                                self.subscriptions
-                                    .insert(channel_or_pattern.to_vec(), (pending_sub.subscription_type, pending_sub.sender));
+                                self.subscriptions.insert(
+                                    channel_or_pattern.to_vec(),
+                                    (pending_sub.subscription_type, pending_sub.sender),
+                                );
 
                                 if pending_sub.more_to_come {
                                     return None;
@@ -622,9 +1181,10 @@ impl NetworkHandler {
                                 None
                             } else {
                                 // last unsubscription notification received
-                                let Some(mut remaining) = self.pending_unsubscriptions.pop_front() else {
+                                let Some(mut remaining) = self.pending_unsubscriptions.pop_front()
+                                else {
                                     error!(
-                                        "[{}] Cannot find channel or pattern to remove: {}", 
+                                        "[{}] Cannot find channel or pattern to remove: {}",
                                         self.tag,
                                         String::from_utf8_lossy(channel_or_pattern)
                                     );
@@ -647,7 +1207,9 @@ impl NetworkHandler {
                     RefPubSubMessage::PMessage(pattern, channel, _) => {
                         match self.subscriptions.get_mut(pattern) {
                             Some((_subscription_type, pub_sub_sender)) => {
-                                if let Err(e) = pub_sub_sender.send(value).await {
+                                if let Err(e) =
+                                    pub_sub_sender.send(value.map(PubSubItem::Message)).await
+                                {
                                     warn!(
                                         "[{}] Cannot send pub/sub message to caller: {e}",
                                         self.tag
@@ -666,6 +1228,12 @@ impl NetworkHandler {
                         None
                     }
                 }
+            } else if self.stream_remaining == 0 && ref_value.is_push_frame() {
+                // e.g. an invalidation message, which is not the reply of a pending command
+                if let Ok(resp_buf) = value {
+                    self.handle_push_message(resp_buf).await;
+                }
+                None
             } else {
                 Some(value)
             }
@@ -674,10 +1242,21 @@ impl NetworkHandler {
         }
     }
 
-    async fn reconnect(&mut self) {
+    async fn reconnect(&mut self, reason: DisconnectReason) {
         debug!("[{}] reconnecting...", self.tag);
         let old_status = self.status;
         self.status = Status::Disconnected;
+        // a transaction does not survive the connection, nor does `CLIENT REPLY`
+        self.in_transaction = false;
+        self.skip_next_reply = false;
+        self.is_reply_on = true;
+        self.publish_connection_state();
+        // a previous reconnection attempt has failed, the disconnection has already been notified
+        if !matches!(old_status, Status::Disconnected) {
+            self.send_connection_event(ConnectionEvent::Disconnected(reason));
+        }
+        self.pending_health_check = None;
+        self.stream_remaining = 0;
 
         for message_to_receive in &mut self.messages_to_receive {
             if message_to_receive.message.retry_on_error {
@@ -698,29 +1277,10 @@ impl NetworkHandler {
                     self.tag, message_to_receive.message.commands
                 );
                 if let Some(message_to_receive) = self.messages_to_receive.pop_front() {
-                    match message_to_receive.message.commands {
-                        Commands::Single(_, Some(result_sender)) => {
-                            if let Err(e) = result_sender
-                                .send(Err(Error::Client("Disconnected from server".to_string())))
-                            {
-                                warn!(
-                                "[{}] Cannot send value to caller because receiver is not there anymore: {e:?}",
-                                self.tag
-                            );
-                            }
-                        }
-                        Commands::Batch(_, results_sender) => {
-                            if let Err(e) = results_sender
-                                .send(Err(Error::Client("Disconnected from server".to_string())))
-                            {
-                                warn!(
-                                "[{}] Cannot send value to caller because receiver is not there anymore: {e:?}",
-                                self.tag
-                            );
-                            }
-                        }
-                        _ => (),
-                    }
+                    Self::fail_message(
+                        message_to_receive.message,
+                        Error::Client("Disconnected from server".to_string()),
+                    );
                 }
             } else {
                 break;
@@ -777,19 +1337,31 @@ impl NetworkHandler {
 
         if let Err(e) = self.connection.reconnect().await {
             error!("[{}] Failed to reconnect: {e:?}", self.tag);
+            self.send_connection_event(ConnectionEvent::Error(e));
             return;
         }
 
+        // the server may have been upgraded or replaced
+        *self.server_features.write().unwrap() = Some(self.connection.server_features());
+        self.client_id.store(
+            self.connection.cached_client_id().unwrap_or_default(),
+            Ordering::Relaxed,
+        );
+
         if self.auto_resubscribe {
             if let Err(e) = self.auto_resubscribe().await {
                 error!("[{}] Failed to reconnect: {e:?}", self.tag);
+                self.send_connection_event(ConnectionEvent::Error(e));
                 return;
             }
+
+            self.notify_resubscribed();
         }
 
         if self.auto_remonitor {
             if let Err(e) = self.auto_remonitor(old_status).await {
                 error!("[{}] Failed to reconnect: {e:?}", self.tag);
+                self.send_connection_event(ConnectionEvent::Error(e));
                 return;
             }
         }
@@ -820,9 +1392,16 @@ impl NetworkHandler {
             self.status = Status::Connected;
         }
 
+        self.publish_connection_state();
+        self.send_connection_event(ConnectionEvent::Reconnected);
         info!("[{}] reconnected!", self.tag);
     }
 
+    fn send_connection_event(&self, event: ConnectionEvent) {
+        // no receiver is not an error
+        let _ = self.connection_event_sender.send(event);
+    }
+
     async fn auto_resubscribe(&mut self) -> Result<()> {
         if !self.subscriptions.is_empty() {
             for (channel_or_pattern, (subscription_type, _)) in &self.subscriptions {
@@ -847,8 +1426,7 @@ impl NetworkHandler {
         }
 
         if !self.pending_subscriptions.is_empty() {
-            for pending_sub in self.pending_subscriptions.drain(..)
-            {
+            for pending_sub in self.pending_subscriptions.drain(..) {
                 match pending_sub.subscription_type {
                     SubscriptionType::Channel => {
                         self.connection
@@ -867,8 +1445,10 @@ impl NetworkHandler {
                     }
                 }
 
-                self.subscriptions
-                    .insert(pending_sub.channel_or_pattern, (pending_sub.subscription_type, pending_sub.sender));
+                self.subscriptions.insert(
+                    pending_sub.channel_or_pattern,
+                    (pending_sub.subscription_type, pending_sub.sender),
+                );
             }
         }
 
@@ -901,6 +1481,29 @@ impl NetworkHandler {
         Ok(())
     }
 
+    /// Notifies each pub/sub stream once that messages may have been missed during the outage
+    fn notify_resubscribed(&self) {
+        let mut notified_senders: Vec<&PubSubSender> = Vec::new();
+
+        for (_subscription_type, pub_sub_sender) in self.subscriptions.values() {
+            if notified_senders
+                .iter()
+                .any(|sender| sender.same_receiver(pub_sub_sender))
+            {
+                continue;
+            }
+
+            if let Err(e) = pub_sub_sender.unbounded_send(Ok(PubSubItem::Reconnected)) {
+                debug!(
+                    "[{}] Cannot send reconnection notification to pub/sub stream: {e}",
+                    self.tag
+                );
+            }
+
+            notified_senders.push(pub_sub_sender);
+        }
+    }
+
     async fn auto_remonitor(&mut self, old_status: Status) -> Result<()> {
         if let Status::Monitor | Status::EnteringMonitor = old_status {
             self.connection.send(&cmd("MONITOR")).await?;
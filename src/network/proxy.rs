@@ -0,0 +1,253 @@
+use crate::{
+    client::{ProxyConfig, ProxyProtocol},
+    Error, Result,
+};
+use log::debug;
+use std::net::IpAddr;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+const SOCKS5_VERSION: u8 = 0x05;
+const SOCKS5_NO_AUTHENTICATION: u8 = 0x00;
+const SOCKS5_USERNAME_PASSWORD: u8 = 0x02;
+const SOCKS5_USERNAME_PASSWORD_VERSION: u8 = 0x01;
+const SOCKS5_CONNECT: u8 = 0x01;
+const SOCKS5_ATYP_IPV4: u8 = 0x01;
+const SOCKS5_ATYP_DOMAIN: u8 = 0x03;
+const SOCKS5_ATYP_IPV6: u8 = 0x04;
+
+/// Maximum size of the response headers of an HTTP proxy
+const HTTP_MAX_RESPONSE_SIZE: usize = 8192;
+
+/// Ask the proxy to which `stream` is connected to open a tunnel to `host`:`port`
+pub(crate) async fn proxy_handshake<S>(
+    stream: &mut S,
+    proxy: &ProxyConfig,
+    host: &str,
+    port: u16,
+) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    debug!(
+        "Opening tunnel to {host}:{port} through proxy {}:{}...",
+        proxy.host, proxy.port
+    );
+
+    match proxy.protocol {
+        ProxyProtocol::Socks5 => socks5_handshake(stream, proxy, host, port).await,
+        ProxyProtocol::Http => http_connect_handshake(stream, proxy, host, port).await,
+    }
+}
+
+/// See [RFC 1928](https://www.rfc-editor.org/rfc/rfc1928)
+/// and [RFC 1929](https://www.rfc-editor.org/rfc/rfc1929)
+async fn socks5_handshake<S>(
+    stream: &mut S,
+    proxy: &ProxyConfig,
+    host: &str,
+    port: u16,
+) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let credentials = match (&proxy.username, &proxy.password) {
+        (None, None) => None,
+        (username, password) => Some((
+            username.as_deref().unwrap_or_default(),
+            password.as_deref().unwrap_or_default(),
+        )),
+    };
+
+    // method negotiation
+    if credentials.is_some() {
+        stream
+            .write_all(&[
+                SOCKS5_VERSION,
+                2,
+                SOCKS5_NO_AUTHENTICATION,
+                SOCKS5_USERNAME_PASSWORD,
+            ])
+            .await?;
+    } else {
+        stream
+            .write_all(&[SOCKS5_VERSION, 1, SOCKS5_NO_AUTHENTICATION])
+            .await?;
+    }
+
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+    if reply[0] != SOCKS5_VERSION {
+        return Err(Error::Client(format!(
+            "Invalid SOCKS version {} from proxy",
+            reply[0]
+        )));
+    }
+
+    match (reply[1], credentials) {
+        (SOCKS5_NO_AUTHENTICATION, _) => (),
+        (SOCKS5_USERNAME_PASSWORD, Some((username, password))) => {
+            if username.len() > 255 || password.len() > 255 {
+                return Err(Error::Client(
+                    "SOCKS username and password must not exceed 255 bytes".to_owned(),
+                ));
+            }
+
+            let mut request = Vec::with_capacity(3 + username.len() + password.len());
+            request.push(SOCKS5_USERNAME_PASSWORD_VERSION);
+            request.push(username.len() as u8);
+            request.extend_from_slice(username.as_bytes());
+            request.push(password.len() as u8);
+            request.extend_from_slice(password.as_bytes());
+            stream.write_all(&request).await?;
+
+            let mut reply = [0u8; 2];
+            stream.read_exact(&mut reply).await?;
+            if reply[1] != 0 {
+                return Err(Error::Client(
+                    "SOCKS proxy authentication failed".to_owned(),
+                ));
+            }
+        }
+        _ => {
+            return Err(Error::Client(
+                "No acceptable SOCKS authentication method".to_owned(),
+            ));
+        }
+    }
+
+    // connection request, the host name being resolved by the proxy
+    let mut request = vec![SOCKS5_VERSION, SOCKS5_CONNECT, 0];
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => {
+            request.push(SOCKS5_ATYP_IPV4);
+            request.extend_from_slice(&ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            request.push(SOCKS5_ATYP_IPV6);
+            request.extend_from_slice(&ip.octets());
+        }
+        Err(_) => {
+            if host.len() > 255 {
+                return Err(Error::Client(format!(
+                    "Host name {host} is too long for a SOCKS proxy"
+                )));
+            }
+            request.push(SOCKS5_ATYP_DOMAIN);
+            request.push(host.len() as u8);
+            request.extend_from_slice(host.as_bytes());
+        }
+    }
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut reply = [0u8; 4];
+    stream.read_exact(&mut reply).await?;
+    if reply[1] != 0 {
+        return Err(Error::Client(format!(
+            "SOCKS proxy cannot connect to {host}:{port}: {}",
+            socks5_reply_message(reply[1])
+        )));
+    }
+
+    // skip the bound address
+    let address_len = match reply[3] {
+        SOCKS5_ATYP_IPV4 => 4,
+        SOCKS5_ATYP_IPV6 => 16,
+        SOCKS5_ATYP_DOMAIN => stream.read_u8().await? as usize,
+        atyp => {
+            return Err(Error::Client(format!(
+                "Invalid SOCKS address type {atyp} from proxy"
+            )))
+        }
+    };
+    let mut bound_address = vec![0u8; address_len + 2];
+    stream.read_exact(&mut bound_address).await?;
+
+    Ok(())
+}
+
+fn socks5_reply_message(reply: u8) -> &'static str {
+    match reply {
+        0x01 => "general SOCKS server failure",
+        0x02 => "connection not allowed by ruleset",
+        0x03 => "network unreachable",
+        0x04 => "host unreachable",
+        0x05 => "connection refused",
+        0x06 => "TTL expired",
+        0x07 => "command not supported",
+        0x08 => "address type not supported",
+        _ => "unknown error",
+    }
+}
+
+async fn http_connect_handshake<S>(
+    stream: &mut S,
+    proxy: &ProxyConfig,
+    host: &str,
+    port: u16,
+) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let authority = if host.contains(':') {
+        format!("[{host}]:{port}")
+    } else {
+        format!("{host}:{port}")
+    };
+
+    let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+    if proxy.username.is_some() || proxy.password.is_some() {
+        let credentials = format!(
+            "{}:{}",
+            proxy.username.as_deref().unwrap_or_default(),
+            proxy.password.as_deref().unwrap_or_default()
+        );
+        request.push_str("Proxy-Authorization: Basic ");
+        request.push_str(&base64_encode(credentials.as_bytes()));
+        request.push_str("\r\n");
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    // read byte by byte to not consume the beginning of the tunneled stream
+    let mut response = Vec::new();
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= HTTP_MAX_RESPONSE_SIZE {
+            return Err(Error::Client("HTTP proxy response is too large".to_owned()));
+        }
+        response.push(stream.read_u8().await?);
+    }
+
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    match status_line.split_whitespace().nth(1) {
+        Some(status) if status.starts_with('2') => Ok(()),
+        _ => Err(Error::Client(format!(
+            "HTTP proxy cannot connect to {authority}: {status_line}"
+        ))),
+    }
+}
+
+pub(crate) fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
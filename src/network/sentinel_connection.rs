@@ -35,6 +35,18 @@ impl SentinelConnection {
         self.inner_connection.read().await
     }
 
+    #[inline]
+    pub fn set_stream_array_header(&mut self, stream_array_header: bool) {
+        self.inner_connection
+            .set_stream_array_header(stream_array_header)
+    }
+
+    #[inline]
+    pub fn set_database(&mut self, database: usize) {
+        self.config.database = database;
+        self.inner_connection.set_database(database);
+    }
+
     #[inline]
     pub async fn reconnect(&mut self) -> Result<()> {
         self.inner_connection =
@@ -1,9 +1,10 @@
 use crate::{
-    client::{Config, PreparedCommand},
+    client::{Config, PreparedCommand, ServerFeatures},
     commands::{
-        ClusterCommands, ConnectionCommands, HelloOptions, SentinelCommands, ServerCommands,
+        ClientInfoAttribute, ClusterCommands, ConnectionCommands, HelloOptions, SentinelCommands,
+        ServerCommands,
     },
-    resp::{BufferDecoder, Command, CommandEncoder, RespBuf},
+    resp::{BufferDecoder, Command, CommandEncoder, RespBuf, ResponseLimits},
     tcp_connect, Error, Future, Result, RetryReason, TcpStreamReader, TcpStreamWriter,
 };
 #[cfg(feature = "tls")]
@@ -33,9 +34,11 @@ impl Streams {
     pub async fn connect(host: &str, port: u16, config: &Config) -> Result<Self> {
         #[cfg(feature = "tls")]
         if let Some(tls_config) = &config.tls_config {
-            let (reader, writer) =
-                tcp_tls_connect(host, port, tls_config, config.connect_timeout).await?;
-            let framed_read = FramedRead::new(reader, BufferDecoder);
+            let (reader, writer) = tcp_tls_connect(host, port, tls_config, config).await?;
+            let framed_read = FramedRead::new(
+                reader,
+                BufferDecoder::new(ResponseLimits::from_config(config)),
+            );
             let framed_write = FramedWrite::new(writer, CommandEncoder);
             Ok(Streams::TcpTls(framed_read, framed_write))
         } else {
@@ -48,7 +51,10 @@ impl Streams {
 
     pub async fn connect_non_secure(host: &str, port: u16, config: &Config) -> Result<Self> {
         let (reader, writer) = tcp_connect(host, port, config).await?;
-        let framed_read = FramedRead::new(reader, BufferDecoder);
+        let framed_read = FramedRead::new(
+            reader,
+            BufferDecoder::new(ResponseLimits::from_config(config)),
+        );
         let framed_write = FramedWrite::new(writer, CommandEncoder);
         Ok(Streams::Tcp(framed_read, framed_write))
     }
@@ -60,7 +66,8 @@ pub struct StandaloneConnection {
     config: Config,
     streams: Streams,
     buffer: BytesMut,
-    version: String,
+    server_features: ServerFeatures,
+    client_id: i64,
     tag: String,
 }
 
@@ -74,7 +81,8 @@ impl StandaloneConnection {
             config: config.clone(),
             streams,
             buffer: BytesMut::new(),
-            version: String::new(),
+            server_features: ServerFeatures::default(),
+            client_id: 0,
             tag: if config.connection_name.is_empty() {
                 format!("{}:{}", host, port)
             } else {
@@ -130,7 +138,7 @@ impl StandaloneConnection {
 
         #[cfg(debug_assertions)]
         if kill_connection {
-            let client_id = self.client_id().await?;
+            let client_id = self.client_id;
             let mut config = self.config.clone();
             config.connection_name = "killer".to_owned();
             let mut connection =
@@ -159,7 +167,9 @@ impl StandaloneConnection {
         } {
             if log_enabled!(Level::Debug) {
                 match &result {
-                    Ok(bytes) => debug!("[{}] Received result {bytes}", self.tag),
+                    // the reply body is logged once matched to its command,
+                    // which may be redacted, see `NetworkHandler::handle_result`
+                    Ok(bytes) => debug!("[{}] Received result ({} bytes)", self.tag, bytes.len()),
                     Err(err) => debug!("[{}] Received result {err:?}", self.tag),
                 }
             }
@@ -170,6 +180,24 @@ impl StandaloneConnection {
         }
     }
 
+    /// see [`BufferDecoder::stream_array_header`]
+    pub fn set_stream_array_header(&mut self, stream_array_header: bool) {
+        match &mut self.streams {
+            Streams::Tcp(framed_read, _) => {
+                framed_read.decoder_mut().stream_array_header = stream_array_header
+            }
+            #[cfg(feature = "tls")]
+            Streams::TcpTls(framed_read, _) => {
+                framed_read.decoder_mut().stream_array_header = stream_array_header
+            }
+        }
+    }
+
+    #[inline]
+    pub fn set_database(&mut self, database: usize) {
+        self.config.database = database;
+    }
+
     pub async fn reconnect(&mut self) -> Result<()> {
         self.streams = Streams::connect(&self.host, self.port, &self.config).await?;
         self.post_connect().await?;
@@ -200,18 +228,73 @@ impl StandaloneConnection {
         }
 
         let hello_result = self.hello(hello_options).await?;
-        self.version = hello_result.version;
+        self.client_id = hello_result.id;
+        self.server_features = ServerFeatures::new(
+            hello_result.server,
+            hello_result.version,
+            hello_result.modules,
+        );
+
+        // client library name & version
+        if let Some(lib_name) = self.config.lib_name.clone() {
+            self.set_client_info(ClientInfoAttribute::LibName, lib_name)
+                .await?;
+        }
+
+        if let Some(lib_version) = self.config.lib_version.clone() {
+            self.set_client_info(ClientInfoAttribute::LibVer, lib_version)
+                .await?;
+        }
 
         // select database
         if self.config.database != 0 {
             self.select(self.config.database).await?;
         }
 
+        // enable read queries on a cluster replica
+        if self.config.readonly {
+            self.readonly().await?;
+        }
+
+        // protect the connection from the client eviction
+        if self.config.no_evict {
+            self.client_no_evict(true).await?;
+        }
+
+        // do not alter the LRU/LFU of the accessed keys
+        if self.config.no_touch {
+            self.client_no_touch(true).await?;
+        }
+
         Ok(())
     }
 
+    /// `CLIENT SETINFO` is only available since Redis 7.2:
+    /// errors returned by older servers are ignored
+    async fn set_client_info(
+        &mut self,
+        attribute: ClientInfoAttribute,
+        value: String,
+    ) -> Result<()> {
+        match self.client_setinfo(attribute, value).await {
+            Err(Error::Redis(e)) => {
+                debug!("[{}] Cannot set client info: {e}", self.tag);
+                Ok(())
+            }
+            result => result,
+        }
+    }
+
     pub fn get_version(&self) -> &str {
-        &self.version
+        &self.server_features.version
+    }
+
+    pub fn server_features(&self) -> &ServerFeatures {
+        &self.server_features
+    }
+
+    pub fn cached_client_id(&self) -> i64 {
+        self.client_id
     }
 
     pub(crate) fn tag(&self) -> &str {
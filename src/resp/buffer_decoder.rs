@@ -1,10 +1,142 @@
 use super::RespDeserializer;
-use crate::{resp::RespBuf, Error, Result};
+use crate::{
+    client::Config,
+    resp::{
+        RespBuf, ARRAY_TAG, BLOB_ERROR_TAG, BULK_STRING_TAG, MAP_TAG, PUSH_TAG, SET_TAG,
+        VERBATIM_STRING_TAG,
+    },
+    Error, Result,
+};
 use bytes::BytesMut;
 use serde::{de::IgnoredAny, Deserialize};
 use tokio_util::codec::Decoder;
 
-pub(crate) struct BufferDecoder;
+/// Guards against misbehaving or malicious servers, `0` meaning no limit
+///
+/// See [`Config::max_bulk_string_size`], [`Config::max_reply_depth`] and [`Config::max_reply_size`]
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ResponseLimits {
+    pub max_bulk_string_size: usize,
+    pub max_depth: usize,
+    pub max_reply_size: usize,
+}
+
+impl ResponseLimits {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            max_bulk_string_size: config.max_bulk_string_size,
+            max_depth: config.max_reply_depth,
+            max_reply_size: config.max_reply_size,
+        }
+    }
+
+    fn is_unlimited(&self) -> bool {
+        self.max_bulk_string_size == 0 && self.max_depth == 0 && self.max_reply_size == 0
+    }
+
+    /// Checks the headers of the reply at the start of `bytes`, which may be incomplete,
+    /// so that a limit is detected before the whole reply is buffered
+    pub(crate) fn check(&self, bytes: &[u8]) -> Result<()> {
+        if self.is_unlimited() {
+            return Ok(());
+        }
+
+        // number of elements left in each aggregate being parsed
+        let mut remaining_elements = Vec::<usize>::new();
+        let mut pos = 0;
+
+        while let Some(end) = bytes[pos..].iter().position(|b| *b == b'\n') {
+            if end == 0 {
+                return Err(Error::Protocol("Empty RESP header line".to_owned()));
+            }
+
+            let tag = bytes[pos];
+            let header = &bytes[pos + 1..pos + end];
+            let header = header.strip_suffix(b"\r").unwrap_or(header);
+            let len = std::str::from_utf8(header)
+                .ok()
+                .and_then(|h| h.parse::<isize>().ok());
+            pos += end + 1;
+
+            self.check_reply_size(pos)?;
+
+            match (tag, len) {
+                // a null bulk string (`$-1`) has no body
+                (BULK_STRING_TAG | VERBATIM_STRING_TAG | BLOB_ERROR_TAG, Some(len)) if len >= 0 => {
+                    let len = len as usize;
+                    if self.max_bulk_string_size > 0 && len > self.max_bulk_string_size {
+                        return Err(Error::ResponseLimitExceeded {
+                            limit: "max_bulk_string_size",
+                            max: self.max_bulk_string_size,
+                            actual: len,
+                        });
+                    }
+                    self.check_reply_size(pos + len + 2)?;
+                    pos += len + 2;
+                }
+                (ARRAY_TAG | SET_TAG | PUSH_TAG | MAP_TAG, Some(len)) if len > 0 => {
+                    let depth = remaining_elements.len() + 1;
+                    if self.max_depth > 0 && depth > self.max_depth {
+                        return Err(Error::ResponseLimitExceeded {
+                            limit: "max_reply_depth",
+                            max: self.max_depth,
+                            actual: depth,
+                        });
+                    }
+                    let len = len as usize;
+                    remaining_elements.push(if tag == MAP_TAG { len * 2 } else { len });
+                    continue;
+                }
+                _ => (),
+            }
+
+            // a value has been parsed
+            while let Some(remaining) = remaining_elements.last_mut() {
+                *remaining -= 1;
+                if *remaining > 0 {
+                    break;
+                }
+                remaining_elements.pop();
+            }
+
+            if remaining_elements.is_empty() || pos >= bytes.len() {
+                return Ok(());
+            }
+        }
+
+        // header line not complete yet
+        self.check_reply_size(bytes.len())
+    }
+
+    fn check_reply_size(&self, size: usize) -> Result<()> {
+        if self.max_reply_size > 0 && size > self.max_reply_size {
+            return Err(Error::ResponseLimitExceeded {
+                limit: "max_reply_size",
+                max: self.max_reply_size,
+                actual: size,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct BufferDecoder {
+    /// When set, the header of a non-empty array or set is decoded alone,
+    /// so that its elements can be decoded one by one as they arrive
+    pub stream_array_header: bool,
+    pub limits: ResponseLimits,
+}
+
+impl BufferDecoder {
+    pub fn new(limits: ResponseLimits) -> Self {
+        Self {
+            stream_array_header: false,
+            limits,
+        }
+    }
+}
 
 impl Decoder for BufferDecoder {
     type Item = RespBuf;
@@ -15,7 +147,19 @@ impl Decoder for BufferDecoder {
             return Ok(None);
         }
 
+        if self.stream_array_header && (src[0] == ARRAY_TAG || src[0] == SET_TAG) {
+            match parse_array_header(src) {
+                Some((len, header_len)) if len > 0 => {
+                    return Ok(Some(RespBuf::new(src.split_to(header_len).freeze())));
+                }
+                Some(_) => (),
+                None => return Ok(None),
+            }
+        }
+
         let bytes = src.as_ref();
+        self.limits.check(bytes)?;
+
         let mut deserializer = RespDeserializer::new(bytes);
         let result = IgnoredAny::deserialize(&mut deserializer);
         match result {
@@ -25,3 +169,17 @@ impl Decoder for BufferDecoder {
         }
     }
 }
+
+/// Parses the header of a RESP array or set (e.g. `*3\r\n`)
+///
+/// Returns the number of elements and the length of the header,
+/// or `None` if the header is not complete or malformed.
+pub(crate) fn parse_array_header(bytes: &[u8]) -> Option<(isize, usize)> {
+    let end = bytes.iter().position(|b| *b == b'\n')?;
+    if end < 2 || bytes[end - 1] != b'\r' {
+        return None;
+    }
+
+    let len = std::str::from_utf8(&bytes[1..end - 1]).ok()?.parse().ok()?;
+    Some((len, end + 1))
+}
@@ -1,26 +1,85 @@
-use crate::resp::{deserialize_byte_buf, serialize_byte_buf};
-use serde::{Deserialize, Serialize};
-use std::{fmt, ops::Deref};
+use bytes::Bytes;
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+/// Maximum length of a bulk string stored inline, without heap allocation
+const INLINE_CAPACITY: usize = 22;
+
+#[derive(Clone)]
+enum Repr {
+    Inline { len: u8, buf: [u8; INLINE_CAPACITY] },
+    Shared(Bytes),
+}
 
 /// Represents the [Bulk String](https://redis.io/docs/reference/protocol-spec/#resp-bulk-strings) RESP type
-#[derive(Deserialize, Serialize)]
-pub struct BulkString(#[serde(deserialize_with = "deserialize_byte_buf", serialize_with = "serialize_byte_buf")] Vec<u8>);
+///
+/// Short bulk strings (up to 22 bytes) are stored inline without heap allocation.
+/// Longer bulk strings are backed by a reference-counted [`Bytes`](bytes::Bytes) buffer,
+/// so that cloning a `BulkString` or converting it from/into [`Bytes`](bytes::Bytes) does not copy its content.
+///
+/// [`RespBuf::to_bulk_string`](crate::resp::RespBuf::to_bulk_string) and
+/// [`RespBuf::to_bulk_strings`](crate::resp::RespBuf::to_bulk_strings) share the buffer of a reply
+/// without copy, whereas serde deserialization copies the content of long bulk strings.
+#[derive(Clone)]
+pub struct BulkString(Repr);
 
 impl BulkString {
     /// Constructs a new `BulkString` from a bytes buffer
     #[inline]
     pub fn new(bytes: Vec<u8>) -> Self {
-        Self(bytes)
+        if bytes.len() <= INLINE_CAPACITY {
+            Self::from_slice(&bytes)
+        } else {
+            Self(Repr::Shared(Bytes::from(bytes)))
+        }
+    }
+
+    /// Constructs a new `BulkString` by copying a byte slice
+    #[inline]
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        if bytes.len() <= INLINE_CAPACITY {
+            let mut buf = [0; INLINE_CAPACITY];
+            buf[..bytes.len()].copy_from_slice(bytes);
+            Self(Repr::Inline {
+                len: bytes.len() as u8,
+                buf,
+            })
+        } else {
+            Self(Repr::Shared(Bytes::copy_from_slice(bytes)))
+        }
     }
 
     /// Returns the internal buffer as a byte slice
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
-        &self.0
+        match &self.0 {
+            Repr::Inline { len, buf } => &buf[..*len as usize],
+            Repr::Shared(bytes) => bytes,
+        }
     }
 
-    pub(crate) fn into_inner(self) -> Vec<u8> {
-        self.0
+    /// Converts the `BulkString` into a [`Bytes`](bytes::Bytes) buffer
+    ///
+    /// No copy occurs for long bulk strings.
+    #[inline]
+    pub fn into_bytes(self) -> Bytes {
+        match self.0 {
+            Repr::Inline { .. } => Bytes::copy_from_slice(self.as_bytes()),
+            Repr::Shared(bytes) => bytes,
+        }
+    }
+
+    /// Returns `true` if the content of the bulk string is stored inline, without heap allocation
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.0, Repr::Inline { .. })
     }
 }
 
@@ -29,34 +88,158 @@ impl Deref for BulkString {
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        &self.0
+        self.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for BulkString {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl PartialEq for BulkString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for BulkString {}
+
+impl Hash for BulkString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
     }
 }
 
 impl From<BulkString> for Vec<u8> {
     #[inline]
     fn from(bs: BulkString) -> Self {
-        bs.0
+        match bs.0 {
+            Repr::Inline { .. } => bs.as_bytes().to_vec(),
+            Repr::Shared(bytes) => bytes.into(),
+        }
+    }
+}
+
+impl From<BulkString> for Bytes {
+    #[inline]
+    fn from(bs: BulkString) -> Self {
+        bs.into_bytes()
     }
 }
 
 impl From<Vec<u8>> for BulkString {
     #[inline]
     fn from(bytes: Vec<u8>) -> Self {
-        BulkString(bytes)
+        BulkString::new(bytes)
+    }
+}
+
+impl From<Bytes> for BulkString {
+    #[inline]
+    fn from(bytes: Bytes) -> Self {
+        if bytes.len() <= INLINE_CAPACITY {
+            BulkString::from_slice(&bytes)
+        } else {
+            BulkString(Repr::Shared(bytes))
+        }
+    }
+}
+
+impl From<&[u8]> for BulkString {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        BulkString::from_slice(bytes)
     }
 }
 
 impl<const N: usize> From<&[u8; N]> for BulkString {
     #[inline]
     fn from(bytes: &[u8; N]) -> Self {
-        BulkString(bytes.to_vec())
+        BulkString::from_slice(bytes)
+    }
+}
+
+impl From<&str> for BulkString {
+    #[inline]
+    fn from(s: &str) -> Self {
+        BulkString::from_slice(s.as_bytes())
+    }
+}
+
+impl From<String> for BulkString {
+    #[inline]
+    fn from(s: String) -> Self {
+        BulkString::new(s.into_bytes())
     }
 }
 
 impl fmt::Debug for BulkString {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("BulkString").field(&self.0).finish()
+        f.debug_tuple("BulkString").field(&self.as_bytes()).finish()
+    }
+}
+
+impl Serialize for BulkString {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(self.as_bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for BulkString {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BulkStringVisitor;
+
+        impl<'de> Visitor<'de> for BulkStringVisitor {
+            type Value = BulkString;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("BulkString")
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(BulkString::from_slice(v))
+            }
+
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(BulkString::new(v))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(BulkString::from_slice(v.as_bytes()))
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(BulkString::new(v.into_bytes()))
+            }
+        }
+
+        // borrowed bytes: short bulk strings do not allocate
+        deserializer.deserialize_bytes(BulkStringVisitor)
     }
 }
@@ -0,0 +1,211 @@
+/*!
+Standalone [`RESP3`](https://github.com/redis/redis-specifications/blob/master/protocol/RESP3.md) encoding and decoding
+
+The functions and types of this module expose the protocol implementation used internally by rustis,
+independently of any connection, so that it can be reused in proxies or tests,
+or fuzzed on its own.
+
+* [`encode_command`] writes a [`Command`] as a RESP array of bulk strings,
+  the format expected by a Redis server,
+* [`encode_value`] writes any [`Value`], the format of Redis replies,
+* [`decode_value`] decodes a single [`Value`] from a buffer,
+* [`Parser`] is a push-based parser: bytes are fed as they are received
+  and values are extracted as soon as they are complete,
+* [`RespCodec`] implements the [`Encoder`](tokio_util::codec::Encoder)
+  and [`Decoder`](tokio_util::codec::Decoder) traits of `tokio-util`,
+  to build custom transports with [`Framed`](tokio_util::codec::Framed).
+
+Decoding never panics on malformed input: a protocol error is returned instead.
+
+# Example
+```
+use rustis::resp::{cmd, codec::{encode_command, Parser}, Value};
+
+let mut buf = bytes::BytesMut::new();
+encode_command(&cmd("GET").arg("key"), &mut buf);
+assert_eq!(b"*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n", &buf[..]);
+
+let mut parser = Parser::new();
+parser.feed(b"+OK\r\n:1");
+assert_eq!(Some(Value::SimpleString("OK".to_owned())), parser.next_value()?);
+// incomplete value
+assert_eq!(None, parser.next_value()?);
+parser.feed(b"2\r\n");
+assert_eq!(Some(Value::Integer(12)), parser.next_value()?);
+# Ok::<(), rustis::Error>(())
+```
+*/
+
+use crate::{
+    resp::{BufferDecoder, Command, CommandEncoder, RespBuf, RespSerializer, Value},
+    Error, Result,
+};
+use bytes::BytesMut;
+use serde::Serialize;
+use tokio_util::codec::{Decoder, Encoder};
+
+/// Encode a [`Command`] at the end of `buf`
+#[inline]
+pub fn encode_command(command: &Command, buf: &mut BytesMut) {
+    // encoding a command cannot fail
+    let _ = CommandEncoder.encode(command, buf);
+}
+
+/// Encode a [`Value`] at the end of `buf`
+pub fn encode_value(value: &Value, buf: &mut BytesMut) -> Result<()> {
+    let mut serializer = RespSerializer::new();
+    value.serialize(&mut serializer)?;
+    buf.extend_from_slice(&serializer.get_output());
+    Ok(())
+}
+
+/// Decode the first [`Value`] of `bytes`
+///
+/// # Return
+/// The decoded value and the number of bytes it spans,
+/// or `None` if `bytes` does not hold a complete value yet.
+///
+/// Redis errors are decoded as [`Value::Error`].
+///
+/// # Errors
+/// [`Error::Protocol`](crate::Error::Protocol) or [`Error::Client`](crate::Error::Client)
+/// if `bytes` does not start with a valid RESP value.
+pub fn decode_value(bytes: &[u8]) -> Result<Option<(Value, usize)>> {
+    let mut buf = BytesMut::from(bytes);
+    match BufferDecoder::default().decode(&mut buf)? {
+        Some(resp_buf) => {
+            let len = resp_buf.len();
+            Ok(Some((to_value(&resp_buf)?, len)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Push-based incremental RESP parser
+///
+/// Bytes are appended with [`feed`](Parser::feed) as they are received,
+/// in chunks of any size, and complete values are extracted with [`next_value`](Parser::next_value)
+/// or [`next_frame`](Parser::next_frame).
+#[derive(Default)]
+pub struct Parser {
+    buf: BytesMut,
+    decoder: BufferDecoder,
+}
+
+impl Parser {
+    /// Create a new parser with an empty buffer
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append received bytes to the internal buffer
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Number of buffered bytes which have not been parsed yet
+    #[must_use]
+    pub fn buffered_len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Extract the next complete value, as a raw RESP buffer
+    ///
+    /// Returns `None` if the buffered bytes do not hold a complete value yet.
+    pub fn next_frame(&mut self) -> Result<Option<RespBuf>> {
+        self.decoder.decode(&mut self.buf)
+    }
+
+    /// Extract the next complete value
+    ///
+    /// Returns `None` if the buffered bytes do not hold a complete value yet.
+    /// Redis errors are decoded as [`Value::Error`].
+    pub fn next_value(&mut self) -> Result<Option<Value>> {
+        match self.next_frame()? {
+            Some(resp_buf) => Ok(Some(to_value(&resp_buf)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Convert a complete RESP buffer to a [`Value`], keeping Redis errors as values
+pub(crate) fn to_value(resp_buf: &RespBuf) -> Result<Value> {
+    match resp_buf.to::<Value>() {
+        Ok(value) => Ok(value),
+        Err(e) => e.into_redis_error().map(Value::Error),
+    }
+}
+
+/// [`tokio-util`](tokio_util::codec) codec encoding [`Command`]s or [`Value`]s and decoding [`Value`]s
+///
+/// It allows to build custom transports (TLS tunnels, proxies, multiplexers, ...)
+/// on top of the rustis protocol implementation.
+///
+/// Redis errors are decoded as [`Value::Error`].
+///
+/// # Example
+/// ```
+/// use rustis::resp::{cmd, codec::RespCodec, Value};
+/// use bytes::BytesMut;
+/// use tokio_util::codec::{Decoder, Encoder};
+///
+/// let mut codec = RespCodec;
+/// let mut buf = BytesMut::new();
+/// codec.encode(&cmd("PING"), &mut buf)?;
+/// assert_eq!(b"*1\r\n$4\r\nPING\r\n", &buf[..]);
+///
+/// let mut buf = BytesMut::from(&b"+PONG\r\n"[..]);
+/// assert_eq!(Some(Value::SimpleString("PONG".to_owned())), codec.decode(&mut buf)?);
+/// # Ok::<(), rustis::Error>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RespCodec;
+
+impl Encoder<&Command> for RespCodec {
+    type Error = Error;
+
+    #[inline]
+    fn encode(&mut self, command: &Command, buf: &mut BytesMut) -> Result<()> {
+        CommandEncoder.encode(command, buf)
+    }
+}
+
+impl Encoder<Command> for RespCodec {
+    type Error = Error;
+
+    #[inline]
+    fn encode(&mut self, command: Command, buf: &mut BytesMut) -> Result<()> {
+        CommandEncoder.encode(&command, buf)
+    }
+}
+
+impl Encoder<&Value> for RespCodec {
+    type Error = Error;
+
+    #[inline]
+    fn encode(&mut self, value: &Value, buf: &mut BytesMut) -> Result<()> {
+        encode_value(value, buf)
+    }
+}
+
+impl Encoder<Value> for RespCodec {
+    type Error = Error;
+
+    #[inline]
+    fn encode(&mut self, value: Value, buf: &mut BytesMut) -> Result<()> {
+        encode_value(&value, buf)
+    }
+}
+
+impl Decoder for RespCodec {
+    type Item = Value;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Value>> {
+        match BufferDecoder::default().decode(src)? {
+            Some(resp_buf) => Ok(Some(to_value(&resp_buf)?)),
+            None => Ok(None),
+        }
+    }
+}
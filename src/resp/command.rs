@@ -1,4 +1,12 @@
-use crate::resp::{CommandArgs, ToArgs};
+use crate::{
+    resp::{write_quoted, CommandArgs, ToArgs},
+    Error, Result,
+};
+use std::{
+    collections::HashSet,
+    fmt,
+    sync::{Mutex, OnceLock},
+};
 
 #[cfg(debug_assertions)]
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -13,8 +21,21 @@ pub fn cmd(name: &'static str) -> Command {
     Command::new(name)
 }
 
+/// Shortcut function for creating a command with room for at least `capacity` arguments.
+///
+/// Useful to avoid reallocations when building commands with a large number of arguments
+/// (e.g. `MSET` with thousands of key/value pairs).
+#[must_use]
+#[inline(always)]
+pub fn cmd_with_capacity(name: &'static str, capacity: usize) -> Command {
+    Command::with_capacity(name, capacity)
+}
+
 /// Generic command meant to be sent to the Redis Server
-#[derive(Debug, Clone)]
+///
+/// The [`Debug`](fmt::Debug) and [`Display`](fmt::Display) outputs of a command
+/// redact its sensitive arguments, see [`is_arg_redacted`](Command::is_arg_redacted).
+#[derive(Clone)]
 pub struct Command {
     /// Name of the command.
     ///
@@ -24,6 +45,22 @@ pub struct Command {
     pub name: &'static str,
     /// Collection of arguments of the command.
     pub args: CommandArgs,
+    /// High priority commands are sent ahead of the other queued commands.
+    ///
+    /// See [`high_priority`](Command::high_priority)
+    pub(crate) high_priority: bool,
+    /// Hash slot of the master node the command is sent to in cluster mode
+    ///
+    /// See [`hash_slot`](Command::hash_slot)
+    pub(crate) hash_slot: Option<u16>,
+    /// Indexes of the arguments flagged as sensitive
+    ///
+    /// See [`arg_redacted`](Command::arg_redacted)
+    pub(crate) redacted_args: Vec<usize>,
+    /// The reply of the command must not appear in logs
+    ///
+    /// See [`redact_reply`](Command::redact_reply)
+    pub(crate) redacted_reply: bool,
     #[doc(hidden)]
     #[cfg(debug_assertions)]
     pub kill_connection_on_write: usize,
@@ -42,6 +79,28 @@ impl Command {
         Self {
             name,
             args: CommandArgs::default(),
+            high_priority: false,
+            hash_slot: None,
+            redacted_args: Vec::new(),
+            redacted_reply: false,
+            #[cfg(debug_assertions)]
+            kill_connection_on_write: 0,
+            #[cfg(debug_assertions)]
+            command_seq: COMMAND_SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst),
+        }
+    }
+
+    /// Creates a new command with room for at least `capacity` arguments.
+    #[must_use]
+    #[inline(always)]
+    pub fn with_capacity(name: &'static str, capacity: usize) -> Self {
+        Self {
+            name,
+            args: CommandArgs::with_capacity(capacity),
+            high_priority: false,
+            hash_slot: None,
+            redacted_args: Vec::new(),
+            redacted_reply: false,
             #[cfg(debug_assertions)]
             kill_connection_on_write: 0,
             #[cfg(debug_assertions)]
@@ -60,6 +119,22 @@ impl Command {
         self
     }
 
+    /// Builder function to add a sensitive argument to an existing command.
+    ///
+    /// The argument is sent as is to the server,
+    /// but it is redacted from the [`Debug`](fmt::Debug) and [`Display`](fmt::Display) outputs of the command,
+    /// so that it does not leak into logs.
+    #[must_use]
+    pub fn arg_redacted<A>(mut self, arg: A) -> Self
+    where
+        A: ToArgs,
+    {
+        let start = self.args.len();
+        arg.write_args(&mut self.args);
+        self.redacted_args.extend(start..self.args.len());
+        self
+    }
+
     /// Builder function to add an argument to an existing command, only if a condition is `true`.
     #[must_use]
     #[inline(always)]
@@ -73,10 +148,354 @@ impl Command {
         self
     }
 
+    /// Builder function to add all the arguments of an iterator to an existing command.
+    #[must_use]
+    #[inline(always)]
+    pub fn extend_from_iter<I, A>(mut self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+        A: ToArgs,
+    {
+        self.args.extend_from_iter(iter);
+        self
+    }
+
+    /// Builder function to schedule the command ahead of the commands
+    /// already queued on the connection and not sent yet.
+    ///
+    /// Useful for latency-sensitive commands like health checks or lock releases,
+    /// which should not wait behind a large pipeline flush.
+    /// The order between high priority commands is preserved.
+    #[must_use]
+    #[inline(always)]
+    pub fn high_priority(mut self) -> Self {
+        self.high_priority = true;
+        self
+    }
+
+    /// `true` if the command has been flagged with [`high_priority`](Command::high_priority)
+    #[must_use]
+    #[inline(always)]
+    pub fn is_high_priority(&self) -> bool {
+        self.high_priority
+    }
+
+    /// Builder function to send the command, in cluster mode, to the master node
+    /// serving the hash slot `hash_slot`, whatever its keys and its request policy.
+    ///
+    /// Useful for node-local commands like [`SCAN`](https://redis.io/commands/scan/),
+    /// which must be sent to each master node in turn.
+    /// Ignored by the other server configurations.
+    #[must_use]
+    #[inline(always)]
+    pub fn hash_slot(mut self, hash_slot: u16) -> Self {
+        self.hash_slot = Some(hash_slot);
+        self
+    }
+
+    /// Flag the argument at `index` as sensitive
+    pub(crate) fn redact_arg(&mut self, index: usize) {
+        if !self.redacted_args.contains(&index) {
+            self.redacted_args.push(index);
+        }
+    }
+
+    /// Flag the reply of the command as sensitive
+    pub(crate) fn redact_reply(&mut self) {
+        self.redacted_reply = true;
+    }
+
+    /// `true` if the reply of the command must not appear in logs,
+    /// e.g. the value of a key matching [`Config::redacted_key_patterns`](crate::client::Config::redacted_key_patterns)
+    #[must_use]
+    pub fn is_reply_redacted(&self) -> bool {
+        self.redacted_reply
+    }
+
+    /// `true` if the argument at `index` must not appear in logs.
+    ///
+    /// Arguments are redacted when they have been added with [`arg_redacted`](Command::arg_redacted),
+    /// when they are values of keys matching [`Config::redacted_key_patterns`](crate::client::Config::redacted_key_patterns),
+    /// or when they are known secrets: passwords of `AUTH`, `HELLO`, `MIGRATE`, `CONFIG SET requirepass|masterauth`
+    /// and `ACL SETUSER` rules.
+    #[must_use]
+    pub fn is_arg_redacted(&self, index: usize) -> bool {
+        self.redacted_args.contains(&index) || is_secret_arg(self, index)
+    }
+
     #[cfg(debug_assertions)]
     #[inline]
     pub fn kill_connection_on_write(mut self, num_kills: usize) -> Self {
         self.kill_connection_on_write = num_kills;
         self
     }
+
+    /// Builds a command from its textual form, as typed in `redis-cli`.
+    ///
+    /// Arguments are separated by whitespaces and follow the `redis-cli` quoting rules:
+    /// * in double quotes, the escape sequences `\"`, `\\`, `\n`, `\r`, `\t`, `\b`, `\a`
+    ///   and `\xHH` (hexadecimal byte) are supported,
+    /// * in single quotes, only `\'` is supported,
+    /// * a closing quote must be followed by a whitespace or the end of the text.
+    ///
+    /// The command name is converted to uppercase.
+    ///
+    /// # Errors
+    /// A [`Client`](crate::Error::Client) error if the text is empty or has unbalanced quotes
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::resp::Command;
+    ///
+    /// let command = Command::parse_inline(r#"set foo "hello world""#).unwrap();
+    /// assert_eq!("SET", command.name);
+    /// assert_eq!(2, command.args.len());
+    /// ```
+    pub fn parse_inline(text: &str) -> Result<Self> {
+        Self::parse_cli(split_inline_args(text)?)
+    }
+
+    /// Builds a command from arguments already split, the first one being the command name.
+    ///
+    /// The command name is converted to uppercase.
+    ///
+    /// # Errors
+    /// A [`Client`](crate::Error::Client) error if there is no command name,
+    /// if the name is not valid UTF-8, or if it is too long
+    /// or too many distinct names have already been parsed
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::resp::Command;
+    ///
+    /// let command = Command::parse_cli(&["SET", "foo", "bar"]).unwrap();
+    /// assert_eq!("SET", command.name);
+    /// assert_eq!(2, command.args.len());
+    /// ```
+    pub fn parse_cli<I, A>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = A>,
+        A: AsRef<[u8]>,
+    {
+        let mut args = args.into_iter();
+        let Some(name) = args.next() else {
+            return Err(Error::Client("Missing command name".to_owned()));
+        };
+        let name = std::str::from_utf8(name.as_ref())
+            .map_err(|e| Error::Client(format!("Invalid command name: {e}")))?;
+
+        let mut command = Self::new(intern_command_name(name)?);
+        for arg in args {
+            command.args.write_arg(arg.as_ref());
+        }
+
+        Ok(command)
+    }
+}
+
+/// Placeholder of the redacted arguments
+const REDACTED: &str = "***";
+
+/// Known secret arguments of the Redis commands
+fn is_secret_arg(command: &Command, index: usize) -> bool {
+    let args = &command.args;
+    let previous = |offset: usize| {
+        index
+            .checked_sub(offset)
+            .and_then(|i| args.get(i))
+            .map(|arg| arg.to_ascii_uppercase())
+    };
+
+    match command.name {
+        "AUTH" => true,
+        // HELLO protover AUTH username password
+        "HELLO" => {
+            previous(1).as_deref() == Some(b"AUTH") || previous(2).as_deref() == Some(b"AUTH")
+        }
+        // MIGRATE ... AUTH password | AUTH2 username password
+        "MIGRATE" => {
+            previous(1).as_deref() == Some(b"AUTH")
+                || previous(1).as_deref() == Some(b"AUTH2")
+                || previous(2).as_deref() == Some(b"AUTH2")
+        }
+        // CONFIG SET parameter value [parameter value ...]
+        "CONFIG" => {
+            args.first()
+                .is_some_and(|arg| arg.eq_ignore_ascii_case(b"SET"))
+                && index & 1 == 0
+                && matches!(previous(1).as_deref(), Some(b"REQUIREPASS" | b"MASTERAUTH"))
+        }
+        // ACL SETUSER username rule [rule ...]
+        "ACL" => {
+            index >= 2
+                && args
+                    .first()
+                    .is_some_and(|arg| arg.eq_ignore_ascii_case(b"SETUSER"))
+                && args
+                    .get(index)
+                    .and_then(|arg| arg.first())
+                    .is_some_and(|c| matches!(c, b'>' | b'<' | b'#' | b'!'))
+        }
+        _ => false,
+    }
+}
+
+impl fmt::Debug for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args: Vec<_> = self
+            .args
+            .iter()
+            .enumerate()
+            .map(|(index, arg)| {
+                if self.is_arg_redacted(index) {
+                    REDACTED.into()
+                } else {
+                    String::from_utf8_lossy(arg)
+                }
+            })
+            .collect();
+
+        f.debug_struct("Command")
+            .field("name", &self.name)
+            .field("args", &args)
+            .field("high_priority", &self.high_priority)
+            .finish()
+    }
+}
+
+/// Formats the command as it would appear in the output of [`MONITOR`](https://redis.io/commands/monitor/),
+/// each argument in double quotes with binary data escaped.
+/// Sensitive arguments are replaced by `"***"`, see [`Command::is_arg_redacted`].
+///
+/// The output can be parsed back with [`Command::parse_inline`].
+///
+/// # Example
+/// ```
+/// use rustis::resp::cmd;
+///
+/// let command = cmd("SET").arg("key").arg("hello\nworld");
+/// assert_eq!(r#""SET" "key" "hello\nworld""#, command.to_string());
+/// ```
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_quoted(f, self.name.as_bytes())?;
+        for (index, arg) in self.args.iter().enumerate() {
+            f.write_str(" ")?;
+            if self.is_arg_redacted(index) {
+                write_quoted(f, REDACTED.as_bytes())?;
+            } else {
+                write_quoted(f, arg)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Maximum length of a command name parsed at runtime
+const MAX_COMMAND_NAME_LEN: usize = 64;
+/// Maximum number of distinct command names parsed at runtime
+const MAX_INTERNED_COMMAND_NAMES: usize = 1024;
+
+/// Command names are `&'static str`: names parsed at runtime are interned,
+/// so each distinct name is allocated only once.
+///
+/// Interned names are never freed, their length and count are bounded
+/// so that untrusted input cannot grow the table without limit.
+fn intern_command_name(name: &str) -> Result<&'static str> {
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+    if name.len() > MAX_COMMAND_NAME_LEN {
+        return Err(Error::Client(format!(
+            "Command name exceeds {MAX_COMMAND_NAME_LEN} bytes"
+        )));
+    }
+
+    let name = name.to_uppercase();
+    let mut names = NAMES.get_or_init(Default::default).lock().unwrap();
+    match names.get(name.as_str()) {
+        Some(name) => Ok(name),
+        None if names.len() >= MAX_INTERNED_COMMAND_NAMES => Err(Error::Client(format!(
+            "Too many distinct command names, cannot parse {name}"
+        ))),
+        None => {
+            let name: &'static str = Box::leak(name.into_boxed_str());
+            names.insert(name);
+            Ok(name)
+        }
+    }
+}
+
+/// Split a command line into arguments, following the `redis-cli` quoting rules
+fn split_inline_args(text: &str) -> Result<Vec<Vec<u8>>> {
+    let bytes = text.as_bytes();
+    let mut args = Vec::new();
+    let mut i = 0;
+
+    loop {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if i == bytes.len() {
+            break;
+        }
+
+        let mut arg = Vec::new();
+        let mut quote: Option<u8> = None;
+
+        loop {
+            let Some(&b) = bytes.get(i) else {
+                if quote.is_some() {
+                    return Err(Error::Client(format!("Unbalanced quotes in `{text}`")));
+                }
+                break;
+            };
+
+            match quote {
+                None if b.is_ascii_whitespace() => break,
+                None if b == b'"' || b == b'\'' => quote = Some(b),
+                None => arg.push(b),
+                Some(q) if b == q => {
+                    // a closing quote must be followed by a space or the end of the text
+                    if bytes.get(i + 1).is_some_and(|b| !b.is_ascii_whitespace()) {
+                        return Err(Error::Client(format!(
+                            "Closing quote must be followed by a space in `{text}`"
+                        )));
+                    }
+                    i += 1;
+                    break;
+                }
+                Some(b'"') if b == b'\\' && i + 1 < bytes.len() => {
+                    i += 1;
+                    match bytes[i] {
+                        b'n' => arg.push(b'\n'),
+                        b'r' => arg.push(b'\r'),
+                        b't' => arg.push(b'\t'),
+                        b'b' => arg.push(0x08),
+                        b'a' => arg.push(0x07),
+                        b'x' if i + 2 < bytes.len()
+                            && bytes[i + 1].is_ascii_hexdigit()
+                            && bytes[i + 2].is_ascii_hexdigit() =>
+                        {
+                            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or_default();
+                            arg.push(u8::from_str_radix(hex, 16).unwrap_or_default());
+                            i += 2;
+                        }
+                        c => arg.push(c),
+                    }
+                }
+                Some(b'\'') if b == b'\\' && bytes.get(i + 1) == Some(&b'\'') => {
+                    i += 1;
+                    arg.push(b'\'');
+                }
+                Some(_) => arg.push(b),
+            }
+
+            i += 1;
+        }
+
+        args.push(arg);
+    }
+
+    Ok(args)
 }
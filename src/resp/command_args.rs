@@ -10,6 +10,22 @@ pub struct CommandArgs {
 }
 
 impl CommandArgs {
+    /// Constructs a new empty collection with room for at least `capacity` arguments
+    /// without reallocating.
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            args: SmallVec::with_capacity(capacity),
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more arguments.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.args.reserve(additional);
+    }
+
     /// Builder function to add an argument to an existing command collection.
     #[inline]
     pub fn arg<A>(&mut self, args: A) -> &mut Self
@@ -44,6 +60,23 @@ impl CommandArgs {
         }
     }
 
+    /// Builder function to add all the arguments of an iterator to an existing command collection.
+    ///
+    /// Capacity is reserved upfront from the iterator size hint.
+    #[inline]
+    pub fn extend_from_iter<I, A>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = A>,
+        A: ToArgs,
+    {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for args in iter {
+            args.write_args(self);
+        }
+        self
+    }
+
     /// helper to build a CommandArgs in one line.
     #[inline]
     pub fn build(&mut self) -> Self {
@@ -71,6 +104,12 @@ impl CommandArgs {
         self.args.push(buf.into());
     }
 
+    /// Consumes the collection, returning the arguments
+    #[inline]
+    pub(crate) fn into_args(self) -> impl Iterator<Item = Vec<u8>> {
+        self.args.into_iter()
+    }
+
     pub(crate) fn retain<F>(&mut self, mut f: F)
     where
         F: FnMut(&[u8]) -> bool,
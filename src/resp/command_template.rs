@@ -0,0 +1,188 @@
+use crate::{
+    client::PreparedCommand,
+    resp::{Command, CommandArgs, Response, ToArgs},
+    Error, Result,
+};
+use std::fmt;
+
+#[derive(Clone)]
+enum TemplatePart {
+    /// Argument encoded once, when the template is built
+    Static(Vec<u8>),
+    /// Argument provided each time the template is bound
+    Placeholder,
+}
+
+/// A [`Command`] built once with placeholder arguments,
+/// and bound many times to different arguments.
+///
+/// Static arguments are encoded once, when the template is built.
+/// Each call to [`bind`](CommandTemplate::bind) only encodes the bound arguments,
+/// which reduces the cost of commands sent on hot paths.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, commands::StringCommands, resp::CommandTemplate, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     // SET <key> <value> EX 60
+///     let set_with_ttl = CommandTemplate::new("SET")
+///         .placeholder()
+///         .placeholder()
+///         .arg("EX")
+///         .arg(60);
+///
+///     for i in 0..10 {
+///         let _: () = set_with_ttl
+///             .prepare(&client, (format!("key{i}"), i))?
+///             .await?;
+///     }
+///
+///     let value: i32 = client.get("key3").await?;
+///     assert_eq!(3, value);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct CommandTemplate {
+    name: &'static str,
+    parts: Vec<TemplatePart>,
+    num_placeholders: usize,
+}
+
+impl CommandTemplate {
+    /// Creates a new template for the command `name`
+    #[must_use]
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            parts: Vec::new(),
+            num_placeholders: 0,
+        }
+    }
+
+    /// Builder function to add static arguments to the template.
+    #[must_use]
+    pub fn arg<A>(mut self, arg: A) -> Self
+    where
+        A: ToArgs,
+    {
+        let mut args = CommandArgs::default();
+        arg.write_args(&mut args);
+        self.parts
+            .extend(args.iter().map(|arg| TemplatePart::Static(arg.clone())));
+        self
+    }
+
+    /// Builder function to add a placeholder for a single argument to the template.
+    #[must_use]
+    pub fn placeholder(mut self) -> Self {
+        self.parts.push(TemplatePart::Placeholder);
+        self.num_placeholders += 1;
+        self
+    }
+
+    /// Builder function to add `count` placeholders to the template.
+    #[must_use]
+    pub fn placeholders(mut self, count: usize) -> Self {
+        self.parts
+            .extend((0..count).map(|_| TemplatePart::Placeholder));
+        self.num_placeholders += count;
+        self
+    }
+
+    /// Name of the command
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Number of placeholders of the template
+    #[must_use]
+    pub fn num_placeholders(&self) -> usize {
+        self.num_placeholders
+    }
+
+    /// Builds a command from the template, filling the placeholders in order
+    /// with the arguments written by `bindings`.
+    ///
+    /// Tuples can be used to bind arguments of different types.
+    ///
+    /// # Errors
+    /// [`Error::Client`](crate::Error::Client) if `bindings` does not write exactly
+    /// one argument per placeholder.
+    pub fn bind<B>(&self, bindings: B) -> Result<Command>
+    where
+        B: ToArgs,
+    {
+        let mut bound_args = CommandArgs::with_capacity(self.num_placeholders);
+        bindings.write_args(&mut bound_args);
+
+        if bound_args.len() != self.num_placeholders {
+            return Err(Error::Client(format!(
+                "Command template {} expects {} arguments, {} provided",
+                self.name,
+                self.num_placeholders,
+                bound_args.len()
+            )));
+        }
+
+        let mut command = Command::with_capacity(self.name, self.parts.len());
+        let mut bound_args = bound_args.into_args();
+        for part in &self.parts {
+            match part {
+                TemplatePart::Static(arg) => command.args.write_arg(arg.clone()),
+                TemplatePart::Placeholder => {
+                    if let Some(arg) = bound_args.next() {
+                        command.args.write_arg(arg);
+                    }
+                }
+            }
+        }
+
+        Ok(command)
+    }
+
+    /// Binds the template (see [`bind`](CommandTemplate::bind))
+    /// and prepares the resulting command to be sent with `executor`
+    /// ([`Client`](crate::client::Client), [`Pipeline`](crate::client::Pipeline), ...).
+    ///
+    /// # Errors
+    /// [`Error::Client`](crate::Error::Client) if `bindings` does not write exactly
+    /// one argument per placeholder.
+    pub fn prepare<'a, E, R, B>(
+        &self,
+        executor: E,
+        bindings: B,
+    ) -> Result<PreparedCommand<'a, E, R>>
+    where
+        R: Response,
+        B: ToArgs,
+    {
+        Ok(PreparedCommand::new(executor, self.bind(bindings)?))
+    }
+}
+
+impl fmt::Debug for CommandTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommandTemplate")
+            .field("name", &self.name)
+            .field(
+                "args",
+                &self
+                    .parts
+                    .iter()
+                    .map(|part| match part {
+                        TemplatePart::Static(arg) => String::from_utf8_lossy(arg),
+                        TemplatePart::Placeholder => "?".into(),
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
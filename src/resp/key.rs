@@ -0,0 +1,304 @@
+use crate::resp::{BulkString, CommandArgs, SingleArg, ToArgs};
+use std::{fmt, marker::PhantomData, ops::Deref, str::FromStr};
+
+/// Name of a Redis key built from a [`KeyFormat`] or a [`TypedKey`]
+///
+/// It can be passed to any command expecting a key.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key(String);
+
+impl Key {
+    /// Create a key from its name
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    /// Name of the key
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consumes the key, returning its name
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for Key {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for Key {
+    #[inline]
+    fn from(name: String) -> Self {
+        Self(name)
+    }
+}
+
+impl From<&str> for Key {
+    #[inline]
+    fn from(name: &str) -> Self {
+        Self(name.to_owned())
+    }
+}
+
+impl From<Key> for String {
+    #[inline]
+    fn from(key: Key) -> Self {
+        key.0
+    }
+}
+
+impl From<Key> for BulkString {
+    #[inline]
+    fn from(key: Key) -> Self {
+        BulkString::from(key.0)
+    }
+}
+
+impl ToArgs for Key {
+    #[inline]
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(self.0.as_str());
+    }
+}
+
+impl ToArgs for &Key {
+    #[inline]
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(self.0.as_str());
+    }
+}
+
+impl SingleArg for Key {}
+impl SingleArg for &Key {}
+
+/// Conversion of a strongly typed key into the name of a Redis key
+///
+/// This trait is usually implemented with the [`key_format!`](crate::key_format) macro,
+/// which also makes the type usable as a command argument.
+pub trait KeyFormat {
+    /// Write the name of the key
+    fn format_key(&self, name: &mut String);
+
+    /// Build the [`Key`]
+    fn to_key(&self) -> Key {
+        let mut name = String::new();
+        self.format_key(&mut name);
+        Key(name)
+    }
+}
+
+/// Define a strongly typed key, formatted into a key name with a format string
+///
+/// The generated struct implements [`KeyFormat`](crate::resp::KeyFormat),
+/// [`SingleArg`](crate::resp::SingleArg), `Display`, and can be converted into a
+/// [`Key`](crate::resp::Key) or a [`BulkString`](crate::resp::BulkString).
+///
+/// Tuple structs with one field are formatted with a positional `{}` placeholder,
+/// structs with named fields with named placeholders.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::Client,
+///     commands::{HashCommands, StringCommands},
+///     key_format,
+///     resp::Key,
+///     Result,
+/// };
+///
+/// key_format! {
+///     /// Profile of a user
+///     #[derive(Debug, Clone, Copy)]
+///     pub struct UserKey(pub u64) => "user:{}";
+/// }
+///
+/// key_format! {
+///     /// Order of a user
+///     #[derive(Debug, Clone, Copy)]
+///     pub struct OrderKey { pub user_id: u64, pub order_id: u32 } => "user:{user_id}:order:{order_id}";
+/// }
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     client.hset(UserKey(12), ("name", "John")).await?;
+///     client.set(OrderKey { user_id: 12, order_id: 1 }, "pending").await?;
+///
+///     assert_eq!("user:12:order:1", Key::from(OrderKey { user_id: 12, order_id: 1 }).as_str());
+///
+///     Ok(())
+/// }
+/// ```
+#[macro_export]
+macro_rules! key_format {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($field_vis:vis $field_type:ty) => $format:literal;
+    ) => {
+        $(#[$meta])*
+        $vis struct $name($field_vis $field_type);
+
+        impl $crate::resp::KeyFormat for $name {
+            fn format_key(&self, name: &mut String) {
+                use std::fmt::Write;
+                let _ = write!(name, $format, self.0);
+            }
+        }
+
+        $crate::key_format!(@impl $name);
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident { $($field_vis:vis $field:ident: $field_type:ty),+ $(,)? } => $format:literal;
+    ) => {
+        $(#[$meta])*
+        $vis struct $name { $($field_vis $field: $field_type),+ }
+
+        impl $crate::resp::KeyFormat for $name {
+            #[allow(unused_variables)]
+            fn format_key(&self, name: &mut String) {
+                use std::fmt::Write;
+                let $name { $($field),+ } = self;
+                let _ = write!(name, $format);
+            }
+        }
+
+        $crate::key_format!(@impl $name);
+    };
+    (@impl $name:ident) => {
+        impl $crate::resp::ToArgs for $name {
+            fn write_args(&self, args: &mut $crate::resp::CommandArgs) {
+                args.arg($crate::resp::KeyFormat::to_key(self));
+            }
+        }
+
+        impl $crate::resp::ToArgs for &$name {
+            fn write_args(&self, args: &mut $crate::resp::CommandArgs) {
+                args.arg($crate::resp::KeyFormat::to_key(*self));
+            }
+        }
+
+        impl $crate::resp::SingleArg for $name {}
+        impl $crate::resp::SingleArg for &$name {}
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&$crate::resp::KeyFormat::to_key(self))
+            }
+        }
+
+        impl From<$name> for $crate::resp::Key {
+            fn from(key: $name) -> Self {
+                $crate::resp::KeyFormat::to_key(&key)
+            }
+        }
+
+        impl From<$name> for $crate::resp::BulkString {
+            fn from(key: $name) -> Self {
+                $crate::resp::KeyFormat::to_key(&key).into()
+            }
+        }
+    };
+}
+
+/// Builder of keys sharing the same format, identified by a value of type `T`
+///
+/// The format contains a single `{}` placeholder, replaced by the identifier.
+///
+/// # Example
+/// ```
+/// use rustis::resp::TypedKey;
+///
+/// let users = TypedKey::<u64>::new("user:{}:profile");
+///
+/// let key = users.key(12);
+/// assert_eq!("user:12:profile", key.as_str());
+/// assert_eq!("user:*:profile", users.pattern());
+/// assert_eq!(Some(12), users.parse(&key));
+/// ```
+pub struct TypedKey<T> {
+    prefix: String,
+    suffix: String,
+    phantom: PhantomData<fn(T)>,
+}
+
+impl<T> TypedKey<T> {
+    /// Create a key builder from a format with a single `{}` placeholder.
+    ///
+    /// If the format has no placeholder, the identifier is appended to it.
+    #[must_use]
+    pub fn new(format: &str) -> Self {
+        let (prefix, suffix) = format.split_once("{}").unwrap_or((format, ""));
+
+        Self {
+            prefix: prefix.to_owned(),
+            suffix: suffix.to_owned(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Glob-style pattern matching all the keys of this format,
+    /// to be used with [`scan`](crate::commands::GenericCommands::scan) or
+    /// [`keys`](crate::commands::GenericCommands::keys)
+    #[must_use]
+    pub fn pattern(&self) -> String {
+        format!("{}*{}", self.prefix, self.suffix)
+    }
+}
+
+impl<T: fmt::Display> TypedKey<T> {
+    /// Build the key of the given identifier
+    #[must_use]
+    pub fn key(&self, id: T) -> Key {
+        Key(format!("{}{id}{}", self.prefix, self.suffix))
+    }
+}
+
+impl<T: FromStr> TypedKey<T> {
+    /// Extract the identifier from a key name, or `None`
+    /// if the key name does not match the format
+    #[must_use]
+    pub fn parse(&self, name: &str) -> Option<T> {
+        name.strip_prefix(self.prefix.as_str())?
+            .strip_suffix(self.suffix.as_str())?
+            .parse()
+            .ok()
+    }
+}
+
+impl<T> Clone for TypedKey<T> {
+    fn clone(&self) -> Self {
+        Self {
+            prefix: self.prefix.clone(),
+            suffix: self.suffix.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for TypedKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedKey")
+            .field("prefix", &self.prefix)
+            .field("suffix", &self.suffix)
+            .finish()
+    }
+}
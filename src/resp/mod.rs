@@ -33,7 +33,8 @@ Current implementation provides the following conversions:
 * `i8`, `u16`, `i16`, `u32`, `i32`, `u64`, `i64`, `usize`, `isize`,
 * `f32`, `f64`,
 * `bool`,
-* `String`, `&String`, `char`, `&str`, [`BulkString`], `Vec<u8>`, `&[u8; N]`, `[u8; N]`, `&[u8]`
+* `String`, `&String`, `char`, `&str`, [`BulkString`], `&BulkString`, `Vec<u8>`, `&[u8; N]`, `[u8; N]`, `&[u8]`,
+  [`Bytes`](bytes::Bytes), `&Bytes`
 * `Option<T>` where `T: SingleArg`
 * `(T, U)`
 * `(T, U, V)`
@@ -61,7 +62,8 @@ Current implementation provides the following conversions:
 * `i8`, `u16`, `i16`, `u32`, `i32`, `u64`, `i64`, `usize`, `isize`,
 * `f32`, `f64`,
 * `bool`,
-* `String`, `&String`, `char`, `&str`, [`BulkString`], `Vec<u8>`, `&[u8; N]`, `[u8; N]`, `&[u8]`
+* `String`, `&String`, `char`, `&str`, [`BulkString`], `&BulkString`, `Vec<u8>`, `&[u8; N]`, `[u8; N]`, `&[u8]`,
+  [`Bytes`](bytes::Bytes), `&Bytes`
 * `Option<T>` where `T: SingleArg`
 
 #### Example
@@ -110,6 +112,14 @@ async fn main() -> Result<()> {
 }
 ```
 
+### Typed keys
+
+Instead of building key names with `format!` all over an application,
+strongly typed keys can be defined with the [`key_format!`](crate::key_format) macro,
+which implements [`KeyFormat`] and [`SingleArg`] for a struct from a format string.
+For keys identified by a single value, the [`TypedKey`] builder produces [`Key`] instances
+from the identifiers, and parses the identifiers back from scanned key names.
+
 ### SingleArgCollection
 
 Several Redis commands expect a collection with elements that will produced a single
@@ -212,11 +222,14 @@ Several Redis commands expect one or multiple key/value pairs.
 
 Current implementation provides the following conversions:
 * `(K, V)` (for the single item case)
-* `Vec<(K, V)>`
-* `[(K, V);N]`
+* `Vec<(K, V)>`, `&Vec<(K, V)>`
+* `[(K, V);N]`, `&[(K, V)]`
 * `SmallVec<A>` where `A: Array<Item = (K, V)>`
-* `BTreeMap<K, V>`
-* `HashMap<K, V, S>`
+* `BTreeMap<K, V>`, `&BTreeMap<K, V>`
+* `HashMap<K, V, S>`, `&HashMap<K, V, S>`
+
+Pairs are flattened into alternating key and value arguments.
+Borrowed collections can be passed to avoid moving or cloning a map owned by the caller.
 
 where each of theses implementations must also implement [`ToArgs`]
 
@@ -253,6 +266,9 @@ async fn main() -> Result<()> {
     client.mset(HashMap::from([("key1", 12), ("key2", 13)])).await?;
     client.mset(BTreeMap::from([("key1", 12), ("key2", 13)])).await?;
 
+    let items = HashMap::from([("key1", "value1"), ("key2", "value2")]);
+    client.mset(&items).await?;
+
     Ok(())
 }
 ```
@@ -291,9 +307,12 @@ Current implementation provides the following deserializations from a RESP Buffe
 * `f32`, `f64`,
 * `bool`,
 * `String`,
-* [`BulkString`],
+* [`BulkString`], `Vec<u8>`, [`Bytes`](bytes::Bytes),
 * `Option<T>`
 
+`BulkString`, `Vec<u8>` and `Bytes` are binary-safe: values which are not valid UTF-8,
+like serialized or compressed payloads, should be read to one of these types rather than to `String`.
+
 #### Example
 ```
 use rustis::{
@@ -339,6 +358,45 @@ async fn main() -> Result<()> {
 }
 ```
 
+### Nil replies
+
+Redis commands reply `nil` when there is nothing to return (`GET` on a missing key, `LPOP` on an empty list, etc.).
+
+`Option<T>` is the recommended way to receive the reply of such commands:
+a `nil` reply is always deserialized to `None`, by the [`RESP deserializer`](RespDeserializer) as well as
+by the [`resp::Value`](Value) deserializer.
+Commands with a fixed response type which can reply `nil` (e.g. [`dump`](crate::commands::GenericCommands::dump),
+[`lmpop`](crate::commands::ListCommands::lmpop) or [`zscore`](crate::commands::SortedSetCommands::zscore))
+return an `Option`.
+
+For compatibility, a `nil` reply can also be deserialized to a type with a natural default value:
+`0` for numbers, `false` for `bool`, an empty string for `String`, an empty collection for collections.
+In that case, `nil` cannot be distinguished from the default value.
+
+```
+use rustis::{
+    client::Client,
+    commands::{FlushingMode, ServerCommands, StringCommands},
+    Result,
+};
+
+#[cfg_attr(feature = "tokio-runtime", tokio::main)]
+#[cfg_attr(feature = "async-std-runtime", async_std::main)]
+async fn main() -> Result<()> {
+    let client = Client::connect("127.0.0.1:6379").await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let value: Option<String> = client.get("key").await?;
+    assert_eq!(None, value);
+
+    // compatibility conversion
+    let value: String = client.get("key").await?;
+    assert_eq!("", value);
+
+    Ok(())
+}
+```
+
 ### CollectionResponse
 
 Several Redis commands return a collection of items.
@@ -430,13 +488,33 @@ async fn main() -> Result<()> {
     Ok(())
 }
 ```
+
+# Command templates
+
+A [`CommandTemplate`] is a command built once with placeholder arguments,
+and bound many times to different arguments, to reduce the cost of commands sent on hot paths.
+
+# Encoding & decoding
+
+The [`codec`] module exposes the RESP encoder and decoder used by rustis,
+independently of any connection.
+
+# DUMP payloads
+
+The [`rdb`] module parses the payloads returned by [`DUMP`](https://redis.io/commands/dump/)
+to report the type, encoding and number of elements of a serialized value.
 */
 
+pub mod codec;
+pub mod rdb;
+
 mod buffer_decoder;
 mod bulk_string;
 mod command;
 mod command_args;
+mod command_template;
 mod command_encoder;
+mod key;
 mod resp_batch_deserializer;
 mod resp_buf;
 mod resp_deserializer;
@@ -453,7 +531,9 @@ pub(crate) use buffer_decoder::*;
 pub use bulk_string::*;
 pub use command::*;
 pub use command_args::*;
+pub use command_template::*;
 pub(crate) use command_encoder::*;
+pub use key::*;
 pub(crate) use resp_batch_deserializer::*;
 pub use resp_buf::*;
 pub use resp_deserializer::*;
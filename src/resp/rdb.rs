@@ -0,0 +1,483 @@
+/*!
+Inspection of [`DUMP`](https://redis.io/commands/dump/) payloads
+
+A `DUMP` payload is a single value serialized in the
+[RDB format](https://rdb.fnordig.de/file_format.html), followed by a footer made of
+the RDB version (2 bytes) and a CRC64 checksum (8 bytes).
+
+[`inspect_dump`] parses a payload just enough to report the type and encoding of the value,
+the RDB version, and the number of elements of the value, without restoring it on a server.
+It is meant for offline inspection or migration tooling, for instance on the payloads
+returned by [`backup_key`](crate::utils::backup_key).
+
+Parsing never panics on malformed input: an [`InvalidDump`](crate::Error::InvalidDump)
+error is returned instead.
+
+# Example
+```
+use rustis::resp::rdb::{inspect_dump, RdbEncoding, RdbValueType};
+
+// DUMP of a string "hello" with RDB version 10
+let payload = b"\x00\x05hello\x0a\x00\x63\x72\xdf\x76\x65\x34\x20\x0a";
+
+let info = inspect_dump(payload).unwrap();
+assert_eq!(RdbValueType::String, info.value_type);
+assert_eq!(RdbEncoding::Raw, info.encoding);
+assert_eq!(10, info.rdb_version);
+assert_eq!(Some(5), info.len);
+```
+*/
+
+use crate::{Error, Result};
+
+/// Length of the footer of a `DUMP` payload: RDB version (2 bytes) and CRC64 (8 bytes)
+pub(crate) const FOOTER_LEN: usize = 10;
+
+/// Type of the value serialized in a `DUMP` payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RdbValueType {
+    String,
+    List,
+    Set,
+    SortedSet,
+    Hash,
+    Stream,
+    Module,
+}
+
+/// Encoding of the value serialized in a `DUMP` payload,
+/// similar to the encodings reported by [`OBJECT ENCODING`](https://redis.io/commands/object-encoding/)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RdbEncoding {
+    /// String stored as is
+    Raw,
+    /// String stored as an integer
+    Int,
+    /// String compressed with LZF
+    Lzf,
+    /// Linked list, hash table or skip list, serialized element by element
+    HashTable,
+    ZipMap,
+    ZipList,
+    IntSet,
+    QuickList,
+    ListPack,
+    /// Hash with fields associated with an expiration time
+    ListPackEx,
+    Stream,
+    Module,
+}
+
+/// Information extracted from a `DUMP` payload by [`inspect_dump`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DumpInfo {
+    /// Type of the value
+    pub value_type: RdbValueType,
+    /// Encoding of the value
+    pub encoding: RdbEncoding,
+    /// RDB version of the payload
+    pub rdb_version: u16,
+    /// Number of elements of the value: length in bytes for strings,
+    /// number of elements for lists, sets and sorted sets, number of fields for hashes,
+    /// number of entries for streams.
+    ///
+    /// `None` if the number of elements is not known without decoding the whole value,
+    /// e.g. for modules.
+    pub len: Option<u64>,
+}
+
+/// Parse a `DUMP` payload and report the type, encoding and number of elements of its value.
+///
+/// The checksum of the payload is not verified,
+/// see [`KeyDump::validate`](crate::utils::KeyDump::validate).
+///
+/// # Errors
+/// An [`InvalidDump`](crate::Error::InvalidDump) error if the payload is malformed
+pub fn inspect_dump(payload: &[u8]) -> Result<DumpInfo> {
+    let Some(footer_start) = payload.len().checked_sub(FOOTER_LEN) else {
+        return Err(invalid(format!(
+            "payload of {} bytes is too short",
+            payload.len()
+        )));
+    };
+
+    let rdb_version = u16::from_le_bytes([payload[footer_start], payload[footer_start + 1]]);
+    let mut reader = RdbReader {
+        data: &payload[..footer_start],
+        pos: 0,
+    };
+
+    let object_type = reader.read_u8()?;
+    let (value_type, encoding, len) = match object_type {
+        0 => {
+            let (encoding, len) = reader.read_string_info()?;
+            (RdbValueType::String, encoding, Some(len))
+        }
+        1 => (
+            RdbValueType::List,
+            RdbEncoding::HashTable,
+            Some(reader.read_len()?),
+        ),
+        2 => (
+            RdbValueType::Set,
+            RdbEncoding::HashTable,
+            Some(reader.read_len()?),
+        ),
+        3 | 5 => (
+            RdbValueType::SortedSet,
+            RdbEncoding::HashTable,
+            Some(reader.read_len()?),
+        ),
+        4 => (
+            RdbValueType::Hash,
+            RdbEncoding::HashTable,
+            Some(reader.read_len()?),
+        ),
+        6 | 7 => (RdbValueType::Module, RdbEncoding::Module, None),
+        9 => {
+            let zipmap = reader.read_string()?;
+            let len = match zipmap.first() {
+                Some(&len) if len < 254 => Some(u64::from(len)),
+                _ => None,
+            };
+            (RdbValueType::Hash, RdbEncoding::ZipMap, len)
+        }
+        10 => (
+            RdbValueType::List,
+            RdbEncoding::ZipList,
+            ziplist_len(&reader.read_string()?)?,
+        ),
+        11 => (
+            RdbValueType::Set,
+            RdbEncoding::IntSet,
+            Some(intset_len(&reader.read_string()?)?),
+        ),
+        12 => (
+            RdbValueType::SortedSet,
+            RdbEncoding::ZipList,
+            ziplist_len(&reader.read_string()?)?.map(|len| len / 2),
+        ),
+        13 => (
+            RdbValueType::Hash,
+            RdbEncoding::ZipList,
+            ziplist_len(&reader.read_string()?)?.map(|len| len / 2),
+        ),
+        14 | 18 => {
+            let num_nodes = reader.read_len()?;
+            let mut len = Some(0);
+            for _ in 0..num_nodes {
+                let packed = if object_type == 18 {
+                    // container: 1 = plain element, 2 = packed in a listpack
+                    reader.read_len()? == 2
+                } else {
+                    true
+                };
+                let node = reader.read_string()?;
+                let node_len = match (packed, object_type) {
+                    (false, _) => Some(1),
+                    (true, 14) => ziplist_len(&node)?,
+                    (true, _) => listpack_len(&node)?,
+                };
+                len = len.zip(node_len).map(|(len, node_len)| len + node_len);
+            }
+            (RdbValueType::List, RdbEncoding::QuickList, len)
+        }
+        15 | 19 | 21 => {
+            let num_listpacks = reader.read_len()?;
+            for _ in 0..num_listpacks {
+                // master entry ID and listpack of entries
+                reader.skip_string()?;
+                reader.skip_string()?;
+            }
+            (
+                RdbValueType::Stream,
+                RdbEncoding::Stream,
+                Some(reader.read_len()?),
+            )
+        }
+        16 => (
+            RdbValueType::Hash,
+            RdbEncoding::ListPack,
+            listpack_len(&reader.read_string()?)?.map(|len| len / 2),
+        ),
+        17 => (
+            RdbValueType::SortedSet,
+            RdbEncoding::ListPack,
+            listpack_len(&reader.read_string()?)?.map(|len| len / 2),
+        ),
+        20 => (
+            RdbValueType::Set,
+            RdbEncoding::ListPack,
+            listpack_len(&reader.read_string()?)?,
+        ),
+        // hash with field expiration, introduced by Redis 7.4
+        22 | 24 => (RdbValueType::Hash, RdbEncoding::HashTable, None),
+        23 | 25 => (RdbValueType::Hash, RdbEncoding::ListPackEx, None),
+        _ => return Err(invalid(format!("unknown object type {object_type}"))),
+    };
+
+    Ok(DumpInfo {
+        value_type,
+        encoding,
+        rdb_version,
+        len,
+    })
+}
+
+/// CRC-64/Jones, the checksum used by Redis for RDB files and `DUMP` payloads
+#[must_use]
+pub fn crc64(data: &[u8]) -> u64 {
+    const POLY: u64 = 0x95ac_9329_ac4b_c9b5; // 0xad93d23594c935a9 reflected
+    const TABLE: [u64; 256] = {
+        let mut table = [0u64; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u64;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ POLY
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    };
+
+    data.iter().fold(0u64, |crc, &b| {
+        TABLE[((crc ^ u64::from(b)) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+fn invalid(message: String) -> Error {
+    Error::InvalidDump(message)
+}
+
+/// Length encoding of a string: special formats
+enum RdbLength {
+    Len(u64),
+    Int8,
+    Int16,
+    Int32,
+    Lzf,
+}
+
+struct RdbReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> RdbReader<'a> {
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| invalid("unexpected end of payload".to_owned()))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_length_encoding(&mut self) -> Result<RdbLength> {
+        let first = self.read_u8()?;
+        match first >> 6 {
+            0 => Ok(RdbLength::Len(u64::from(first & 0x3f))),
+            1 => Ok(RdbLength::Len(
+                (u64::from(first & 0x3f) << 8) | u64::from(self.read_u8()?),
+            )),
+            2 => match first {
+                0x80 => {
+                    let bytes = self.read_bytes(4)?;
+                    Ok(RdbLength::Len(u64::from(u32::from_be_bytes([
+                        bytes[0], bytes[1], bytes[2], bytes[3],
+                    ]))))
+                }
+                0x81 => {
+                    let mut bytes = [0u8; 8];
+                    bytes.copy_from_slice(self.read_bytes(8)?);
+                    Ok(RdbLength::Len(u64::from_be_bytes(bytes)))
+                }
+                _ => Err(invalid(format!("invalid length encoding {first:#04x}"))),
+            },
+            _ => match first & 0x3f {
+                0 => Ok(RdbLength::Int8),
+                1 => Ok(RdbLength::Int16),
+                2 => Ok(RdbLength::Int32),
+                3 => Ok(RdbLength::Lzf),
+                _ => Err(invalid(format!("invalid string encoding {first:#04x}"))),
+            },
+        }
+    }
+
+    fn read_len(&mut self) -> Result<u64> {
+        match self.read_length_encoding()? {
+            RdbLength::Len(len) => Ok(len),
+            _ => Err(invalid(
+                "unexpected string encoding for a length".to_owned(),
+            )),
+        }
+    }
+
+    fn read_usize(&mut self) -> Result<usize> {
+        usize::try_from(self.read_len()?).map_err(|_| invalid("length overflow".to_owned()))
+    }
+
+    /// Encoding and length of a string, without decompressing it
+    fn read_string_info(&mut self) -> Result<(RdbEncoding, u64)> {
+        match self.read_length_encoding()? {
+            RdbLength::Len(len) => {
+                self.read_bytes(usize::try_from(len).unwrap_or(usize::MAX))?;
+                Ok((RdbEncoding::Raw, len))
+            }
+            RdbLength::Int8 => Ok((RdbEncoding::Int, int_len(i64::from(self.read_u8()? as i8)))),
+            RdbLength::Int16 => {
+                let bytes = self.read_bytes(2)?;
+                Ok((
+                    RdbEncoding::Int,
+                    int_len(i64::from(i16::from_le_bytes([bytes[0], bytes[1]]))),
+                ))
+            }
+            RdbLength::Int32 => {
+                let bytes = self.read_bytes(4)?;
+                Ok((
+                    RdbEncoding::Int,
+                    int_len(i64::from(i32::from_le_bytes([
+                        bytes[0], bytes[1], bytes[2], bytes[3],
+                    ]))),
+                ))
+            }
+            RdbLength::Lzf => {
+                let compressed_len = self.read_usize()?;
+                let len = self.read_usize()?;
+                check_lzf_len(compressed_len, len)?;
+                self.read_bytes(compressed_len)?;
+                Ok((RdbEncoding::Lzf, len as u64))
+            }
+        }
+    }
+
+    /// Read a string, decompressing it if needed
+    fn read_string(&mut self) -> Result<Vec<u8>> {
+        match self.read_length_encoding()? {
+            RdbLength::Len(len) => Ok(self
+                .read_bytes(usize::try_from(len).unwrap_or(usize::MAX))?
+                .to_vec()),
+            RdbLength::Int8 => Ok(self.read_bytes(1)?.to_vec()),
+            RdbLength::Int16 => Ok(self.read_bytes(2)?.to_vec()),
+            RdbLength::Int32 => Ok(self.read_bytes(4)?.to_vec()),
+            RdbLength::Lzf => {
+                let compressed_len = self.read_usize()?;
+                let len = self.read_usize()?;
+                check_lzf_len(compressed_len, len)?;
+                lzf_decompress(self.read_bytes(compressed_len)?, len)
+            }
+        }
+    }
+
+    fn skip_string(&mut self) -> Result<()> {
+        self.read_string_info().map(|_| ())
+    }
+}
+
+/// Number of characters of the decimal representation of an integer
+fn int_len(value: i64) -> u64 {
+    value.to_string().len() as u64
+}
+
+/// Number of entries of a ziplist, `None` if the ziplist does not store it (more than 65534 entries)
+fn ziplist_len(ziplist: &[u8]) -> Result<Option<u64>> {
+    // zlbytes (4 bytes), zltail (4 bytes), zllen (2 bytes)
+    let Some(zllen) = ziplist.get(8..10) else {
+        return Err(invalid("ziplist header is too short".to_owned()));
+    };
+    let len = u16::from_le_bytes([zllen[0], zllen[1]]);
+    Ok((len != u16::MAX).then_some(u64::from(len)))
+}
+
+/// Number of entries of a listpack, `None` if the listpack does not store it (more than 65534 entries)
+fn listpack_len(listpack: &[u8]) -> Result<Option<u64>> {
+    // total bytes (4 bytes), number of elements (2 bytes)
+    let Some(num_elements) = listpack.get(4..6) else {
+        return Err(invalid("listpack header is too short".to_owned()));
+    };
+    let len = u16::from_le_bytes([num_elements[0], num_elements[1]]);
+    Ok((len != u16::MAX).then_some(u64::from(len)))
+}
+
+fn intset_len(intset: &[u8]) -> Result<u64> {
+    // encoding (4 bytes), length (4 bytes)
+    let Some(len) = intset.get(4..8) else {
+        return Err(invalid("intset header is too short".to_owned()));
+    };
+    Ok(u64::from(u32::from_le_bytes([
+        len[0], len[1], len[2], len[3],
+    ])))
+}
+
+/// Maximum expansion of LZF: a 3-byte back reference expands to 264 bytes
+const LZF_MAX_RATIO: usize = 88;
+
+/// Reject the declared uncompressed lengths that LZF cannot produce,
+/// before allocating the output buffer
+fn check_lzf_len(compressed_len: usize, len: usize) -> Result<()> {
+    if len > compressed_len.saturating_mul(LZF_MAX_RATIO) {
+        return Err(invalid(format!(
+            "LZF string of {compressed_len} bytes cannot expand to {len} bytes"
+        )));
+    }
+    Ok(())
+}
+
+/// Decompress a string compressed with [LZF](http://oldhome.schmorp.de/marc/liblzf.html)
+fn lzf_decompress(input: &[u8], len: usize) -> Result<Vec<u8>> {
+    let corrupted = || invalid("corrupted LZF string".to_owned());
+    let mut output = Vec::with_capacity(len);
+    let mut i = 0;
+
+    while i < input.len() {
+        let ctrl = usize::from(input[i]);
+        i += 1;
+
+        if ctrl < 32 {
+            // literal run of ctrl + 1 bytes
+            let literal = input.get(i..i + ctrl + 1).ok_or_else(corrupted)?;
+            output.extend_from_slice(literal);
+            i += ctrl + 1;
+        } else {
+            // back reference
+            let mut run_len = ctrl >> 5;
+            if run_len == 7 {
+                run_len += usize::from(*input.get(i).ok_or_else(corrupted)?);
+                i += 1;
+            }
+            let offset =
+                ((ctrl & 0x1f) << 8) + usize::from(*input.get(i).ok_or_else(corrupted)?) + 1;
+            i += 1;
+
+            let start = output.len().checked_sub(offset).ok_or_else(corrupted)?;
+            for j in 0..run_len + 2 {
+                let b = output[start + j];
+                output.push(b);
+            }
+        }
+
+        if output.len() > len {
+            return Err(corrupted());
+        }
+    }
+
+    if output.len() != len {
+        return Err(corrupted());
+    }
+
+    Ok(output)
+}
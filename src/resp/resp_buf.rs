@@ -1,11 +1,12 @@
 use crate::{
     resp::{
-        RespDeserializer, Value, ARRAY_TAG, BLOB_ERROR_TAG, ERROR_TAG, PUSH_TAG, SIMPLE_STRING_TAG,
+        parse_array_header, BulkString, RespDeserializer, Value, ARRAY_TAG, BLOB_ERROR_TAG,
+        ERROR_TAG, NIL_TAG, PUSH_TAG, SET_TAG, SIMPLE_STRING_TAG,
     },
-    Result,
+    Error, Result,
 };
 use bytes::{BufMut, Bytes, BytesMut};
-use serde::Deserialize;
+use serde::{de::IgnoredAny, Deserialize};
 use std::{fmt, ops::Deref};
 
 /// Represents a [RESP](https://redis.io/docs/reference/protocol-spec/) Buffer incoming from the network
@@ -49,6 +50,12 @@ impl RespBuf {
         (!self.0.is_empty() && self.0[0] == PUSH_TAG) || self.is_monitor_message()
     }
 
+    /// Returns `true` if the RESP Buffer is a RESP3 push frame (`>`), excluding monitor messages
+    #[inline]
+    pub(crate) fn is_push_frame(&self) -> bool {
+        !self.0.is_empty() && self.0[0] == PUSH_TAG
+    }
+
     /// Returns `true` if the RESP Buffer is a monitor message
     #[inline]
     pub fn is_monitor_message(&self) -> bool {
@@ -61,6 +68,35 @@ impl RespBuf {
         self.0.len() > 1 && (self.0[0] == ERROR_TAG || self.0[0] == BLOB_ERROR_TAG)
     }
 
+    /// Returns `true` if the RESP Buffer is an array or a set
+    #[inline]
+    pub(crate) fn is_array(&self) -> bool {
+        !self.0.is_empty() && (self.0[0] == ARRAY_TAG || self.0[0] == SET_TAG)
+    }
+
+    /// Returns `true` if the RESP Buffer is nil
+    #[inline]
+    pub(crate) fn is_nil(&self) -> bool {
+        !self.0.is_empty() && self.0[0] == NIL_TAG
+    }
+
+    /// Splits a RESP array or set into its elements, without copy
+    pub(crate) fn array_elements(&self) -> Result<Vec<RespBuf>> {
+        let (len, mut pos) = parse_array_header(&self.0)
+            .ok_or_else(|| Error::Protocol("Cannot parse array header".to_owned()))?;
+
+        let mut elements = Vec::with_capacity(len.max(0) as usize);
+        for _ in 0..len {
+            let mut deserializer = RespDeserializer::new(&self.0[pos..]);
+            IgnoredAny::deserialize(&mut deserializer)?;
+            let end = pos + deserializer.get_pos();
+            elements.push(RespBuf(self.0.slice(pos..end)));
+            pos = end;
+        }
+
+        Ok(elements)
+    }
+
     /// Convert the RESP Buffer to a Rust type `T` by using serde deserialization
     #[inline]
     pub fn to<'de, T: Deserialize<'de>>(&'de self) -> Result<T> {
@@ -68,6 +104,41 @@ impl RespBuf {
         T::deserialize(&mut deserializer)
     }
 
+    /// Convert the RESP Buffer to a [`BulkString`]
+    ///
+    /// Unlike [`to`](RespBuf::to), a long bulk string shares the buffer, without copy.
+    ///
+    /// # Errors
+    /// If the RESP Buffer cannot be converted to a [`BulkString`]
+    #[inline]
+    pub fn to_bulk_string(&self) -> Result<BulkString> {
+        RespDeserializer::from_bytes(&self.0).deserialize_bulk_string()
+    }
+
+    /// Convert the RESP Buffer, an array or a set, to a collection of [`BulkString`]s,
+    /// e.g. the reply of [`MGET`](https://redis.io/commands/mget/)
+    ///
+    /// Unlike [`to`](RespBuf::to), long bulk strings share the buffer, without copy.
+    /// A nil reply is converted to an empty collection.
+    ///
+    /// # Errors
+    /// If the RESP Buffer cannot be converted to a collection of [`BulkString`]s
+    pub fn to_bulk_strings(&self) -> Result<Vec<BulkString>> {
+        if self.is_nil() {
+            return Ok(Vec::new());
+        }
+        if !self.is_array() {
+            return Err(Error::Client(
+                "Cannot parse to bulk strings a RESP value which is not an array".to_owned(),
+            ));
+        }
+
+        self.array_elements()?
+            .iter()
+            .map(RespBuf::to_bulk_string)
+            .collect()
+    }
+
     /// Returns the internal buffer as a byte slice
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
@@ -1,8 +1,15 @@
-use crate::{resp::PUSH_FAKE_FIELD, Error, RedisError, Result};
+use crate::{
+    resp::{BulkString, PUSH_FAKE_FIELD},
+    Error, RedisError, Result,
+};
+use bytes::Bytes;
 use memchr::memchr;
 use serde::{
-    de::{DeserializeSeed, EnumAccess, IntoDeserializer, VariantAccess, Visitor},
-    forward_to_deserialize_any, Deserializer,
+    de::{
+        value::U8Deserializer, DeserializeSeed, EnumAccess, IntoDeserializer, VariantAccess,
+        Visitor,
+    },
+    forward_to_deserialize_any, Deserialize, Deserializer,
 };
 use std::str::{self, FromStr};
 
@@ -30,6 +37,8 @@ pub struct RespDeserializer<'de> {
     buf: &'de [u8],
     pos: usize,
     eat_error: bool,
+    /// Shared buffer of `buf`, from which bulk strings can be sliced without copy
+    source: Option<&'de Bytes>,
 }
 
 impl<'de> RespDeserializer<'de> {
@@ -40,9 +49,32 @@ impl<'de> RespDeserializer<'de> {
             buf,
             pos: 0,
             eat_error: true,
+            source: None,
+        }
+    }
+
+    /// Creates a new `RespDeserializer` which can share the content of `source`
+    #[inline]
+    pub(crate) fn from_bytes(source: &'de Bytes) -> Self {
+        RespDeserializer {
+            source: Some(source),
+            ..RespDeserializer::new(source)
         }
     }
 
+    /// Deserializes a [`BulkString`], sharing the source buffer without copy if there is one
+    pub(crate) fn deserialize_bulk_string(&mut self) -> Result<BulkString> {
+        if let Some(source) = self.source {
+            if self.peek()? == BULK_STRING_TAG {
+                self.advance();
+                let bs = self.parse_bulk_string()?;
+                return Ok(BulkString::from(source.slice_ref(bs)));
+            }
+        }
+
+        BulkString::deserialize(self)
+    }
+
     /// Get current position in the input byte buffer
     #[inline]
     pub fn get_pos(&self) -> usize {
@@ -58,13 +90,13 @@ impl<'de> RespDeserializer<'de> {
                     ERROR_TAG => {
                         self.advance();
                         let str = self.parse_string()?;
-                        Err(Error::Redis(RedisError::from_str(str)?))
+                        Err(RedisError::from_str(str)?.into())
                     }
                     BLOB_ERROR_TAG => {
                         self.advance();
                         let bs = self.parse_bulk_string()?;
                         let str = str::from_utf8(bs)?;
-                        Err(Error::Redis(RedisError::from_str(str)?))
+                        Err(RedisError::from_str(str)?.into())
                     }
                     _ => Ok(byte),
                 }
@@ -123,7 +155,7 @@ impl<'de> RespDeserializer<'de> {
     {
         let next_line = self.next_line()?;
         fast_float::parse(next_line).map_err(|_| {
-            Error::Client(format!(
+            Error::Protocol(format!(
                 "Cannot parse number from {}",
                 String::from_utf8_lossy(next_line)
             ))
@@ -137,7 +169,7 @@ impl<'de> RespDeserializer<'de> {
     {
         let next_line = self.next_line()?;
         atoi::atoi(next_line).ok_or_else(|| {
-            Error::Client(format!(
+            Error::Protocol(format!(
                 "Cannot parse integer from {}",
                 String::from_utf8_lossy(next_line)
             ))
@@ -151,7 +183,7 @@ impl<'de> RespDeserializer<'de> {
     {
         let next_line = self.peek_line()?;
         atoi::atoi(&next_line[1..]).ok_or_else(|| {
-            Error::Client(format!(
+            Error::Protocol(format!(
                 "Cannot parse integer from {}",
                 String::from_utf8_lossy(next_line)
             ))
@@ -164,7 +196,7 @@ impl<'de> RespDeserializer<'de> {
         if self.buf.len() - self.pos < len + 2 {
             eof()
         } else if self.buf[self.pos + len] != b'\r' || self.buf[self.pos + len + 1] != b'\n' {
-            Err(Error::Client(format!(
+            Err(Error::Protocol(format!(
                 "Expected \\r\\n after bulk string. Got '{}''{}'",
                 self.buf[self.pos + len] as char,
                 self.buf[self.pos + len + 1] as char
@@ -181,7 +213,13 @@ impl<'de> RespDeserializer<'de> {
     /// The fourth byte is always :. Then the real string follows.
     #[inline]
     fn parse_verbatim_string(&mut self) -> Result<&'de [u8]> {
-        Ok(&self.parse_bulk_string()?[4..])
+        let bs = self.parse_bulk_string()?;
+        bs.get(4..).ok_or_else(|| {
+            Error::Protocol(format!(
+                "Verbatim string of {} bytes is missing its format prefix",
+                bs.len()
+            ))
+        })
     }
 
     #[inline(always)]
@@ -208,7 +246,7 @@ impl<'de> RespDeserializer<'de> {
         if next_line.is_empty() {
             Ok(())
         } else {
-            Err(Error::Client(format!(
+            Err(Error::Protocol(format!(
                 "Expected \\r\\n after null. Got '{}'",
                 String::from_utf8_lossy(next_line)
             )))
@@ -221,13 +259,73 @@ impl<'de> RespDeserializer<'de> {
         match next_line {
             b"t" => Ok(true),
             b"f" => Ok(false),
-            _ => Err(Error::Client(format!(
+            _ => Err(Error::Protocol(format!(
                 "Expected boolean. Got '{}'",
                 String::from_utf8_lossy(next_line)
             ))),
         }
     }
 
+    /// Fast path for the `+OK\r\n` reply
+    #[inline(always)]
+    fn try_parse_ok(&mut self) -> bool {
+        if self.buf[self.pos..].starts_with(b"+OK\r\n") {
+            self.pos += 5;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Fast path for integer replies (`:N\r\n`) of up to 18 digits, which cannot overflow an `i64`
+    #[inline(always)]
+    fn try_parse_small_integer(&mut self) -> Option<i64> {
+        let bytes = &self.buf[self.pos..];
+        if bytes.first() != Some(&INTEGER_TAG) {
+            return None;
+        }
+
+        let negative = bytes.get(1) == Some(&b'-');
+        let start = if negative { 2 } else { 1 };
+        let mut idx = start;
+        let mut value: i64 = 0;
+        while let Some(byte) = bytes.get(idx) {
+            if !byte.is_ascii_digit() {
+                break;
+            }
+            if idx - start == 18 {
+                return None;
+            }
+            value = value * 10 + (byte - b'0') as i64;
+            idx += 1;
+        }
+
+        if idx == start || bytes.get(idx..idx + 2) != Some(b"\r\n") {
+            return None;
+        }
+
+        self.pos += idx + 2;
+        Some(if negative { -value } else { value })
+    }
+
+    /// Fast path for bulk strings (`$N\r\n...\r\n`) of less than 10 bytes
+    #[inline(always)]
+    fn try_parse_small_bulk_string(&mut self) -> Option<&'de [u8]> {
+        let bytes = &self.buf[self.pos..];
+        match bytes {
+            [BULK_STRING_TAG, len @ b'0'..=b'9', b'\r', b'\n', ..] => {
+                let len = (len - b'0') as usize;
+                if bytes.len() >= len + 6 && &bytes[len + 4..len + 6] == b"\r\n" {
+                    self.pos += len + 6;
+                    Some(&bytes[4..len + 4])
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
     #[inline]
     fn parse_integer_ex<T>(&mut self) -> Result<T>
     where
@@ -270,8 +368,8 @@ impl<'de> RespDeserializer<'de> {
                     Err(Error::Client("Cannot parse number".to_owned()))
                 }
             }
-            ERROR_TAG => Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => Err(self.parse_blob_error()?.into()),
             _ => Err(Error::Client("Cannot parse number".to_owned())),
         }
     }
@@ -301,8 +399,8 @@ impl<'de> RespDeserializer<'de> {
                 fast_float::parse(next_line)
                     .map_err(|_| Error::Client("Cannot parse number".to_owned()))
             }
-            ERROR_TAG => Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => Err(self.parse_blob_error()?.into()),
             _ => Err(Error::Client("Cannot parse number".to_owned())),
         }
     }
@@ -339,7 +437,7 @@ impl<'de> RespDeserializer<'de> {
         if self.buf.len() - self.pos < len + 2 {
             eof()
         } else if self.buf[self.pos + len] != b'\r' || self.buf[self.pos + len + 1] != b'\n' {
-            Err(Error::Client(format!(
+            Err(Error::Protocol(format!(
                 "Expected \\r\\n after bulk string. Got '{}''{}'",
                 self.buf[self.pos + len] as char,
                 self.buf[self.pos + len + 1] as char
@@ -409,9 +507,9 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
             BOOL_TAG => self.deserialize_bool(visitor),
             VERBATIM_STRING_TAG => self.deserialize_bytes(visitor),
             PUSH_TAG => visitor.visit_map(PushMapAccess::new(self)),
-            ERROR_TAG => Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => Err(Error::Redis(self.parse_blob_error()?)),
-            _ => Err(Error::Client(format!(
+            ERROR_TAG => Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => Err(self.parse_blob_error()?.into()),
+            _ => Err(Error::Protocol(format!(
                 "Unknown data type '{}' (0x{:02x})",
                 first_byte as char, first_byte
             ))),
@@ -422,6 +520,13 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
     where
         V: Visitor<'de>,
     {
+        if self.try_parse_ok() {
+            return visitor.visit_bool(true);
+        }
+        if let Some(value) = self.try_parse_small_integer() {
+            return visitor.visit_bool(value != 0);
+        }
+
         let result: bool = match self.next()? {
             INTEGER_TAG => self.parse_integer::<i64>()? != 0,
             DOUBLE_TAG => self.parse_float::<f64>()? != 0.,
@@ -439,8 +544,8 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                 self.parse_nil()?;
                 false
             }
-            ERROR_TAG => return Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => return Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => return Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => return Err(self.parse_blob_error()?.into()),
             _ => return Err(Error::Client("Cannot parse to bool".to_owned())),
         };
 
@@ -476,6 +581,10 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
     where
         V: Visitor<'de>,
     {
+        if let Some(value) = self.try_parse_small_integer() {
+            return visitor.visit_i64(value);
+        }
+
         visitor.visit_i64(self.parse_integer_ex()?)
     }
 
@@ -553,8 +662,8 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                 self.parse_nil()?;
                 '\0'
             }
-            ERROR_TAG => return Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => return Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => return Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => return Err(self.parse_blob_error()?.into()),
             _ => return Err(Error::Client("Cannot parse to char".to_owned())),
         };
 
@@ -565,6 +674,10 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
     where
         V: Visitor<'de>,
     {
+        if let Some(bs) = self.try_parse_small_bulk_string() {
+            return visitor.visit_borrowed_str(str::from_utf8(bs)?);
+        }
+
         let result = match self.next()? {
             BULK_STRING_TAG => {
                 let bs = self.parse_bulk_string()?;
@@ -579,8 +692,8 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                 self.parse_nil()?;
                 ""
             }
-            ERROR_TAG => return Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => return Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => return Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => return Err(self.parse_blob_error()?.into()),
             tag => {
                 return Err(Error::Client(format!(
                     "Cannot parse to str a RESP value starting with `{}`",
@@ -596,6 +709,10 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
     where
         V: Visitor<'de>,
     {
+        if let Some(bs) = self.try_parse_small_bulk_string() {
+            return visitor.visit_string(str::from_utf8(bs)?.to_owned());
+        }
+
         let result = match self.next()? {
             DOUBLE_TAG => self.parse_float::<f64>()?.to_string(),
             BULK_STRING_TAG => {
@@ -611,8 +728,8 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                 String::from("")
             }
             SIMPLE_STRING_TAG => self.parse_string()?.to_owned(),
-            ERROR_TAG => return Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => return Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => return Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => return Err(self.parse_blob_error()?.into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse to string: `{}`",
@@ -636,8 +753,8 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                 &[]
             }
             SIMPLE_STRING_TAG => self.parse_string()?.as_bytes(),
-            ERROR_TAG => return Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => return Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => return Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => return Err(self.parse_blob_error()?.into()),
             _ => return Err(Error::Client("Cannot parse to bytes".to_owned())),
         };
 
@@ -656,8 +773,8 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                 vec![]
             }
             SIMPLE_STRING_TAG => self.parse_string()?.as_bytes().to_vec(),
-            ERROR_TAG => return Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => return Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => return Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => return Err(self.parse_blob_error()?.into()),
             _ => return Err(Error::Client("Cannot parse to byte buffer".to_owned())),
         };
 
@@ -682,8 +799,8 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                     visitor.visit_some(self)
                 }
             }
-            ERROR_TAG => Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => Err(self.parse_blob_error()?.into()),
             _ => visitor.visit_some(self),
         }
     }
@@ -694,10 +811,14 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
     where
         V: Visitor<'de>,
     {
+        if self.try_parse_ok() {
+            return visitor.visit_unit();
+        }
+
         let byte = self.peek()?;
         match byte {
-            ERROR_TAG => Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => Err(self.parse_blob_error()?.into()),
             _ => {
                 self.ignore_value()?;
                 visitor.visit_unit()
@@ -742,8 +863,15 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                 let len = self.parse_integer()?;
                 visitor.visit_seq(MapAccess { de: self, len })
             }
-            ERROR_TAG => Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => Err(Error::Redis(self.parse_blob_error()?)),
+            // binary-safe deserialization of a bulk string to `Vec<u8>`:
+            // sequence visitors reject `visit_bytes`, byte visitors (`BulkString`, `Bytes`)
+            // go through `deserialize_bytes` & `deserialize_byte_buf` instead
+            BULK_STRING_TAG => {
+                let bytes = self.parse_bulk_string()?;
+                visitor.visit_seq(BytesSeqAccess(bytes.iter()))
+            }
+            ERROR_TAG => Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => Err(self.parse_blob_error()?.into()),
             tag => Err(Error::Client(format!(
                 "Cannot parse to sequence a RESP value starting with {}",
                 tag as char
@@ -785,8 +913,8 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                 let len = self.parse_integer()?;
                 visitor.visit_map(MapAccess { de: self, len })
             }
-            ERROR_TAG => Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => Err(self.parse_blob_error()?.into()),
             _ => Err(Error::Client("Cannot parse map".to_owned())),
         }
     }
@@ -827,8 +955,8 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                 let len = self.parse_integer()?;
                 visitor.visit_map(MapAccess { de: self, len })
             }
-            ERROR_TAG => Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => Err(self.parse_blob_error()?.into()),
             _ => Err(Error::Client("Cannot parse struct".to_owned())),
         }
     }
@@ -878,8 +1006,8 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                     ))
                 }
             }
-            ERROR_TAG => Err(Error::Redis(self.parse_error()?)),
-            BLOB_ERROR_TAG => Err(Error::Redis(self.parse_blob_error()?)),
+            ERROR_TAG => Err(self.parse_error()?.into()),
+            BLOB_ERROR_TAG => Err(self.parse_blob_error()?.into()),
             _ => Err(Error::Client(format!("Cannot parse enum `{name}`"))),
         }
     }
@@ -924,6 +1052,32 @@ impl<'de> serde::de::SeqAccess<'de> for NilSeqAccess {
     }
 }
 
+/// Bytes of a bulk string, with an exact size hint so that the whole buffer is allocated at once
+struct BytesSeqAccess<'de>(std::slice::Iter<'de, u8>);
+
+impl<'de> serde::de::SeqAccess<'de> for BytesSeqAccess<'de> {
+    type Error = Error;
+
+    #[inline]
+    fn next_element_seed<T>(
+        &mut self,
+        seed: T,
+    ) -> std::result::Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.0.next() {
+            Some(byte) => seed.deserialize(U8Deserializer::new(*byte)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
 struct SeqAccess<'a, 'de: 'a> {
     de: &'a mut RespDeserializer<'de>,
     len: usize,
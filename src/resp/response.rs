@@ -1,4 +1,5 @@
 use crate::resp::{BulkString, Value};
+use bytes::Bytes;
 use serde::de::DeserializeOwned;
 use smallvec::SmallVec;
 use std::{
@@ -30,6 +31,8 @@ impl PrimitiveResponse for f64 {}
 impl PrimitiveResponse for bool {}
 impl PrimitiveResponse for String {}
 impl PrimitiveResponse for BulkString {}
+impl PrimitiveResponse for Vec<u8> {}
+impl PrimitiveResponse for Bytes {}
 impl<T: PrimitiveResponse + DeserializeOwned> PrimitiveResponse for Option<T> {}
 
 /// Marker for a collection response
@@ -1,10 +1,12 @@
 use crate::resp::{BulkString, CommandArgs};
+use bytes::Bytes;
 use dtoa::Float;
 use itoa::Integer;
 use smallvec::SmallVec;
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     hash::BuildHasher,
+    marker::PhantomData,
 };
 
 /// Types compatible with command args
@@ -123,7 +125,28 @@ impl ToArgs for bool {
 impl ToArgs for BulkString {
     #[inline]
     fn write_args(&self, args: &mut CommandArgs) {
-        args.write_arg(self.into_inner());
+        args.write_arg(self.as_bytes());
+    }
+}
+
+impl ToArgs for &BulkString {
+    #[inline]
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.write_arg(self.as_bytes());
+    }
+}
+
+impl ToArgs for Bytes {
+    #[inline]
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.write_arg(self.as_ref());
+    }
+}
+
+impl ToArgs for &Bytes {
+    #[inline]
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.write_arg(self.as_ref());
     }
 }
 
@@ -165,7 +188,7 @@ impl ToArgs for &str {
 impl ToArgs for String {
     #[inline]
     fn write_args(&self, args: &mut CommandArgs) {
-        args.write_arg(self.into_bytes());
+        args.write_arg(self.as_bytes());
     }
 }
 
@@ -205,6 +228,7 @@ impl<T: ToArgs> ToArgs for Option<T> {
 impl<T: ToArgs, const N: usize> ToArgs for [T; N] {
     #[inline]
     fn write_args(&self, args: &mut CommandArgs) {
+        args.reserve(self.len());
         for e in self {
             e.write_args(args);
         }
@@ -219,6 +243,7 @@ impl<T: ToArgs, const N: usize> ToArgs for [T; N] {
 impl<T: ToArgs> ToArgs for Vec<T> {
     #[inline]
     fn write_args(&self, args: &mut CommandArgs) {
+        args.reserve(self.len());
         for e in self {
             e.write_args(args);
         }
@@ -233,6 +258,7 @@ impl<T: ToArgs> ToArgs for Vec<T> {
 impl<T: ToArgs> ToArgs for &[T] {
     #[inline]
     fn write_args(&self, args: &mut CommandArgs) {
+        args.reserve(self.len());
         for e in self.iter() {
             e.write_args(args);
         }
@@ -244,6 +270,18 @@ impl<T: ToArgs> ToArgs for &[T] {
     }
 }
 
+impl<T: ToArgs> ToArgs for &Vec<T> {
+    #[inline]
+    fn write_args(&self, args: &mut CommandArgs) {
+        self.as_slice().write_args(args);
+    }
+
+    #[inline]
+    fn num_args(&self) -> usize {
+        self.as_slice().num_args()
+    }
+}
+
 impl<T, A> ToArgs for SmallVec<A>
 where
     A: smallvec::Array<Item = T>,
@@ -251,6 +289,7 @@ where
 {
     #[inline]
     fn write_args(&self, args: &mut CommandArgs) {
+        args.reserve(self.len());
         for e in self {
             e.write_args(args);
         }
@@ -268,6 +307,7 @@ where
 {
     #[inline]
     fn write_args(&self, args: &mut CommandArgs) {
+        args.reserve(self.len());
         for e in self {
             e.write_args(args);
         }
@@ -285,6 +325,7 @@ where
 {
     #[inline]
     fn write_args(&self, args: &mut CommandArgs) {
+        args.reserve(self.len());
         for e in self {
             e.write_args(args);
         }
@@ -303,6 +344,7 @@ where
 {
     #[inline]
     fn write_args(&self, args: &mut CommandArgs) {
+        args.reserve(self.len() * 2);
         for (key, value) in self {
             key.write_args(args);
             value.write_args(args);
@@ -316,6 +358,22 @@ where
     }
 }
 
+impl<K, V, S: BuildHasher> ToArgs for &HashMap<K, V, S>
+where
+    K: ToArgs,
+    V: ToArgs,
+{
+    #[inline]
+    fn write_args(&self, args: &mut CommandArgs) {
+        (*self).write_args(args);
+    }
+
+    #[inline]
+    fn num_args(&self) -> usize {
+        (*self).num_args()
+    }
+}
+
 impl<K, V> ToArgs for BTreeMap<K, V>
 where
     K: ToArgs,
@@ -323,6 +381,7 @@ where
 {
     #[inline]
     fn write_args(&self, args: &mut CommandArgs) {
+        args.reserve(self.len() * 2);
         for (key, value) in self {
             key.write_args(args);
             value.write_args(args);
@@ -336,6 +395,22 @@ where
     }
 }
 
+impl<K, V> ToArgs for &BTreeMap<K, V>
+where
+    K: ToArgs,
+    V: ToArgs,
+{
+    #[inline]
+    fn write_args(&self, args: &mut CommandArgs) {
+        (*self).write_args(args);
+    }
+
+    #[inline]
+    fn num_args(&self) -> usize {
+        (*self).num_args()
+    }
+}
+
 impl<T, U> ToArgs for (T, U)
 where
     T: ToArgs,
@@ -424,6 +499,9 @@ impl<const N: usize> SingleArg for [u8; N] {}
 impl SingleArg for &[u8] {}
 impl SingleArg for Vec<u8> {}
 impl SingleArg for BulkString {}
+impl SingleArg for &BulkString {}
+impl SingleArg for Bytes {}
+impl SingleArg for &Bytes {}
 impl<T: SingleArg> SingleArg for Option<T> {}
 
 /// Generic Marker for Collections of `ToArgs`
@@ -469,6 +547,51 @@ impl<T> SingleArgCollection<T> for BTreeSet<T> where T: SingleArg {}
 
 impl<T> SingleArgCollection<T> for T where T: SingleArg {}
 
+/// Adapter writing the number of items of a collection before the items themselves.
+///
+/// Used for commands expecting a `numkeys key [key ...]` sequence
+/// like [`LMPOP`](https://redis.io/commands/lmpop/) or [`ZUNION`](https://redis.io/commands/zunion/).
+pub struct KeysWithCount<T, C>
+where
+    T: SingleArg,
+    C: SingleArgCollection<T>,
+{
+    keys: C,
+    phantom: PhantomData<T>,
+}
+
+impl<T, C> KeysWithCount<T, C>
+where
+    T: SingleArg,
+    C: SingleArgCollection<T>,
+{
+    /// Wrap a collection of keys
+    #[inline]
+    pub fn new(keys: C) -> Self {
+        Self {
+            keys,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, C> ToArgs for KeysWithCount<T, C>
+where
+    T: SingleArg,
+    C: SingleArgCollection<T>,
+{
+    #[inline]
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(self.keys.num_args());
+        self.keys.write_args(args);
+    }
+
+    #[inline]
+    fn num_args(&self) -> usize {
+        1 + self.keys.num_args()
+    }
+}
+
 /// Marker for key/value collections of Args
 ///
 /// The key and the value can only produce a single arg each.
@@ -528,3 +651,24 @@ where
     V: SingleArg,
 {
 }
+
+impl<K, V> KeyValueArgsCollection<K, V> for &Vec<(K, V)>
+where
+    K: SingleArg,
+    V: SingleArg,
+{
+}
+
+impl<K, V, S: BuildHasher> KeyValueArgsCollection<K, V> for &HashMap<K, V, S>
+where
+    K: SingleArg,
+    V: SingleArg,
+{
+}
+
+impl<K, V> KeyValueArgsCollection<K, V> for &BTreeMap<K, V>
+where
+    K: SingleArg,
+    V: SingleArg,
+{
+}
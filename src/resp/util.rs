@@ -242,3 +242,22 @@ where
         deserialize_vec_of_pairs(deserializer)
     }
 }
+
+/// Write `bytes` in double quotes, escaping binary data like `redis-cli` and `MONITOR` do
+pub(crate) fn write_quoted(f: &mut impl fmt::Write, bytes: &[u8]) -> fmt::Result {
+    f.write_char('"')?;
+    for byte in bytes {
+        match byte {
+            b'"' => f.write_str("\\\"")?,
+            b'\\' => f.write_str("\\\\")?,
+            b'\n' => f.write_str("\\n")?,
+            b'\r' => f.write_str("\\r")?,
+            b'\t' => f.write_str("\\t")?,
+            0x07 => f.write_str("\\a")?,
+            0x08 => f.write_str("\\b")?,
+            b if b.is_ascii_graphic() || *b == b' ' => f.write_char(*b as char)?,
+            b => write!(f, "\\x{b:02x}")?,
+        }
+    }
+    f.write_char('"')
+}
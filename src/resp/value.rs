@@ -1,4 +1,4 @@
-use crate::{RedisError, Result};
+use crate::{resp::write_quoted, RedisError, Result};
 use serde::de::DeserializeOwned;
 use std::{
     collections::HashMap,
@@ -9,7 +9,7 @@ use std::{
 /// Generic Redis Object Model
 ///
 /// This enum is a direct mapping to [`Redis serialization protocol`](https://redis.io/docs/reference/protocol-spec/) (RESP)
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub enum Value {
     /// [RESP Simple String](https://redis.io/docs/reference/protocol-spec/#resp-simple-strings)
     SimpleString(String),
@@ -48,6 +48,90 @@ impl Value {
     {
         T::deserialize(&self)
     }
+
+    /// Renders the value like `redis-cli` does, with nested arrays, maps and sets indented
+    /// and binary data escaped losslessly.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::resp::Value;
+    ///
+    /// let value = Value::Array(vec![
+    ///     Value::BulkString(b"hello\xff".to_vec()),
+    ///     Value::Array(vec![Value::Integer(12), Value::Nil]),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     "1) \"hello\\xff\"\n2) 1) (integer) 12\n   2) (nil)",
+    ///     value.pretty()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn pretty(&self) -> String {
+        let mut output = String::new();
+        // writing into a String cannot fail
+        let _ = self.write_pretty(&mut output, 0);
+        output
+    }
+
+    fn write_pretty(&self, f: &mut String, indent: usize) -> fmt::Result {
+        match self {
+            Value::SimpleString(s) => f.write_str(s),
+            Value::Integer(i) => write!(f, "(integer) {i}"),
+            Value::Double(d) => write!(f, "(double) {d}"),
+            Value::BulkString(s) => write_quoted(f, s),
+            Value::Boolean(b) => write!(f, "({b})"),
+            Value::Array(values) | Value::Push(values) => {
+                Self::write_pretty_elements(f, indent, ")", values.iter().map(|v| (v, None)))
+            }
+            Value::Set(values) => {
+                Self::write_pretty_elements(f, indent, "~", values.iter().map(|v| (v, None)))
+            }
+            Value::Map(map) => Self::write_pretty_elements(
+                f,
+                indent,
+                "#",
+                map.iter().map(|(key, value)| (key, Some(value))),
+            ),
+            Value::Error(e) => write!(f, "(error) {e}"),
+            Value::Nil => f.write_str("(nil)"),
+        }
+    }
+
+    fn write_pretty_elements<'a>(
+        f: &mut String,
+        indent: usize,
+        marker: &str,
+        elements: impl ExactSizeIterator<Item = (&'a Value, Option<&'a Value>)>,
+    ) -> fmt::Result {
+        let len = elements.len();
+        if len == 0 {
+            return f.write_str(match marker {
+                "#" => "(empty hash)",
+                "~" => "(empty set)",
+                _ => "(empty array)",
+            });
+        }
+
+        let width = len.to_string().len();
+        for (index, (value, map_value)) in elements.enumerate() {
+            if index > 0 {
+                f.write_char('\n')?;
+                f.write_str(&" ".repeat(indent))?;
+            }
+
+            let prefix = format!("{:>width$}{marker} ", index + 1);
+            f.write_str(&prefix)?;
+            let indent = indent + prefix.len();
+            value.write_pretty(f, indent)?;
+            if let Some(map_value) = map_value {
+                f.write_str(" => ")?;
+                map_value.write_pretty(f, indent)?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Hash for Value {
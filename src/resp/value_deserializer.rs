@@ -1,6 +1,9 @@
 use crate::{resp::Value, Error, Result};
 use serde::{
-    de::{DeserializeSeed, EnumAccess, IntoDeserializer, VariantAccess, Visitor},
+    de::{
+        value::SeqDeserializer, DeserializeSeed, EnumAccess, IntoDeserializer, VariantAccess,
+        Visitor,
+    },
     forward_to_deserialize_any, Deserialize, Deserializer,
 };
 use std::{
@@ -25,7 +28,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::Map(values) => visitor.visit_map(MapAccess::new(values)),
             Value::Set(values) => visitor.visit_seq(SeqAccess::new(values)),
             Value::Push(values) => visitor.visit_seq(SeqAccess::new(values)),
-            Value::Error(e) => Err(Error::Redis(e.clone())),
+            Value::Error(e) => Err(e.clone().into()),
             Value::Nil => visitor.visit_none(),
         }
     }
@@ -43,7 +46,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::BulkString(s) if s == b"0" || s == b"false" => false,
             Value::BulkString(s) if s == b"1" || s == b"true" => true,
             Value::Boolean(b) => *b,
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to bool",
@@ -65,7 +68,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<i8>()?,
             Value::SimpleString(s) => s.parse::<i8>()?,
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to i8",
@@ -87,7 +90,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<i16>()?,
             Value::SimpleString(s) => s.parse::<i16>()?,
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to i16",
@@ -109,7 +112,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<i32>()?,
             Value::SimpleString(s) => s.parse::<i32>()?,
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to i32",
@@ -133,7 +136,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::BulkString(s) => str::from_utf8(s)?.parse::<i64>()?,
             Value::SimpleString(s) => s.parse::<i64>()?,
             Value::Array(a) if a.len() == 1 => i64::deserialize(&a[0])?,
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to i64",
@@ -155,7 +158,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<u8>()?,
             Value::SimpleString(s) => s.parse::<u8>()?,
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to u8",
@@ -177,7 +180,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<u16>()?,
             Value::SimpleString(s) => s.parse::<u16>()?,
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to u16",
@@ -199,7 +202,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<u32>()?,
             Value::SimpleString(s) => s.parse::<u32>()?,
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to u32",
@@ -222,7 +225,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::BulkString(s) => str::from_utf8(s)?.parse::<u64>()?,
             Value::SimpleString(s) => s.parse::<u64>()?,
             Value::Array(a) if a.len() == 1 => u64::deserialize(&a[0])?,
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to u64",
@@ -244,7 +247,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::BulkString(bs) => str::from_utf8(bs)?.parse::<f32>()?,
             Value::Nil => 0.,
             Value::SimpleString(s) => s.parse::<f32>()?,
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse result {:?} to f32",
@@ -266,7 +269,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::BulkString(bs) => str::from_utf8(bs)?.parse::<f64>()?,
             Value::Nil => 0.,
             Value::SimpleString(s) => s.parse::<f64>()?,
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse result {:?} to f64",
@@ -299,7 +302,7 @@ impl<'de> Deserializer<'de> for &'de Value {
                 }
             }
             Value::Nil => '\0',
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => return Err(Error::Client("Cannot parse to char".to_owned())),
         };
 
@@ -314,7 +317,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::BulkString(s) => str::from_utf8(s)?,
             Value::Nil => "",
             Value::SimpleString(s) => s.as_str(),
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to str",
@@ -336,7 +339,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::BulkString(s) => str::from_utf8(s)?.to_owned(),
             Value::Nil => String::from(""),
             Value::SimpleString(s) => s.clone(),
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to String",
@@ -356,7 +359,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::BulkString(s) => s.as_slice(),
             Value::Nil => &[],
             Value::SimpleString(s) => s.as_bytes(),
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to byte buffer",
@@ -376,7 +379,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::BulkString(s) => s.clone(),
             Value::Nil => vec![],
             Value::SimpleString(s) => s.as_bytes().to_vec(),
-            Value::Error(e) => return Err(Error::Redis(e.clone())),
+            Value::Error(e) => return Err(e.clone().into()),
             _ => {
                 return Err(Error::Client(format!(
                     "Cannot parse value {:?} to byte buffer",
@@ -396,7 +399,7 @@ impl<'de> Deserializer<'de> for &'de Value {
         match self {
             Value::Nil => visitor.visit_none(),
             Value::Array(values) if values.is_empty() => visitor.visit_none(),
-            Value::Error(e) => Err(Error::Redis(e.clone())),
+            Value::Error(e) => Err(e.clone().into()),
             _ => visitor.visit_some(self),
         }
     }
@@ -414,7 +417,7 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::Array(a) if a.is_empty() => visitor.visit_unit(),
             Value::Set(s) if s.is_empty() => visitor.visit_unit(),
             Value::Map(m) if m.is_empty() => visitor.visit_unit(),
-            Value::Error(e) => Err(Error::Redis(e.clone())),
+            Value::Error(e) => Err(e.clone().into()),
             _ => Err(Error::Client("Expected nil".to_owned())),
         }
     }
@@ -449,7 +452,10 @@ impl<'de> Deserializer<'de> for &'de Value {
                 visitor.visit_seq(SeqAccess::new(values))
             }
             Value::Map(values) => visitor.visit_seq(MapAccess::new(values)),
-            Value::Error(e) => Err(Error::Redis(e.clone())),
+            Value::BulkString(bytes) => {
+                visitor.visit_seq(SeqDeserializer::<_, Error>::new(bytes.iter().copied()))
+            }
+            Value::Error(e) => Err(e.clone().into()),
             _ => Err(Error::Client(format!(
                 "Cannot parse sequence from value `{self}`"
             ))),
@@ -484,7 +490,7 @@ impl<'de> Deserializer<'de> for &'de Value {
         match self {
             Value::Array(values) => visitor.visit_map(SeqAccess::new(values)),
             Value::Map(values) => visitor.visit_map(MapAccess::new(values)),
-            Value::Error(e) => Err(Error::Redis(e.clone())),
+            Value::Error(e) => Err(e.clone().into()),
             _ => Err(Error::Client("Cannot parse map".to_owned())),
         }
     }
@@ -517,7 +523,7 @@ impl<'de> Deserializer<'de> for &'de Value {
                 }
             }
             Value::Map(values) => visitor.visit_map(MapAccess::new(values)),
-            Value::Error(e) => Err(Error::Redis(e.clone())),
+            Value::Error(e) => Err(e.clone().into()),
             _ => Err(Error::Client("Cannot parse struct".to_owned())),
         }
     }
@@ -563,7 +569,7 @@ impl<'de> Deserializer<'de> for &'de Value {
                     )))
                 }
             }
-            Value::Error(e) => Err(Error::Redis(e.clone())),
+            Value::Error(e) => Err(e.clone().into()),
             _ => Err(Error::Client(format!(
                 "Cannot parse enum `{name}` from `{self}`"
             ))),
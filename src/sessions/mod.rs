@@ -0,0 +1,54 @@
+/*!
+Server-side sessions stored in Redis
+
+[`RedisSessionStore`] stores session payloads as Redis strings, one key per session:
+* a session is created with a random 128-bit identifier and an expiration,
+* its payload is serialized with any [`CacheSerializer`](crate::utils::CacheSerializer),
+* with rolling expiration, the expiration is pushed back each time the session is loaded.
+
+The store has no dependency on a web framework:
+it exposes the create/load/update/destroy operations expected by the session middlewares
+of frameworks like [axum](https://docs.rs/axum) or [actix-web](https://docs.rs/actix-web),
+so that it can be plugged into them through thin adapters.
+
+# Example
+```
+# #[cfg(feature = "cache-json")]
+# {
+use rustis::{client::Client, sessions::RedisSessionStore, utils::JsonSerializer, Result};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize)]
+struct UserSession {
+    user_id: u64,
+    theme: String,
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::main)]
+#[cfg_attr(feature = "async-std-runtime", async_std::main)]
+async fn main() -> Result<()> {
+    let client = Client::connect("127.0.0.1:6379").await?;
+    let store = RedisSessionStore::new(&client, JsonSerializer)
+        .with_ttl(Duration::from_secs(30 * 60))
+        .with_rolling_expiration(true);
+
+    let session_id = store
+        .create(&UserSession { user_id: 12, theme: "dark".to_owned() })
+        .await?;
+
+    let session: Option<UserSession> = store.load(&session_id).await?;
+    assert_eq!(12, session.unwrap().user_id);
+
+    store.destroy(&session_id).await?;
+
+    Ok(())
+}
+# main().unwrap();
+# }
+```
+*/
+
+mod redis_session_store;
+
+pub use redis_session_store::*;
@@ -0,0 +1,174 @@
+use crate::{
+    client::Client,
+    commands::{
+        ExpireOption, GenericCommands, GetExOptions, SetCondition, SetExpiration, StringCommands,
+    },
+    utils::CacheSerializer,
+    Error, Result,
+};
+use rand::Rng;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fmt::Write, time::Duration};
+
+/// Maximum number of attempts to generate a session id which is not already used
+const MAX_CREATE_ATTEMPTS: usize = 3;
+
+/// Session store built on Redis strings
+///
+/// See the [module documentation](crate::sessions) for an example.
+pub struct RedisSessionStore<S> {
+    client: Client,
+    serializer: S,
+    prefix: String,
+    ttl: Duration,
+    rolling_expiration: bool,
+}
+
+impl<S> RedisSessionStore<S>
+where
+    S: CacheSerializer,
+{
+    /// Create a new store with the default settings:
+    /// keys prefixed by `session:`, sessions expiring after 24 hours, no rolling expiration.
+    #[must_use]
+    pub fn new(client: &Client, serializer: S) -> Self {
+        Self {
+            client: client.clone(),
+            serializer,
+            prefix: "session:".to_owned(),
+            ttl: Duration::from_secs(24 * 60 * 60),
+            rolling_expiration: false,
+        }
+    }
+
+    /// Prefix prepended to the session ids to build the keys of the sessions
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Time to live of the sessions
+    #[must_use]
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// When enabled, the time to live of a session is reset each time the session is loaded,
+    /// so that only inactive sessions expire.
+    #[must_use]
+    pub fn with_rolling_expiration(mut self, rolling_expiration: bool) -> Self {
+        self.rolling_expiration = rolling_expiration;
+        self
+    }
+
+    /// Create a new session holding `data`
+    ///
+    /// # Return
+    /// The id of the new session
+    pub async fn create<T: Serialize>(&self, data: &T) -> Result<String> {
+        let bytes = self.serializer.serialize(data)?;
+
+        for _ in 0..MAX_CREATE_ATTEMPTS {
+            let session_id = generate_session_id();
+            let created = self
+                .client
+                .set_with_options(
+                    self.key(&session_id),
+                    bytes.as_slice(),
+                    SetCondition::NX,
+                    self.expiration(),
+                    false,
+                )
+                .await?;
+
+            if created {
+                return Ok(session_id);
+            }
+        }
+
+        Err(Error::Client(
+            "Cannot generate a unique session id".to_owned(),
+        ))
+    }
+
+    /// Load the data of the session `session_id`
+    ///
+    /// With rolling expiration, the time to live of the session is reset.
+    ///
+    /// # Return
+    /// The data of the session, or `None` if the session does not exist or has expired.
+    pub async fn load<T: DeserializeOwned>(&self, session_id: &str) -> Result<Option<T>> {
+        let bytes: Option<Vec<u8>> = if self.rolling_expiration {
+            self.client
+                .getex(self.key(session_id), GetExOptions::Px(self.ttl_millis()))
+                .await?
+        } else {
+            self.client.get(self.key(session_id)).await?
+        };
+
+        match bytes {
+            Some(bytes) => Ok(Some(self.serializer.deserialize(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Replace the data of the session `session_id` and reset its time to live
+    ///
+    /// # Return
+    /// `false` if the session does not exist or has expired.
+    pub async fn update<T: Serialize>(&self, session_id: &str, data: &T) -> Result<bool> {
+        let bytes = self.serializer.serialize(data)?;
+        self.client
+            .set_with_options(
+                self.key(session_id),
+                bytes,
+                SetCondition::XX,
+                self.expiration(),
+                false,
+            )
+            .await
+    }
+
+    /// Reset the time to live of the session `session_id`, without loading it
+    ///
+    /// # Return
+    /// `false` if the session does not exist or has expired.
+    pub async fn touch(&self, session_id: &str) -> Result<bool> {
+        self.client
+            .pexpire(self.key(session_id), self.ttl_millis(), ExpireOption::None)
+            .await
+    }
+
+    /// Destroy the session `session_id`
+    ///
+    /// # Return
+    /// `false` if the session does not exist or has expired.
+    pub async fn destroy(&self, session_id: &str) -> Result<bool> {
+        Ok(self.client.del(self.key(session_id)).await? > 0)
+    }
+
+    fn key(&self, session_id: &str) -> String {
+        format!("{}{session_id}", self.prefix)
+    }
+
+    fn ttl_millis(&self) -> u64 {
+        self.ttl.as_millis() as u64
+    }
+
+    fn expiration(&self) -> SetExpiration {
+        SetExpiration::Px(self.ttl_millis())
+    }
+}
+
+/// Random 128-bit identifier, hex encoded
+fn generate_session_id() -> String {
+    let bytes: [u8; 16] = rand::thread_rng().gen();
+    bytes
+        .iter()
+        .fold(String::with_capacity(32), |mut id, byte| {
+            let _ = write!(id, "{byte:02x}");
+            id
+        })
+}
@@ -0,0 +1,65 @@
+use crate::{
+    client::Client,
+    commands::{GenericCommands, ObjectEncoding},
+    resp::{CommandArgs, SingleArg},
+    Result,
+};
+
+/// Asserts that the value stored at `key` is stored with the `expected` internal encoding.
+///
+/// The encodings are compared with [`ObjectEncoding::is_equivalent`],
+/// so that `listpack` and `ziplist` match whatever the version of the server.
+/// This allows tests to check that data stays within compact encodings,
+/// a common performance regression.
+///
+/// # Errors
+/// Any Redis driver [`Error`](crate::Error) that occurs while querying the encoding
+///
+/// # Panics
+/// If the key does not exist or if its encoding differs from `expected`
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::Client,
+///     commands::{HashCommands, ObjectEncoding},
+///     testing::assert_encoding,
+///     Result,
+/// };
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     client.hset("user:1", [("name", "John")]).await?;
+///
+///     assert_encoding(&client, "user:1", ObjectEncoding::ListPack).await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn assert_encoding<K>(client: &Client, key: K, expected: ObjectEncoding) -> Result<()>
+where
+    K: SingleArg,
+{
+    let key: Vec<u8> = CommandArgs::default()
+        .arg(key)
+        .build()
+        .into_iter()
+        .next()
+        .unwrap_or_default()
+        .to_vec();
+    let actual: Option<ObjectEncoding> = client.object_encoding(key.clone()).await?;
+
+    match actual {
+        Some(actual) if actual.is_equivalent(&expected) => Ok(()),
+        Some(actual) => panic!(
+            "key `{}` is encoded as `{actual}`, expected `{expected}`",
+            String::from_utf8_lossy(&key)
+        ),
+        None => panic!(
+            "key `{}` does not exist, expected encoding `{expected}`",
+            String::from_utf8_lossy(&key)
+        ),
+    }
+}
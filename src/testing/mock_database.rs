@@ -0,0 +1,748 @@
+use crate::{
+    client::PreparedCommand,
+    commands::{
+        BitmapCommands, ConnectionCommands, GenericCommands, GeoCommands, HashCommands,
+        HyperLogLogCommands, ListCommands, ScriptingCommands, ServerCommands, SetCommands,
+        SortedSetCommands, StreamCommands, StringCommands,
+    },
+    resp::{Command, RespBuf, RespSerializer, Value},
+    Error, Future, RedisError, RedisErrorKind, Result,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    future::IntoFuture,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+type MockResult = std::result::Result<Value, RedisError>;
+type Hash = BTreeMap<Vec<u8>, Vec<u8>>;
+
+/// In-memory stand-in for a Redis server, to unit test applications without a running server.
+///
+/// The built-in command traits are implemented for `&MockDatabase`,
+/// so that application code written against these traits, instead of a concrete
+/// [`Client`](crate::client::Client), can be exercised against a `MockDatabase`.
+///
+/// Two modes can be combined:
+/// * an in-memory store, supporting the common string, hash, expiration and generic commands
+///   (`GET`, `SET`, `MGET`, `INCR`, `HSET`, `HGETALL`, `EXPIRE`, `TTL`, `DEL`, `EXISTS`, ...),
+/// * scripted replies, registered with [`expect`](MockDatabase::expect),
+///   which are returned in order as long as the received commands match the expected ones.
+///
+/// A mock created with [`scripted`](MockDatabase::scripted) only accepts the expected commands.
+///
+/// Cloning a `MockDatabase` returns a handle to the same store.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     commands::{GenericCommands, StringCommands},
+///     resp::{cmd, Value},
+///     testing::MockDatabase,
+///     Result,
+/// };
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     // in-memory store
+///     let db = MockDatabase::new();
+///     db.set("key", "value").await?;
+///     let value: String = db.get("key").await?;
+///     assert_eq!("value", value);
+///     assert_eq!(1, db.del("key").await?);
+///
+///     // scripted replies
+///     let db = MockDatabase::scripted();
+///     db.expect(cmd("GET").arg("key"), Value::BulkString(b"42".to_vec()));
+///     let value: i64 = db.get("key").await?;
+///     assert_eq!(42, value);
+///     assert_eq!(0, db.remaining_expectations());
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct MockDatabase {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Default)]
+struct MockState {
+    store: Store,
+    expectations: VecDeque<(Command, Value)>,
+    history: Vec<Command>,
+    scripted_only: bool,
+}
+
+impl MockDatabase {
+    /// Create a mock backed by an empty in-memory store
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a mock which only replies to the commands registered with [`expect`](MockDatabase::expect)
+    ///
+    /// Any other command fails with an [`Error::Client`](crate::Error::Client) error.
+    #[must_use]
+    pub fn scripted() -> Self {
+        let mock = Self::default();
+        mock.lock().scripted_only = true;
+        mock
+    }
+
+    /// Register the reply of the next expected command
+    ///
+    /// Expectations are consumed in their registration order.
+    /// A command which does not match the next expectation
+    /// fails with an [`Error::Client`](crate::Error::Client) error.
+    ///
+    /// A [`Value::Error`] reply is returned as an [`Error::Redis`](crate::Error::Redis) error.
+    pub fn expect(&self, command: Command, reply: Value) -> &Self {
+        self.lock().expectations.push_back((command, reply));
+        self
+    }
+
+    /// Number of expectations which have not been consumed yet
+    #[must_use]
+    pub fn remaining_expectations(&self) -> usize {
+        self.lock().expectations.len()
+    }
+
+    /// All the commands received by the mock, in order
+    #[must_use]
+    pub fn history(&self) -> Vec<Command> {
+        self.lock().history.clone()
+    }
+
+    /// Send an arbitrary command to the mock
+    ///
+    /// This is the equivalent of [`Client::send`](crate::client::Client::send).
+    pub fn send(&self, command: Command) -> Result<RespBuf> {
+        let value = self.execute(command)?;
+        let mut serializer = RespSerializer::new();
+        value.serialize(&mut serializer)?;
+        Ok(RespBuf::new(serializer.get_output().freeze()))
+    }
+
+    fn execute(&self, command: Command) -> Result<Value> {
+        let mut state = self.lock();
+        state.history.push(command.clone());
+
+        if let Some((expected, reply)) = state.expectations.pop_front() {
+            if expected.name.eq_ignore_ascii_case(command.name)
+                && expected.args[..] == command.args[..]
+            {
+                return Ok(reply);
+            } else {
+                return Err(Error::Client(format!(
+                    "[MockDatabase] unexpected command {command:?}, expected {expected:?}"
+                )));
+            }
+        }
+
+        if state.scripted_only {
+            return Err(Error::Client(format!(
+                "[MockDatabase] unexpected command {command:?}"
+            )));
+        }
+
+        Ok(match state.store.execute(&command) {
+            Ok(value) => value,
+            Err(e) => Value::Error(e),
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        // a panicking test must not poison the other ones
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<'a, R> IntoFuture for PreparedCommand<'a, &'a MockDatabase, R>
+where
+    R: DeserializeOwned + Send + 'a,
+{
+    type Output = Result<R>;
+    type IntoFuture = Future<'a, R>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            if self.custom_converter.is_some() {
+                return Err(Error::Client(format!(
+                    "[MockDatabase] command {} is not supported",
+                    self.command.name
+                )));
+            }
+
+            self.executor.send(self.command)?.to()
+        })
+    }
+}
+
+impl<'a> BitmapCommands<'a> for &'a MockDatabase {}
+impl<'a> ConnectionCommands<'a> for &'a MockDatabase {}
+impl<'a> GenericCommands<'a> for &'a MockDatabase {}
+impl<'a> GeoCommands<'a> for &'a MockDatabase {}
+impl<'a> HashCommands<'a> for &'a MockDatabase {}
+impl<'a> HyperLogLogCommands<'a> for &'a MockDatabase {}
+impl<'a> ListCommands<'a> for &'a MockDatabase {}
+impl<'a> ScriptingCommands<'a> for &'a MockDatabase {}
+impl<'a> ServerCommands<'a> for &'a MockDatabase {}
+impl<'a> SetCommands<'a> for &'a MockDatabase {}
+impl<'a> SortedSetCommands<'a> for &'a MockDatabase {}
+impl<'a> StreamCommands<'a> for &'a MockDatabase {}
+impl<'a> StringCommands<'a> for &'a MockDatabase {}
+
+enum Data {
+    String(Vec<u8>),
+    Hash(Hash),
+}
+
+struct Entry {
+    data: Data,
+    /// Unix time in milliseconds
+    expires_at: Option<u64>,
+}
+
+#[derive(Default)]
+struct Store {
+    entries: HashMap<Vec<u8>, Entry>,
+}
+
+impl Store {
+    fn execute(&mut self, command: &Command) -> MockResult {
+        let name = command.name.to_ascii_uppercase();
+        let args: Vec<&[u8]> = command.args.iter().map(|a| a.as_slice()).collect();
+
+        match (name.as_str(), args.as_slice()) {
+            ("PING", []) => Ok(Value::SimpleString("PONG".to_owned())),
+            ("PING", [message]) => Ok(bulk(message)),
+            ("ECHO", [message]) => Ok(bulk(message)),
+            ("FLUSHDB" | "FLUSHALL", _) => {
+                self.entries.clear();
+                Ok(ok())
+            }
+            ("DBSIZE", []) => {
+                let now = now_millis();
+                self.entries.retain(|_, e| !e.is_expired(now));
+                Ok(Value::Integer(self.entries.len() as i64))
+            }
+
+            // generic
+            ("DEL" | "UNLINK", keys) if !keys.is_empty() => {
+                let now = now_millis();
+                let deleted = keys
+                    .iter()
+                    .filter(|key| {
+                        self.entries
+                            .remove(**key)
+                            .map(|e| !e.is_expired(now))
+                            .unwrap_or(false)
+                    })
+                    .count();
+                Ok(Value::Integer(deleted as i64))
+            }
+            ("EXISTS", keys) if !keys.is_empty() => {
+                let count = keys.iter().filter(|key| self.entry(key).is_some()).count();
+                Ok(Value::Integer(count as i64))
+            }
+            ("TYPE", [key]) => Ok(Value::SimpleString(
+                match self.entry(key) {
+                    Some(Entry {
+                        data: Data::String(_),
+                        ..
+                    }) => "string",
+                    Some(Entry {
+                        data: Data::Hash(_),
+                        ..
+                    }) => "hash",
+                    None => "none",
+                }
+                .to_owned(),
+            )),
+            ("EXPIRE", [key, seconds, options @ ..]) => {
+                let at = now_millis() as i64 + parse_i64(seconds)?.saturating_mul(1000);
+                self.expire_at(key, at, options)
+            }
+            ("PEXPIRE", [key, milliseconds, options @ ..]) => {
+                let at = now_millis() as i64 + parse_i64(milliseconds)?;
+                self.expire_at(key, at, options)
+            }
+            ("EXPIREAT", [key, seconds, options @ ..]) => {
+                let at = parse_i64(seconds)?.saturating_mul(1000);
+                self.expire_at(key, at, options)
+            }
+            ("PEXPIREAT", [key, milliseconds, options @ ..]) => {
+                let at = parse_i64(milliseconds)?;
+                self.expire_at(key, at, options)
+            }
+            ("TTL", [key]) => Ok(Value::Integer(match self.ttl(key) {
+                Ok(ttl) => (ttl + 500) / 1000,
+                Err(code) => code,
+            })),
+            ("PTTL", [key]) => Ok(Value::Integer(match self.ttl(key) {
+                Ok(ttl) => ttl,
+                Err(code) => code,
+            })),
+            ("EXPIRETIME" | "PEXPIRETIME", [key]) => Ok(Value::Integer(match self.entry(key) {
+                Some(Entry {
+                    expires_at: Some(at),
+                    ..
+                }) if name == "EXPIRETIME" => (*at as i64 + 500) / 1000,
+                Some(Entry {
+                    expires_at: Some(at),
+                    ..
+                }) => *at as i64,
+                Some(_) => -1,
+                None => -2,
+            })),
+            ("PERSIST", [key]) => Ok(Value::Integer(match self.entry(key) {
+                Some(entry) if entry.expires_at.is_some() => {
+                    entry.expires_at = None;
+                    1
+                }
+                _ => 0,
+            })),
+
+            // strings
+            ("GET", [key]) => Ok(self.string(key)?.map(|s| bulk(s)).unwrap_or(Value::Nil)),
+            ("GETDEL", [key]) => {
+                let value = self.string(key)?.map(|s| bulk(s)).unwrap_or(Value::Nil);
+                self.entries.remove(*key);
+                Ok(value)
+            }
+            ("SET", [key, value, options @ ..]) => self.set(key, value, options),
+            ("SETNX", [key, value]) => {
+                if self.entry(key).is_some() {
+                    Ok(Value::Integer(0))
+                } else {
+                    self.insert_string(key, value.to_vec(), None);
+                    Ok(Value::Integer(1))
+                }
+            }
+            ("SETEX", [key, seconds, value]) => {
+                let at = now_millis() as i64 + parse_i64(seconds)?.saturating_mul(1000);
+                self.insert_string(key, value.to_vec(), Some(at.max(0) as u64));
+                Ok(ok())
+            }
+            ("PSETEX", [key, milliseconds, value]) => {
+                let at = now_millis() as i64 + parse_i64(milliseconds)?;
+                self.insert_string(key, value.to_vec(), Some(at.max(0) as u64));
+                Ok(ok())
+            }
+            ("MGET", keys) if !keys.is_empty() => Ok(Value::Array(
+                keys.iter()
+                    .map(|key| match self.entry(key) {
+                        Some(Entry {
+                            data: Data::String(s),
+                            ..
+                        }) => bulk(s),
+                        _ => Value::Nil,
+                    })
+                    .collect(),
+            )),
+            ("MSET", items) if !items.is_empty() && items.len() % 2 == 0 => {
+                for pair in items.chunks(2) {
+                    self.insert_string(pair[0], pair[1].to_vec(), None);
+                }
+                Ok(ok())
+            }
+            ("MSETNX", items) if !items.is_empty() && items.len() % 2 == 0 => {
+                if items.chunks(2).any(|pair| self.entry(pair[0]).is_some()) {
+                    return Ok(Value::Integer(0));
+                }
+                for pair in items.chunks(2) {
+                    self.insert_string(pair[0], pair[1].to_vec(), None);
+                }
+                Ok(Value::Integer(1))
+            }
+            ("INCR", [key]) => self.incr_by(key, 1),
+            ("DECR", [key]) => self.incr_by(key, -1),
+            ("INCRBY", [key, increment]) => self.incr_by(key, parse_i64(increment)?),
+            ("DECRBY", [key, decrement]) => self.incr_by(key, -parse_i64(decrement)?),
+            ("INCRBYFLOAT", [key, increment]) => {
+                let increment = parse_f64(increment)?;
+                let current = match self.string(key)? {
+                    Some(s) => parse_f64(s)?,
+                    None => 0.,
+                };
+                let value = (current + increment).to_string().into_bytes();
+                self.update_string(key, value.clone());
+                Ok(Value::BulkString(value))
+            }
+            ("APPEND", [key, value]) => {
+                let len = match self.string(key)? {
+                    Some(s) => {
+                        s.extend_from_slice(value);
+                        s.len()
+                    }
+                    None => {
+                        self.insert_string(key, value.to_vec(), None);
+                        value.len()
+                    }
+                };
+                Ok(Value::Integer(len as i64))
+            }
+            ("STRLEN", [key]) => Ok(Value::Integer(
+                self.string(key)?.map(|s| s.len()).unwrap_or(0) as i64,
+            )),
+
+            // hashes
+            ("HSET" | "HMSET", [key, items @ ..]) if !items.is_empty() && items.len() % 2 == 0 => {
+                let hash = self.hash_or_insert(key)?;
+                let added = items
+                    .chunks(2)
+                    .filter(|pair| hash.insert(pair[0].to_vec(), pair[1].to_vec()).is_none())
+                    .count();
+                if name == "HMSET" {
+                    Ok(ok())
+                } else {
+                    Ok(Value::Integer(added as i64))
+                }
+            }
+            ("HSETNX", [key, field, value]) => {
+                let hash = self.hash_or_insert(key)?;
+                if hash.contains_key(*field) {
+                    Ok(Value::Integer(0))
+                } else {
+                    hash.insert(field.to_vec(), value.to_vec());
+                    Ok(Value::Integer(1))
+                }
+            }
+            ("HGET", [key, field]) => Ok(self
+                .hash(key)?
+                .and_then(|h| h.get(*field))
+                .map(|v| bulk(v))
+                .unwrap_or(Value::Nil)),
+            ("HMGET", [key, fields @ ..]) if !fields.is_empty() => {
+                let hash = self.hash(key)?;
+                Ok(Value::Array(
+                    fields
+                        .iter()
+                        .map(|field| {
+                            hash.as_ref()
+                                .and_then(|h| h.get(*field))
+                                .map(|v| bulk(v))
+                                .unwrap_or(Value::Nil)
+                        })
+                        .collect(),
+                ))
+            }
+            ("HGETALL", [key]) => Ok(Value::Map(
+                self.hash(key)?
+                    .map(|h| h.iter().map(|(f, v)| (bulk(f), bulk(v))).collect())
+                    .unwrap_or_default(),
+            )),
+            ("HKEYS", [key]) => Ok(Value::Array(
+                self.hash(key)?
+                    .map(|h| h.keys().map(|f| bulk(f)).collect())
+                    .unwrap_or_default(),
+            )),
+            ("HVALS", [key]) => Ok(Value::Array(
+                self.hash(key)?
+                    .map(|h| h.values().map(|v| bulk(v)).collect())
+                    .unwrap_or_default(),
+            )),
+            ("HLEN", [key]) => Ok(Value::Integer(
+                self.hash(key)?.map(|h| h.len()).unwrap_or(0) as i64,
+            )),
+            ("HEXISTS", [key, field]) => Ok(Value::Integer(
+                self.hash(key)?
+                    .map(|h| h.contains_key(*field))
+                    .unwrap_or(false) as i64,
+            )),
+            ("HDEL", [key, fields @ ..]) if !fields.is_empty() => {
+                let (deleted, is_empty) = match self.hash(key)? {
+                    Some(hash) => (
+                        fields
+                            .iter()
+                            .filter(|field| hash.remove(**field).is_some())
+                            .count(),
+                        hash.is_empty(),
+                    ),
+                    None => (0, false),
+                };
+                if is_empty {
+                    self.entries.remove(*key);
+                }
+                Ok(Value::Integer(deleted as i64))
+            }
+            ("HINCRBY", [key, field, increment]) => {
+                let increment = parse_i64(increment)?;
+                let hash = self.hash_or_insert(key)?;
+                let current = match hash.get(*field) {
+                    Some(v) => parse_i64(v)?,
+                    None => 0,
+                };
+                let value = current
+                    .checked_add(increment)
+                    .ok_or_else(|| err("increment or decrement would overflow"))?;
+                hash.insert(field.to_vec(), value.to_string().into_bytes());
+                Ok(Value::Integer(value))
+            }
+
+            (
+                "PING" | "ECHO" | "DBSIZE" | "DEL" | "UNLINK" | "EXISTS" | "TYPE" | "EXPIRE"
+                | "PEXPIRE" | "EXPIREAT" | "PEXPIREAT" | "TTL" | "PTTL" | "EXPIRETIME"
+                | "PEXPIRETIME" | "PERSIST" | "GET" | "GETDEL" | "SET" | "SETNX" | "SETEX"
+                | "PSETEX" | "MGET" | "MSET" | "MSETNX" | "INCR" | "DECR" | "INCRBY" | "DECRBY"
+                | "INCRBYFLOAT" | "APPEND" | "STRLEN" | "HSET" | "HMSET" | "HSETNX" | "HGET"
+                | "HMGET" | "HGETALL" | "HKEYS" | "HVALS" | "HLEN" | "HEXISTS" | "HDEL" | "HINCRBY",
+                _,
+            ) => Err(err(&format!(
+                "wrong number of arguments for '{}' command",
+                name.to_ascii_lowercase()
+            ))),
+            _ => Err(err(&format!(
+                "unknown command '{}' (not supported by MockDatabase)",
+                name.to_ascii_lowercase()
+            ))),
+        }
+    }
+
+    /// Get a live entry, removing it first if it has expired
+    fn entry(&mut self, key: &[u8]) -> Option<&mut Entry> {
+        let now = now_millis();
+        if self
+            .entries
+            .get(key)
+            .map(|e| e.is_expired(now))
+            .unwrap_or(false)
+        {
+            self.entries.remove(key);
+        }
+        self.entries.get_mut(key)
+    }
+
+    fn string(&mut self, key: &[u8]) -> std::result::Result<Option<&mut Vec<u8>>, RedisError> {
+        match self.entry(key) {
+            Some(Entry {
+                data: Data::String(s),
+                ..
+            }) => Ok(Some(s)),
+            Some(_) => Err(wrong_type()),
+            None => Ok(None),
+        }
+    }
+
+    fn hash(&mut self, key: &[u8]) -> std::result::Result<Option<&mut Hash>, RedisError> {
+        match self.entry(key) {
+            Some(Entry {
+                data: Data::Hash(h),
+                ..
+            }) => Ok(Some(h)),
+            Some(_) => Err(wrong_type()),
+            None => Ok(None),
+        }
+    }
+
+    fn hash_or_insert(&mut self, key: &[u8]) -> std::result::Result<&mut Hash, RedisError> {
+        if self.entry(key).is_none() {
+            self.entries.insert(
+                key.to_vec(),
+                Entry {
+                    data: Data::Hash(BTreeMap::new()),
+                    expires_at: None,
+                },
+            );
+        }
+
+        match self.entries.get_mut(key) {
+            Some(Entry {
+                data: Data::Hash(h),
+                ..
+            }) => Ok(h),
+            _ => Err(wrong_type()),
+        }
+    }
+
+    fn insert_string(&mut self, key: &[u8], value: Vec<u8>, expires_at: Option<u64>) {
+        self.entries.insert(
+            key.to_vec(),
+            Entry {
+                data: Data::String(value),
+                expires_at,
+            },
+        );
+    }
+
+    /// Replace the value of a string, keeping its time to live
+    fn update_string(&mut self, key: &[u8], value: Vec<u8>) {
+        let expires_at = self.entry(key).and_then(|e| e.expires_at);
+        self.insert_string(key, value, expires_at);
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8], options: &[&[u8]]) -> MockResult {
+        let mut nx = false;
+        let mut xx = false;
+        let mut get = false;
+        let mut keep_ttl = false;
+        let mut expires_at = None;
+
+        let mut options = options.iter();
+        while let Some(option) = options.next() {
+            let option = option.to_ascii_uppercase();
+            match option.as_slice() {
+                b"NX" => nx = true,
+                b"XX" => xx = true,
+                b"GET" => get = true,
+                b"KEEPTTL" => keep_ttl = true,
+                b"EX" | b"PX" | b"EXAT" | b"PXAT" => {
+                    let time = parse_i64(options.next().ok_or_else(syntax_error)?)?;
+                    if time <= 0 {
+                        return Err(err("invalid expire time in 'set' command"));
+                    }
+                    let now = now_millis() as i64;
+                    expires_at = Some(match option.as_slice() {
+                        b"EX" => now + time.saturating_mul(1000),
+                        b"PX" => now + time,
+                        b"EXAT" => time.saturating_mul(1000),
+                        _ => time,
+                    } as u64);
+                }
+                _ => return Err(syntax_error()),
+            }
+        }
+
+        if nx && xx {
+            return Err(syntax_error());
+        }
+
+        let previous = if get {
+            self.string(key)?.map(|s| bulk(s)).unwrap_or(Value::Nil)
+        } else {
+            Value::Nil
+        };
+
+        let exists = self.entry(key).is_some();
+        if (nx && exists) || (xx && !exists) {
+            return Ok(if get { previous } else { Value::Nil });
+        }
+
+        if keep_ttl {
+            expires_at = self.entry(key).and_then(|e| e.expires_at);
+        }
+        self.insert_string(key, value.to_vec(), expires_at);
+
+        Ok(if get { previous } else { ok() })
+    }
+
+    fn incr_by(&mut self, key: &[u8], increment: i64) -> MockResult {
+        let current = match self.string(key)? {
+            Some(s) => parse_i64(s)?,
+            None => 0,
+        };
+        let value = current
+            .checked_add(increment)
+            .ok_or_else(|| err("increment or decrement would overflow"))?;
+        self.update_string(key, value.to_string().into_bytes());
+        Ok(Value::Integer(value))
+    }
+
+    fn expire_at(&mut self, key: &[u8], at: i64, options: &[&[u8]]) -> MockResult {
+        let option = match options {
+            [] => None,
+            [option] => Some(option.to_ascii_uppercase()),
+            _ => return Err(syntax_error()),
+        };
+
+        let entry = match self.entry(key) {
+            Some(entry) => entry,
+            None => return Ok(Value::Integer(0)),
+        };
+
+        // no expiration is an infinite time to live
+        let is_allowed = match (option.as_deref(), entry.expires_at) {
+            (None, _) => true,
+            (Some(b"NX"), current) => current.is_none(),
+            (Some(b"XX"), current) => current.is_some(),
+            (Some(b"GT"), Some(current)) => at > current as i64,
+            (Some(b"GT"), None) => false,
+            (Some(b"LT"), Some(current)) => at < current as i64,
+            (Some(b"LT"), None) => true,
+            _ => return Err(syntax_error()),
+        };
+
+        if !is_allowed {
+            return Ok(Value::Integer(0));
+        }
+
+        if at <= now_millis() as i64 {
+            self.entries.remove(key);
+        } else {
+            entry.expires_at = Some(at as u64);
+        }
+
+        Ok(Value::Integer(1))
+    }
+
+    /// Remaining time to live in milliseconds, or the error code of TTL/PTTL
+    fn ttl(&mut self, key: &[u8]) -> std::result::Result<i64, i64> {
+        match self.entry(key) {
+            Some(Entry {
+                expires_at: Some(at),
+                ..
+            }) => Ok(*at as i64 - now_millis() as i64),
+            Some(_) => Err(-1),
+            None => Err(-2),
+        }
+    }
+}
+
+impl Entry {
+    fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.map(|at| at <= now).unwrap_or(false)
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn bulk(bytes: &[u8]) -> Value {
+    Value::BulkString(bytes.to_vec())
+}
+
+fn ok() -> Value {
+    Value::SimpleString("OK".to_owned())
+}
+
+fn err(description: &str) -> RedisError {
+    RedisError {
+        kind: RedisErrorKind::Err,
+        description: description.to_owned(),
+    }
+}
+
+fn syntax_error() -> RedisError {
+    err("syntax error")
+}
+
+fn wrong_type() -> RedisError {
+    RedisError {
+        kind: RedisErrorKind::WrongType,
+        description: "Operation against a key holding the wrong kind of value".to_owned(),
+    }
+}
+
+fn parse_i64(bytes: &[u8]) -> std::result::Result<i64, RedisError> {
+    std::str::from_utf8(bytes)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| err("value is not an integer or out of range"))
+}
+
+fn parse_f64(bytes: &[u8]) -> std::result::Result<f64, RedisError> {
+    std::str::from_utf8(bytes)
+        .ok()
+        .and_then(|s| s.parse::<f64>().ok())
+        .filter(|f| f.is_finite())
+        .ok_or_else(|| err("value is not a valid float"))
+}
@@ -0,0 +1,22 @@
+/*!
+Helpers to test applications built on top of rustis
+
+The [`MockDatabase`] struct implements the built-in command traits on top of an in-memory store
+and of scripted replies, so that application code can be unit tested without a Redis server.
+
+The [`assert_encoding`] function checks that a value stays within the expected internal encoding,
+e.g. a compact `listpack`, whatever the version of the server.
+
+With the `test-server` feature, the [`RedisServer`] struct spawns a temporary `redis-server` process
+for integration tests.
+*/
+
+mod assertions;
+mod mock_database;
+#[cfg(feature = "test-server")]
+mod redis_server;
+
+pub use assertions::*;
+pub use mock_database::*;
+#[cfg(feature = "test-server")]
+pub use redis_server::*;
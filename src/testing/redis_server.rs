@@ -0,0 +1,154 @@
+use crate::{client::Client, sleep, Error, Result};
+use std::{
+    env, fs,
+    net::TcpListener,
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+/// Environment variable overriding the path of the `redis-server` executable
+pub const REDIS_SERVER_BIN_ENV: &str = "REDIS_SERVER_BIN";
+
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
+const STARTUP_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+static INSTANCE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Temporary `redis-server` process, for integration tests
+///
+/// The server listens on a random free port of the loopback interface,
+/// with a dedicated temporary working directory and persistence disabled.
+/// The process is killed and its directory removed when the `RedisServer` is dropped.
+///
+/// The `redis-server` executable is searched in the `PATH`,
+/// unless the [`REDIS_SERVER_BIN`](REDIS_SERVER_BIN_ENV) environment variable is set.
+///
+/// # Example
+/// ```no_run
+/// use rustis::{commands::StringCommands, testing::RedisServer, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let server = RedisServer::start().await?;
+///     let client = server.client().await?;
+///
+///     client.set("key", "value").await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "test-server")))]
+pub struct RedisServer {
+    process: Child,
+    port: u16,
+    dir: PathBuf,
+}
+
+impl RedisServer {
+    /// Spawn a new server and wait until it accepts connections
+    pub async fn start() -> Result<Self> {
+        Self::start_with_args::<&str>(&[]).await
+    }
+
+    /// Spawn a new server with additional command line arguments
+    /// (e.g. `["--maxmemory", "10mb"]`) and wait until it accepts connections
+    pub async fn start_with_args<A: AsRef<str>>(args: &[A]) -> Result<Self> {
+        let port = free_port()?;
+        let dir = env::temp_dir().join(format!(
+            "rustis-{}-{}",
+            std::process::id(),
+            INSTANCE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&dir)?;
+
+        let executable =
+            env::var(REDIS_SERVER_BIN_ENV).unwrap_or_else(|_| "redis-server".to_owned());
+        let process = Command::new(&executable)
+            .arg("--port")
+            .arg(port.to_string())
+            .arg("--bind")
+            .arg("127.0.0.1")
+            .arg("--dir")
+            .arg(&dir)
+            .arg("--save")
+            .arg("")
+            .arg("--appendonly")
+            .arg("no")
+            .args(args.iter().map(AsRef::as_ref))
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| {
+                let _ = fs::remove_dir_all(&dir);
+                Error::Client(format!("Cannot spawn `{executable}`: {e}"))
+            })?;
+
+        let mut server = Self { process, port, dir };
+        server.wait_until_ready().await?;
+        Ok(server)
+    }
+
+    /// Port on which the server is listening
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Address of the server in the format `127.0.0.1:<port>`
+    #[must_use]
+    pub fn addr(&self) -> String {
+        format!("127.0.0.1:{}", self.port)
+    }
+
+    /// Working directory of the server
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Connect a new client to the server
+    pub async fn client(&self) -> Result<Client> {
+        Client::connect(self.addr()).await
+    }
+
+    async fn wait_until_ready(&mut self) -> Result<()> {
+        let start = Instant::now();
+        loop {
+            if let Some(status) = self.process.try_wait()? {
+                return Err(Error::Client(format!(
+                    "redis-server exited during startup: {status}"
+                )));
+            }
+
+            if let Ok(client) = self.client().await {
+                let _ = client.close().await;
+                return Ok(());
+            }
+
+            if start.elapsed() > STARTUP_TIMEOUT {
+                return Err(Error::Timeout(
+                    "redis-server did not accept connections in time".to_owned(),
+                ));
+            }
+
+            sleep(STARTUP_POLL_INTERVAL).await;
+        }
+    }
+}
+
+impl Drop for RedisServer {
+    fn drop(&mut self) {
+        let _ = self.process.kill();
+        let _ = self.process.wait();
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Ask the OS for a free port
+fn free_port() -> Result<u16> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    Ok(listener.local_addr()?.port())
+}
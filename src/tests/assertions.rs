@@ -0,0 +1,62 @@
+use crate::{
+    commands::{GenericCommands, HashCommands, ObjectEncoding, SetCommands, StringCommands},
+    testing::assert_encoding,
+    tests::get_test_client,
+    Result,
+};
+use serial_test::serial;
+
+#[test]
+fn object_encoding() {
+    assert_eq!(ObjectEncoding::ListPack, "listpack".parse().unwrap());
+    assert_eq!(ObjectEncoding::IntSet, "intset".parse().unwrap());
+    assert_eq!(
+        ObjectEncoding::Other("unknown".to_owned()),
+        "unknown".parse().unwrap()
+    );
+    assert_eq!("embstr", ObjectEncoding::EmbStr.to_string());
+
+    assert!(ObjectEncoding::ListPack.is_equivalent(&ObjectEncoding::ZipList));
+    assert!(ObjectEncoding::ZipList.is_equivalent(&ObjectEncoding::ListPack));
+    assert!(!ObjectEncoding::ListPack.is_equivalent(&ObjectEncoding::HashTable));
+
+    assert!(ObjectEncoding::IntSet.is_compact());
+    assert!(!ObjectEncoding::SkipList.is_compact());
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn assert_compact_encoding() -> Result<()> {
+    let client = get_test_client().await?;
+
+    client.del(["string", "hash", "set"]).await?;
+    client.set("string", 12).await?;
+    client.hset("hash", [("field", "value")]).await?;
+    client.sadd("set", [1, 2, 3]).await?;
+
+    let encoding: ObjectEncoding = client.object_encoding("string").await?;
+    assert_eq!(ObjectEncoding::Int, encoding);
+
+    assert_encoding(&client, "string", ObjectEncoding::Int).await?;
+    // ziplist and listpack are equivalent across server versions
+    assert_encoding(&client, "hash", ObjectEncoding::ZipList).await?;
+    assert_encoding(&client, "set", ObjectEncoding::IntSet).await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+#[should_panic(expected = "expected `skiplist`")]
+async fn assert_encoding_mismatch() {
+    let client = get_test_client().await.unwrap();
+
+    client.del("set").await.unwrap();
+    client.sadd("set", ["a", "b"]).await.unwrap();
+
+    assert_encoding(&client, "set", ObjectEncoding::SkipList)
+        .await
+        .unwrap();
+}
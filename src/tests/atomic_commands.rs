@@ -0,0 +1,135 @@
+use crate::{
+    commands::{FlushingMode, GenericCommands, ScriptingCommands, ServerCommands, StringCommands},
+    tests::get_test_client,
+    utils::{AtomicCommands, CheckAndSetResult, Script},
+    Result,
+};
+use serial_test::serial;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn script_call() -> Result<()> {
+    let client = get_test_client().await?;
+    client.script_flush(FlushingMode::Sync).await?;
+
+    let script = Script::new("return KEYS[1] .. ARGV[1]");
+
+    // first call loads the script
+    let value: String = script.call(&client, "hello", " world").await?;
+    assert_eq!("hello world", value);
+
+    // second call hits the script cache
+    let value: String = script.call(&client, "hello", " world").await?;
+    assert_eq!("hello world", value);
+
+    let sha1: String = client.script_load(script.code()).await?;
+    assert_eq!(script.sha1(), sha1);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn set_if_equal() -> Result<()> {
+    let client = get_test_client().await?;
+    client.del("key").await?;
+
+    let result = client.set_if_equal("key", "value1", "value2").await?;
+    assert!(!result);
+
+    client.set("key", "value1").await?;
+    let result = client.set_if_equal("key", "other", "value2").await?;
+    assert!(!result);
+    let value: String = client.get("key").await?;
+    assert_eq!("value1", value);
+
+    let result = client.set_if_equal("key", "value1", "value2").await?;
+    assert!(result);
+    let value: String = client.get("key").await?;
+    assert_eq!("value2", value);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn get_and_expire() -> Result<()> {
+    let client = get_test_client().await?;
+    client.del("key").await?;
+
+    let value: Option<String> = client.get_and_expire("key", 10_000).await?;
+    assert_eq!(None, value);
+
+    client.set("key", "value").await?;
+    let value: Option<String> = client.get_and_expire("key", 10_000).await?;
+    assert_eq!(Some("value".to_owned()), value);
+
+    let ttl = client.pttl("key").await?;
+    assert!(ttl > 0 && ttl <= 10_000);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn compare_and_delete() -> Result<()> {
+    let client = get_test_client().await?;
+    client.set("key", "value").await?;
+
+    let result = client.compare_and_delete("key", "other").await?;
+    assert!(!result);
+    assert_eq!(1, client.exists("key").await?);
+
+    let result = client.compare_and_delete("key", "value").await?;
+    assert!(result);
+    assert_eq!(0, client.exists("key").await?);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn check_and_set() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    let result = client
+        .check_and_set("version", 0, [("key1", "value1"), ("key2", "value2")])
+        .await?;
+    assert_eq!(CheckAndSetResult::Applied { version: 1 }, result);
+
+    let values: Vec<String> = client.mget(["key1", "key2"]).await?;
+    assert_eq!(vec!["value1".to_owned(), "value2".to_owned()], values);
+
+    // stale version
+    let result = client
+        .check_and_set("version", 0, [("key1", "other")])
+        .await?;
+    assert_eq!(CheckAndSetResult::Conflict { current_version: 1 }, result);
+    let value: String = client.get("key1").await?;
+    assert_eq!("value1", value);
+
+    let result = client
+        .check_and_set("version", 1, [("key1", "other")])
+        .await?;
+    assert_eq!(CheckAndSetResult::Applied { version: 2 }, result);
+    let value: String = client.get("key1").await?;
+    assert_eq!("other", value);
+
+    client.close().await?;
+
+    Ok(())
+}
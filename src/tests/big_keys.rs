@@ -0,0 +1,78 @@
+use crate::{
+    commands::{FlushingMode, HashCommands, ListCommands, ServerCommands, StringCommands},
+    tests::get_test_client,
+    utils::{find_big_keys, BigKeysOptions},
+    Result,
+};
+use futures_util::TryStreamExt;
+use serial_test::serial;
+use std::time::Instant;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn find_big_keys_() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    for i in 0..20 {
+        client.set(format!("small:{i}"), "value").await?;
+    }
+    client
+        .rpush(
+            "list",
+            (0..1000).map(|i| format!("item{i}")).collect::<Vec<_>>(),
+        )
+        .await?;
+    client
+        .hset(
+            "hash",
+            (0..1000)
+                .map(|i| (format!("field{i}"), format!("value{i}")))
+                .collect::<Vec<_>>(),
+        )
+        .await?;
+    client.set("string", "x".repeat(10_000)).await?;
+
+    let mut big_keys: Vec<_> = find_big_keys(&client, 5_000, BigKeysOptions::default())
+        .try_collect()
+        .await?;
+    big_keys.sort_by(|k1, k2| k1.key.cmp(&k2.key));
+
+    assert_eq!(3, big_keys.len());
+    assert_eq!("hash", big_keys[0].key);
+    assert_eq!("hash", big_keys[0].key_type);
+    assert_eq!(Some(1000), big_keys[0].elements);
+    assert_eq!("list", big_keys[1].key);
+    assert_eq!(Some(1000), big_keys[1].elements);
+    assert_eq!("string", big_keys[2].key);
+    assert_eq!(None, big_keys[2].elements);
+    assert!(big_keys[2].size >= 10_000);
+
+    // pattern
+    let big_keys: Vec<_> = find_big_keys(
+        &client,
+        5_000,
+        BigKeysOptions::default().match_pattern("l*"),
+    )
+    .try_collect()
+    .await?;
+    assert_eq!(1, big_keys.len());
+    assert_eq!("list", big_keys[0].key);
+
+    // rate limiting: 23 keys at 50 keys per second
+    let start = Instant::now();
+    let big_keys: Vec<_> = find_big_keys(
+        &client,
+        0,
+        BigKeysOptions::default()
+            .scan_count(5)
+            .max_keys_per_second(50),
+    )
+    .try_collect()
+    .await?;
+    assert_eq!(23, big_keys.len());
+    assert!(start.elapsed().as_millis() >= 400);
+
+    Ok(())
+}
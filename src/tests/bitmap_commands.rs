@@ -1,7 +1,8 @@
 use crate::{
     commands::{
-        BitFieldGetSubCommand, BitFieldOverflow, BitFieldSubCommand, BitOperation, BitRange,
-        BitUnit, BitmapCommands, StringCommands,
+        BitFieldEncoding, BitFieldGetSubCommand, BitFieldOffset, BitFieldOperations,
+        BitFieldOverflow, BitFieldSubCommand, BitOperation, BitRange, BitUnit, BitmapCommands,
+        StringCommands,
     },
     tests::get_test_client,
     Result,
@@ -101,6 +102,55 @@ async fn bitfield() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn bitfield_encoding_and_offset() {
+    assert_eq!("u8", BitFieldEncoding::U8.to_string());
+    assert_eq!("i64", BitFieldEncoding::I64.to_string());
+    assert_eq!("u4", BitFieldEncoding::unsigned(4).to_string());
+    assert_eq!("i5", BitFieldEncoding::signed(5).to_string());
+    assert_eq!(16, BitFieldEncoding::U16.bits());
+    assert_eq!("100", BitFieldOffset::bit(100).to_string());
+    assert_eq!("#3", BitFieldOffset::index(3).to_string());
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn bitfield_typed() -> Result<()> {
+    let client = get_test_client().await?;
+
+    client.set("mykey", "foobar").await?;
+
+    let results = client
+        .bitfield_typed(
+            "mykey",
+            BitFieldOperations::default()
+                .get(BitFieldEncoding::U8, BitFieldOffset::index(1))
+                .set(BitFieldEncoding::I8, BitFieldOffset::index(0), -1)
+                .get(BitFieldEncoding::I8, BitFieldOffset::bit(0))
+                .overflow(BitFieldOverflow::Fail)
+                .incr_by(BitFieldEncoding::U8, BitFieldOffset::index(1), 200),
+        )
+        .await?;
+    assert_eq!(vec![Some(111), Some(102), Some(-1), None], results);
+
+    // typed encodings and offsets also work with the untyped sub-commands
+    let results = client
+        .bitfield(
+            "mykey",
+            [BitFieldSubCommand::get(
+                BitFieldEncoding::U8,
+                BitFieldOffset::index(1),
+            )],
+        )
+        .await?;
+    assert_eq!(vec![111], results);
+
+    client.close().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -1,10 +1,13 @@
 use bytes::{BytesMut};
 use tokio_util::codec::Decoder;
 
-use crate::{resp::BufferDecoder, Result};
+use crate::{
+    resp::{BufferDecoder, RespBuf, ResponseLimits},
+    Error, Result,
+};
 
 fn decode(str: &str) -> Result<Option<Vec<u8>>> {
-    let mut buffer_decoder = BufferDecoder;
+    let mut buffer_decoder = BufferDecoder::default();
     let mut buf: BytesMut = str.into();
     buffer_decoder.decode(&mut buf).map(|b| b.map(|b| b.to_vec()))
 }
@@ -202,4 +205,119 @@ fn map() -> Result<()> {
     assert_eq!(None, result);
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[test]
+fn stream_array_header() -> Result<()> {
+    let mut buffer_decoder = BufferDecoder {
+        stream_array_header: true,
+        ..Default::default()
+    };
+
+    let mut buf: BytesMut = "*2\r\n$5\r\nhello\r\n".into();
+    let result = buffer_decoder.decode(&mut buf)?.map(|b| b.to_vec());
+    assert_eq!(Some(b"*2\r\n".to_vec()), result);
+
+    buffer_decoder.stream_array_header = false;
+    let result = buffer_decoder.decode(&mut buf)?.map(|b| b.to_vec());
+    assert_eq!(Some(b"$5\r\nhello\r\n".to_vec()), result);
+
+    // incomplete header
+    buffer_decoder.stream_array_header = true;
+    let mut buf: BytesMut = "~3\r".into();
+    assert!(buffer_decoder.decode(&mut buf)?.is_none());
+
+    // empty arrays are decoded as a whole
+    let mut buf: BytesMut = "*0\r\n".into();
+    let result = buffer_decoder.decode(&mut buf)?.map(|b| b.to_vec());
+    assert_eq!(Some(b"*0\r\n".to_vec()), result);
+
+    // other types are not affected
+    let mut buf: BytesMut = ":12\r\n".into();
+    let result = buffer_decoder.decode(&mut buf)?.map(|b| b.to_vec());
+    assert_eq!(Some(b":12\r\n".to_vec()), result);
+
+    Ok(())
+}
+
+#[test]
+fn array_elements() -> Result<()> {
+    let resp_buf = RespBuf::from_slice(b"*3\r\n$5\r\nhello\r\n:12\r\n*1\r\n+OK\r\n");
+    let elements = resp_buf.array_elements()?;
+    assert_eq!(3, elements.len());
+    assert_eq!(b"$5\r\nhello\r\n", elements[0].as_bytes());
+    assert_eq!(b":12\r\n", elements[1].as_bytes());
+    assert_eq!(b"*1\r\n+OK\r\n", elements[2].as_bytes());
+
+    let resp_buf = RespBuf::from_slice(b"*0\r\n");
+    assert!(resp_buf.array_elements()?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn response_limits() -> Result<()> {
+    fn decode_with_limits(str: &str, limits: ResponseLimits) -> Result<Option<Vec<u8>>> {
+        let mut buffer_decoder = BufferDecoder::new(limits);
+        let mut buf: BytesMut = str.into();
+        Ok(buffer_decoder.decode(&mut buf)?.map(|b| b.to_vec()))
+    }
+
+    let limits = ResponseLimits {
+        max_bulk_string_size: 5,
+        max_depth: 2,
+        max_reply_size: 64,
+    };
+
+    // within the limits
+    let result = decode_with_limits("*2\r\n*1\r\n$5\r\nhello\r\n:12\r\n", limits)?;
+    assert_eq!(Some(b"*2\r\n*1\r\n$5\r\nhello\r\n:12\r\n".to_vec()), result);
+
+    // the announced size is rejected before the string is received
+    let result = decode_with_limits("*2\r\n$1000000\r\nhel", limits);
+    assert!(matches!(
+        result,
+        Err(Error::ResponseLimitExceeded {
+            limit: "max_bulk_string_size",
+            max: 5,
+            actual: 1000000
+        })
+    ));
+
+    let result = decode_with_limits("*1\r\n%1\r\n*1\r\n:1\r\n:2\r\n", limits);
+    assert!(matches!(
+        result,
+        Err(Error::ResponseLimitExceeded {
+            limit: "max_reply_depth",
+            max: 2,
+            actual: 3
+        })
+    ));
+
+    // incomplete reply already too large
+    let reply = format!("*100\r\n{}", ":1\r\n".repeat(20));
+    let result = decode_with_limits(&reply, limits);
+    assert!(matches!(
+        result,
+        Err(Error::ResponseLimitExceeded {
+            limit: "max_reply_size",
+            max: 64,
+            ..
+        })
+    ));
+
+    // next replies are not accounted to the current one
+    let result = decode_with_limits(&":1\r\n".repeat(20), limits)?;
+    assert_eq!(Some(b":1\r\n".to_vec()), result);
+
+    // a null bulk string has no body to skip
+    limits.check(b"*2\r\n$-1\r\n+a$100\r\n")?;
+
+    // empty header line
+    let result = decode_with_limits("\n", limits);
+    assert!(matches!(result, Err(Error::Protocol(_))));
+    let result = decode_with_limits("*2\r\n:1\r\n\n", limits);
+    assert!(matches!(result, Err(Error::Protocol(_))));
+
+    Ok(())
+}
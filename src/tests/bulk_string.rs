@@ -0,0 +1,112 @@
+use crate::{
+    resp::{BulkString, RespBuf, RespDeserializer, Value},
+    tests::log_try_init,
+    Result,
+};
+use bytes::Bytes;
+use serde::Deserialize;
+
+#[test]
+fn inline() {
+    log_try_init();
+
+    let bs: BulkString = b"abc".into();
+    assert!(bs.is_inline());
+    assert_eq!(b"abc", bs.as_bytes());
+
+    let bs = BulkString::new(vec![b'a'; 22]);
+    assert!(bs.is_inline());
+
+    let bs = BulkString::new(vec![b'a'; 23]);
+    assert!(!bs.is_inline());
+    assert_eq!(23, bs.len());
+}
+
+#[test]
+fn bytes() {
+    log_try_init();
+
+    let bytes = Bytes::from(vec![b'a'; 100]);
+    let bs = BulkString::from(bytes.clone());
+    assert!(!bs.is_inline());
+    // no copy
+    assert_eq!(bytes.as_ptr(), bs.as_bytes().as_ptr());
+    let into_bytes = bs.into_bytes();
+    assert_eq!(bytes.as_ptr(), into_bytes.as_ptr());
+
+    let bs: BulkString = "short".into();
+    assert_eq!(Bytes::from_static(b"short"), bs.into_bytes());
+
+    let bs: BulkString = "value".to_owned().into();
+    let vec: Vec<u8> = bs.into();
+    assert_eq!(b"value".to_vec(), vec);
+}
+
+#[test]
+fn equality() {
+    log_try_init();
+
+    let bs1 = BulkString::new(vec![b'a'; 30]);
+    let bs2 = BulkString::from_slice(&[b'a'; 30]);
+    assert_eq!(bs1, bs2);
+    assert_eq!(bs1.clone(), bs2);
+    assert_ne!(BulkString::from("abc"), BulkString::from("abd"));
+}
+
+#[test]
+fn deserialize() -> Result<()> {
+    log_try_init();
+
+    let mut deserializer = RespDeserializer::new(b"$5\r\nvalue\r\n");
+    let bs = BulkString::deserialize(&mut deserializer)?;
+    assert!(bs.is_inline());
+    assert_eq!(b"value", bs.as_bytes());
+
+    let mut deserializer = RespDeserializer::new(b"*2\r\n$3\r\nabc\r\n_\r\n");
+    let values = Vec::<BulkString>::deserialize(&mut deserializer)?;
+    assert_eq!(vec![BulkString::from("abc"), BulkString::from("")], values);
+
+    let value = Value::BulkString(b"value".to_vec());
+    let bs = BulkString::deserialize(&value)?;
+    assert_eq!(b"value", bs.as_bytes());
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_without_copy() -> Result<()> {
+    log_try_init();
+
+    let resp_buf =
+        RespBuf::from_slice(b"*2\r\n$30\r\naaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\r\n$3\r\nabc\r\n");
+    let values = resp_buf.to_bulk_strings()?;
+    assert_eq!(2, values.len());
+
+    // the long bulk string shares the buffer of the RespBuf
+    assert!(!values[0].is_inline());
+    assert_eq!(b"a".repeat(30), values[0].as_bytes());
+    let range = resp_buf.as_bytes().as_ptr_range();
+    assert!(range.contains(&values[0].as_bytes().as_ptr()));
+
+    assert!(values[1].is_inline());
+    assert_eq!(b"abc", values[1].as_bytes());
+
+    // the buffer outlives the RespBuf
+    drop(resp_buf);
+    assert_eq!(b"a".repeat(30), values[0].as_bytes());
+
+    let resp_buf = RespBuf::from_slice(b"$30\r\naaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\r\n");
+    let value = resp_buf.to_bulk_string()?;
+    let range = resp_buf.as_bytes().as_ptr_range();
+    assert!(range.contains(&value.as_bytes().as_ptr()));
+
+    // serde deserialization copies
+    let value: BulkString = resp_buf.to()?;
+    assert!(!range.contains(&value.as_bytes().as_ptr()));
+    assert_eq!(b"a".repeat(30), value.as_bytes());
+
+    assert!(RespBuf::nil().to_bulk_strings()?.is_empty());
+    assert!(RespBuf::ok().to_bulk_strings().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+#[cfg(feature = "cache-bincode")]
+use crate::utils::BincodeSerializer;
+#[cfg(feature = "cache-msgpack")]
+use crate::utils::MessagePackSerializer;
+use crate::{
+    commands::{FlushingMode, GenericCommands, ServerCommands},
+    tests::get_test_client,
+    utils::{Cache, CacheSerializer, JsonSerializer},
+    Result,
+};
+use futures_util::future;
+use serde::{Deserialize, Serialize};
+use serial_test::serial;
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct User {
+    name: String,
+    age: u32,
+    tags: Vec<String>,
+}
+
+fn user() -> User {
+    User {
+        name: "John".to_owned(),
+        age: 42,
+        tags: vec!["admin".to_owned(); 100],
+    }
+}
+
+async fn check_serializer<S: CacheSerializer>(serializer: S) -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let cache = Cache::<User, _>::new(&client, serializer).with_prefix("users:");
+
+    assert_eq!(None, cache.get("1").await?);
+
+    cache.set("1", &user(), None).await?;
+    assert_eq!(Some(user()), cache.get("1").await?);
+    assert_eq!(-1, client.ttl("users:1").await?);
+
+    cache
+        .set("2", &user(), Some(Duration::from_secs(10)))
+        .await?;
+    assert!(client.ttl("users:2").await? > 0);
+
+    assert!(cache.delete("1").await?);
+    assert!(!cache.delete("1").await?);
+    assert_eq!(None, cache.get("1").await?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn json() -> Result<()> {
+    check_serializer(JsonSerializer).await
+}
+
+#[cfg(feature = "cache-msgpack")]
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn msgpack() -> Result<()> {
+    check_serializer(MessagePackSerializer).await
+}
+
+#[cfg(feature = "cache-bincode")]
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn bincode() -> Result<()> {
+    check_serializer(BincodeSerializer).await
+}
+
+#[cfg(feature = "cache-compression")]
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn compression() -> Result<()> {
+    use crate::commands::StringCommands;
+
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let cache = Cache::<User, _>::new(&client, JsonSerializer).with_compression(100);
+    cache.set("big", &user(), None).await?;
+    assert_eq!(Some(user()), cache.get("big").await?);
+
+    let uncompressed_len = JsonSerializer.serialize(&user())?.len();
+    assert!(client.strlen("big").await? < uncompressed_len);
+
+    let small_cache = Cache::<u32, _>::new(&client, JsonSerializer).with_compression(100);
+    small_cache.set("small", &12, None).await?;
+    assert_eq!(Some(12), small_cache.get("small").await?);
+    assert_eq!(3, client.strlen("small").await?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn get_or_compute() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let cache = Cache::<User, _>::new(&client, JsonSerializer);
+    let num_computations = AtomicUsize::new(0);
+
+    let results = future::join_all((0..5).map(|_| {
+        cache.get_or_compute("user", Some(Duration::from_secs(10)), || async {
+            num_computations.fetch_add(1, Ordering::SeqCst);
+            crate::sleep(Duration::from_millis(100)).await;
+            Ok(user())
+        })
+    }))
+    .await;
+
+    assert_eq!(1, num_computations.load(Ordering::SeqCst));
+    for result in results {
+        assert_eq!(user(), result?);
+    }
+
+    // no lock left behind
+    assert_eq!(0, client.exists("user:lock").await?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn get_or_set_locked() -> Result<()> {
+    use crate::{
+        commands::{SetCondition, SetExpiration, StringCommands},
+        spawn,
+        utils::CacheLockOptions,
+    };
+
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let cache = Cache::<u32, _>::new(&client, JsonSerializer).with_lock_options(
+        CacheLockOptions::default()
+            .lock_timeout(Duration::from_secs(1))
+            .retry_interval(Duration::from_millis(20))
+            .jitter(Duration::from_millis(10)),
+    );
+
+    // another process holds the lock and stores the value
+    client
+        .set_with_options(
+            "key1:lock",
+            "other",
+            SetCondition::NX,
+            SetExpiration::Px(1000),
+            false,
+        )
+        .await?;
+    let other_client = get_test_client().await?;
+    spawn(async move {
+        crate::sleep(Duration::from_millis(100)).await;
+        let _ = other_client.set("key1", 12).await;
+    });
+
+    let value = cache
+        .get_or_set_locked("key1", None, || async {
+            panic!("the value should not be loaded");
+        })
+        .await?;
+    assert_eq!(12, value);
+
+    // another process holds the lock and never stores the value
+    client
+        .set_with_options(
+            "key2:lock",
+            "other",
+            SetCondition::NX,
+            SetExpiration::Px(200),
+            false,
+        )
+        .await?;
+
+    let value = cache
+        .get_or_set_locked("key2", None, || async { Ok(13) })
+        .await?;
+    assert_eq!(13, value);
+    assert_eq!(Some(13), cache.get("key2").await?);
+    assert_eq!(0, client.exists("key2:lock").await?);
+
+    Ok(())
+}
@@ -1,16 +1,24 @@
 use std::time::Duration;
 
 use crate::{
-    client::{Client, IntoConfig},
+    client::{
+        Client, ConnectionEvent, ConnectionEvents, ConnectionState, DisconnectReason, IntoConfig,
+    },
     commands::{
-        BlockingCommands, ClientKillOptions, ConnectionCommands, FlushingMode, LMoveWhere,
-        ListCommands, ServerCommands, StringCommands,
+        BlockingCommands, ClientFlag, ClientKillOptions, ConnectionCommands, FlushingMode,
+        LMoveWhere, ListCommands, ServerCommands, StringCommands,
     },
     resp::cmd,
-    tests::{get_default_addr, get_test_client, log_try_init},
+    sleep, spawn,
+    tests::{get_default_addr, get_default_port, get_test_client, log_try_init},
     Error, Result,
 };
+use futures_util::{StreamExt, TryStreamExt};
 use serial_test::serial;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
@@ -39,6 +47,40 @@ async fn forget() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn send_stream() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let elements: Vec<String> = (0..10_000).map(|i| format!("element{i}")).collect();
+    client.rpush("mylist", elements.clone()).await?;
+
+    let stream = client.send_stream::<String>(cmd("LRANGE").arg("mylist").arg(0).arg(-1))?;
+    let streamed: Vec<String> = stream.try_collect().await?;
+    assert_eq!(elements, streamed);
+
+    // next commands are not affected
+    let len = client.llen("mylist").await?;
+    assert_eq!(10_000, len);
+
+    // empty reply
+    let stream = client.send_stream::<String>(cmd("LRANGE").arg("unknown").arg(0).arg(-1))?;
+    let streamed: Vec<String> = stream.try_collect().await?;
+    assert!(streamed.is_empty());
+
+    // error reply
+    client.set("key", "value").await?;
+    let mut stream = client.send_stream::<String>(cmd("LRANGE").arg("key").arg(0).arg(-1))?;
+    assert!(matches!(stream.next().await, Some(Err(Error::Redis(_)))));
+    assert!(stream.next().await.is_none());
+
+    client.close().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -68,6 +110,311 @@ async fn on_reconnect() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn client_reply_after_reconnect() -> Result<()> {
+    let client1 = get_test_client().await?;
+    let client2 = get_test_client().await?;
+
+    let client1_id = client1.client_id().await?;
+    client1.send_and_forget(cmd("CLIENT").arg("REPLY").arg("SKIP"), None)?;
+    client2
+        .client_kill(ClientKillOptions::default().id(client1_id))
+        .await?;
+
+    // the new connection replies to every command
+    client1.set("key", "value").await?;
+    let value: String = client1.get("key").await?;
+    assert_eq!("value", value);
+
+    client1.close().await?;
+    client2.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn on_connection_event() -> Result<()> {
+    #[derive(Default)]
+    struct Counts {
+        disconnects: AtomicUsize,
+        reconnects: AtomicUsize,
+    }
+
+    struct Handler(Arc<Counts>);
+
+    impl ConnectionEvents for Handler {
+        fn on_disconnect(&self, reason: DisconnectReason) {
+            assert_eq!(DisconnectReason::ConnectionClosed, reason);
+            self.0.disconnects.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_reconnect(&self) {
+            self.0.reconnects.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let client1 = Client::connect_lazy(get_default_addr())?;
+    let client2 = get_test_client().await?;
+
+    let mut receiver = client1.on_connection_event();
+    let counts = Arc::new(Counts::default());
+    client1.subscribe_connection_events(Handler(counts.clone()));
+
+    let client1_id = client1.client_id().await?;
+    assert!(matches!(
+        receiver.try_recv(),
+        Ok(ConnectionEvent::Connected)
+    ));
+
+    client2
+        .client_kill(ClientKillOptions::default().id(client1_id))
+        .await?;
+
+    // send command to be sure that the reconnection has been done
+    client1.set("key", "value").await?;
+
+    assert!(matches!(
+        receiver.try_recv(),
+        Ok(ConnectionEvent::Disconnected(
+            DisconnectReason::ConnectionClosed
+        ))
+    ));
+    assert!(matches!(
+        receiver.try_recv(),
+        Ok(ConnectionEvent::Reconnected)
+    ));
+    assert!(receiver.try_recv().is_err());
+
+    sleep(Duration::from_millis(10)).await;
+    assert_eq!(1, counts.disconnects.load(Ordering::SeqCst));
+    assert_eq!(1, counts.reconnects.load(Ordering::SeqCst));
+
+    client1.close().await?;
+    client2.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn select_after_reconnect() -> Result<()> {
+    let client1 = get_test_client().await?;
+    let client2 = get_test_client().await?;
+    client1.flushall(FlushingMode::Sync).await?;
+
+    assert_eq!(0, client1.db_index());
+
+    // invalid database: not tracked
+    assert!(client1.select(100_000).await.is_err());
+    assert_eq!(0, client1.db_index());
+
+    client1.select(1).await?;
+    assert_eq!(1, client1.db_index());
+    client1.set("key", "db1").await?;
+
+    let client1_id = client1.client_id().await?;
+    client2
+        .client_kill(ClientKillOptions::default().id(client1_id))
+        .await?;
+
+    // still on database 1 after the reconnection
+    let value: String = client1.get("key").await?;
+    assert_eq!("db1", value);
+    assert_eq!(1, client1.db_index());
+
+    client1.swapdb(0, 1).await?;
+    let value: Option<String> = client1.get("key").await?;
+    assert_eq!(None, value);
+
+    client1.reset().await?;
+    assert_eq!(0, client1.db_index());
+    let value: String = client1.get("key").await?;
+    assert_eq!("db1", value);
+
+    client1.close().await?;
+    client2.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn connect_lazy() -> Result<()> {
+    log_try_init();
+
+    // nothing listens on this port: no error until the first command
+    let client = Client::connect_lazy("127.0.0.1:1")?;
+    assert!(client.send(cmd("PING"), None).await.is_err());
+    client.close().await?;
+
+    let client = Client::connect_lazy(get_default_addr())?;
+    client.set("key", "value").await?;
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn connect_hostname() -> Result<()> {
+    log_try_init();
+
+    // localhost usually resolves to both ::1 and 127.0.0.1
+    let client = Client::connect(format!("localhost:{}", get_default_port())).await?;
+    client.set("key", "value").await?;
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+
+    // the hostname is resolved again on reconnection
+    let client2 = get_test_client().await?;
+    let client_id = client.client_id().await?;
+    client2
+        .client_kill(ClientKillOptions::default().id(client_id))
+        .await?;
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+    client.close().await?;
+    client2.close().await?;
+
+    assert!(Client::connect("unknown.invalid:6379").await.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn shutdown() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+    let client2 = client.clone();
+
+    // in-flight commands are not interrupted
+    let in_flight = crate::spawn(async move {
+        let result: Result<Option<String>> = client2
+            .blmove("list", "list2", LMoveWhere::Left, LMoveWhere::Left, 0.2)
+            .await;
+        (result, client2)
+    });
+    sleep(Duration::from_millis(50)).await;
+
+    client.clone().shutdown(Duration::from_secs(1)).await?;
+
+    let (result, client2) = in_flight.await.unwrap();
+    assert_eq!(None, result?);
+
+    // no command can be sent anymore
+    assert!(client2.get::<_, String>("key").await.is_err());
+    assert!(client.set("key", "value").await.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn health_check() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let latency = client.health_check().await?;
+    assert!(latency < Duration::from_secs(1));
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn health_check_interval() -> Result<()> {
+    log_try_init();
+
+    let mut config = get_default_addr().into_config()?;
+    config.health_check_interval = Duration::from_millis(100);
+    let client1 = Client::connect(config).await?;
+    let client2 = get_test_client().await?;
+
+    let mut receiver = client1.on_reconnect();
+
+    let client1_id = client1.client_id().await?;
+    client2
+        .client_kill(ClientKillOptions::default().id(client1_id))
+        .await?;
+
+    // the background health check reconnects without any user command
+    sleep(Duration::from_millis(500)).await;
+    let result = receiver.try_recv();
+    assert!(result.is_ok());
+
+    client1.close().await?;
+    client2.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn health_check_during_blocking_command() -> Result<()> {
+    log_try_init();
+
+    let mut config = get_default_addr().into_config()?;
+    config.health_check_interval = Duration::from_millis(100);
+    let client = Client::connect(config).await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    let mut receiver = client.on_reconnect();
+
+    // the unanswered health check must not be taken for a dead connection
+    let result: Option<(String, String)> = client.blpop("mylist", 1.0).await?;
+    assert_eq!(None, result);
+    assert!(receiver.try_recv().is_err());
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn health_check_during_transaction() -> Result<()> {
+    log_try_init();
+
+    let mut config = get_default_addr().into_config()?;
+    config.health_check_interval = Duration::from_millis(100);
+    let client = Client::connect(config).await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    let mut receiver = client.on_reconnect();
+
+    // no PING must be queued between MULTI and EXEC
+    client.send(cmd("MULTI"), None).await?;
+    client
+        .send(cmd("SET").arg("key").arg("value"), None)
+        .await?;
+    sleep(Duration::from_millis(300)).await;
+    client.send(cmd("INCR").arg("counter"), None).await?;
+    let (set, incr): (String, i64) = client.send(cmd("EXEC"), None).await?.to()?;
+    assert_eq!("OK", set);
+    assert_eq!(1, incr);
+    assert!(receiver.try_recv().is_err());
+
+    client.close().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -100,6 +447,64 @@ async fn command_timeout() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn max_in_flight_commands() -> Result<()> {
+    log_try_init();
+
+    let mut config = get_default_addr().into_config()?;
+    config.max_in_flight_commands = 1;
+    config.backpressure_fail_fast = true;
+
+    let client = Client::connect(config).await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    // the only in-flight slot is taken by a blocking command
+    let blocking_client = client.clone();
+    let blocking_task = spawn(async move {
+        let _result: Option<(String, Vec<String>)> = blocking_client
+            .blmpop(1., "key", LMoveWhere::Left, 1)
+            .await
+            .unwrap();
+    });
+    sleep(Duration::from_millis(100)).await;
+
+    let result = client.get::<_, Option<String>>("key").await;
+    assert!(matches!(result, Err(Error::Overloaded(_))));
+
+    // the slot is released once the blocking command is answered
+    blocking_task.await?;
+    let value: Option<String> = client.get("key").await?;
+    assert_eq!(None, value);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn high_priority() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    // all the commands are queued before the network handler gets a chance to send them
+    for i in 0..100 {
+        client.send_and_forget(cmd("RPUSH").arg("list").arg(i), None)?;
+    }
+    client.send_and_forget(cmd("RPUSH").arg("list").arg("first").high_priority(), None)?;
+    client.send_and_forget(cmd("RPUSH").arg("list").arg("second").high_priority(), None)?;
+
+    let values: Vec<String> = client.lrange("list", 0, 2).await?;
+    assert_eq!(vec!["first", "second", "0"], values);
+
+    client.close().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -121,6 +526,91 @@ async fn connection_name() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn lib_name() -> Result<()> {
+    log_try_init();
+
+    let client = get_test_client().await?;
+    let client_info = client.client_info().await?;
+    assert_eq!(
+        Some(&"rustis".to_owned()),
+        client_info.additional_arguments.get("lib-name")
+    );
+    assert_eq!(
+        Some(&env!("CARGO_PKG_VERSION").to_owned()),
+        client_info.additional_arguments.get("lib-ver")
+    );
+    client.close().await?;
+
+    let mut config = get_default_addr().into_config()?;
+    config.lib_name = None;
+    config.lib_version = None;
+
+    let client = Client::connect(config).await?;
+    let client_info = client.client_info().await?;
+    assert_eq!(None, client_info.additional_arguments.get("lib-name"));
+    assert_eq!(None, client_info.additional_arguments.get("lib-ver"));
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn no_evict_no_touch() -> Result<()> {
+    log_try_init();
+
+    let mut config = get_default_addr().into_config()?;
+    config.no_evict = true;
+    config.no_touch = true;
+
+    let client = Client::connect(config).await?;
+    let client_info = client.client_info().await?;
+    assert!(client_info.has_flag(ClientFlag::NoEvict));
+    assert!(client_info.has_flag(ClientFlag::NoTouch));
+    client.close().await?;
+
+    let client = get_test_client().await?;
+    let client_info = client.client_info().await?;
+    assert!(!client_info.has_flag(ClientFlag::NoEvict));
+    assert!(!client_info.has_flag(ClientFlag::NoTouch));
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn cached_client_id() -> Result<()> {
+    log_try_init();
+
+    let client1 = get_test_client().await?;
+    let client2 = get_test_client().await?;
+
+    let client1_id = client1.client_id().await?;
+    assert_eq!(Some(client1_id), client1.cached_client_id());
+
+    // the id changes with the connection
+    client2
+        .client_kill(ClientKillOptions::default().id(client1_id))
+        .await?;
+    let new_client1_id = client1.client_id().await?;
+    assert_ne!(client1_id, new_client1_id);
+    assert_eq!(Some(new_client1_id), client1.cached_client_id());
+
+    client1.close().await?;
+    client2.close().await?;
+
+    let client = Client::connect_lazy(get_default_addr())?;
+    assert_eq!(None, client.cached_client_id());
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -155,3 +645,31 @@ async fn mget_mset() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn connection_state() -> Result<()> {
+    let client = get_test_client().await?;
+    assert_eq!(ConnectionState::Normal, client.connection_state());
+
+    client.send(cmd("MULTI"), None).await?;
+    assert_eq!(ConnectionState::Transaction, client.connection_state());
+
+    let result = client.send(cmd("WATCH").arg("key"), None).await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    client.send(cmd("DISCARD"), None).await?;
+    assert_eq!(ConnectionState::Normal, client.connection_state());
+
+    let mut monitor_stream = client.monitor().await?;
+    assert_eq!(ConnectionState::Monitoring, client.connection_state());
+
+    let result = client.get::<_, Option<String>>("key").await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    monitor_stream.close().await?;
+    assert_eq!(ConnectionState::Normal, client.connection_state());
+
+    Ok(())
+}
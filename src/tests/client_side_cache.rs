@@ -0,0 +1,124 @@
+use crate::{
+    client::Client,
+    commands::{FlushingMode, ServerCommands, StringCommands},
+    sleep,
+    tests::{get_test_client, log_try_init},
+    utils::{ClientSideCache, ClientSideCacheOptions, TrackingMode},
+    Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+/// Wait for the invalidation task to empty the local cache
+async fn wait_invalidation(cache: &ClientSideCache) {
+    for _ in 0..100 {
+        if cache.is_empty() {
+            return;
+        }
+        sleep(Duration::from_millis(10)).await;
+    }
+    panic!("no invalidation received");
+}
+
+async fn create_cache(options: ClientSideCacheOptions) -> Result<(Client, ClientSideCache)> {
+    let client = get_test_client().await?;
+    let invalidation_client = get_test_client().await?;
+    let cache = ClientSideCache::new(&client, &invalidation_client, options).await?;
+    Ok((client, cache))
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn client_side_cache() -> Result<()> {
+    log_try_init();
+    let other_client = get_test_client().await?;
+    other_client.flushdb(FlushingMode::Sync).await?;
+    other_client.set("key", "value1").await?;
+
+    let (_client, cache) = create_cache(ClientSideCacheOptions::default()).await?;
+    assert_eq!(&TrackingMode::Default, cache.mode());
+
+    assert_eq!(Some("value1".to_owned()), cache.get("key").await?);
+    assert_eq!(None, cache.get("unknown").await?);
+    assert_eq!(2, cache.len());
+
+    other_client.set("key", "value2").await?;
+    other_client.set("unknown", "value").await?;
+    wait_invalidation(&cache).await;
+    assert_eq!(Some("value2".to_owned()), cache.get("key").await?);
+
+    assert_eq!(
+        Some("value".to_owned()),
+        cache.get_uncached("unknown").await?
+    );
+    assert_eq!(1, cache.len());
+    cache.clear();
+    assert!(cache.is_empty());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn client_side_cache_optin_optout() -> Result<()> {
+    log_try_init();
+    let other_client = get_test_client().await?;
+    other_client.flushdb(FlushingMode::Sync).await?;
+    other_client.set("key", "value1").await?;
+
+    // optin: keys read with get are tracked thanks to CLIENT CACHING YES
+    let (_client, cache) = create_cache(ClientSideCacheOptions::default().optin()).await?;
+    assert_eq!(Some("value1".to_owned()), cache.get("key").await?);
+    other_client.set("key", "value2").await?;
+    wait_invalidation(&cache).await;
+    assert_eq!(Some("value2".to_owned()), cache.get("key").await?);
+
+    // optout: keys read with get_uncached are not tracked
+    let (_client, cache) = create_cache(ClientSideCacheOptions::default().optout()).await?;
+    assert_eq!(Some("value2".to_owned()), cache.get_uncached("key").await?);
+    assert!(cache.is_empty());
+    assert_eq!(Some("value2".to_owned()), cache.get("key").await?);
+    other_client.set("key", "value3").await?;
+    wait_invalidation(&cache).await;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn client_side_cache_broadcast() -> Result<()> {
+    log_try_init();
+    let other_client = get_test_client().await?;
+    other_client.flushdb(FlushingMode::Sync).await?;
+    other_client.set("user:1", "value1").await?;
+    other_client.set("other", "value1").await?;
+
+    let (client, cache) = create_cache(
+        ClientSideCacheOptions::default()
+            .broadcast(["user:"])
+            .no_loop(),
+    )
+    .await?;
+
+    assert_eq!(Some("value1".to_owned()), cache.get("user:1").await?);
+    assert_eq!(Some("value1".to_owned()), cache.get("other").await?);
+
+    // keys outside of the prefixes are not cached
+    assert_eq!(1, cache.len());
+
+    other_client.set("other", "value2").await?;
+    assert_eq!(Some("value2".to_owned()), cache.get("other").await?);
+    other_client.set("user:1", "value2").await?;
+    wait_invalidation(&cache).await;
+    assert_eq!(Some("value2".to_owned()), cache.get("user:1").await?);
+
+    // no loop: writes of the caching client are not notified
+    client.set("user:1", "value3").await?;
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(Some("value2".to_owned()), cache.get("user:1").await?);
+
+    Ok(())
+}
@@ -4,14 +4,16 @@ use crate::{
         CallBuilder, ClusterCommands, ClusterNodeResult,
         ClusterSetSlotSubCommand::{Importing, Migrating, Node},
         ClusterShardResult, ConnectionCommands, FlushingMode, GenericCommands, HelloOptions,
-        MigrateOptions, ScriptingCommands, ServerCommands, StringCommands,
+        MigrateOptions, ScanOptions, ScriptingCommands, ServerCommands, StringCommands,
     },
     network::{ClusterConnection, Version},
+    resp::cmd,
     sleep, spawn,
     tests::{get_cluster_test_client, get_cluster_test_client_with_command_timeout},
+    utils::scan_stream,
     Error, RedisError, RedisErrorKind, Result,
 };
-use futures_util::try_join;
+use futures_util::{try_join, TryStreamExt};
 use serial_test::serial;
 use std::{collections::HashSet, future::IntoFuture, time::Duration};
 
@@ -463,6 +465,36 @@ async fn commands_to_different_nodes() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn scan_stream_all_masters() -> Result<()> {
+    // Assume test cluster has following slots split: [0 - 5460], [5461 - 10922], [10923 - 16383]
+    let client = get_cluster_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    client.set("key0", "0").await?; // cluster keyslot key0 = 13252
+    client.set("key1", "1").await?; // cluster keyslot key1 = 9189
+    client.set("key2", "2").await?; // cluster keyslot key2 = 4998
+
+    let keys: HashSet<String> = scan_stream(&client, ScanOptions::default().match_pattern("key*"))
+        .try_collect()
+        .await?;
+    assert_eq!(
+        HashSet::from(["key0".to_owned(), "key1".to_owned(), "key2".to_owned()]),
+        keys
+    );
+
+    // RANDOMKEY only sees the keys of the master serving the hash slot
+    let value: Option<String> = client
+        .send(cmd("RANDOMKEY").hash_slot(0), None)
+        .await?
+        .to()?;
+    assert_eq!(Some("key2".to_owned()), value);
+
+    Ok(())
+}
+
 /// test reconnection to replica when master is stopped
 /// master stop is not automated but must be done manually
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
@@ -0,0 +1,183 @@
+use crate::{
+    resp::{
+        cmd,
+        codec::{decode_value, encode_command, encode_value, Parser, RespCodec},
+        Value,
+    },
+    tests::log_try_init,
+    Error, RedisError, RedisErrorKind, Result,
+};
+use bytes::BytesMut;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use tokio_util::codec::{Decoder, Encoder};
+
+#[test]
+fn encode() -> Result<()> {
+    log_try_init();
+
+    let mut buf = BytesMut::new();
+    encode_command(&cmd("SET").arg("key").arg(12), &mut buf);
+    assert_eq!(b"*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$2\r\n12\r\n", &buf[..]);
+
+    let mut buf = BytesMut::new();
+    encode_value(
+        &Value::Array(vec![
+            Value::Integer(12),
+            Value::BulkString(b"value".to_vec()),
+        ]),
+        &mut buf,
+    )?;
+    assert_eq!(b"*2\r\n:12\r\n$5\r\nvalue\r\n", &buf[..]);
+
+    Ok(())
+}
+
+#[test]
+fn decode() -> Result<()> {
+    log_try_init();
+
+    assert_eq!(
+        Some((Value::Integer(12), 5)),
+        decode_value(b":12\r\n+OK\r\n")?
+    );
+    assert_eq!(None, decode_value(b"*2\r\n:12\r\n")?);
+    assert_eq!(
+        Some((
+            Value::Error(RedisError {
+                kind: RedisErrorKind::Err,
+                description: "error".to_owned()
+            }),
+            12
+        )),
+        decode_value(b"-ERR error\r\n")?
+    );
+    assert!(decode_value(b"?12\r\n").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn parser() -> Result<()> {
+    log_try_init();
+
+    let mut parser = Parser::new();
+    let input = b"%1\r\n$5\r\nfield\r\n$5\r\nvalue\r\n:12\r\n>2\r\n$7\r\nmessage\r\n$4\r\nbody\r\n";
+
+    // feed byte by byte
+    let mut values = Vec::new();
+    for byte in input {
+        parser.feed(&[*byte]);
+        while let Some(value) = parser.next_value()? {
+            values.push(value);
+        }
+    }
+
+    assert_eq!(3, values.len());
+    // maps are decoded as flat arrays of keys & values
+    assert_eq!(
+        Value::Array(vec![
+            Value::BulkString(b"field".to_vec()),
+            Value::BulkString(b"value".to_vec())
+        ]),
+        values[0]
+    );
+    assert_eq!(Value::Integer(12), values[1]);
+    assert!(matches!(values[2], Value::Push(_)));
+    assert_eq!(0, parser.buffered_len());
+
+    Ok(())
+}
+
+#[test]
+fn resp_codec() -> Result<()> {
+    log_try_init();
+
+    let mut codec = RespCodec;
+    let mut buf = BytesMut::new();
+
+    codec.encode(cmd("GET").arg("key"), &mut buf)?;
+    codec.encode(&Value::SimpleString("OK".to_owned()), &mut buf)?;
+    codec.encode(Value::Array(vec![Value::Integer(1), Value::Nil]), &mut buf)?;
+
+    // the encoded command is decoded as an array of bulk strings
+    assert_eq!(
+        Some(Value::Array(vec![
+            Value::BulkString(b"GET".to_vec()),
+            Value::BulkString(b"key".to_vec())
+        ])),
+        codec.decode(&mut buf)?
+    );
+    assert_eq!(
+        Some(Value::SimpleString("OK".to_owned())),
+        codec.decode(&mut buf)?
+    );
+
+    // incomplete value
+    let mut partial = buf.split_to(buf.len() - 2);
+    assert_eq!(None, codec.decode(&mut partial)?);
+    partial.unsplit(buf);
+    assert_eq!(
+        Some(Value::Array(vec![Value::Integer(1), Value::Nil])),
+        codec.decode(&mut partial)?
+    );
+    assert!(partial.is_empty());
+
+    Ok(())
+}
+
+/// Arbitrary inputs must never panic
+#[test]
+fn decode_random_bytes() {
+    log_try_init();
+
+    let alphabet = b"+-:$*%~,_#=>!(|0123456789\r\n";
+    // seeded to be reproducible
+    let mut rng = StdRng::seed_from_u64(0x5eed);
+
+    for _ in 0..10_000 {
+        let len = rng.gen_range(0..32);
+        let bytes: Vec<u8> = (0..len)
+            .map(|_| {
+                if rng.gen_bool(0.8) {
+                    alphabet[rng.gen_range(0..alphabet.len())]
+                } else {
+                    rng.gen()
+                }
+            })
+            .collect();
+
+        let _result = decode_value(&bytes);
+
+        let mut parser = Parser::new();
+        parser.feed(&bytes);
+        while let Ok(Some(_)) = parser.next_value() {}
+    }
+}
+
+#[test]
+fn decode_malformed_values() -> Result<()> {
+    log_try_init();
+
+    // verbatim string shorter than its format prefix
+    assert!(matches!(
+        decode_value(b"=2\r\nab\r\n"),
+        Err(Error::Protocol(_))
+    ));
+    let mut parser = Parser::new();
+    parser.feed(b"=2\r\nab\r\n");
+    assert!(matches!(parser.next_value(), Err(Error::Protocol(_))));
+    let mut buf = BytesMut::from(&b"=3\r\ntxt\r\n"[..]);
+    assert!(matches!(
+        RespCodec.decode(&mut buf),
+        Err(Error::Protocol(_))
+    ));
+
+    // negative verbatim length
+    assert!(decode_value(b"=-2\r\n").is_err());
+
+    // truncated aggregate
+    assert_eq!(None, decode_value(b"*2\r\n:1\r\n")?);
+    assert_eq!(None, decode_value(b"%1\r\n+key\r\n")?);
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::{
+    client::Client,
+    commands::StringCommands,
+    resp::{cmd, Command, Value},
+    tests::get_test_client,
+    Error, Result,
+};
+use serial_test::serial;
+
+fn args(command: &Command) -> Vec<Vec<u8>> {
+    (&command.args).into_iter().map(<[u8]>::to_vec).collect()
+}
+
+#[test]
+fn parse_inline() -> Result<()> {
+    let command = Command::parse_inline("set  foo bar")?;
+    assert_eq!("SET", command.name);
+    assert_eq!(vec![b"foo".to_vec(), b"bar".to_vec()], args(&command));
+
+    let command = Command::parse_inline(r#"SET "hello world" "a\"b\n\x41""#)?;
+    assert_eq!(
+        vec![b"hello world".to_vec(), b"a\"b\nA".to_vec()],
+        args(&command)
+    );
+
+    let command = Command::parse_inline(r#"SET 'it\'s' '\n' """#)?;
+    assert_eq!(
+        vec![b"it's".to_vec(), b"\\n".to_vec(), Vec::new()],
+        args(&command)
+    );
+
+    assert!(matches!(
+        Command::parse_inline("   "),
+        Err(Error::Client(_))
+    ));
+    assert!(matches!(
+        Command::parse_inline(r#"SET "foo"#),
+        Err(Error::Client(_))
+    ));
+    assert!(matches!(
+        Command::parse_inline(r#"SET "foo"bar"#),
+        Err(Error::Client(_))
+    ));
+
+    // the same name is interned once
+    let command2 = Command::parse_inline("Set foo bar")?;
+    assert!(std::ptr::eq(command.name, command2.name));
+
+    Ok(())
+}
+
+#[test]
+fn parse_cli() -> Result<()> {
+    let command = Command::parse_cli(["get", "foo"])?;
+    assert_eq!("GET", command.name);
+    assert_eq!(vec![b"foo".to_vec()], args(&command));
+
+    let parts: &[&str] = &["SET", "foo", "bar baz"];
+    let command = Command::parse_cli(parts)?;
+    assert_eq!(vec![b"foo".to_vec(), b"bar baz".to_vec()], args(&command));
+
+    assert!(matches!(
+        Command::parse_cli(Vec::<String>::new()),
+        Err(Error::Client(_))
+    ));
+
+    // names are interned once
+    let command1 = Command::parse_cli(["my.command"])?;
+    let command2 = Command::parse_cli(["MY.COMMAND"])?;
+    assert!(std::ptr::eq(command1.name, command2.name));
+
+    assert!(matches!(
+        Command::parse_cli(["x".repeat(65)]),
+        Err(Error::Client(_))
+    ));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn send_parsed_command() -> Result<()> {
+    let client: Client = get_test_client().await?;
+
+    client
+        .send(Command::parse_inline(r#"SET key "hello world""#)?, None)
+        .await?;
+    let value: String = client.get("key").await?;
+    assert_eq!("hello world", value);
+
+    let value: Value = client
+        .send(Command::parse_cli(["GET", "key"])?, None)
+        .await?
+        .to()?;
+    assert_eq!(Value::BulkString(b"hello world".to_vec()), value);
+
+    Ok(())
+}
+
+#[test]
+fn display() -> Result<()> {
+    let command = cmd("SET")
+        .arg("key")
+        .arg(b"hello \"world\"\n\x01".as_slice());
+    assert_eq!(
+        r#""SET" "key" "hello \"world\"\n\x01""#,
+        command.to_string()
+    );
+
+    // the output can be parsed back
+    let parsed = Command::parse_inline(&command.to_string())?;
+    assert_eq!(command.name, parsed.name);
+    assert_eq!(args(&command), args(&parsed));
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use crate::{
     commands::{GenericCommands, HashCommands, SetCommands},
+    resp::{
+        cmd_with_capacity, CommandArgs, KeyValueArgsCollection, KeysWithCount, SingleArg, ToArgs,
+    },
     tests::get_test_client,
     Result,
 };
@@ -87,3 +90,85 @@ async fn set_collection() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn keys_with_count() {
+    let args = CommandArgs::default()
+        .arg(KeysWithCount::new(["key1", "key2"]))
+        .build();
+    assert_eq!(
+        vec![b"2".to_vec(), b"key1".to_vec(), b"key2".to_vec()],
+        args.iter().cloned().collect::<Vec<_>>()
+    );
+
+    let args = CommandArgs::default()
+        .arg(KeysWithCount::new("key"))
+        .build();
+    assert_eq!(
+        vec![b"1".to_vec(), b"key".to_vec()],
+        args.iter().cloned().collect::<Vec<_>>()
+    );
+    assert_eq!(2, KeysWithCount::new("key").num_args());
+}
+
+#[test]
+fn with_capacity() {
+    let args = CommandArgs::with_capacity(1_000);
+    assert!(args.is_empty());
+
+    let command = cmd_with_capacity("MSET", 2_000)
+        .extend_from_iter((0..1_000).map(|i| (format!("key{i}"), format!("value{i}"))));
+    assert_eq!("MSET", command.name);
+    assert_eq!(2_000, command.args.len());
+    assert_eq!(b"key0", command.args[0].as_slice());
+    assert_eq!(b"value999", command.args[1_999].as_slice());
+}
+
+#[test]
+fn extend_from_iter() {
+    let args = CommandArgs::default()
+        .arg("SADD")
+        .extend_from_iter(["member1", "member2"])
+        .extend_from_iter(vec![1, 2])
+        .build();
+    assert_eq!(
+        vec![
+            b"SADD".to_vec(),
+            b"member1".to_vec(),
+            b"member2".to_vec(),
+            b"1".to_vec(),
+            b"2".to_vec()
+        ],
+        args.iter().cloned().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn borrowed_key_value_collection() {
+    fn flatten<K: SingleArg, V: SingleArg>(
+        items: impl KeyValueArgsCollection<K, V>,
+    ) -> Vec<Vec<u8>> {
+        CommandArgs::default().arg(items).build().to_vec()
+    }
+
+    let expected = vec![
+        b"field1".to_vec(),
+        b"value1".to_vec(),
+        b"field2".to_vec(),
+        b"value2".to_vec(),
+    ];
+
+    let items = vec![("field1", "value1"), ("field2", "value2")];
+    assert_eq!(expected, flatten(&items));
+
+    let items = BTreeMap::from([("field1", "value1"), ("field2", "value2")]);
+    assert_eq!(expected, flatten(&items));
+
+    let items = HashMap::from([("field1", "value1"), ("field2", "value2")]);
+    let mut args = flatten(&items);
+    assert_eq!(4, args.len());
+    args.sort();
+    let mut sorted_expected = expected;
+    sorted_expected.sort();
+    assert_eq!(sorted_expected, args);
+}
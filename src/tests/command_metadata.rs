@@ -0,0 +1,198 @@
+use crate::{
+    client::{
+        is_blocking_command, is_read_only_command, is_write_command, CommandMetadata, RetryPolicy,
+    },
+    resp::{cmd, Command},
+};
+
+fn keys(command: &Command) -> Option<Vec<&str>> {
+    CommandMetadata::keys(command).map(|keys| {
+        keys.into_iter()
+            .map(|key| std::str::from_utf8(key).unwrap())
+            .collect()
+    })
+}
+
+#[test]
+fn command_keys() {
+    assert_eq!(Some(vec!["key"]), keys(&cmd("GET").arg("key")));
+    assert_eq!(
+        Some(vec!["key1", "key2"]),
+        keys(
+            &cmd("MSET")
+                .arg("key1")
+                .arg("value1")
+                .arg("key2")
+                .arg("value2")
+        )
+    );
+    assert_eq!(
+        Some(vec!["key1", "key2"]),
+        keys(&cmd("BLPOP").arg("key1").arg("key2").arg(0))
+    );
+    assert_eq!(
+        Some(vec!["key1", "key2"]),
+        keys(
+            &cmd("EVAL")
+                .arg("return 0")
+                .arg(2)
+                .arg("key1")
+                .arg("key2")
+                .arg("arg")
+        )
+    );
+    // missing arguments
+    assert_eq!(Some(Vec::<&str>::new()), keys(&cmd("GET")));
+    assert_eq!(
+        Some(Vec::<&str>::new()),
+        keys(&cmd("OBJECT").arg("ENCODING"))
+    );
+    assert_eq!(Some(Vec::<&str>::new()), keys(&cmd("MSET")));
+    assert_eq!(
+        Some(vec!["stream1", "stream2"]),
+        keys(
+            &cmd("XREAD")
+                .arg("COUNT")
+                .arg(1)
+                .arg("STREAMS")
+                .arg("stream1")
+                .arg("stream2")
+                .arg("0-0")
+                .arg("0-0")
+        )
+    );
+    assert_eq!(
+        Some(vec!["Sicily", "result"]),
+        keys(
+            &cmd("GEORADIUS")
+                .arg("Sicily")
+                .arg(15)
+                .arg(37)
+                .arg(200)
+                .arg("km")
+                .arg("STOREDIST")
+                .arg("result")
+        )
+    );
+    assert_eq!(
+        Some(vec!["Sicily"]),
+        keys(
+            &cmd("GEORADIUSBYMEMBER")
+                .arg("Sicily")
+                .arg("STORE")
+                .arg(200)
+                .arg("km")
+        )
+    );
+    assert_eq!(Some(vec![]), keys(&cmd("DBSIZE")));
+    assert_eq!(None, keys(&cmd("UNKNOWN").arg("key")));
+}
+
+#[test]
+fn command_flags() {
+    let metadata = CommandMetadata::get("GET").unwrap();
+    assert!(metadata.read_only);
+    assert!(!metadata.blocking);
+
+    let metadata = CommandMetadata::get("BLPOP").unwrap();
+    assert!(!metadata.read_only);
+    assert!(metadata.blocking);
+
+    assert!(is_read_only_command(&cmd("XREAD")));
+    assert!(!is_blocking_command(
+        &cmd("XREAD").arg("STREAMS").arg("s").arg("$")
+    ));
+    assert!(is_blocking_command(
+        &cmd("XREAD")
+            .arg("BLOCK")
+            .arg(0)
+            .arg("STREAMS")
+            .arg("s")
+            .arg("$")
+    ));
+
+    let retry_policy = RetryPolicy::default();
+    assert!(retry_policy.is_retryable(&cmd("XREAD").arg("STREAMS").arg("s").arg("$")));
+    assert!(!retry_policy.is_retryable(
+        &cmd("XREAD")
+            .arg("BLOCK")
+            .arg(0)
+            .arg("STREAMS")
+            .arg("s")
+            .arg("$")
+    ));
+}
+
+#[test]
+fn write_commands() {
+    assert!(is_write_command(&cmd("SET").arg("key").arg("value")));
+    assert!(is_write_command(&cmd("EVAL").arg("return 1").arg(0)));
+    assert!(is_write_command(&cmd("FLUSHDB")));
+    assert!(!is_write_command(&cmd("GET").arg("key")));
+    assert!(!is_write_command(&cmd("PING")));
+    assert!(!is_write_command(&cmd("MULTI")));
+}
+
+/// Every command of **rustis** taking a key argument must be registered
+/// with its key specs, or its keys escape prefixing and routing
+#[test]
+fn key_bearing_commands_registered() {
+    const SOURCES: &[&str] = &[
+        include_str!("../commands/bitmap_commands.rs"),
+        include_str!("../commands/blocking_commands.rs"),
+        include_str!("../commands/generic_commands.rs"),
+        include_str!("../commands/geo_commands.rs"),
+        include_str!("../commands/hash_commands.rs"),
+        include_str!("../commands/hyper_log_log_commands.rs"),
+        include_str!("../commands/list_commands.rs"),
+        include_str!("../commands/scripting_commands.rs"),
+        include_str!("../commands/server_commands.rs"),
+        include_str!("../commands/set_commands.rs"),
+        include_str!("../commands/sorted_set_commands.rs"),
+        include_str!("../commands/stream_commands.rs"),
+        include_str!("../commands/string_commands.rs"),
+        include_str!("../commands/transaction_commands.rs"),
+    ];
+    const KEY_PARAMS: &[&str] = &[
+        "key:",
+        "key1:",
+        "keys:",
+        "source:",
+        "destination:",
+        "src:",
+        "dst:",
+    ];
+    // the key of MIGRATE moves between the 3rd argument and the `KEYS` keyword
+    const UNREGISTERED: &[&str] = &["MIGRATE"];
+
+    let mut missing = Vec::new();
+
+    for source in SOURCES {
+        for function in source.split("fn ").skip(1) {
+            let signature = function.split('{').next().unwrap_or_default();
+            if !KEY_PARAMS.iter().any(|param| signature.contains(param)) {
+                continue;
+            }
+
+            for command in function.split("cmd(\"").skip(1) {
+                let name = command.split('"').next().unwrap_or_default();
+                if UNREGISTERED.contains(&name) {
+                    continue;
+                }
+
+                let registered = matches!(
+                    CommandMetadata::get(name),
+                    Some(metadata) if !metadata.key_specs.is_empty()
+                );
+                if !registered {
+                    missing.push(name);
+                }
+            }
+        }
+    }
+
+    assert!(
+        missing.is_empty(),
+        "commands without key specs: {missing:?}"
+    );
+}
@@ -0,0 +1,72 @@
+use crate::{
+    client::BatchPreparedCommand,
+    commands::{FlushingMode, ServerCommands, StringCommands},
+    resp::CommandTemplate,
+    tests::get_test_client,
+    Error, Result,
+};
+use serial_test::serial;
+
+#[test]
+fn bind() -> Result<()> {
+    let template = CommandTemplate::new("SET")
+        .placeholder()
+        .placeholder()
+        .arg(("EX", 60));
+    assert_eq!(2, template.num_placeholders());
+
+    let command = template.bind(("key", 12))?;
+    assert_eq!("SET", command.name);
+    let args: Vec<&[u8]> = command.args.into_iter().collect();
+    assert_eq!(vec![&b"key"[..], &b"12"[..], &b"EX"[..], &b"60"[..]], args);
+
+    // the template can be bound again
+    let command = template.bind(["other", "value"])?;
+    let args: Vec<&[u8]> = command.args.into_iter().collect();
+    assert_eq!(
+        vec![&b"other"[..], &b"value"[..], &b"EX"[..], &b"60"[..]],
+        args
+    );
+
+    assert!(matches!(template.bind("key"), Err(Error::Client(_))));
+    assert!(matches!(
+        template.bind(("key", "value", "extra")),
+        Err(Error::Client(_))
+    ));
+
+    let template = CommandTemplate::new("MGET").placeholders(3);
+    let command = template.bind(["key1", "key2", "key3"])?;
+    assert_eq!(3, command.args.len());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn prepare() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let set = CommandTemplate::new("SET").placeholders(2);
+    let get = CommandTemplate::new("GET").placeholder();
+
+    for i in 0..3 {
+        let _: () = set.prepare(&client, (format!("key{i}"), i))?.await?;
+    }
+
+    let value: i32 = get.prepare(&client, "key2")?.await?;
+    assert_eq!(2, value);
+
+    let mut pipeline = client.create_pipeline();
+    set.prepare::<_, (), _>(&mut pipeline, ("key", "value"))?
+        .forget();
+    get.prepare::<_, String, _>(&mut pipeline, "key")?.queue();
+    let value: String = pipeline.execute().await?;
+    assert_eq!("value", value);
+
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+
+    Ok(())
+}
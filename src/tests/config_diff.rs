@@ -0,0 +1,72 @@
+use crate::{
+    commands::ServerCommands,
+    tests::get_test_client,
+    utils::{config_diff, parse_redis_conf, ConfigDifference},
+    Result,
+};
+use serial_test::serial;
+use std::collections::HashMap;
+
+#[test]
+fn redis_conf() {
+    let parameters = parse_redis_conf(
+        r#"
+# memory
+maxmemory 1gb
+maxmemory-policy allkeys-lru
+
+save 3600 1
+save 300 100
+appendonly yes
+dir "/var/lib/redis"
+"#,
+    );
+
+    assert_eq!(
+        HashMap::from([
+            ("maxmemory".to_owned(), "1gb".to_owned()),
+            ("maxmemory-policy".to_owned(), "allkeys-lru".to_owned()),
+            ("save".to_owned(), "3600 1 300 100".to_owned()),
+            ("appendonly".to_owned(), "yes".to_owned()),
+            ("dir".to_owned(), "/var/lib/redis".to_owned()),
+        ]),
+        parameters
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn config_diff_normalizes_values() -> Result<()> {
+    let client = get_test_client().await?;
+    let maxmemory: HashMap<String, String> = client.config_get("maxmemory").await?;
+    client.config_set(("maxmemory", "100mb")).await?;
+    client.config_set(("lazyfree-lazy-user-del", "no")).await?;
+
+    let expected = HashMap::from([
+        ("MAXMEMORY".to_owned(), "100mb".to_owned()),
+        ("lazyfree-lazy-user-del".to_owned(), "false".to_owned()),
+        ("maxmemory-samples".to_owned(), "1234".to_owned()),
+        ("unknown-parameter".to_owned(), "value".to_owned()),
+    ]);
+    let differences = config_diff(&client, &expected).await?;
+
+    client
+        .config_set(("maxmemory", maxmemory["maxmemory"].as_str()))
+        .await?;
+
+    assert_eq!(2, differences.len());
+    assert_eq!("maxmemory-samples", differences[0].parameter);
+    assert_eq!(
+        ConfigDifference {
+            parameter: "unknown-parameter".to_owned(),
+            expected: "value".to_owned(),
+            actual: None,
+        },
+        differences[1]
+    );
+
+    client.close().await?;
+
+    Ok(())
+}
@@ -1,10 +1,10 @@
 use crate::{
     client::{BatchPreparedCommand, Client, ClientPreparedCommand},
     commands::{
-        ClientCachingMode, ClientKillOptions, ClientListOptions, ClientPauseMode, ClientReplyMode,
-        ClientTrackingOptions, ClientTrackingStatus, ClientUnblockMode, ConnectionCommands,
-        FlushingMode, GenericCommands, HelloOptions, PingOptions, ServerCommands,
-        StringCommands,
+        ClientCachingMode, ClientFlag, ClientInfo, ClientInfoAttribute, ClientKillOptions,
+        ClientListOptions, ClientPauseMode, ClientReplyMode, ClientTrackingOptions,
+        ClientTrackingStatus, ClientType, ClientUnblockMode, ConnectionCommands, FlushingMode,
+        GenericCommands, HelloOptions, PingOptions, ServerCommands, StringCommands,
     },
     network::spawn,
     sleep,
@@ -13,6 +13,7 @@ use crate::{
 };
 use futures_util::StreamExt;
 use serial_test::serial;
+use std::time::Duration;
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
@@ -100,9 +101,19 @@ async fn client_kill() -> Result<()> {
     let client2 = get_test_client().await?;
 
     let client_id = client1.client_id().await?;
-    client2
+    let killed = client2
         .client_kill(ClientKillOptions::default().id(client_id))
         .await?;
+    assert_eq!(1, killed);
+
+    let killed = client2
+        .client_kill(
+            ClientKillOptions::default()
+                .client_type(ClientType::PubSub)
+                .skip_me(true),
+        )
+        .await?;
+    assert_eq!(0, killed);
 
     Ok(())
 }
@@ -115,9 +126,44 @@ async fn client_list() -> Result<()> {
 
     let current_client_id = client.client_id().await?;
 
-    let _result = client
+    let result = client
         .client_list(ClientListOptions::default().client_ids(current_client_id))
         .await?;
+    assert_eq!(1, result.client_infos.len());
+    let client_info = &result.client_infos[0];
+    assert_eq!(current_client_id, client_info.id);
+    assert!(client_info.has_flag(ClientFlag::None));
+    assert_eq!(Some("client|list"), client_info.last_command());
+
+    let result = client
+        .client_list(ClientListOptions::default().client_type(ClientType::PubSub))
+        .await?;
+    assert!(result.client_infos.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn client_info_from_line() -> Result<()> {
+    log_try_init();
+
+    let client_info = ClientInfo::from_line(
+        "id=3 addr=127.0.0.1:6379 laddr=127.0.0.1:6380 fd=8 name= age=65 idle=12 flags=Pt db=0 sub=1 psub=0 ssub=0 multi=-1 qbuf=0 qbuf-free=0 argv-mem=0 multi-mem=0 obl=0 oll=0 omem=0 tot-mem=1024 events=r cmd=subscribe user=default redir=-1 resp=3 lib-name=rustis\n",
+    )?;
+
+    assert_eq!(3, client_info.id);
+    assert_eq!(Duration::from_secs(65), client_info.connection_age());
+    assert_eq!(Duration::from_secs(12), client_info.idle_time());
+    assert_eq!(
+        vec![ClientFlag::PubSub, ClientFlag::Tracking],
+        client_info.client_flags()
+    );
+    assert!(!client_info.has_flag(ClientFlag::Multi));
+    assert_eq!(Some("subscribe"), client_info.last_command());
+    assert_eq!(
+        Some(&"rustis".to_owned()),
+        client_info.additional_arguments.get("lib-name")
+    );
 
     Ok(())
 }
@@ -134,6 +180,49 @@ async fn client_no_evict() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn client_no_touch() -> Result<()> {
+    let client = get_test_client().await?;
+
+    client.client_no_touch(true).await?;
+    let client_info = client.client_info().await?;
+    assert!(client_info.has_flag(ClientFlag::NoTouch));
+
+    client.client_no_touch(false).await?;
+    let client_info = client.client_info().await?;
+    assert!(!client_info.has_flag(ClientFlag::NoTouch));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn client_setinfo() -> Result<()> {
+    let client = get_test_client().await?;
+
+    client
+        .client_setinfo(ClientInfoAttribute::LibName, "rustis(test)")
+        .await?;
+    client
+        .client_setinfo(ClientInfoAttribute::LibVer, "1.2.3")
+        .await?;
+
+    let client_info = client.client_info().await?;
+    assert_eq!(
+        Some(&"rustis(test)".to_owned()),
+        client_info.additional_arguments.get("lib-name")
+    );
+    assert_eq!(
+        Some(&"1.2.3".to_owned()),
+        client_info.additional_arguments.get("lib-ver")
+    );
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -175,6 +264,17 @@ async fn client_reply() -> Result<()> {
     assert_eq!("value2", values[1]);
     assert_eq!("value3", values[2]);
 
+    // skip
+    client.client_reply(ClientReplyMode::Skip).forget()?;
+    client.set("key", "value4").forget()?;
+    let value: String = client.get("key").await?;
+    assert_eq!("value4", value);
+
+    // skip_reply
+    client.set("key", "value5").skip_reply()?;
+    let value: String = client.get("key").await?;
+    assert_eq!("value5", value);
+
     Ok(())
 }
 
@@ -0,0 +1,67 @@
+use crate::{
+    client::{Client, IntoConfig},
+    commands::{FlushingMode, GenericCommands, ServerCommands, StringCommands},
+    tests::{get_default_addr, get_test_client},
+    utils::{copy_key_between, CopyKeyOptions},
+    Error, Result,
+};
+use serial_test::serial;
+
+async fn get_db1_client() -> Result<Client> {
+    let mut config = get_default_addr().into_config()?;
+    config.database = 1;
+    Client::connect(config).await
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn copy_key() -> Result<()> {
+    let src_client = get_test_client().await?;
+    let dst_client = get_db1_client().await?;
+    src_client.flushall(FlushingMode::Sync).await?;
+
+    let copied =
+        copy_key_between(&src_client, &dst_client, "key", CopyKeyOptions::default()).await?;
+    assert!(!copied);
+
+    src_client.set("key", "value").await?;
+    src_client
+        .pexpire("key", 100_000, Default::default())
+        .await?;
+
+    let copied =
+        copy_key_between(&src_client, &dst_client, "key", CopyKeyOptions::default()).await?;
+    assert!(copied);
+    let value: String = dst_client.get("key").await?;
+    assert_eq!("value", value);
+    let ttl = dst_client.pttl("key").await?;
+    assert!(ttl > 0 && ttl <= 100_000);
+
+    // key already exists
+    let result = copy_key_between(&src_client, &dst_client, "key", CopyKeyOptions::default()).await;
+    assert!(matches!(result, Err(Error::Redis(_))));
+
+    src_client.set("key", "value2").await?;
+    let copied = copy_key_between(
+        &src_client,
+        &dst_client,
+        "key",
+        CopyKeyOptions::default()
+            .replace()
+            .abs_ttl()
+            .delete_source(),
+    )
+    .await?;
+    assert!(copied);
+    let value: String = dst_client.get("key").await?;
+    assert_eq!("value2", value);
+    // `set` has removed the expiration
+    assert_eq!(-1, dst_client.pttl("key").await?);
+    assert_eq!(0, src_client.exists("key").await?);
+
+    src_client.close().await?;
+    dst_client.close().await?;
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::{
+    commands::{FlushingMode, GenericCommands, ServerCommands},
+    tests::get_test_client,
+    utils::{Counters, Granularity},
+    Result,
+};
+use serial_test::serial;
+use std::time::{Duration, UNIX_EPOCH};
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn counters() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let counters = Counters::new(&client, "stats");
+    let now = std::time::SystemTime::now();
+    let now_secs = now.duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let minute = now_secs - now_secs % 60;
+    let previous_minute = now - Duration::from_secs(60);
+
+    counters.incr_at("views", 2, now).await?;
+    counters.incr_at("views", 3, now).await?;
+    counters.incr_at("views", 1, previous_minute).await?;
+
+    let key = format!("stats:views:minute:{minute}");
+    let ttl = client.ttl(&key).await?;
+    assert!(ttl > 2 * 3600 && ttl <= 2 * 3600 + 60);
+
+    let values = counters
+        .range(
+            "views",
+            Granularity::Minute,
+            now - Duration::from_secs(120),
+            now,
+        )
+        .await?;
+    assert_eq!(3, values.len());
+    assert_eq!(UNIX_EPOCH + Duration::from_secs(minute), values[2].0);
+    assert_eq!(5, values[2].1);
+    assert_eq!(1, values[1].1);
+    assert_eq!(0, values[0].1);
+
+    let total = counters
+        .total("views", Granularity::Day, previous_minute, now)
+        .await?;
+    assert_eq!(6, total);
+    let total = counters
+        .total("views", Granularity::Minute, previous_minute, now)
+        .await?;
+    assert_eq!(6, total);
+
+    // more buckets than a single MGET
+    let values = counters
+        .range(
+            "views",
+            Granularity::Minute,
+            now - Duration::from_secs(60 * 1500),
+            now,
+        )
+        .await?;
+    assert_eq!(1501, values.len());
+    assert_eq!(6, values.iter().map(|(_, value)| value).sum::<i64>());
+
+    assert!(counters
+        .range(
+            "views",
+            Granularity::Hour,
+            now,
+            previous_minute - Duration::from_secs(3600)
+        )
+        .await
+        .is_err());
+
+    let counters = Counters::new(&client, "other").granularities(&[Granularity::Hour]);
+    counters.incr("views", 1).await?;
+    let keys: Vec<String> = client.keys("other:*").await?;
+    assert_eq!(1, keys.len());
+
+    client.close().await?;
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use crate::{
-    commands::{ConnectionCommands, DebugCommands, PingOptions},
+    commands::{
+        ConnectionCommands, DebugCommands, FlushingMode, PingOptions, ServerCommands,
+        StringCommands,
+    },
     tests::{get_cluster_test_client_with_command_timeout, get_test_client},
     Error, Result,
 };
@@ -40,3 +43,25 @@ async fn cluster_server_panic() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn debug_object_and_digest() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client.set("key", "value").await?;
+
+    let object = client.debug_object("key").await?;
+    assert!(object.contains("encoding:embstr"));
+
+    let digest = client.debug_digest().await?;
+    assert_eq!(40, digest.len());
+
+    let digests: Vec<String> = client.debug_digest_value(["key", "unknown"]).await?;
+    assert_eq!(2, digests.len());
+    assert_ne!(digests[0], digests[1]);
+
+    Ok(())
+}
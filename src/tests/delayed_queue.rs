@@ -0,0 +1,86 @@
+use crate::{
+    commands::{FlushingMode, ServerCommands, SortedSetCommands},
+    sleep,
+    tests::get_test_client,
+    utils::DelayedQueue,
+    Result,
+};
+use futures_util::StreamExt;
+use serial_test::serial;
+use std::time::{Duration, SystemTime};
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn delayed_queue() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let queue = DelayedQueue::new(&client, "jobs");
+    let id1 = queue.schedule("job1", SystemTime::now()).await?;
+    let id2 = queue
+        .schedule_in("job2", Duration::from_millis(200))
+        .await?;
+    assert_ne!(id1, id2);
+    assert_eq!(2, client.zcard("jobs").await?);
+
+    let jobs = queue.claim().await?;
+    assert_eq!(1, jobs.len());
+    assert_eq!(id1, jobs[0].id);
+    assert_eq!("job1", jobs[0].payload);
+    assert_eq!(1, jobs[0].attempts);
+
+    // claimed jobs are not delivered again before the visibility timeout
+    assert!(queue.claim().await?.is_empty());
+
+    assert!(queue.ack(&id1).await?);
+    assert!(!queue.ack(&id1).await?);
+
+    let mut jobs = Box::pin(
+        queue
+            .clone()
+            .poll_interval(Duration::from_millis(50))
+            .into_stream(),
+    );
+    let job = jobs.next().await.unwrap()?;
+    assert_eq!(id2, job.id);
+    assert_eq!("job2", job.payload);
+    job.ack().await?;
+
+    assert_eq!(0, client.zcard("jobs").await?);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn delayed_queue_redelivery() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let queue = DelayedQueue::new(&client, "jobs").visibility_timeout(Duration::from_millis(100));
+    let id = queue.schedule("job", SystemTime::now()).await?;
+
+    let jobs = queue.claim().await?;
+    assert_eq!(1, jobs.len());
+
+    // not acknowledged within the visibility timeout
+    sleep(Duration::from_millis(200)).await;
+    let jobs = queue.claim().await?;
+    assert_eq!(1, jobs.len());
+    assert_eq!(id, jobs[0].id);
+    assert_eq!(2, jobs[0].attempts);
+
+    // explicit retry
+    let job = jobs.into_iter().next().unwrap();
+    job.retry(Duration::ZERO).await?;
+    let jobs = queue.claim().await?;
+    assert_eq!(3, jobs[0].attempts);
+
+    client.close().await?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::{
+    commands::{FlushingMode, GenericCommands, ServerCommands, StringCommands},
+    tests::get_test_client,
+    utils::{delete_matching, DeleteMatchingOptions},
+    Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn delete_matching_in_batches() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let items: Vec<(String, usize)> = (0..1050).map(|i| (format!("session:{i}"), i)).collect();
+    client.mset(items).await?;
+    client.set("other", "value").await?;
+
+    let deleted = delete_matching(
+        &client,
+        "session:*",
+        DeleteMatchingOptions::default()
+            .batch_size(100)
+            .pause(Duration::from_millis(1)),
+    )
+    .await?;
+    assert_eq!(1050, deleted);
+    assert_eq!(1, client.dbsize().await?);
+    assert_eq!(1, client.exists("other").await?);
+
+    let deleted = delete_matching(&client, "session:*", DeleteMatchingOptions::default()).await?;
+    assert_eq!(0, deleted);
+
+    Ok(())
+}
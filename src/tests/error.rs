@@ -1,4 +1,9 @@
-use crate::{resp::cmd, tests::get_test_client, Error, RedisError, RedisErrorKind, Result};
+use crate::{
+    resp::{cmd, RespDeserializer, Value},
+    tests::get_test_client,
+    Error, RedisError, RedisErrorKind, Result,
+};
+use serde::Deserialize;
 use serial_test::serial;
 use std::str::FromStr;
 
@@ -35,6 +40,130 @@ fn moved_error() {
     ));
 }
 
+#[test]
+fn moved_error_from_server() {
+    let mut deserializer = RespDeserializer::new(b"-MOVED 3999 127.0.0.1:6381\r\n");
+    let result = <()>::deserialize(&mut deserializer);
+    assert!(matches!(
+        result,
+        Err(Error::Moved { hash_slot: 3999, address: (host, 6381) }) if host == "127.0.0.1"
+    ));
+
+    let mut deserializer = RespDeserializer::new(b"-ASK 3999 127.0.0.1:6381\r\n");
+    let result = String::deserialize(&mut deserializer);
+    assert!(matches!(
+        result,
+        Err(Error::Ask { hash_slot: 3999, address: (host, 6381) }) if host == "127.0.0.1"
+    ));
+
+    let value = Value::Error(RedisError::from_str("MOVED 3999 127.0.0.1:6381").unwrap());
+    let result: Result<String> = value.into();
+    assert!(matches!(
+        result,
+        Err(Error::Moved {
+            hash_slot: 3999,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn loading_error() {
+    let raw_error = "LOADING Redis is loading the dataset in memory";
+    let error = RedisError::from_str(raw_error);
+    assert!(matches!(
+        error,
+        Ok(RedisError {
+            kind: RedisErrorKind::Loading,
+            description
+        }) if description == "Redis is loading the dataset in memory"
+    ));
+}
+
+#[test]
+fn error_kinds() -> Result<()> {
+    let errors = [
+        ("BUSY Redis is busy running a script", RedisErrorKind::Busy),
+        (
+            "BUSYKEY Target key name already exists.",
+            RedisErrorKind::BusyKey,
+        ),
+        ("EXECABORT Transaction discarded", RedisErrorKind::ExecAbort),
+        ("NOAUTH Authentication required.", RedisErrorKind::NoAuth),
+        (
+            "NOREPLICAS Not enough good replicas to write.",
+            RedisErrorKind::NoReplicas,
+        ),
+        ("NOSCRIPT No matching script.", RedisErrorKind::NoScript),
+        (
+            "OOM command not allowed when used memory > 'maxmemory'.",
+            RedisErrorKind::OutOfMemory,
+        ),
+        (
+            "READONLY You can't write against a read only replica.",
+            RedisErrorKind::Readonly,
+        ),
+        (
+            "WRONGTYPE Operation against a key holding the wrong kind of value",
+            RedisErrorKind::WrongType,
+        ),
+    ];
+
+    for (raw_error, kind) in errors {
+        let error = Error::Redis(RedisError::from_str(raw_error)?);
+        assert_eq!(Some(kind), error.redis_kind());
+    }
+
+    assert_eq!(None, Error::Client("error".to_owned()).redis_kind());
+
+    let error = Error::from(RedisError::from_str("MOVED 3999 127.0.0.1:6381")?);
+    assert!(matches!(error, Error::Moved { .. }));
+    assert_eq!(
+        Some(RedisErrorKind::Moved {
+            hash_slot: 3999,
+            address: ("127.0.0.1".to_owned(), 6381)
+        }),
+        error.redis_kind()
+    );
+
+    let error = Error::from(RedisError::from_str("ASK 3999 127.0.0.1:6381")?);
+    assert!(matches!(error, Error::Ask { .. }));
+    assert_eq!(
+        Some(RedisErrorKind::Ask {
+            hash_slot: 3999,
+            address: ("127.0.0.1".to_owned(), 6381)
+        }),
+        error.redis_kind()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn out_of_memory() -> Result<()> {
+    let error = Error::Redis(RedisError::from_str(
+        "OOM command not allowed when used memory > 'maxmemory'.",
+    )?);
+    assert!(error.is_out_of_memory());
+
+    let error = Error::Redis(RedisError::from_str("ERR unknown command")?);
+    assert!(!error.is_out_of_memory());
+    assert!(!Error::Client("OOM".to_owned()).is_out_of_memory());
+
+    Ok(())
+}
+
+#[test]
+fn protocol_error() {
+    let mut deserializer = RespDeserializer::new(b":abc\r\n");
+    let result = i64::deserialize(&mut deserializer);
+    assert!(matches!(result, Err(Error::Protocol(_))));
+
+    let mut deserializer = RespDeserializer::new(b"?abc\r\n");
+    let result = Value::deserialize(&mut deserializer);
+    assert!(matches!(result, Err(Error::Protocol(_))));
+}
+
 #[test]
 fn ask_error() {
     let raw_error = "ASK 3999 127.0.0.1:6381";
@@ -0,0 +1,135 @@
+use crate::{
+    commands::{
+        FlushingMode, HashCommands, ListCommands, ServerCommands, SortedSetCommands, StringCommands,
+    },
+    resp::Value,
+    tests::get_test_client,
+    utils::{
+        encode_string, export_keys, export_keys_async, export_stream, json_value, ExportFormat,
+        ExportOptions, ExportedKey,
+    },
+    Result,
+};
+use futures_util::TryStreamExt;
+use serde_json::json;
+use serial_test::serial;
+use std::time::Duration;
+
+#[test]
+fn exported_key_lines() {
+    let key = ExportedKey {
+        key: b"user:1".to_vec(),
+        key_type: "hash".to_owned(),
+        ttl: Some(Duration::from_millis(1500)),
+        value: json!({"name": "Doe, \"John\""}),
+    };
+
+    assert_eq!(
+        r#"{"key":"user:1","ttl":1500,"type":"hash","value":{"name":"Doe, \"John\""}}"#,
+        key.to_json_line()
+    );
+    assert_eq!(
+        r#"user:1,hash,1500,"{""name"":""Doe, \""John\""""}""#,
+        key.to_csv_record()
+    );
+
+    let key = ExportedKey {
+        key: b"greeting".to_vec(),
+        key_type: "string".to_owned(),
+        ttl: None,
+        value: json!("hello"),
+    };
+    assert_eq!("greeting,string,,hello", key.to_csv_record());
+}
+
+#[test]
+fn binary_strings() {
+    assert_eq!("hello", encode_string(b"hello"));
+    assert_eq!("base64:/wA=", encode_string(b"\xff\x00"));
+    // strings which look encoded are encoded too
+    assert_eq!("base64:YmFzZTY0OmFi", encode_string(b"base64:ab"));
+
+    let key = ExportedKey {
+        key: b"bin:\xfe".to_vec(),
+        key_type: "string".to_owned(),
+        ttl: None,
+        value: json_value("string", Value::BulkString(b"\xff\x00".to_vec())),
+    };
+    assert_eq!(
+        r#"{"key":"base64:YmluOv4=","ttl":null,"type":"string","value":"base64:/wA="}"#,
+        key.to_json_line()
+    );
+
+    assert_eq!(
+        json!({"base64:/w==": "base64:/g=="}),
+        json_value(
+            "hash",
+            Value::Array(vec![
+                Value::BulkString(b"\xff".to_vec()),
+                Value::BulkString(b"\xfe".to_vec())
+            ])
+        )
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn export() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    client.set("export:string", "value").await?;
+    client.rpush("export:list", ["a", "b"]).await?;
+    client.hset("export:hash", [("field", "value")]).await?;
+    client
+        .zadd("export:zset", [(1.5, "member")], Default::default())
+        .await?;
+    client.set("other", "value").await?;
+
+    let mut keys: Vec<ExportedKey> =
+        export_stream(&client, ExportOptions::default().match_pattern("export:*"))
+            .try_collect()
+            .await?;
+    keys.sort_by(|k1, k2| k1.key.cmp(&k2.key));
+
+    assert_eq!(4, keys.len());
+    assert_eq!(b"export:hash", keys[0].key.as_slice());
+    assert_eq!(json!({"field": "value"}), keys[0].value);
+    assert_eq!(b"export:list", keys[1].key.as_slice());
+    assert_eq!(json!(["a", "b"]), keys[1].value);
+    assert_eq!(b"export:string", keys[2].key.as_slice());
+    assert_eq!(json!("value"), keys[2].value);
+    assert_eq!(None, keys[2].ttl);
+    assert_eq!(b"export:zset", keys[3].key.as_slice());
+    assert_eq!(json!([["member", 1.5]]), keys[3].value);
+
+    let mut csv = Vec::new();
+    let num_keys = export_keys(
+        &client,
+        ExportOptions::default()
+            .key_type("string")
+            .format(ExportFormat::Csv),
+        &mut csv,
+    )
+    .await?;
+    assert_eq!(2, num_keys);
+    let csv = String::from_utf8(csv).unwrap();
+    assert!(csv.starts_with("key,type,ttl,value\n"));
+    assert!(csv.contains("export:string,string,,value\n"));
+
+    let mut json_lines = Vec::new();
+    let num_keys = export_keys_async(
+        &client,
+        ExportOptions::default().match_pattern("other"),
+        &mut json_lines,
+    )
+    .await?;
+    assert_eq!(1, num_keys);
+    assert_eq!(
+        "{\"key\":\"other\",\"ttl\":null,\"type\":\"string\",\"value\":\"value\"}\n",
+        String::from_utf8(json_lines).unwrap()
+    );
+
+    Ok(())
+}
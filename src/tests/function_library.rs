@@ -0,0 +1,65 @@
+use crate::{
+    commands::{CallBuilder, FlushingMode, ScriptingCommands, StringCommands},
+    tests::get_test_client,
+    utils::FunctionLibrary,
+    Result,
+};
+use serial_test::serial;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn function_library() -> Result<()> {
+    let client = get_test_client().await?;
+    client.function_flush(FlushingMode::Sync).await?;
+
+    let library = FunctionLibrary::new("mylib")
+        .function(
+            "set_and_get",
+            "function(keys, args) redis.call('SET', keys[1], args[1]) return redis.call('GET', keys[1]) end",
+        )
+        .read_only_function(
+            "get",
+            "function(keys, args) return redis.call('GET', keys[1]) end",
+        );
+
+    assert!(library.register(&client).await?);
+    // already up to date
+    assert!(!library.register(&client).await?);
+
+    let value: String = library.call(&client, "set_and_get", "key", "value").await?;
+    assert_eq!("value", value);
+    let value: String = library
+        .call(&client, "get", "key", Vec::<String>::new())
+        .await?;
+    assert_eq!("value", value);
+
+    // FCALL_RO rejects functions without the no-writes flag
+    let result: Result<String> = client
+        .fcall_readonly(
+            CallBuilder::function("set_and_get")
+                .keys("key")
+                .args("value"),
+        )
+        .await;
+    assert!(result.is_err());
+
+    // a new version of the library replaces the loaded one
+    let library = library.read_only_function(
+        "len",
+        "function(keys, args) return redis.call('STRLEN', keys[1]) end",
+    );
+    assert!(library.register(&client).await?);
+    let len: usize = library
+        .call(&client, "len", "key", Vec::<String>::new())
+        .await?;
+    assert_eq!(5, len);
+
+    client.set("key", "other").await?;
+    let value: String = library
+        .call(&client, "get", "key", Vec::<String>::new())
+        .await?;
+    assert_eq!("other", value);
+
+    Ok(())
+}
@@ -8,7 +8,10 @@ use crate::{
     Result,
 };
 use serial_test::serial;
-use std::{collections::HashSet, time::SystemTime};
+use std::{
+    collections::HashSet,
+    time::{Duration, SystemTime},
+};
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
@@ -79,7 +82,10 @@ async fn dump() -> Result<()> {
     client.set("key", "value").await?;
 
     let dump = client.dump("key").await?;
-    assert!(!dump.0.is_empty());
+    assert!(matches!(dump, Some(dump) if !dump.0.is_empty()));
+
+    let dump = client.dump("unknown").await?;
+    assert!(dump.is_none());
 
     Ok(())
 }
@@ -149,6 +155,18 @@ async fn expire() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn expire_option_from_str() -> Result<()> {
+    assert_eq!(ExpireOption::None, "".parse()?);
+    assert_eq!(ExpireOption::Nx, "NX".parse()?);
+    assert_eq!(ExpireOption::Xx, "xx".parse()?);
+    assert_eq!(ExpireOption::Gt, "Gt".parse()?);
+    assert_eq!(ExpireOption::Lt, "lt".parse()?);
+    assert!("gte".parse::<ExpireOption>().is_err());
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -203,6 +221,66 @@ async fn expireat() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn expire_in() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // whole seconds
+    client.set("key", "value").await?;
+    let result = client
+        .expire_in("key", Duration::from_secs(10), ExpireOption::None)
+        .await?;
+    assert!(result);
+    assert_eq!(10, client.ttl("key").await?);
+
+    // milliseconds
+    client.set("key", "value").await?;
+    let result = client
+        .expire_in("key", Duration::from_millis(10500), ExpireOption::None)
+        .await?;
+    assert!(result);
+    let pttl = client.pttl("key").await?;
+    assert!((10000..=10500).contains(&pttl));
+
+    // nx
+    let result = client
+        .expire_in("key", Duration::from_secs(20), ExpireOption::Nx)
+        .await?;
+    assert!(!result);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn expire_at() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // whole seconds
+    client.set("key", "value").await?;
+    let time = SystemTime::UNIX_EPOCH + Duration::from_secs(33177117420);
+    assert!(client.expire_at("key", time, ExpireOption::None).await?);
+    assert_eq!(33177117420, client.expiretime("key").await?);
+
+    // milliseconds
+    let time = SystemTime::UNIX_EPOCH + Duration::from_millis(33177117420500);
+    assert!(client.expire_at("key", time, ExpireOption::None).await?);
+    assert_eq!(33177117420500, client.pexpiretime("key").await?);
+
+    // in the past
+    assert!(
+        client
+            .expire_at("key", SystemTime::UNIX_EPOCH, ExpireOption::None)
+            .await?
+    );
+    assert_eq!(0, client.exists("key").await?);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -293,6 +371,9 @@ async fn object_encoding() -> Result<()> {
     let encoding: String = client.object_encoding("unknown").await?;
     assert_eq!("", encoding);
 
+    let encoding: Option<String> = client.object_encoding("unknown").await?;
+    assert_eq!(None, encoding);
+
     Ok(())
 }
 
@@ -322,7 +403,10 @@ async fn object_idle_time() -> Result<()> {
     client.set("key", "value").await?;
 
     let idle_time = client.object_idle_time("key").await?;
-    assert!(idle_time < 1);
+    assert!(matches!(idle_time, Some(idle_time) if idle_time < 1));
+
+    let idle_time = client.object_idle_time("unknown").await?;
+    assert_eq!(None, idle_time);
 
     Ok(())
 }
@@ -337,7 +421,10 @@ async fn object_refcount() -> Result<()> {
     client.set("key", "value").await?;
 
     let refcount = client.object_refcount("key").await?;
-    assert_eq!(1, refcount);
+    assert_eq!(Some(1), refcount);
+
+    let refcount = client.object_refcount("unknown").await?;
+    assert_eq!(None, refcount);
 
     Ok(())
 }
@@ -546,7 +633,7 @@ async fn restore() -> Result<()> {
 
     client.set("key", "value").await?;
 
-    let dump = client.dump("key").await?;
+    let dump = client.dump("key").await?.unwrap();
     client.del("key").await?;
     client
         .restore("key", 0, dump.0, RestoreOptions::default())
@@ -1,7 +1,8 @@
 use crate::{
     commands::{
-        GenericCommands, GeoAddCondition, GeoCommands, GeoSearchBy, GeoSearchFrom,
-        GeoSearchOptions, GeoSearchOrder, GeoSearchResult, GeoSearchStoreOptions, GeoUnit,
+        GenericCommands, GeoAddCondition, GeoCommands, GeoRadiusStoreOptions, GeoSearchBy,
+        GeoSearchFrom, GeoSearchOptions, GeoSearchOrder, GeoSearchResult, GeoSearchStoreOptions,
+        GeoUnit, SortedSetCommands,
     },
     tests::get_test_client,
     Result,
@@ -391,3 +392,142 @@ async fn geosearchstore() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+#[allow(deprecated)]
+async fn georadius() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del("Sicily").await?;
+
+    let len = client
+        .geoadd(
+            "Sicily",
+            Default::default(),
+            false,
+            [
+                (13.361389, 38.115556, "Palermo"),
+                (15.087269, 37.502669, "Catania"),
+                (13.583333, 37.316667, "Agrigento"),
+            ],
+        )
+        .await?;
+    assert_eq!(3, len);
+
+    let results: Vec<GeoSearchResult<String>> = client
+        .georadius(
+            "Sicily",
+            15.0,
+            37.0,
+            200.0,
+            GeoUnit::Kilometers,
+            GeoSearchOptions::default()
+                .order(GeoSearchOrder::Asc)
+                .with_dist(),
+        )
+        .await?;
+    assert_eq!(3, results.len());
+    assert_eq!("Catania", results[0].member);
+    assert_eq!(Some(56.4413), results[0].distance);
+    assert_eq!("Agrigento", results[1].member);
+    assert_eq!("Palermo", results[2].member);
+
+    let results: Vec<GeoSearchResult<String>> = client
+        .georadius_ro(
+            "Sicily",
+            15.0,
+            37.0,
+            100.0,
+            GeoUnit::Kilometers,
+            GeoSearchOptions::default(),
+        )
+        .await?;
+    assert_eq!(1, results.len());
+    assert_eq!("Catania", results[0].member);
+
+    let results: Vec<GeoSearchResult<String>> = client
+        .georadiusbymember(
+            "Sicily",
+            "Agrigento",
+            100.0,
+            GeoUnit::Kilometers,
+            GeoSearchOptions::default().order(GeoSearchOrder::Asc),
+        )
+        .await?;
+    assert_eq!(2, results.len());
+    assert_eq!("Agrigento", results[0].member);
+    assert_eq!("Palermo", results[1].member);
+
+    let results: Vec<GeoSearchResult<String>> = client
+        .georadiusbymember_ro(
+            "Sicily",
+            "Agrigento",
+            100.0,
+            GeoUnit::Kilometers,
+            GeoSearchOptions::default().count(1, false),
+        )
+        .await?;
+    assert_eq!(1, results.len());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+#[allow(deprecated)]
+async fn georadius_store() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del(["Sicily", "out"]).await?;
+
+    client
+        .geoadd(
+            "Sicily",
+            Default::default(),
+            false,
+            [
+                (13.361389, 38.115556, "Palermo"),
+                (15.087269, 37.502669, "Catania"),
+            ],
+        )
+        .await?;
+
+    let len = client
+        .georadius_store(
+            "Sicily",
+            15.0,
+            37.0,
+            200.0,
+            GeoUnit::Kilometers,
+            "out",
+            GeoRadiusStoreOptions::default(),
+        )
+        .await?;
+    assert_eq!(2, len);
+    let score: Option<f64> = client.zscore("out", "Catania").await?;
+    assert_eq!(Some(3479447370796909.0), score);
+
+    let len = client
+        .georadiusbymember_store(
+            "Sicily",
+            "Palermo",
+            200.0,
+            GeoUnit::Kilometers,
+            "out",
+            GeoRadiusStoreOptions::default()
+                .order(GeoSearchOrder::Asc)
+                .count(1, false)
+                .store_dist(true),
+        )
+        .await?;
+    assert_eq!(1, len);
+    let score: Option<f64> = client.zscore("out", "Palermo").await?;
+    assert_eq!(Some(0.0), score);
+
+    Ok(())
+}
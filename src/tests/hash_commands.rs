@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 
 use crate::{
-    commands::{GenericCommands, HScanOptions, HScanResult, HashCommands},
+    commands::{
+        ExpireOption, GenericCommands, HScanOptions, HScanResult, HashCommands,
+        HashFieldExpireResult, HashFieldPersistResult,
+    },
     tests::get_test_client,
     Result,
 };
@@ -51,6 +54,82 @@ async fn hexists() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn hexpire() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del("key").await?;
+
+    client
+        .hset("key", [("field1", "value1"), ("field2", "value2")])
+        .await?;
+
+    let results = client
+        .hexpire("key", 100, ExpireOption::None, ["field1", "unknown"])
+        .await?;
+    assert_eq!(
+        vec![
+            HashFieldExpireResult::Set,
+            HashFieldExpireResult::NoSuchField
+        ],
+        results
+    );
+
+    let results = client
+        .hexpire("key", 200, ExpireOption::Nx, "field1")
+        .await?;
+    assert_eq!(vec![HashFieldExpireResult::ConditionNotMet], results);
+
+    let ttls = client.httl("key", ["field1", "field2", "unknown"]).await?;
+    assert_eq!(3, ttls.len());
+    assert!(ttls[0] > 0 && ttls[0] <= 100);
+    assert_eq!(-1, ttls[1]);
+    assert_eq!(-2, ttls[2]);
+
+    let ttls = client.hpttl("key", "field1").await?;
+    assert!(ttls[0] > 0 && ttls[0] <= 100_000);
+
+    let times = client.hexpiretime("key", "field1").await?;
+    let ptimes = client.hpexpiretime("key", "field1").await?;
+    assert!(times[0] > 0);
+    assert_eq!(times[0], ptimes[0] / 1000);
+
+    let results = client
+        .hpersist("key", ["field1", "field2", "unknown"])
+        .await?;
+    assert_eq!(
+        vec![
+            HashFieldPersistResult::Persisted,
+            HashFieldPersistResult::NoExpiration,
+            HashFieldPersistResult::NoSuchField
+        ],
+        results
+    );
+
+    let results = client
+        .hpexpire("key", 100_000, ExpireOption::None, "field1")
+        .await?;
+    assert_eq!(vec![HashFieldExpireResult::Set], results);
+
+    // an expiration in the past deletes the field
+    let results = client
+        .hexpireat("key", 1, ExpireOption::None, "field1")
+        .await?;
+    assert_eq!(vec![HashFieldExpireResult::Deleted], results);
+    let results = client
+        .hpexpireat("key", 1, ExpireOption::None, "field2")
+        .await?;
+    assert_eq!(vec![HashFieldExpireResult::Deleted], results);
+
+    let len = client.hlen("key").await?;
+    assert_eq!(0, len);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -254,6 +333,33 @@ async fn hscan() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn hscan_novalues() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del("key").await?;
+
+    let fields_and_values: Vec<_> = (1..21)
+        .map(|i| (format!("field{}", i), format!("value{}", i)))
+        .collect();
+
+    client.hset("key", fields_and_values).await?;
+
+    let (cursor, fields): (u64, Vec<String>) = client
+        .hscan_novalues("key", 0, HScanOptions::default().count(20))
+        .await?;
+
+    assert_eq!(0, cursor);
+    assert_eq!(20, fields.len());
+    assert_eq!("field1", fields[0]);
+    assert_eq!("field2", fields[1]);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
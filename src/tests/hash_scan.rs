@@ -0,0 +1,39 @@
+use crate::{
+    commands::{FlushingMode, HashCommands, ServerCommands},
+    tests::get_test_client,
+    utils::{hash_entries_stream, hash_fields_stream},
+    Result,
+};
+use futures_util::TryStreamExt;
+use serial_test::serial;
+use std::collections::HashMap;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn hash_scan_streams() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    // large enough to need several HSCAN iterations with a hashtable encoding
+    let entries: HashMap<String, String> = (0..3000)
+        .map(|i| (format!("field{i}"), format!("value{i}")))
+        .collect();
+    client.hset("key", entries.clone()).await?;
+
+    let mut fields: Vec<String> = hash_fields_stream(&client, "key").try_collect().await?;
+    fields.sort();
+    fields.dedup();
+    assert_eq!(entries.len(), fields.len());
+
+    let scanned: HashMap<String, String> =
+        hash_entries_stream(&client, "key").try_collect().await?;
+    assert_eq!(entries, scanned);
+
+    let fields: Vec<String> = hash_fields_stream(&client, "unknown").try_collect().await?;
+    assert!(fields.is_empty());
+
+    client.close().await?;
+
+    Ok(())
+}
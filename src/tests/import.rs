@@ -0,0 +1,178 @@
+use crate::{
+    commands::{FlushingMode, GenericCommands, HashCommands, ServerCommands, StringCommands},
+    resp::Command,
+    tests::get_test_client,
+    utils::{
+        import_commands, parse_command_line, read_resp_command, ImportErrorPolicy, ImportFormat,
+        ImportOptions,
+    },
+    Error, Result,
+};
+use serial_test::serial;
+use std::collections::HashMap;
+
+fn args(command: &Command) -> Vec<Vec<u8>> {
+    (&command.args).into_iter().map(<[u8]>::to_vec).collect()
+}
+
+#[test]
+fn parse_command_lines() -> Result<()> {
+    let command = parse_command_line(r#"set key "hello world""#, ImportFormat::Inline)?.unwrap();
+    assert_eq!("SET", command.name);
+    assert_eq!(
+        vec![b"key".to_vec(), b"hello world".to_vec()],
+        args(&command)
+    );
+
+    let command =
+        parse_command_line(r#"["incrby", "counter", 12]"#, ImportFormat::JsonLines)?.unwrap();
+    assert_eq!("INCRBY", command.name);
+    assert_eq!(vec![b"counter".to_vec(), b"12".to_vec()], args(&command));
+
+    assert!(parse_command_line("  # comment", ImportFormat::Inline)?.is_none());
+    assert!(parse_command_line("\n", ImportFormat::JsonLines)?.is_none());
+
+    assert!(matches!(
+        parse_command_line(r#"["SET", null]"#, ImportFormat::JsonLines),
+        Err(Error::Client(_))
+    ));
+    assert!(matches!(
+        parse_command_line("SET key value", ImportFormat::JsonLines),
+        Err(Error::Client(_))
+    ));
+    assert!(matches!(
+        parse_command_line("SET key value", ImportFormat::Resp),
+        Err(Error::Client(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn read_untrusted_resp_headers() -> Result<()> {
+    let mut line_number = 0;
+    let input = "*2\r\n$4\r\nINCR\r\n$3\r\nkey\r\n";
+    let (line, command) = read_resp_command(&mut input.as_bytes(), &mut line_number)?.unwrap();
+    let command = command?;
+    assert_eq!(1, line);
+    assert_eq!("INCR", command.name);
+    assert_eq!(5, line_number);
+
+    // announced lengths are not allocated up front
+    let input = "*18446744073709551615\r\n$18446744073709551615\r\nSET\r\n";
+    assert!(matches!(
+        read_resp_command(&mut input.as_bytes(), &mut 0),
+        Err(Error::Client(_))
+    ));
+    let input = "*1000000000\r\n$1000000\r\nSET\r\n";
+    assert!(matches!(
+        read_resp_command(&mut input.as_bytes(), &mut 0),
+        Err(Error::Client(_))
+    ));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn import_inline() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    let input = "\
+        # seed\n\
+        SET key1 value1\n\
+        \n\
+        HSET hash field1 \"value 1\" field2 value2\n\
+        INCR key1\n\
+        SET key2 \"unterminated\n\
+        SET key3 value3\n";
+
+    let options = ImportOptions::default()
+        .batch_size(2)
+        .error_policy(ImportErrorPolicy::Collect);
+    let report = import_commands(&client, options, input.as_bytes()).await?;
+    assert_eq!(3, report.num_commands);
+    assert_eq!(2, report.num_errors);
+    assert_eq!(2, report.errors.len());
+    assert_eq!(5, report.errors[0].line);
+    assert_eq!(Some("INCR"), report.errors[0].command_name);
+    assert!(matches!(report.errors[0].error, Error::Redis(_)));
+    assert_eq!(6, report.errors[1].line);
+    assert_eq!(None, report.errors[1].command_name);
+    assert!(matches!(report.errors[1].error, Error::Client(_)));
+
+    let value: String = client.get("key3").await?;
+    assert_eq!("value3", value);
+    let hash: HashMap<String, String> = client.hgetall("hash").await?;
+    assert_eq!(Some(&"value 1".to_owned()), hash.get("field1"));
+
+    let options = ImportOptions::default().error_policy(ImportErrorPolicy::Skip);
+    let report = import_commands(&client, options, input.as_bytes()).await?;
+    assert_eq!(3, report.num_commands);
+    assert_eq!(2, report.num_errors);
+    assert!(report.errors.is_empty());
+
+    let result = import_commands(&client, ImportOptions::default(), input.as_bytes()).await;
+    assert!(matches!(result, Err(Error::Redis(_))));
+
+    client.flushall(FlushingMode::Sync).await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn import_json_lines_and_resp() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    let input = "[\"SET\", \"json\", \"a\\nb\"]\n[\"APPEND\", \"json\", 1]\n";
+    let options = ImportOptions::default().format(ImportFormat::JsonLines);
+    let report = import_commands(&client, options, input.as_bytes()).await?;
+    assert_eq!(2, report.num_commands);
+    let value: String = client.get("json").await?;
+    assert_eq!("a\nb1", value);
+
+    let input = "*3\r\n$3\r\nSET\r\n$4\r\nresp\r\n$3\r\na\nb\r\n\
+        *2\r\n$4\r\nINCR\r\n$4\r\nresp\r\n\
+        *3\r\n$6\r\nAPPEND\r\n$4\r\nresp\r\n$1\r\nc\r\n";
+    let options = ImportOptions::default()
+        .format(ImportFormat::Resp)
+        .error_policy(ImportErrorPolicy::Collect);
+    let report = import_commands(&client, options, input.as_bytes()).await?;
+    assert_eq!(2, report.num_commands);
+    assert_eq!(1, report.errors.len());
+    assert_eq!(9, report.errors[0].line);
+    let value: String = client.get("resp").await?;
+    assert_eq!("a\nbc", value);
+
+    let options = ImportOptions::default()
+        .format(ImportFormat::Resp)
+        .error_policy(ImportErrorPolicy::Skip);
+    let result = import_commands(&client, options, "*1\r\n+PING\r\n".as_bytes()).await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    // invalid commands follow the error policy
+    let input = "*0\r\n*3\r\n$3\r\nSET\r\n$5\r\nvalid\r\n$1\r\na\r\n";
+    let options = ImportOptions::default()
+        .format(ImportFormat::Resp)
+        .error_policy(ImportErrorPolicy::Collect);
+    let report = import_commands(&client, options, input.as_bytes()).await?;
+    assert_eq!(1, report.num_commands);
+    assert_eq!(1, report.errors.len());
+    assert_eq!(1, report.errors[0].line);
+
+    // the commands before a framing error are executed
+    let input = "*3\r\n$3\r\nSET\r\n$6\r\nbefore\r\n$1\r\na\r\n*1\r\n+PING\r\n";
+    let options = ImportOptions::default().format(ImportFormat::Resp);
+    let result = import_commands(&client, options, input.as_bytes()).await;
+    assert!(matches!(result, Err(Error::Client(_))));
+    assert_eq!(2, client.exists(["valid", "before"]).await?);
+
+    client.flushall(FlushingMode::Sync).await?;
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use crate::{
+    client::{Client, Interceptor},
+    commands::{FlushingMode, ListCommands, ServerCommands, StringCommands},
+    resp::{cmd, Command, Value},
+    tests::get_test_client,
+    Error, RedisError, RedisErrorKind, Result,
+};
+use serial_test::serial;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+#[derive(Default)]
+struct Counter {
+    sent: Arc<AtomicUsize>,
+    received: Arc<AtomicUsize>,
+    errors: Arc<AtomicUsize>,
+}
+
+impl Interceptor for Counter {
+    fn before_send(&self, _command: &mut Command) {
+        self.sent.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn after_receive(&self, _command: &Command, value: &mut Value) {
+        self.received.fetch_add(1, Ordering::SeqCst);
+        if let Value::Error(_) = value {
+            self.errors.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+struct UpperCaseGet;
+
+impl Interceptor for UpperCaseGet {
+    fn after_receive(&self, command: &Command, value: &mut Value) {
+        if command.name == "GET" {
+            if let Value::BulkString(bs) = value {
+                bs.make_ascii_uppercase();
+            }
+        }
+    }
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn counter() -> Result<()> {
+    let counter = Counter::default();
+    let sent = counter.sent.clone();
+    let received = counter.received.clone();
+    let errors = counter.errors.clone();
+
+    let client = get_test_client().await?.with_interceptor(counter);
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client.set("key", "value").await?;
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+
+    // server errors are intercepted and still returned to the caller
+    let result = client.lpush("key", "element").await;
+    assert!(matches!(
+        result,
+        Err(Error::Redis(RedisError {
+            kind: RedisErrorKind::WrongType,
+            description: _
+        }))
+    ));
+
+    let mut pipeline = client.create_pipeline();
+    pipeline.queue(cmd("PING"));
+    pipeline.queue(cmd("PING"));
+    pipeline.execute::<()>().await?;
+
+    assert_eq!(6, sent.load(Ordering::SeqCst));
+    assert_eq!(6, received.load(Ordering::SeqCst));
+    assert_eq!(1, errors.load(Ordering::SeqCst));
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn modify_reply() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+    client.set("key", "value").await?;
+
+    let intercepted_client = client.clone().with_interceptor(UpperCaseGet);
+
+    let value: String = intercepted_client.get("key").await?;
+    assert_eq!("VALUE", value);
+
+    let value: String = intercepted_client
+        .send(cmd("GETRANGE").arg("key").arg(0).arg(-1), None)
+        .await?
+        .to()?;
+    assert_eq!("value", value);
+
+    // interceptors are not shared with the original client
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn modify_command() -> Result<()> {
+    struct Rename;
+
+    impl Interceptor for Rename {
+        fn before_send(&self, command: &mut Command) {
+            if command.name == "GET" {
+                *command = cmd("GET").arg("other_key");
+            }
+        }
+    }
+
+    let client = Client::connect("127.0.0.1:6379")
+        .await?
+        .with_interceptor(Rename);
+    client.flushdb(FlushingMode::Sync).await?;
+    client.set("key", "value").await?;
+    client.set("other_key", "other_value").await?;
+
+    let value: String = client.get("key").await?;
+    assert_eq!("other_value", value);
+
+    client.close().await?;
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use crate::{
+    commands::{FlushingMode, GenericCommands, RestoreOptions, ServerCommands, StringCommands},
+    resp::rdb::crc64,
+    tests::get_test_client,
+    utils::{backup_key, restore_key, KeyDump},
+    Error, Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+fn make_dump(value: &[u8], rdb_version: u16) -> KeyDump {
+    // string object type, length-prefixed value
+    let mut payload = vec![0, value.len() as u8];
+    payload.extend_from_slice(value);
+    payload.extend_from_slice(&rdb_version.to_le_bytes());
+    let crc = crc64(&payload);
+    payload.extend_from_slice(&crc.to_le_bytes());
+
+    KeyDump { payload, ttl: None }
+}
+
+#[test]
+fn crc64_check_value() {
+    assert_eq!(0xe9c6_d914_c4b8_d9ca, crc64(b"123456789"));
+}
+
+#[test]
+fn validate_dump() -> Result<()> {
+    let dump = make_dump(b"hello", 10);
+    assert_eq!(10, dump.rdb_version()?);
+    dump.validate()?;
+
+    let mut corrupted = dump.clone();
+    corrupted.payload[3] ^= 0xff;
+    assert!(matches!(corrupted.validate(), Err(Error::InvalidDump(_))));
+
+    let truncated = KeyDump {
+        payload: dump.payload[..8].to_vec(),
+        ttl: None,
+    };
+    assert!(matches!(
+        truncated.rdb_version(),
+        Err(Error::InvalidDump(_))
+    ));
+    assert!(matches!(truncated.validate(), Err(Error::InvalidDump(_))));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn backup_restore() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    assert_eq!(None, backup_key(&client, "key").await?);
+
+    client.set("key", "value").await?;
+    let dump = backup_key(&client, "key").await?.unwrap();
+    assert_eq!(None, dump.ttl);
+    dump.validate()?;
+
+    restore_key(&client, "key2", &dump, RestoreOptions::default()).await?;
+    let value: String = client.get("key2").await?;
+    assert_eq!("value", value);
+    assert_eq!(-1, client.pttl("key2").await?);
+
+    client.pexpire("key", 100_000, Default::default()).await?;
+    let dump = backup_key(&client, "key").await?.unwrap();
+    assert!(dump
+        .ttl
+        .is_some_and(|ttl| ttl <= Duration::from_millis(100_000)));
+
+    restore_key(&client, "key2", &dump, RestoreOptions::default().replace()).await?;
+    let ttl = client.pttl("key2").await?;
+    assert!(ttl > 0 && ttl <= 100_000);
+
+    let mut corrupted = dump.clone();
+    corrupted.payload[1] ^= 0xff;
+    let result = restore_key(&client, "key3", &corrupted, RestoreOptions::default()).await;
+    assert!(matches!(result, Err(Error::InvalidDump(_))));
+    assert_eq!(0, client.exists("key3").await?);
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use crate::{
+    commands::{GenericCommands, ScanOptions, StringCommands},
+    key_format,
+    resp::{BulkString, Key, KeyFormat, TypedKey},
+    tests::get_test_client,
+    Result,
+};
+use serial_test::serial;
+
+key_format! {
+    #[derive(Debug, Clone, Copy)]
+    struct UserKey(u64) => "user:{}";
+}
+
+key_format! {
+    #[derive(Debug, Clone)]
+    struct OrderKey { user_id: u64, order_id: u32 } => "user:{user_id}:order:{order_id}";
+}
+
+#[test]
+fn key_format() {
+    assert_eq!("user:12", UserKey(12).to_key().as_str());
+    assert_eq!("user:12", UserKey(12).to_string());
+    assert_eq!(
+        "user:12:order:3",
+        Key::from(OrderKey {
+            user_id: 12,
+            order_id: 3
+        })
+        .as_str()
+    );
+    assert_eq!(BulkString::from("user:12"), BulkString::from(UserKey(12)));
+}
+
+#[test]
+fn typed_key() {
+    let orders = TypedKey::<u32>::new("order:{}:items");
+    assert_eq!("order:3:items", orders.key(3).as_str());
+    assert_eq!("order:*:items", orders.pattern());
+    assert_eq!(Some(3), orders.parse("order:3:items"));
+    assert_eq!(None, orders.parse("order:abc:items"));
+    assert_eq!(None, orders.parse("user:3"));
+
+    let users = TypedKey::<u64>::new("user:");
+    assert_eq!("user:12", users.key(12).as_str());
+    assert_eq!("user:*", users.pattern());
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn typed_keys() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let user = UserKey(12);
+    client.set(user, "John").await?;
+    let order = OrderKey {
+        user_id: 12,
+        order_id: 1,
+    };
+    client.set(&order, "pending").await?;
+
+    let name: String = client.get("user:12").await?;
+    assert_eq!("John", name);
+    let status: String = client.get(&order).await?;
+    assert_eq!("pending", status);
+
+    let orders = TypedKey::<u32>::new("user:12:order:{}");
+    client.set(orders.key(2), "shipped").await?;
+
+    let (_, keys): (u64, Vec<String>) = client
+        .scan(
+            0,
+            ScanOptions::default()
+                .match_pattern(orders.pattern())
+                .count(1000),
+        )
+        .await?;
+    let mut ids: Vec<u32> = keys.iter().filter_map(|key| orders.parse(key)).collect();
+    ids.sort_unstable();
+    assert_eq!(vec![1, 2], ids);
+
+    client
+        .del([user.to_key(), order.to_key(), orders.key(2)])
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use crate::{
+    client::{Client, Interceptor, IntoConfig, KeyPrefix},
+    commands::{
+        BlockingCommands, FlushingMode, GenericCommands, ListCommands, ServerCommands,
+        StringCommands,
+    },
+    resp::{cmd, Command, Value},
+    tests::get_default_addr,
+    Result,
+};
+use serial_test::serial;
+
+fn prefix(command: Command) -> Vec<String> {
+    let mut command = command;
+    KeyPrefix::new("app:").before_send(&mut command);
+    command
+        .args
+        .iter()
+        .map(|arg| String::from_utf8_lossy(arg).into_owned())
+        .collect()
+}
+
+#[test]
+fn before_send() {
+    assert_eq!(vec!["app:key"], prefix(cmd("GET").arg("key")));
+    assert_eq!(
+        vec!["app:key", "value", "EX", "10"],
+        prefix(cmd("SET").arg("key").arg("value").arg("EX").arg(10))
+    );
+    assert_eq!(
+        vec!["app:key1", "value1", "app:key2", "value2"],
+        prefix(
+            cmd("MSET")
+                .arg("key1")
+                .arg("value1")
+                .arg("key2")
+                .arg("value2")
+        )
+    );
+    assert_eq!(
+        vec!["app:key1", "app:key2", "10"],
+        prefix(cmd("BLPOP").arg("key1").arg("key2").arg(10))
+    );
+    assert_eq!(
+        vec!["app:dst", "2", "app:key1", "app:key2", "WEIGHTS", "1", "2"],
+        prefix(
+            cmd("ZUNIONSTORE")
+                .arg("dst")
+                .arg(2)
+                .arg("key1")
+                .arg("key2")
+                .arg("WEIGHTS")
+                .arg(1)
+                .arg(2)
+        )
+    );
+    assert_eq!(
+        vec!["sha1", "1", "app:key", "arg"],
+        prefix(cmd("EVALSHA").arg("sha1").arg(1).arg("key").arg("arg"))
+    );
+    assert_eq!(
+        vec!["COUNT", "10", "STREAMS", "app:key1", "app:key2", "0", "0"],
+        prefix(
+            cmd("XREAD")
+                .arg("COUNT")
+                .arg(10)
+                .arg("STREAMS")
+                .arg("key1")
+                .arg("key2")
+                .arg(0)
+                .arg(0)
+        )
+    );
+    assert_eq!(vec!["app:key*"], prefix(cmd("KEYS").arg("key*")));
+    assert_eq!(
+        vec!["0", "MATCH", "app:key*", "COUNT", "10"],
+        prefix(
+            cmd("SCAN")
+                .arg(0)
+                .arg("MATCH")
+                .arg("key*")
+                .arg("COUNT")
+                .arg(10)
+        )
+    );
+    assert_eq!(vec!["0", "MATCH", "app:*"], prefix(cmd("SCAN").arg(0)));
+    assert_eq!(vec!["arg"], prefix(cmd("UNKNOWN").arg("arg")));
+}
+
+#[test]
+fn after_receive() {
+    let key_prefix = KeyPrefix::new("app:");
+
+    let command = cmd("KEYS").arg("app:*");
+    assert!(key_prefix.intercepts_reply(&command));
+    let mut value = Value::Array(vec![
+        Value::BulkString(b"app:key1".to_vec()),
+        Value::BulkString(b"app:key2".to_vec()),
+    ]);
+    key_prefix.after_receive(&command, &mut value);
+    assert_eq!(
+        Value::Array(vec![
+            Value::BulkString(b"key1".to_vec()),
+            Value::BulkString(b"key2".to_vec()),
+        ]),
+        value
+    );
+
+    let command = cmd("BLPOP").arg("app:key").arg(0);
+    let mut value = Value::Array(vec![
+        Value::BulkString(b"app:key".to_vec()),
+        Value::BulkString(b"element".to_vec()),
+    ]);
+    key_prefix.after_receive(&command, &mut value);
+    assert_eq!(
+        Value::Array(vec![
+            Value::BulkString(b"key".to_vec()),
+            Value::BulkString(b"element".to_vec()),
+        ]),
+        value
+    );
+
+    assert!(!key_prefix.intercepts_reply(&cmd("GET").arg("app:key")));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn config_key_prefix() -> Result<()> {
+    let client = Client::connect(get_default_addr()).await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let mut config = get_default_addr().into_config()?;
+    config.key_prefix = Some("app:".to_owned());
+    let prefixed_client = Client::connect(config).await?;
+
+    prefixed_client.set("key", "value").await?;
+    let value: String = prefixed_client.get("key").await?;
+    assert_eq!("value", value);
+
+    let value: String = client.get("app:key").await?;
+    assert_eq!("value", value);
+
+    let keys: Vec<String> = prefixed_client.keys("*").await?;
+    assert_eq!(vec!["key".to_owned()], keys);
+
+    prefixed_client.lpush("list", "element").await?;
+    let result: Option<(String, String)> = prefixed_client.blpop("list", 1.0).await?;
+    assert_eq!(Some(("list".to_owned(), "element".to_owned())), result);
+
+    client.close().await?;
+    prefixed_client.close().await?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::{
+    cluster::{is_valid_hash_tag, key_slot, same_slot},
+    hash_tag,
+};
+
+#[test]
+fn key_slot_values() {
+    assert_eq!(11058, key_slot("somekey"));
+    assert_eq!(2515, key_slot("foo{hash_tag}"));
+    assert_eq!(13252, key_slot("key0"));
+    assert_eq!(9189, key_slot("key1"));
+    assert_eq!(4998, key_slot(b"key2"));
+    assert_eq!(key_slot("key0"), key_slot(String::from("key0")));
+}
+
+#[test]
+fn key_slot_hash_tags() {
+    assert_eq!(
+        key_slot("{user1000}.following"),
+        key_slot("{user1000}.followers")
+    );
+    assert_eq!(key_slot("bar"), key_slot("foo{bar}{zap}"));
+    assert_eq!(key_slot("{bar"), key_slot("foo{{bar}}zap"));
+    // empty hash tag: the whole key is hashed
+    assert_eq!(
+        crc16::State::<crc16::XMODEM>::calculate(b"foo{}{bar}") % 16384,
+        key_slot("foo{}{bar}")
+    );
+    // no closing brace
+    assert_ne!(key_slot("bar"), key_slot("foo{bar"));
+}
+
+#[test]
+fn same_slot_keys() {
+    assert!(same_slot(["{user:1}:profile", "{user:1}:orders", "user:1"]));
+    assert!(!same_slot(["user:1:profile", "user:1:orders"]));
+    assert!(same_slot(["single"]));
+    assert!(same_slot(Vec::<String>::new()));
+}
+
+#[test]
+fn hash_tag_macro() {
+    const PREFIX: &str = hash_tag!("user:42");
+    assert_eq!("{user:42}", PREFIX);
+    assert_eq!("{user:42}:profile", hash_tag!("user:42", ":profile"));
+    assert_eq!("{42}:a:b", hash_tag!(42, ":a", ":b"));
+
+    assert!(is_valid_hash_tag("a{b"));
+    assert!(!is_valid_hash_tag(""));
+    assert!(!is_valid_hash_tag("a}b"));
+}
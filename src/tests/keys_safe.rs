@@ -0,0 +1,34 @@
+use crate::{
+    commands::{FlushingMode, ServerCommands, StringCommands},
+    tests::get_test_client,
+    utils::{keys_safe, keys_safe_stream},
+    Result,
+};
+use futures_util::TryStreamExt;
+use serial_test::serial;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn keys_safe_matches_pattern() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let items: Vec<(String, usize)> = (0..2500).map(|i| (format!("user:{i}"), i)).collect();
+    client.mset(items).await?;
+    client.set("other", "value").await?;
+
+    let mut keys = keys_safe(&client, "user:*").await?;
+    keys.sort();
+    keys.dedup();
+    assert_eq!(2500, keys.len());
+    assert!(keys.iter().all(|key| key.starts_with("user:")));
+
+    let keys: Vec<String> = keys_safe_stream(&client, "oth*").try_collect().await?;
+    assert_eq!(vec!["other".to_owned()], keys);
+
+    let keys = keys_safe(&client, "unknown:*").await?;
+    assert!(keys.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::{
+    commands::{FlushingMode, ListCommands, ServerCommands, StringCommands},
+    tests::get_test_client,
+    utils::{KeyspaceAnalyzer, Ttl},
+    Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn keyspace_analyzer() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    for i in 0..10 {
+        client.set(format!("user:{i}:name"), "John").await?;
+    }
+    client
+        .set_with_expiry("session:1", "data", Duration::from_secs(60))
+        .await?;
+    client
+        .rpush(
+            "queue:jobs",
+            (0..1000).map(|i| format!("job{i}")).collect::<Vec<_>>(),
+        )
+        .await?;
+    client.set("counter", 12).await?;
+
+    let report = KeyspaceAnalyzer::new(&client).top_keys(2).analyze().await?;
+    assert_eq!(13, report.scanned_keys);
+    assert_eq!(13, report.sampled_keys);
+    assert_eq!(report.memory_usage, report.estimated_memory_usage());
+
+    assert_eq!(4, report.prefixes.len());
+    assert_eq!("queue:*", report.prefixes[0].prefix);
+    assert_eq!("queue:jobs", report.prefixes[0].biggest_key);
+    assert_eq!(Some(&1), report.prefixes[0].types.get("list"));
+
+    let users = report
+        .prefixes
+        .iter()
+        .find(|p| p.prefix == "user:*")
+        .unwrap();
+    assert_eq!(10, users.keys);
+    assert_eq!(0, users.keys_with_ttl);
+    assert_eq!(Some(&10), users.types.get("string"));
+
+    let sessions = report
+        .prefixes
+        .iter()
+        .find(|p| p.prefix == "session:*")
+        .unwrap();
+    assert_eq!(1, sessions.keys_with_ttl);
+    assert!(report.prefixes.iter().any(|p| p.prefix == "counter"));
+
+    assert_eq!(2, report.biggest_keys.len());
+    assert_eq!("queue:jobs", report.biggest_keys[0].key);
+    assert!(report.biggest_keys[0].memory_usage >= report.biggest_keys[1].memory_usage);
+
+    // prefix depth & pattern
+    let report = KeyspaceAnalyzer::new(&client)
+        .match_pattern("user:*")
+        .prefix_depth(2)
+        .analyze()
+        .await?;
+    assert_eq!(10, report.scanned_keys);
+    assert_eq!(10, report.prefixes.len());
+    assert!(report.prefixes.iter().any(|p| p.prefix == "user:3:*"));
+
+    // sampling & limit
+    let report = KeyspaceAnalyzer::new(&client)
+        .sample_rate(0.)
+        .max_keys(5)
+        .scan_count(2)
+        .analyze()
+        .await?;
+    assert_eq!(5, report.scanned_keys);
+    assert_eq!(0, report.sampled_keys);
+
+    let infos = KeyspaceAnalyzer::new(&client)
+        .inspect(&["counter".to_owned(), "unknown".to_owned()])
+        .await?;
+    assert_eq!(1, infos.len());
+    assert_eq!("string", infos[0].key_type);
+    assert_eq!("int", infos[0].encoding);
+    assert_eq!(Ttl::NoExpiry, infos[0].ttl);
+
+    Ok(())
+}
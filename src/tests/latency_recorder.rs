@@ -0,0 +1,76 @@
+use crate::{
+    client::{LatencyHistogram, LatencyRecorder},
+    commands::StringCommands,
+    tests::get_test_client,
+    Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+#[test]
+fn latency_histogram() {
+    let mut histogram = LatencyHistogram::default();
+    assert_eq!(Duration::ZERO, histogram.percentile(50.));
+
+    for micros in 1..=1000 {
+        histogram.record(Duration::from_micros(micros));
+    }
+
+    assert_eq!(1000, histogram.count());
+    assert_eq!(Duration::from_micros(1000), histogram.max());
+
+    // relative error of 1/32
+    for (percentile, expected) in [(50., 500.), (95., 950.), (99., 990.)] {
+        let value = histogram.percentile(percentile).as_micros() as f64;
+        assert!(
+            value >= expected && value <= expected * (1. + 1. / 32.),
+            "p{percentile}: {value}"
+        );
+    }
+    assert_eq!(Duration::from_micros(1000), histogram.percentile(100.));
+
+    histogram.record(Duration::from_secs(u64::MAX));
+    assert_eq!(Duration::from_micros(u64::MAX), histogram.percentile(100.));
+}
+
+#[test]
+fn latency_recorder() {
+    let recorder = LatencyRecorder::new();
+    recorder.record("GET", Duration::from_micros(10));
+    recorder.record("GET", Duration::from_micros(20));
+    recorder.record("SET", Duration::from_micros(30));
+
+    let report = recorder.report();
+    assert_eq!(2, report.len());
+    assert_eq!(2, report["GET"].count);
+    assert_eq!(Duration::from_micros(10), report["GET"].p50);
+    assert_eq!(Duration::from_micros(20), report["GET"].max);
+    assert_eq!(Duration::from_micros(30), report["SET"].p99);
+
+    recorder.reset();
+    assert!(recorder.report().is_empty());
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn latency_report() -> Result<()> {
+    let client = get_test_client().await?;
+    assert!(client.latency_report().is_empty());
+
+    let client = client.with_latency_recorder(LatencyRecorder::new());
+    client.set("key", "value").await?;
+    for _ in 0..10 {
+        let _value: String = client.get("key").await?;
+    }
+
+    let report = client.latency_report();
+    assert_eq!(1, report["SET"].count);
+    assert_eq!(10, report["GET"].count);
+    assert!(report["GET"].p50 <= report["GET"].max);
+
+    client.reset_latency_report();
+    assert!(client.latency_report().is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use crate::{
+    commands::{FlushingMode, ServerCommands},
+    tests::get_test_client,
+    utils::{Leaderboard, LeaderboardEntry},
+    Result,
+};
+use serial_test::serial;
+
+fn entry(member: &str, score: f64, rank: usize) -> LeaderboardEntry {
+    LeaderboardEntry {
+        member: member.to_owned(),
+        score,
+        rank,
+    }
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn leaderboard() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let leaderboard = Leaderboard::new(&client, "scores");
+    for (i, member) in ["a", "b", "c", "d", "e", "f"].iter().enumerate() {
+        leaderboard.set_score(*member, (i * 10) as f64).await?;
+    }
+    assert_eq!(6, leaderboard.len().await?);
+    assert_eq!(35., leaderboard.incr_score("c", 15.).await?);
+
+    assert_eq!(Some(1), leaderboard.rank("f").await?);
+    assert_eq!(Some(3), leaderboard.rank("c").await?);
+    assert_eq!(None, leaderboard.rank("unknown").await?);
+    assert_eq!(Some(35.), leaderboard.score("c").await?);
+    assert_eq!(Some(entry("c", 35., 3)), leaderboard.entry("c").await?);
+    assert_eq!(None, leaderboard.entry("unknown").await?);
+
+    assert_eq!(
+        vec![entry("f", 50., 1), entry("e", 40., 2)],
+        leaderboard.top(2).await?
+    );
+    assert_eq!(
+        vec![entry("c", 35., 3), entry("d", 30., 4)],
+        leaderboard.page(1, 2).await?
+    );
+    assert_eq!(
+        vec![entry("e", 40., 2), entry("c", 35., 3), entry("d", 30., 4)],
+        leaderboard.around_member("c", 1).await?
+    );
+    assert_eq!(
+        vec![entry("f", 50., 1), entry("e", 40., 2)],
+        leaderboard.around_member("f", 1).await?
+    );
+    assert!(leaderboard.around_member("unknown", 1).await?.is_empty());
+
+    assert!(leaderboard.remove("f").await?);
+    assert!(!leaderboard.remove("f").await?);
+
+    let leaderboard = leaderboard.lower_is_better();
+    assert_eq!(Some(1), leaderboard.rank("a").await?);
+    assert_eq!(
+        vec![entry("a", 0., 1), entry("b", 10., 2)],
+        leaderboard.top(2).await?
+    );
+
+    client.close().await?;
+
+    Ok(())
+}
@@ -304,11 +304,16 @@ async fn lmpop() -> Result<()> {
         )
         .await?;
 
-    let result: (String, Vec<String>) = client.lmpop("mylist", Left, 1).await?;
+    let result: Option<(String, Vec<String>)> = client.lmpop("mylist", Left, 1).await?;
+    let result = result.unwrap();
     assert_eq!("mylist", result.0);
     assert_eq!(1, result.1.len());
     assert_eq!("element5".to_string(), result.1[0]);
 
+    client.del("mylist").await?;
+    let result: Option<(String, Vec<String>)> = client.lmpop("mylist", Left, 1).await?;
+    assert!(result.is_none());
+
     Ok(())
 }
 
@@ -0,0 +1,105 @@
+use crate::{
+    commands::{GenericCommands, StringCommands},
+    locks::{RedisLock, Redlock},
+    tests::get_test_client,
+    Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn redis_lock() -> Result<()> {
+    let client = get_test_client().await?;
+    client.del("lock").await?;
+
+    let lock = RedisLock::acquire(&client, "lock", Duration::from_secs(10)).await?;
+    assert!(lock.is_some());
+    let lock = lock.unwrap();
+    let token: String = client.get("lock").await?;
+    assert_eq!(lock.token(), token);
+
+    let other_lock = RedisLock::acquire_with_retry(
+        &client,
+        "lock",
+        Duration::from_secs(10),
+        2,
+        Duration::from_millis(10),
+    )
+    .await?;
+    assert!(other_lock.is_none());
+
+    assert!(lock.extend(Duration::from_secs(20)).await?);
+    let ttl = client.pttl("lock").await?;
+    assert!(ttl > 10_000);
+
+    assert!(lock.release().await?);
+    assert_eq!(0, client.exists("lock").await?);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn redis_lock_expired() -> Result<()> {
+    let client = get_test_client().await?;
+    client.del("lock").await?;
+
+    let lock = RedisLock::acquire(&client, "lock", Duration::from_secs(10))
+        .await?
+        .unwrap();
+
+    // simulate expiration and acquisition by another owner
+    client.set("lock", "other").await?;
+
+    assert!(!lock.extend(Duration::from_secs(10)).await?);
+    assert!(!lock.release().await?);
+    let value: String = client.get("lock").await?;
+    assert_eq!("other", value);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn redlock() -> Result<()> {
+    let client = get_test_client().await?;
+    client.del("lock").await?;
+
+    // a single instance is enough to check the algorithm
+    let redlock = Redlock::new(vec![client.clone()]).retry_count(1);
+
+    let mut guard = redlock
+        .lock("lock", Duration::from_secs(10))
+        .await?
+        .unwrap();
+    assert!(guard.validity() < Duration::from_secs(10));
+
+    let other_guard = redlock.lock("lock", Duration::from_secs(10)).await?;
+    assert!(other_guard.is_none());
+
+    assert!(guard.extend(Duration::from_secs(20)).await?);
+    assert!(guard.validity() > Duration::from_secs(10));
+    // the clock drift is subtracted as when the lock is acquired
+    assert!(guard.validity() < Duration::from_millis(19_800));
+
+    guard.release().await;
+    assert_eq!(0, client.exists("lock").await?);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "clock drift factor")]
+fn redlock_invalid_clock_drift_factor() {
+    let _redlock = Redlock::new(Vec::new()).clock_drift_factor(f64::NAN);
+}
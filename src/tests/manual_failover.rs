@@ -0,0 +1,80 @@
+use crate::{
+    commands::{InfoSection, RoleResult, ServerCommands, StringCommands},
+    sleep,
+    testing::RedisServer,
+    utils::{FailoverStrategy, ManualFailover},
+    Error, Result,
+};
+use std::time::Duration;
+
+async fn start_master_and_replica() -> Result<(RedisServer, RedisServer)> {
+    let master = RedisServer::start().await?;
+    let replica =
+        RedisServer::start_with_args(&["--replicaof", "127.0.0.1", &master.port().to_string()])
+            .await?;
+
+    // wait for the initial synchronization
+    let replica_client = replica.client().await?;
+    for _ in 0..100 {
+        let info = replica_client.info(Vec::<InfoSection>::new()).await?;
+        if info.contains("master_link_status:up") {
+            break;
+        }
+        sleep(Duration::from_millis(50)).await;
+    }
+
+    Ok((master, replica))
+}
+
+async fn failover(strategy: FailoverStrategy) -> Result<()> {
+    let (master, replica) = start_master_and_replica().await?;
+    let master_client = master.client().await?;
+    let replica_client = replica.client().await?;
+
+    master_client.set("key", "value").await?;
+
+    ManualFailover::new(&master_client, &replica_client, "127.0.0.1", replica.port())
+        .strategy(strategy)
+        .execute()
+        .await?;
+
+    assert!(matches!(
+        replica_client.role().await?,
+        RoleResult::Master { .. }
+    ));
+    assert!(matches!(
+        master_client.role().await?,
+        RoleResult::Replica { .. }
+    ));
+
+    let value: String = replica_client.get("key").await?;
+    assert_eq!("value", value);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn failover_with_replicaof() -> Result<()> {
+    failover(FailoverStrategy::ReplicaOf).await
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn failover_with_failover_command() -> Result<()> {
+    failover(FailoverStrategy::Failover).await
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn failover_to_master() -> Result<()> {
+    let server = RedisServer::start().await?;
+    let client = server.client().await?;
+
+    let result = ManualFailover::new(&client, &client, "127.0.0.1", server.port())
+        .execute()
+        .await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    Ok(())
+}
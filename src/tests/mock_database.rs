@@ -0,0 +1,183 @@
+use crate::{
+    commands::{
+        ExpireOption, GenericCommands, HashCommands, SetCondition, SetExpiration, StringCommands,
+    },
+    resp::{cmd, Value},
+    testing::MockDatabase,
+    Error, RedisError, RedisErrorKind, Result,
+};
+use std::collections::HashMap;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn strings() -> Result<()> {
+    let db = MockDatabase::new();
+
+    db.set("key", "value").await?;
+    let value: String = db.get("key").await?;
+    assert_eq!("value", value);
+
+    let value: Option<String> = db.get("unknown").await?;
+    assert_eq!(None, value);
+
+    assert_eq!(10, db.append("key", "12345").await?);
+    assert_eq!(10, db.strlen("key").await?);
+
+    assert_eq!(1, db.incr("counter").await?);
+    assert_eq!(11, db.incrby("counter", 10).await?);
+    assert_eq!(9, db.decrby("counter", 2).await?);
+
+    let result = db.incr("key").await;
+    assert!(matches!(
+        result,
+        Err(Error::Redis(RedisError {
+            kind: RedisErrorKind::Err,
+            description: _
+        }))
+    ));
+
+    db.mset([("key1", "value1"), ("key2", "value2")]).await?;
+    let values: Vec<Option<String>> = db.mget(["key1", "unknown", "key2"]).await?;
+    assert_eq!(
+        vec![Some("value1".to_owned()), None, Some("value2".to_owned())],
+        values
+    );
+
+    let set: bool = db
+        .set_with_options("key1", "new", SetCondition::NX, SetExpiration::None, false)
+        .await?;
+    assert!(!set);
+
+    assert_eq!(2, db.exists(["key1", "key2"]).await?);
+    assert_eq!(2, db.del(["key1", "key2", "unknown"]).await?);
+    assert_eq!(0, db.exists(["key1", "key2"]).await?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn hashes() -> Result<()> {
+    let db = MockDatabase::new();
+
+    assert_eq!(
+        2,
+        db.hset("hash", [("field1", "value1"), ("field2", "value2")])
+            .await?
+    );
+    assert_eq!(0, db.hset("hash", ("field1", "new")).await?);
+
+    let value: String = db.hget("hash", "field1").await?;
+    assert_eq!("new", value);
+
+    let all: HashMap<String, String> = db.hgetall("hash").await?;
+    assert_eq!(2, all.len());
+    assert_eq!(Some(&"value2".to_owned()), all.get("field2"));
+
+    assert_eq!(5, db.hincrby("hash", "counter", 5).await?);
+    assert_eq!(3, db.hlen("hash").await?);
+    assert_eq!(3, db.hdel("hash", ["field1", "field2", "counter"]).await?);
+    assert_eq!(0, db.exists("hash").await?);
+
+    db.set("key", "value").await?;
+    let result: Result<Option<String>> = db.hget("key", "field").await;
+    assert!(matches!(
+        result,
+        Err(Error::Redis(RedisError {
+            kind: RedisErrorKind::WrongType,
+            description: _
+        }))
+    ));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn expiration() -> Result<()> {
+    let db = MockDatabase::new();
+
+    db.set("key", "value").await?;
+    assert_eq!(-1, db.ttl("key").await?);
+    assert_eq!(-2, db.ttl("unknown").await?);
+
+    assert!(db.expire("key", 10, ExpireOption::None).await?);
+    assert_eq!(10, db.ttl("key").await?);
+    assert!(!db.expire("key", 20, ExpireOption::Nx).await?);
+    assert!(db.expire("key", 20, ExpireOption::Gt).await?);
+    assert_eq!(20, db.ttl("key").await?);
+
+    assert!(db.persist("key").await?);
+    assert_eq!(-1, db.ttl("key").await?);
+
+    assert!(db.pexpire("key", 1, ExpireOption::None).await?);
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    assert_eq!(0, db.exists("key").await?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn scripted() -> Result<()> {
+    let db = MockDatabase::scripted();
+    db.expect(cmd("GET").arg("key"), Value::BulkString(b"12".to_vec()))
+        .expect(
+            cmd("INCR").arg("key"),
+            Value::Error(RedisError {
+                kind: RedisErrorKind::Readonly,
+                description: "You can't write against a read only replica.".to_owned(),
+            }),
+        );
+
+    let value: i64 = db.get("key").await?;
+    assert_eq!(12, value);
+
+    let result = db.incr("key").await;
+    assert!(matches!(
+        result,
+        Err(Error::Redis(RedisError {
+            kind: RedisErrorKind::Readonly,
+            description: _
+        }))
+    ));
+    assert_eq!(0, db.remaining_expectations());
+
+    // no more expectation
+    let result: Result<Value> = db.get("key").await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    // unexpected command
+    db.expect(cmd("GET").arg("key"), Value::Nil);
+    let result: Result<Value> = db.get("other").await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    let history = db.history();
+    assert_eq!(4, history.len());
+    assert_eq!("INCR", history[1].name);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn scripted_with_store() -> Result<()> {
+    let db = MockDatabase::new();
+    db.expect(
+        cmd("GET").arg("key"),
+        Value::BulkString(b"scripted".to_vec()),
+    );
+
+    // expectations first
+    let value: String = db.get("key").await?;
+    assert_eq!("scripted", value);
+
+    // then in-memory store
+    let value: Option<String> = db.get("key").await?;
+    assert_eq!(None, value);
+    db.set("key", "value").await?;
+    let value: String = db.get("key").await?;
+    assert_eq!("value", value);
+
+    Ok(())
+}
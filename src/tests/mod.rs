@@ -1,45 +1,102 @@
+mod assertions;
+mod atomic_commands;
+mod big_keys;
 mod bitmap_commands;
 #[cfg(feature = "redis-bloom")]
 mod bloom_commands;
 mod buffer_decoder;
+mod bulk_string;
+#[cfg(feature = "cache-json")]
+mod cache;
 mod client;
+mod client_side_cache;
 mod cluster;
 mod cluster_commands;
+mod codec;
+mod command;
 mod command_args;
 mod command_info_manager;
+mod command_metadata;
+mod command_template;
 mod config;
+mod config_diff;
 mod connection_commands;
+mod copy_key;
+mod counters;
 #[cfg(feature = "redis-bloom")]
 mod count_min_sktech_commands;
 #[cfg(feature = "redis-bloom")]
 mod cuckoo_commands;
+mod delayed_queue;
+mod delete_matching;
 mod error;
+#[cfg(feature = "export")]
+mod export;
 mod from_value;
+mod function_library;
 mod generic_commands;
 mod geo_commands;
 #[cfg(feature = "redis-graph")]
 mod graph_commands;
 mod hash_commands;
+mod hash_scan;
 mod hyper_log_log_commands;
+#[cfg(feature = "import")]
+mod import;
+mod interceptor;
 #[cfg(feature = "redis-json")]
 mod json_commands;
+mod key_dump;
+mod key_format;
+mod key_slot;
+mod key_prefix;
+mod keys_safe;
+mod keyspace_analyzer;
+mod latency_recorder;
+mod leaderboard;
 mod list_commands;
+#[cfg(feature = "test-server")]
+mod manual_failover;
+#[cfg(feature = "locks")]
+mod locks;
+mod mock_database;
 mod multiplexed_client;
 mod pipeline;
 #[cfg(feature = "pool")]
 mod pooled_client_manager;
+#[cfg(feature = "tokio-runtime")]
+mod proxy;
 mod pub_sub_commands;
+mod pub_sub_bridge;
+mod pub_sub_router;
+mod push_messages;
+#[cfg(feature = "test-server")]
+mod redis_server;
+mod rate_limiter;
+mod rdb;
+mod read_coalescer;
+mod redact_keys;
+mod reliable_queue;
+mod replicated_client;
 mod resp3;
 mod resp_deserializer;
 mod resp_serializer;
+mod retry_policy;
+mod scan_stream;
 mod scripting_commands;
 #[cfg(feature = "redis-search")]
 mod search_commands;
 mod sentinel;
 mod server_commands;
+mod server_features;
+#[cfg(all(feature = "sessions", feature = "cache-json"))]
+mod sessions;
 mod set_commands;
+mod slowlog_tail;
 mod sorted_set_commands;
 mod stream_commands;
+mod stream_consumer;
+mod stream_producer;
 mod string_commands;
 mod debug_commands;
 #[cfg(feature = "redis-bloom")]
@@ -50,10 +107,13 @@ mod tls;
 #[cfg(feature = "redis-bloom")]
 mod top_k_commands;
 mod transaction;
+mod ttl_many;
 mod util;
 mod value;
 mod value_deserialize;
 mod value_deserializer;
 mod value_serialize;
+mod wait_for_key;
+mod write_concern;
 
 pub(crate) use util::*;
@@ -1,5 +1,8 @@
 use crate::{
-    client::PooledClientManager, commands::StringCommands, tests::get_default_addr, Result,
+    client::{PoolExt, PooledClientManager},
+    commands::{ConnectionCommands, FlushingMode, ServerCommands, StringCommands},
+    tests::get_default_addr,
+    Result,
 };
 use serial_test::serial;
 
@@ -17,3 +20,61 @@ async fn pooled_client_manager() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn database_restored_on_checkout() -> Result<()> {
+    let manager = PooledClientManager::new(get_default_addr())?;
+    let pool = crate::bb8::Pool::builder()
+        .max_size(1)
+        .build(manager)
+        .await?;
+
+    {
+        let client = pool.get().await.unwrap();
+        client.flushall(FlushingMode::Sync).await?;
+        client.select(1).await?;
+        client.set("key", "db1").await?;
+        assert_eq!(1, client.db_index());
+    }
+
+    let client = pool.get().await.unwrap();
+    assert_eq!(0, client.db_index());
+    let value: Option<String> = client.get("key").await?;
+    assert_eq!(None, value);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn get_db() -> Result<()> {
+    let manager = PooledClientManager::new(get_default_addr())?;
+    let pool = crate::bb8::Pool::builder()
+        .max_size(1)
+        .build(manager)
+        .await?;
+
+    {
+        let client = pool.get_db(3).await?;
+        client.flushall(FlushingMode::Sync).await?;
+        assert_eq!(3, client.db_index());
+        client.set("key", "db3").await?;
+    }
+
+    {
+        let client = pool.get_db(3).await?;
+        let value: String = client.get("key").await?;
+        assert_eq!("db3", value);
+    }
+
+    // the default database is selected again on a regular checkout
+    let client = pool.get().await.unwrap();
+    assert_eq!(0, client.db_index());
+    let value: Option<String> = client.get("key").await?;
+    assert_eq!(None, value);
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::{
+    client::Client,
+    commands::{
+        FlushingMode, GenericCommands, PubSubCommands, ServerCommands, StreamCommands, StreamEntry,
+        StringCommands,
+    },
+    network::timeout,
+    sleep, spawn,
+    tests::{get_default_addr, get_test_client},
+    utils::PubSubBridge,
+    Error, RedisErrorKind, Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn pub_sub_bridge() -> Result<()> {
+    let pub_sub_client = Client::connect(get_default_addr()).await?;
+    let regular_client = get_test_client().await?;
+    regular_client.flushall(FlushingMode::Sync).await?;
+
+    let bridge = PubSubBridge::new(&pub_sub_client, &regular_client, "bridge")
+        .channel("mychannel")
+        .pattern("o*")
+        .max_len(2, false);
+    let _join_handle = spawn(bridge.run());
+
+    // wait for the subscriptions
+    sleep(Duration::from_millis(100)).await;
+
+    regular_client.publish("mychannel", "message1").await?;
+    regular_client.publish("other", "message2").await?;
+    regular_client.publish("unknown", "message3").await?;
+    regular_client.publish("mychannel", "message4").await?;
+
+    // wait for the messages to be appended
+    sleep(Duration::from_millis(100)).await;
+
+    let entries: Vec<StreamEntry<String>> = regular_client.xrange("bridge", "-", "+", None).await?;
+    assert_eq!(2, entries.len());
+    assert_eq!(Some(&"other".to_owned()), entries[0].items.get("channel"));
+    assert_eq!(Some(&"o*".to_owned()), entries[0].items.get("pattern"));
+    assert_eq!(
+        Some(&"message2".to_owned()),
+        entries[0].items.get("payload")
+    );
+    assert_eq!(
+        Some(&"mychannel".to_owned()),
+        entries[1].items.get("channel")
+    );
+    assert_eq!(None, entries[1].items.get("pattern"));
+    assert_eq!(
+        Some(&"message4".to_owned()),
+        entries[1].items.get("payload")
+    );
+
+    regular_client.del("bridge").await?;
+    pub_sub_client.close().await?;
+    regular_client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn pub_sub_bridge_permanent_error() -> Result<()> {
+    let pub_sub_client = Client::connect(get_default_addr()).await?;
+    let regular_client = get_test_client().await?;
+    regular_client.flushall(FlushingMode::Sync).await?;
+    // not a stream
+    regular_client.set("bridge", "value").await?;
+
+    let bridge = PubSubBridge::new(&pub_sub_client, &regular_client, "bridge").channel("mychannel");
+    let join_handle = spawn(bridge.run());
+
+    // wait for the subscriptions
+    sleep(Duration::from_millis(100)).await;
+
+    regular_client.publish("mychannel", "message").await?;
+
+    // the bridge stops instead of retrying forever
+    let result = timeout(Duration::from_secs(1), join_handle).await??;
+    assert!(matches!(
+        result,
+        Err(Error::Redis(e)) if e.kind == RedisErrorKind::WrongType
+    ));
+
+    regular_client.del("bridge").await?;
+    pub_sub_client.close().await?;
+    regular_client.close().await?;
+
+    Ok(())
+}
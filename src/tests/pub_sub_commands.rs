@@ -1,5 +1,5 @@
 use crate::{
-    client::{Client, IntoConfig},
+    client::{Client, IntoConfig, PubSubEvent},
     commands::{
         ClientKillOptions, ClusterCommands, ClusterShardResult, ConnectionCommands, FlushingMode,
         ListCommands, PubSubChannelsOptions, PubSubCommands, ServerCommands, StringCommands,
@@ -262,6 +262,18 @@ async fn pub_sub_numsub() -> Result<()> {
     assert_eq!(Some(&1usize), num_sub.get("mychannel1"));
     assert_eq!(Some(&1usize), num_sub.get("mychannel2"));
 
+    let num_sub: Vec<(String, usize)> = regular_client
+        .pub_sub_numsub(["mychannel2", "mychannel1", "otherchannel"])
+        .await?;
+    assert_eq!(
+        vec![
+            ("mychannel2".to_owned(), 1),
+            ("mychannel1".to_owned(), 1),
+            ("otherchannel".to_owned(), 0)
+        ],
+        num_sub
+    );
+
     stream.close().await?;
 
     Ok(())
@@ -575,6 +587,46 @@ async fn auto_resubscribe() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn auto_resubscribe_events() -> Result<()> {
+    let pub_sub_client = get_test_client().await?;
+    let regular_client = get_test_client().await?;
+
+    let pub_sub_client_id = pub_sub_client.client_id().await?;
+    let mut pub_sub_stream = pub_sub_client.subscribe("mychannel").await?;
+    pub_sub_stream.psubscribe("o*").await?;
+    let (sink, stream) = pub_sub_stream.split();
+    let mut events = stream.into_events();
+
+    let mut on_reconnect = pub_sub_client.on_reconnect();
+
+    regular_client
+        .client_kill(ClientKillOptions::default().id(pub_sub_client_id))
+        .await?;
+
+    // wait for reconnection before publishing
+    on_reconnect.recv().await.unwrap();
+
+    regular_client.publish("mychannel", "mymessage").await?;
+
+    // a single notification for both subscriptions of the stream
+    let event = events.try_next().await?.unwrap();
+    assert!(matches!(event, PubSubEvent::Reconnected));
+
+    let event = events.try_next().await?.unwrap();
+    let PubSubEvent::Message(message) = event else {
+        panic!("Unexpected event {event:?}");
+    };
+    assert_eq!(b"mychannel".to_vec(), message.channel);
+    assert_eq!(b"mymessage".to_vec(), message.payload);
+
+    sink.close().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -729,6 +781,46 @@ async fn punsubscribe() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn sunsubscribe() -> Result<()> {
+    let pub_sub_client = get_test_client().await?;
+    let regular_client = get_test_client().await?;
+
+    // cleanup
+    regular_client.flushdb(FlushingMode::Sync).await?;
+
+    let mut pub_sub_stream = pub_sub_client
+        .ssubscribe(["mychannel1", "mychannel2"])
+        .await?;
+
+    let num_sub: HashMap<String, usize> = regular_client
+        .pub_sub_shardnumsub(["mychannel1", "mychannel2"])
+        .await?;
+    assert_eq!(Some(&1usize), num_sub.get("mychannel1"));
+    assert_eq!(Some(&1usize), num_sub.get("mychannel2"));
+
+    pub_sub_stream.sunsubscribe("mychannel1").await?;
+
+    let num_sub: HashMap<String, usize> = regular_client
+        .pub_sub_shardnumsub(["mychannel1", "mychannel2"])
+        .await?;
+    assert_eq!(Some(&0usize), num_sub.get("mychannel1"));
+    assert_eq!(Some(&1usize), num_sub.get("mychannel2"));
+
+    regular_client.spublish("mychannel2", "mymessage2").await?;
+
+    let message = pub_sub_stream.next().await.unwrap()?;
+    assert_eq!(b"mychannel2", message.channel.as_slice());
+    assert_eq!(b"mymessage2", message.payload.as_slice());
+
+    pub_sub_stream.close().await?;
+    regular_client.close().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -0,0 +1,61 @@
+use crate::{
+    client::{Client, PubSubMessage},
+    commands::PubSubCommands,
+    sleep, spawn,
+    tests::{get_default_addr, get_test_client},
+    utils::PubSubRouter,
+    Result,
+};
+use futures_channel::mpsc;
+use futures_util::StreamExt;
+use serial_test::serial;
+use std::time::Duration;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn pub_sub_router() -> Result<()> {
+    let pub_sub_client = Client::connect(get_default_addr()).await?;
+    let regular_client = get_test_client().await?;
+
+    let (sender, mut receiver) = mpsc::unbounded::<(&str, PubSubMessage)>();
+    let channel_sender = sender.clone();
+    let pattern_sender = sender;
+
+    let router = PubSubRouter::new(&pub_sub_client)
+        .channel("mychannel", move |message| {
+            let sender = channel_sender.clone();
+            async move {
+                sender.unbounded_send(("channel", message)).unwrap();
+            }
+        })
+        .pattern("o*", move |message| {
+            let sender = pattern_sender.clone();
+            async move {
+                sender.unbounded_send(("pattern", message)).unwrap();
+            }
+        });
+
+    let _join_handle = spawn(router.run());
+
+    // wait for the subscriptions
+    sleep(Duration::from_millis(100)).await;
+
+    regular_client.publish("mychannel", "message1").await?;
+    let (route, message) = receiver.next().await.unwrap();
+    assert_eq!("channel", route);
+    assert_eq!(b"mychannel".to_vec(), message.channel);
+    assert_eq!(b"message1".to_vec(), message.payload);
+
+    regular_client.publish("other", "message2").await?;
+    let (route, message) = receiver.next().await.unwrap();
+    assert_eq!("pattern", route);
+    assert_eq!(b"o*".to_vec(), message.pattern);
+    assert_eq!(b"other".to_vec(), message.channel);
+    assert_eq!(b"message2".to_vec(), message.payload);
+
+    pub_sub_client.close().await?;
+    regular_client.close().await?;
+
+    Ok(())
+}
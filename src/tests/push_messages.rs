@@ -0,0 +1,122 @@
+use crate::{
+    client::{Client, PushKind, PushMessage},
+    commands::{
+        ClientTrackingOptions, ClientTrackingStatus, ConnectionCommands, FlushingMode,
+        PubSubCommands, ServerCommands, StringCommands,
+    },
+    network::timeout,
+    resp::{RespBuf, Value},
+    tests::{get_default_addr, get_test_client, log_try_init},
+    Result,
+};
+use futures_util::StreamExt;
+use serial_test::serial;
+use std::time::Duration;
+
+#[test]
+fn push_message_from_resp() -> Result<()> {
+    let resp_buf = RespBuf::from_slice(b">2\r\n$10\r\ninvalidate\r\n*1\r\n$3\r\nkey\r\n");
+    let push_message = PushMessage::from_resp(&resp_buf)?;
+    assert_eq!(PushKind::Invalidate, push_message.kind);
+    assert_eq!(
+        vec![Value::Array(vec![Value::BulkString(b"key".to_vec())])],
+        push_message.data
+    );
+
+    let resp_buf = RespBuf::from_slice(b">2\r\n$6\r\nmodule\r\n:12\r\n");
+    let push_message = PushMessage::from_resp(&resp_buf)?;
+    assert_eq!(PushKind::Other("module".to_owned()), push_message.kind);
+    assert_eq!(vec![Value::Integer(12)], push_message.data);
+
+    let resp_buf = RespBuf::from_slice(b"*1\r\n$3\r\nkey\r\n");
+    assert!(PushMessage::from_resp(&resp_buf).is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn invalidation_push_message() -> Result<()> {
+    log_try_init();
+
+    let client1 = Client::connect(get_default_addr()).await?;
+    let client2 = get_test_client().await?;
+    client2.flushall(FlushingMode::Sync).await?;
+    client2.set("key", "value").await?;
+
+    let mut receiver = client1.on_push_message();
+    client1
+        .client_tracking(ClientTrackingStatus::On, ClientTrackingOptions::default())
+        .await?;
+
+    // track the key
+    let value: String = client1.get("key").await?;
+    assert_eq!("value", value);
+
+    client2.set("key", "new_value").await?;
+
+    let push_message = timeout(Duration::from_secs(1), receiver.recv())
+        .await?
+        .unwrap();
+    assert_eq!(PushKind::Invalidate, push_message.kind);
+    assert_eq!(
+        vec![Value::Array(vec![Value::BulkString(b"key".to_vec())])],
+        push_message.data
+    );
+
+    // the reply of the command is not mixed up with the push message
+    let value: String = client1.get("key").await?;
+    assert_eq!("new_value", value);
+
+    client1.close().await?;
+    client2.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn push_message_while_subscribed() -> Result<()> {
+    log_try_init();
+
+    let client1 = Client::connect(get_default_addr()).await?;
+    let client2 = get_test_client().await?;
+    client2.flushall(FlushingMode::Sync).await?;
+    client2.set("key", "value").await?;
+
+    let mut receiver = client1.on_push_message();
+    let mut pub_sub_stream = client1.subscribe("mychannel").await?;
+    client1
+        .client_tracking(ClientTrackingStatus::On, ClientTrackingOptions::default())
+        .await?;
+
+    // track the key
+    let value: String = client1.get("key").await?;
+    assert_eq!("value", value);
+
+    client2.set("key", "new_value").await?;
+    client2.publish("mychannel", "message").await?;
+
+    // the invalidation is dispatched out of band, not as the reply of the next command
+    let value: String = client1.get("key").await?;
+    assert_eq!("new_value", value);
+
+    let push_message = timeout(Duration::from_secs(1), receiver.recv())
+        .await?
+        .unwrap();
+    assert_eq!(PushKind::Invalidate, push_message.kind);
+
+    // pub/sub messages are delivered to their stream only
+    let message = pub_sub_stream.next().await.unwrap()?;
+    assert_eq!(b"mychannel".to_vec(), message.channel);
+    assert_eq!(b"message".to_vec(), message.payload);
+    assert!(receiver.try_recv().is_err());
+
+    pub_sub_stream.close().await?;
+    client1.close().await?;
+    client2.close().await?;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use crate::{
+    commands::GenericCommands,
+    tests::get_test_client,
+    utils::{RateLimitAlgorithm, RateLimiter},
+    Error, Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+async fn check_limit(algorithm: RateLimitAlgorithm) -> Result<()> {
+    let client = get_test_client().await?;
+    client.del("rate").await?;
+
+    let rate_limiter = RateLimiter::new(&client, algorithm, 3, Duration::from_secs(10));
+
+    for remaining in (0..3).rev() {
+        let decision = rate_limiter.check("rate").await?;
+        assert!(decision.allowed);
+        assert_eq!(remaining, decision.remaining);
+        assert!(decision.reset_after <= Duration::from_secs(10));
+    }
+
+    let decision = rate_limiter.check("rate").await?;
+    assert!(!decision.allowed);
+    assert_eq!(0, decision.remaining);
+    assert!(decision.reset_after > Duration::ZERO);
+    assert!(decision.reset_after <= Duration::from_secs(10));
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn fixed_window() -> Result<()> {
+    check_limit(RateLimitAlgorithm::FixedWindow).await
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn sliding_window_log() -> Result<()> {
+    check_limit(RateLimitAlgorithm::SlidingWindowLog).await
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn token_bucket() -> Result<()> {
+    check_limit(RateLimitAlgorithm::TokenBucket).await
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn token_bucket_refill() -> Result<()> {
+    let client = get_test_client().await?;
+    client.del("rate").await?;
+
+    // 1 token every 100ms
+    let rate_limiter = RateLimiter::token_bucket(&client, 10, Duration::from_secs(1));
+
+    for _ in 0..10 {
+        assert!(rate_limiter.check("rate").await?.allowed);
+    }
+    assert!(!rate_limiter.check("rate").await?.allowed);
+
+    crate::sleep(Duration::from_millis(150)).await;
+    assert!(rate_limiter.check("rate").await?.allowed);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn invalid_limit() -> Result<()> {
+    let client = get_test_client().await?;
+    client.del("rate").await?;
+
+    let rate_limiter = RateLimiter::token_bucket(&client, 0, Duration::from_secs(10));
+    let result = rate_limiter.check("rate").await;
+    assert!(matches!(result, Err(Error::Client(_))));
+    assert_eq!(0, client.exists("rate").await?);
+
+    for window in [Duration::ZERO, Duration::from_micros(999)] {
+        let rate_limiter = RateLimiter::fixed_window(&client, 10, window);
+        let result = rate_limiter.check("rate").await;
+        assert!(matches!(result, Err(Error::Client(_))));
+    }
+    assert_eq!(0, client.exists("rate").await?);
+
+    client.close().await?;
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use crate::{
+    commands::{
+        FlushingMode, HashCommands, ListCommands, ServerCommands, SetCommands, SortedSetCommands,
+        StreamCommands, StringCommands, XAddOptions, ZAddOptions,
+    },
+    resp::rdb::{crc64, inspect_dump, RdbEncoding, RdbValueType},
+    tests::get_test_client,
+    utils::backup_key,
+    Error, Result,
+};
+use serial_test::serial;
+
+fn make_payload(value: &[u8]) -> Vec<u8> {
+    let mut payload = value.to_vec();
+    payload.extend_from_slice(&10u16.to_le_bytes());
+    let crc = crc64(&payload);
+    payload.extend_from_slice(&crc.to_le_bytes());
+    payload
+}
+
+#[test]
+fn inspect_strings() -> Result<()> {
+    let info = inspect_dump(&make_payload(b"\x00\x05hello"))?;
+    assert_eq!(RdbValueType::String, info.value_type);
+    assert_eq!(RdbEncoding::Raw, info.encoding);
+    assert_eq!(10, info.rdb_version);
+    assert_eq!(Some(5), info.len);
+
+    // int8
+    let info = inspect_dump(&make_payload(b"\x00\xc0\x7b"))?;
+    assert_eq!(RdbEncoding::Int, info.encoding);
+    assert_eq!(Some(3), info.len);
+
+    // LZF compressed, 6 bytes expanded to 9
+    let info = inspect_dump(&make_payload(b"\x00\xc3\x06\x09\x02abc\x80\x02"))?;
+    assert_eq!(RdbEncoding::Lzf, info.encoding);
+    assert_eq!(Some(9), info.len);
+
+    Ok(())
+}
+
+#[test]
+fn inspect_collections() -> Result<()> {
+    // set as listpack: total bytes, 3 elements
+    let info = inspect_dump(&make_payload(b"\x14\x07\x07\x00\x00\x00\x03\x00\xff"))?;
+    assert_eq!(RdbValueType::Set, info.value_type);
+    assert_eq!(RdbEncoding::ListPack, info.encoding);
+    assert_eq!(Some(3), info.len);
+
+    // hash as LZF compressed listpack of 2 elements, i.e. 1 field
+    let info = inspect_dump(&make_payload(
+        b"\x10\xc3\x09\x09\x05\x0c\x00\x00\x00\x02\x00\x20\x01",
+    ))?;
+    assert_eq!(RdbValueType::Hash, info.value_type);
+    assert_eq!(Some(1), info.len);
+
+    // intset of 2 integers
+    let info = inspect_dump(&make_payload(
+        b"\x0b\x0c\x02\x00\x00\x00\x02\x00\x00\x00\x01\x00\x02\x00",
+    ))?;
+    assert_eq!(RdbValueType::Set, info.value_type);
+    assert_eq!(RdbEncoding::IntSet, info.encoding);
+    assert_eq!(Some(2), info.len);
+
+    // quicklist with one packed node of 4 elements and one plain node
+    let info = inspect_dump(&make_payload(
+        b"\x12\x02\x02\x07\x07\x00\x00\x00\x04\x00\xff\x01\x03big",
+    ))?;
+    assert_eq!(RdbValueType::List, info.value_type);
+    assert_eq!(RdbEncoding::QuickList, info.encoding);
+    assert_eq!(Some(5), info.len);
+
+    Ok(())
+}
+
+#[test]
+fn inspect_malformed() {
+    assert!(matches!(inspect_dump(b"\x00"), Err(Error::InvalidDump(_))));
+    assert!(matches!(
+        inspect_dump(&make_payload(b"\x00\x05hel")),
+        Err(Error::InvalidDump(_))
+    ));
+    assert!(matches!(
+        inspect_dump(&make_payload(b"\x63")),
+        Err(Error::InvalidDump(_))
+    ));
+    // LZF back reference before the start of the string
+    assert!(matches!(
+        inspect_dump(&make_payload(b"\x10\xc3\x06\x09\x02abc\x80\x09")),
+        Err(Error::InvalidDump(_))
+    ));
+    // LZF string declaring an uncompressed length it cannot expand to
+    assert!(matches!(
+        inspect_dump(&make_payload(
+            b"\x00\xc3\x02\x81\x40\x00\x00\x00\x00\x00\x00\x00\x00a"
+        )),
+        Err(Error::InvalidDump(_))
+    ));
+    assert!(matches!(
+        inspect_dump(&make_payload(
+            b"\x10\xc3\x02\x81\x40\x00\x00\x00\x00\x00\x00\x00\x00a"
+        )),
+        Err(Error::InvalidDump(_))
+    ));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn inspect_server_dumps() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    client.set("string", "value").await?;
+    client.set("int", 12345).await?;
+    client.rpush("list", ["a", "b", "c"]).await?;
+    client.sadd("intset", [1, 2, 3, 4]).await?;
+    client.sadd("set", ["a", "b"]).await?;
+    client
+        .zadd("zset", [(1., "a"), (2., "b")], ZAddOptions::default())
+        .await?;
+    client.hset("hash", [("f1", "v1"), ("f2", "v2")]).await?;
+    let _id: String = client
+        .xadd("stream", "*", [("f", "v")], XAddOptions::default())
+        .await?;
+    let _id: String = client
+        .xadd("stream", "*", [("f", "v")], XAddOptions::default())
+        .await?;
+
+    for (key, value_type, len) in [
+        ("string", RdbValueType::String, 5),
+        ("int", RdbValueType::String, 5),
+        ("list", RdbValueType::List, 3),
+        ("intset", RdbValueType::Set, 4),
+        ("set", RdbValueType::Set, 2),
+        ("zset", RdbValueType::SortedSet, 2),
+        ("hash", RdbValueType::Hash, 2),
+        ("stream", RdbValueType::Stream, 2),
+    ] {
+        let dump = backup_key(&client, key).await?.unwrap();
+        let info = dump.inspect()?;
+        assert_eq!(value_type, info.value_type, "{key}");
+        assert_eq!(Some(len), info.len, "{key}");
+    }
+
+    Ok(())
+}
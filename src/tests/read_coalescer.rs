@@ -0,0 +1,122 @@
+use crate::{
+    client::{Client, Interceptor, KeyPrefix, ReadCoalescer},
+    commands::{FlushingMode, ServerCommands, StringCommands},
+    resp::{cmd, Command, RespBuf},
+    sleep,
+    tests::get_test_client,
+    Result,
+};
+use futures_util::future::join_all;
+use serial_test::serial;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+#[derive(Default)]
+struct SentCounter(Arc<AtomicUsize>);
+
+impl Interceptor for SentCounter {
+    fn before_send(&self, _command: &mut Command) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn read_coalescer() -> Result<()> {
+    let coalescer = ReadCoalescer::new();
+    let num_sent = AtomicUsize::new(0);
+    let send = |_command: Command| {
+        num_sent.fetch_add(1, Ordering::SeqCst);
+        async {
+            // leave time for the other callers to wait for the pending reply
+            sleep(Duration::from_millis(10)).await;
+            Ok(RespBuf::ok())
+        }
+    };
+
+    let results = join_all([
+        coalescer.send(0, cmd("GET").arg("key"), send),
+        coalescer.send(0, cmd("GET").arg("key"), send),
+        coalescer.send(0, cmd("GET").arg("key"), send),
+        coalescer.send(0, cmd("GET").arg("other"), send),
+        coalescer.send(0, cmd("SET").arg("key").arg("value"), send),
+        coalescer.send(1, cmd("GET").arg("key"), send),
+    ])
+    .await;
+
+    assert!(results.iter().all(|r| r.is_ok()));
+    assert_eq!(4, num_sent.load(Ordering::SeqCst));
+
+    // the pending entry is removed once the reply has been fanned out
+    coalescer.send(0, cmd("GET").arg("key"), send).await?;
+    assert_eq!(5, num_sent.load(Ordering::SeqCst));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn read_coalescing() -> Result<()> {
+    let sent_counter = SentCounter::default();
+    let num_sent = sent_counter.0.clone();
+
+    let client = get_test_client()
+        .await?
+        .with_interceptor(sent_counter)
+        .with_read_coalescing();
+    client.flushall(FlushingMode::Sync).await?;
+    client.set("key", "value").await?;
+    num_sent.store(0, Ordering::SeqCst);
+
+    let values: Vec<Result<String>> = join_all((0..10).map(|_| {
+        let client: Client = client.clone();
+        async move { client.get("key").await }
+    }))
+    .await;
+
+    assert_eq!(1, num_sent.load(Ordering::SeqCst));
+    for value in values {
+        assert_eq!("value", value?);
+    }
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn read_coalescing_key_prefixes() -> Result<()> {
+    let client = get_test_client().await?.with_read_coalescing();
+    client.flushall(FlushingMode::Sync).await?;
+    client.set("a:key", "a").await?;
+    client.set("b:key", "b").await?;
+
+    let client_a = client.clone().with_interceptor(KeyPrefix::new("a:"));
+    let client_b = client.clone().with_interceptor(KeyPrefix::new("b:"));
+
+    let values: Vec<Result<String>> = join_all((0..10).map(|i| {
+        let client: Client = if i % 2 == 0 {
+            client_a.clone()
+        } else {
+            client_b.clone()
+        };
+        async move { client.get("key").await }
+    }))
+    .await;
+
+    for (i, value) in values.into_iter().enumerate() {
+        assert_eq!(if i % 2 == 0 { "a" } else { "b" }, value?);
+    }
+
+    client.close().await?;
+
+    Ok(())
+}
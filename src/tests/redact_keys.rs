@@ -0,0 +1,134 @@
+use crate::{
+    client::{Client, Interceptor, IntoConfig, RedactKeys},
+    commands::{FlushingMode, ServerCommands, StringCommands},
+    resp::{cmd, Command},
+    tests::{get_default_addr, start_log_capture, stop_log_capture},
+    Result,
+};
+use serial_test::serial;
+
+fn redact(command: Command) -> String {
+    let mut command = command;
+    RedactKeys::new(["token:*", "user:?:password"]).before_send(&mut command);
+    command.to_string()
+}
+
+#[test]
+fn before_send() {
+    assert_eq!(
+        r#""SET" "token:1" "***" "***" "***""#,
+        redact(cmd("SET").arg("token:1").arg("secret").arg("EX").arg(10))
+    );
+    assert_eq!(
+        r#""MSET" "user:1:password" "***" "key" "***""#,
+        redact(
+            cmd("MSET")
+                .arg("user:1:password")
+                .arg("secret")
+                .arg("key")
+                .arg("value")
+        )
+    );
+    assert_eq!(
+        r#""SET" "user:12:password" "value""#,
+        redact(cmd("SET").arg("user:12:password").arg("value"))
+    );
+    assert_eq!(
+        r#""SET" "key" "value""#,
+        redact(cmd("SET").arg("key").arg("value"))
+    );
+    assert_eq!(r#""GET" "token:1""#, redact(cmd("GET").arg("token:1")));
+}
+
+#[test]
+fn redacted_reply() {
+    let mut command = cmd("GET").arg("token:1");
+    RedactKeys::new(["token:*"]).before_send(&mut command);
+    assert!(command.is_reply_redacted());
+
+    let mut command = cmd("GET").arg("key");
+    RedactKeys::new(["token:*"]).before_send(&mut command);
+    assert!(!command.is_reply_redacted());
+}
+
+#[test]
+fn known_secrets() {
+    assert_eq!(
+        r#""AUTH" "***" "***""#,
+        cmd("AUTH").arg("user").arg("pwd").to_string()
+    );
+    assert_eq!(
+        r#""HELLO" "3" "AUTH" "***" "***" "SETNAME" "name""#,
+        cmd("HELLO")
+            .arg(3)
+            .arg("AUTH")
+            .arg("user")
+            .arg("pwd")
+            .arg("SETNAME")
+            .arg("name")
+            .to_string()
+    );
+    assert_eq!(
+        r#""CONFIG" "SET" "requirepass" "***" "maxmemory" "100mb""#,
+        cmd("CONFIG")
+            .arg("SET")
+            .arg("requirepass")
+            .arg("pwd")
+            .arg("maxmemory")
+            .arg("100mb")
+            .to_string()
+    );
+    assert_eq!(
+        r#""ACL" "SETUSER" "user" "on" "***" "~*""#,
+        cmd("ACL")
+            .arg("SETUSER")
+            .arg("user")
+            .arg("on")
+            .arg(">pwd")
+            .arg("~*")
+            .to_string()
+    );
+
+    let command = cmd("CUSTOM").arg("public").arg_redacted("secret");
+    assert_eq!(r#""CUSTOM" "public" "***""#, command.to_string());
+    assert!(!format!("{command:?}").contains("secret"));
+    assert_eq!(b"secret", command.args[1].as_slice());
+}
+
+#[test]
+fn config_redacted_key_patterns() -> Result<()> {
+    let config = "redis://127.0.0.1?redacted_key_patterns=token:*,session:*".into_config()?;
+    assert_eq!(vec!["token:*", "session:*"], config.redacted_key_patterns);
+    assert_eq!(
+        "redis://127.0.0.1?redacted_key_patterns=token:*,session:*",
+        config.to_string()
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn redacted_reply_logs() -> Result<()> {
+    let mut config = get_default_addr().into_config()?;
+    config.redacted_key_patterns = vec!["token:*".to_owned()];
+    let client = Client::connect(config).await?;
+    client.flushall(FlushingMode::Sync).await?;
+    client.set("token:1", "secret").await?;
+    client.set("key", "public").await?;
+
+    start_log_capture();
+    let secret: String = client.get("token:1").await?;
+    let public: String = client.get("key").await?;
+    let logs = stop_log_capture();
+
+    assert_eq!("secret", secret);
+    assert_eq!("public", public);
+    assert!(logs.iter().all(|log| !log.contains("secret")), "{logs:?}");
+    assert!(logs.iter().any(|log| log.contains("public")), "{logs:?}");
+
+    client.close().await?;
+
+    Ok(())
+}
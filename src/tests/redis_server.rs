@@ -0,0 +1,20 @@
+use crate::{commands::StringCommands, testing::RedisServer, Result};
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn start_and_drop() -> Result<()> {
+    let server = RedisServer::start_with_args(&["--maxmemory", "10mb"]).await?;
+    let dir = server.dir().to_path_buf();
+    assert!(dir.exists());
+
+    let client = server.client().await?;
+    client.set("key", "value").await?;
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+    client.close().await?;
+
+    drop(server);
+    assert!(!dir.exists());
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use crate::{
+    commands::{FlushingMode, ServerCommands},
+    sleep,
+    tests::get_test_client,
+    utils::ReliableQueue,
+    Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn reliable_queue() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let queue = ReliableQueue::new(&client, "jobs", "worker1");
+    queue.push("job1").await?;
+    queue.push("job2").await?;
+    assert_eq!(2, queue.push("job3").await?);
+
+    let next: Vec<String> = queue.peek(2).await?;
+    assert_eq!(vec!["job1".to_owned(), "job2".to_owned()], next);
+
+    let job: Option<String> = queue.pop(Duration::from_millis(100)).await?;
+    assert_eq!(Some("job1".to_owned()), job);
+    let job: Option<String> = queue.try_pop().await?;
+    assert_eq!(Some("job2".to_owned()), job);
+    assert_eq!(1, queue.len().await?);
+    assert_eq!(2, queue.processing_len().await?);
+
+    let processing: Vec<String> = queue.processing_items().await?;
+    assert_eq!(vec!["job1".to_owned(), "job2".to_owned()], processing);
+
+    assert!(queue.ack("job1").await?);
+    assert!(!queue.ack("job1").await?);
+    assert_eq!(1, queue.processing_len().await?);
+
+    let job: Option<String> = queue.try_pop().await?;
+    assert_eq!(Some("job3".to_owned()), job);
+    let job: Option<String> = queue.pop(Duration::from_millis(100)).await?;
+    assert_eq!(None, job);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn reliable_queue_requeue_stale() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let worker1 = ReliableQueue::new(&client, "jobs", "worker1")
+        .visibility_timeout(Duration::from_millis(100));
+    let worker2 = ReliableQueue::new(&client, "jobs", "worker2");
+
+    worker1.push("job1").await?;
+    worker1.push("job2").await?;
+    worker1.push("job3").await?;
+
+    let _: Option<String> = worker1.try_pop().await?;
+    let _: Option<String> = worker1.try_pop().await?;
+    let _: Option<String> = worker2.try_pop().await?;
+
+    // worker1 is still alive
+    assert_eq!(0, worker2.requeue_stale().await?);
+
+    sleep(Duration::from_millis(200)).await;
+    assert_eq!(2, worker2.requeue_stale().await?);
+    assert_eq!(0, worker1.processing_len().await?);
+    assert_eq!(1, worker2.processing_len().await?);
+
+    // requeued items keep their order and are popped first
+    let job: Option<String> = worker2.try_pop().await?;
+    assert_eq!(Some("job1".to_owned()), job);
+    let job: Option<String> = worker2.try_pop().await?;
+    assert_eq!(Some("job2".to_owned()), job);
+
+    // worker1 has lost its items
+    assert!(!worker1.ack("job1").await?);
+
+    client.close().await?;
+
+    Ok(())
+}
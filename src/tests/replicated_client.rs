@@ -0,0 +1,125 @@
+use crate::{
+    client::{is_read_only_command, ReadFrom, ReplicatedClient},
+    commands::{ConnectionCommands, FlushingMode, ServerCommands, StringCommands},
+    resp::cmd,
+    tests::{get_default_addr, log_try_init},
+    Error, Result,
+};
+use serial_test::serial;
+
+#[test]
+fn read_only_commands() {
+    assert!(is_read_only_command(&cmd("GET")));
+    assert!(is_read_only_command(&cmd("HGETALL")));
+    assert!(is_read_only_command(&cmd("ZRANGE")));
+    assert!(is_read_only_command(&cmd("EVAL_RO")));
+    assert!(!is_read_only_command(&cmd("SET")));
+    assert!(!is_read_only_command(&cmd("EVAL")));
+    assert!(!is_read_only_command(&cmd("FLUSHDB")));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn round_robin() -> Result<()> {
+    log_try_init();
+
+    let client = ReplicatedClient::connect(
+        get_default_addr(),
+        [get_default_addr(), get_default_addr()],
+        ReadFrom::RoundRobin,
+    )
+    .await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client.set("key", "value").await?;
+    for _ in 0..4 {
+        let value: String = client.get("key").await?;
+        assert_eq!("value", value);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn lowest_latency() -> Result<()> {
+    log_try_init();
+
+    let client = ReplicatedClient::connect(
+        get_default_addr(),
+        ["127.0.0.1:1".to_owned(), get_default_addr()],
+        ReadFrom::LowestLatency,
+    )
+    .await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client.set("key", "value").await?;
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn fallback_to_master() -> Result<()> {
+    log_try_init();
+
+    // unreachable replica
+    let client =
+        ReplicatedClient::connect(get_default_addr(), ["127.0.0.1:1"], ReadFrom::RoundRobin)
+            .await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client.set("key", "value").await?;
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn skip_failed_replica() -> Result<()> {
+    log_try_init();
+
+    let client = ReplicatedClient::connect(
+        get_default_addr(),
+        ["127.0.0.1:1".to_owned(), get_default_addr()],
+        ReadFrom::RoundRobin,
+    )
+    .await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client.set("key", "value").await?;
+    // the unreachable replica fails once, then is skipped
+    for _ in 0..4 {
+        let value: String = client.get("key").await?;
+        assert_eq!("value", value);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn select_refused() -> Result<()> {
+    log_try_init();
+
+    let client = ReplicatedClient::connect(
+        get_default_addr(),
+        [get_default_addr()],
+        ReadFrom::RoundRobin,
+    )
+    .await?;
+
+    let result = client.select(1).await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    Ok(())
+}
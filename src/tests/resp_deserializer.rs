@@ -1,6 +1,9 @@
 use crate::{
-    resp::RespDeserializer, tests::log_try_init, Error, RedisError, RedisErrorKind, Result,
+    resp::{BulkString, RespDeserializer},
+    tests::log_try_init,
+    Error, RedisError, RedisErrorKind, Result,
 };
+use bytes::Bytes;
 use serde::Deserialize;
 use smallvec::SmallVec;
 use std::collections::HashMap;
@@ -97,6 +100,45 @@ fn integer() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn fast_path() -> Result<()> {
+    log_try_init();
+
+    deserialize::<()>("+OK\r\n")?;
+
+    let result: bool = deserialize("+OK\r\n")?;
+    assert!(result);
+
+    let result: bool = deserialize(":0\r\n")?;
+    assert!(!result);
+
+    let result: i64 = deserialize(":-12\r\n")?;
+    assert_eq!(-12, result);
+
+    let result: i64 = deserialize(":999999999999999999\r\n")?; // 18 digits
+    assert_eq!(999_999_999_999_999_999, result);
+
+    let result: i64 = deserialize(":9223372036854775807\r\n")?; // 19 digits
+    assert_eq!(i64::MAX, result);
+
+    let result: Result<i64> = deserialize(":99999999999999999999\r\n"); // overflow
+    assert!(result.is_err());
+
+    let result: String = deserialize("$5\r\nhello\r\n")?;
+    assert_eq!("hello", result);
+
+    let result: &str = deserialize("$0\r\n\r\n")?;
+    assert_eq!("", result);
+
+    let result: String = deserialize("$10\r\nhelloworld\r\n")?;
+    assert_eq!("helloworld", result);
+
+    let result: Result<String> = deserialize("$5\r\nhello");
+    assert!(matches!(result, Err(Error::EOF)));
+
+    Ok(())
+}
+
 #[test]
 fn float() -> Result<()> {
     log_try_init();
@@ -200,6 +242,39 @@ fn string() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn binary() -> Result<()> {
+    log_try_init();
+
+    let buf = b"$4\r\n\xff\x00\xfe\x01\r\n";
+
+    let result: Vec<u8> = Vec::<u8>::deserialize(&mut RespDeserializer::new(buf))?;
+    assert_eq!(vec![0xff, 0x00, 0xfe, 0x01], result);
+
+    let result: Bytes = Bytes::deserialize(&mut RespDeserializer::new(buf))?;
+    assert_eq!(&[0xff, 0x00, 0xfe, 0x01][..], &result[..]);
+
+    let result: BulkString = BulkString::deserialize(&mut RespDeserializer::new(buf))?;
+    assert_eq!(&[0xff, 0x00, 0xfe, 0x01][..], result.as_bytes());
+
+    // not valid UTF-8
+    let result: Result<String> = String::deserialize(&mut RespDeserializer::new(buf));
+    assert!(result.is_err());
+
+    let result: Vec<u8> = deserialize("_\r\n")?; // nil
+    assert!(result.is_empty());
+
+    // the buffer is allocated once
+    let mut buf = b"$100000\r\n".to_vec();
+    buf.extend(vec![b'a'; 100_000]);
+    buf.extend(b"\r\n");
+    let result: Vec<u8> = Vec::<u8>::deserialize(&mut RespDeserializer::new(&buf))?;
+    assert_eq!(100_000, result.len());
+    assert_eq!(100_000, result.capacity());
+
+    Ok(())
+}
+
 #[test]
 fn option() -> Result<()> {
     log_try_init();
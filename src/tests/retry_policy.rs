@@ -0,0 +1,89 @@
+use crate::{
+    client::{Client, IntoConfig, RetryPolicy},
+    commands::StringCommands,
+    resp::cmd,
+    tests::{get_default_addr, log_try_init},
+    Error, RedisError, RedisErrorKind, Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+#[test]
+fn is_retryable() {
+    let retry_policy = RetryPolicy::default();
+
+    assert!(retry_policy.is_retryable(&cmd("GET").arg("key")));
+    assert!(retry_policy.is_retryable(&cmd("EXISTS").arg("key")));
+    assert!(retry_policy.is_retryable(&cmd("TTL").arg("key")));
+    assert!(retry_policy.is_retryable(&cmd("ZRANGE").arg("key").arg(0).arg(-1)));
+    assert!(!retry_policy.is_retryable(&cmd("SET").arg("key").arg("value")));
+    assert!(!retry_policy.is_retryable(&cmd("INCR").arg("key")));
+    assert!(!retry_policy.is_retryable(&cmd("LPUSH").arg("key").arg("value")));
+}
+
+#[test]
+fn is_transient() {
+    let retry_policy = RetryPolicy::default();
+
+    let redis_error = |kind| {
+        Error::Redis(RedisError {
+            kind,
+            description: String::new(),
+        })
+    };
+
+    assert!(retry_policy.is_transient(&Error::IO("connection reset".to_owned())));
+    assert!(retry_policy.is_transient(&redis_error(RedisErrorKind::Loading)));
+    assert!(retry_policy.is_transient(&redis_error(RedisErrorKind::ClusterDown)));
+    assert!(retry_policy.is_transient(&redis_error(RedisErrorKind::TryAgain)));
+    assert!(!retry_policy.is_transient(&redis_error(RedisErrorKind::WrongType)));
+    assert!(!retry_policy.is_transient(&Error::Client("error".to_owned())));
+}
+
+#[test]
+fn backoff() {
+    let retry_policy = RetryPolicy {
+        max_attempts: 10,
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_millis(1_000),
+    };
+
+    assert_eq!(Duration::from_millis(100), retry_policy.backoff(1));
+    assert_eq!(Duration::from_millis(200), retry_policy.backoff(2));
+    assert_eq!(Duration::from_millis(400), retry_policy.backoff(3));
+    assert_eq!(Duration::from_millis(1_000), retry_policy.backoff(5));
+    assert_eq!(Duration::from_millis(1_000), retry_policy.backoff(100));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn client_with_retry_policy() -> Result<()> {
+    log_try_init();
+
+    let mut config = get_default_addr().into_config()?;
+    config.retry_policy = Some(RetryPolicy::default());
+    let client = Client::connect(config).await?;
+
+    client.set("key", "value").await?;
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+
+    // non transient errors are not retried
+    client.set("key", "value").await?;
+    let result: Result<Vec<String>> = client
+        .send(cmd("LRANGE").arg("key").arg(0).arg(-1), None)
+        .await?
+        .to();
+    assert!(matches!(
+        result,
+        Err(Error::Redis(RedisError {
+            kind: RedisErrorKind::WrongType,
+            description: _
+        }))
+    ));
+
+    client.close().await?;
+
+    Ok(())
+}
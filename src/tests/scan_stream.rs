@@ -0,0 +1,56 @@
+use crate::{
+    commands::{FlushingMode, ScanOptions, ServerCommands, StringCommands},
+    tests::get_test_client,
+    utils::{is_cluster_support_disabled, keys_safe, scan_stream},
+    Error, RedisError, Result,
+};
+use futures_util::TryStreamExt;
+use serial_test::serial;
+use std::{collections::HashSet, str::FromStr};
+
+#[test]
+fn cluster_support_disabled() -> Result<()> {
+    let error = Error::Redis(RedisError::from_str(
+        "ERR This instance has cluster support disabled",
+    )?);
+    assert!(is_cluster_support_disabled(&error));
+
+    let error = Error::Redis(RedisError::from_str(
+        "NOPERM User has no permissions to run the 'cluster|shards' command",
+    )?);
+    assert!(!is_cluster_support_disabled(&error));
+    assert!(!is_cluster_support_disabled(&Error::Client(
+        "cluster support disabled".to_owned()
+    )));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn scan_stream_standalone() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    for i in 0..50 {
+        client.set(format!("scan:{i}"), i).await?;
+    }
+    client.set("other", "value").await?;
+
+    let keys: HashSet<String> = scan_stream(
+        &client,
+        ScanOptions::default().match_pattern("scan:*").count(10),
+    )
+    .try_collect()
+    .await?;
+    assert_eq!(50, keys.len());
+    assert!(keys.contains("scan:42"));
+
+    let keys = keys_safe(&client, "other").await?;
+    assert_eq!(vec!["other".to_owned()], keys);
+
+    client.flushall(FlushingMode::Sync).await?;
+
+    Ok(())
+}
@@ -4,8 +4,8 @@ use crate::{
         AclCatOptions, AclDryRunOptions, AclGenPassOptions, AclLogOptions, BlockingCommands,
         ClientInfo, ClientKillOptions, CommandDoc, CommandHistogram, CommandListOptions,
         ConnectionCommands, FailOverOptions, FlushingMode, InfoSection, LatencyHistoryEvent,
-        MemoryUsageOptions, ModuleInfo, ModuleLoadOptions, ReplicaOfOptions, RoleResult,
-        ServerCommands, SlowLogOptions, StringCommands,
+        LolWutOptions, MemoryUsageOptions, ModuleInfo, ModuleLoadOptions, ReplicaOfOptions,
+        RoleResult, ServerCommands, SlowLogOptions, StringCommands,
     },
     resp::{cmd, Value},
     spawn,
@@ -773,6 +773,29 @@ async fn lolwut() -> Result<()> {
     let report = client.lolwut(Default::default()).await?;
     assert!(!report.is_empty());
 
+    let report = client.lolwut(LolWutOptions::default().version(5)).await?;
+    assert!(!report.is_empty());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn command_raw() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let count: usize = client.command_raw("COMMAND", "COUNT").await?;
+    assert_eq!(client.command_count().await?, count);
+
+    client.set("key", "value").await?;
+    let usage: Option<usize> = client.command_raw("MEMORY", ["USAGE", "key"]).await?;
+    assert!(usage.is_some());
+
+    let result: Result<Value> = client.command_raw("CONFIG", "UNKNOWN").await;
+    assert!(matches!(result, Err(Error::Redis(_))));
+
     Ok(())
 }
 
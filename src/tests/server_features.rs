@@ -0,0 +1,64 @@
+use crate::{
+    client::{Client, ServerFeatures},
+    commands::ExpireOption,
+    resp::cmd,
+    tests::{get_default_addr, get_test_client},
+    Error, Result,
+};
+use serial_test::serial;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn server_features() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let server_features = client.server_features().unwrap();
+    assert_eq!("redis", server_features.server);
+    assert!(server_features.is_at_least(server_features.major, server_features.minor));
+    assert!(!server_features.has_module("unknown-module"));
+
+    let client = Client::connect_lazy(get_default_addr())?;
+    assert!(client.server_features().is_none());
+    client.send(cmd("PING"), None).await?;
+    assert!(client.server_features().is_some());
+
+    Ok(())
+}
+
+#[test]
+fn check_command() {
+    let server_features = ServerFeatures::new("redis".to_owned(), "6.2.14".to_owned(), Vec::new());
+    assert_eq!(
+        (6, 2, 14),
+        (
+            server_features.major,
+            server_features.minor,
+            server_features.revision
+        )
+    );
+
+    assert!(matches!(
+        server_features.check_command(&cmd("SINTERCARD").arg(2).arg("key1").arg("key2")),
+        Err(Error::UnsupportedByServer(_))
+    ));
+    assert!(matches!(
+        server_features.check_command(&cmd("EXPIRE").arg("key").arg(10).arg(ExpireOption::Nx)),
+        Err(Error::UnsupportedByServer(_))
+    ));
+    assert!(server_features
+        .check_command(&cmd("EXPIRE").arg("key").arg(10))
+        .is_ok());
+    assert!(server_features
+        .check_command(&cmd("UNLINK").arg("key"))
+        .is_ok());
+    assert!(server_features
+        .check_command(&cmd("OBJECT").arg("FREQ").arg("key"))
+        .is_ok());
+
+    // unknown versions are not checked
+    let server_features = ServerFeatures::new("keydb".to_owned(), "custom".to_owned(), Vec::new());
+    assert!(server_features
+        .check_command(&cmd("SINTERCARD").arg(1).arg("key"))
+        .is_ok());
+}
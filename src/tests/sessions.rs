@@ -0,0 +1,95 @@
+use crate::{
+    commands::{FlushingMode, GenericCommands, ServerCommands},
+    sessions::RedisSessionStore,
+    tests::get_test_client,
+    utils::JsonSerializer,
+    Result,
+};
+use serde::{Deserialize, Serialize};
+use serial_test::serial;
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct UserSession {
+    user_id: u64,
+    theme: String,
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn session_lifecycle() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let store = RedisSessionStore::new(&client, JsonSerializer).with_prefix("sess:");
+
+    let session_id = store
+        .create(&UserSession {
+            user_id: 12,
+            theme: "dark".to_owned(),
+        })
+        .await?;
+    assert_eq!(32, session_id.len());
+    assert_eq!(1, client.exists(format!("sess:{session_id}")).await?);
+
+    let session: Option<UserSession> = store.load(&session_id).await?;
+    assert_eq!(
+        Some(UserSession {
+            user_id: 12,
+            theme: "dark".to_owned()
+        }),
+        session
+    );
+
+    let updated = store
+        .update(
+            &session_id,
+            &UserSession {
+                user_id: 12,
+                theme: "light".to_owned(),
+            },
+        )
+        .await?;
+    assert!(updated);
+    let session: Option<UserSession> = store.load(&session_id).await?;
+    assert_eq!("light", session.unwrap().theme);
+
+    assert!(store.destroy(&session_id).await?);
+    assert!(!store.destroy(&session_id).await?);
+    let session: Option<UserSession> = store.load(&session_id).await?;
+    assert_eq!(None, session);
+    assert!(!store.update(&session_id, &"unknown").await?);
+    assert!(!store.touch(&session_id).await?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn session_rolling_expiration() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let store = RedisSessionStore::new(&client, JsonSerializer)
+        .with_ttl(Duration::from_secs(100))
+        .with_rolling_expiration(true);
+    let session_id = store.create(&"data").await?;
+    let key = format!("session:{session_id}");
+
+    client.pexpire(&key, 10_000, Default::default()).await?;
+    let _: Option<String> = store.load(&session_id).await?;
+    assert!(client.pttl(&key).await? > 10_000);
+
+    client.pexpire(&key, 10_000, Default::default()).await?;
+    assert!(store.touch(&session_id).await?);
+    assert!(client.pttl(&key).await? > 10_000);
+
+    let store = store.with_rolling_expiration(false);
+    client.pexpire(&key, 10_000, Default::default()).await?;
+    let _: Option<String> = store.load(&session_id).await?;
+    assert!(client.pttl(&key).await? <= 10_000);
+
+    Ok(())
+}
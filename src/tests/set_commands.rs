@@ -118,6 +118,32 @@ async fn sintercard() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn intersection_size() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del(["key1", "key2", "key3"]).await?;
+
+    client.sadd("key1", ["a", "b", "c", "d"]).await?;
+    client.sadd("key2", ["a", "c", "d", "f"]).await?;
+    client.sadd("key3", ["a", "c", "d", "e"]).await?;
+
+    let len = client
+        .intersection_size(["key1", "key2", "key3"], 0)
+        .await?;
+    assert_eq!(3, len);
+
+    let len = client
+        .intersection_size(["key1", "key2", "key3"], 2)
+        .await?;
+    assert_eq!(2, len);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
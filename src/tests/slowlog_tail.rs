@@ -0,0 +1,52 @@
+use crate::{
+    commands::{ServerCommands, StringCommands},
+    sleep, spawn,
+    tests::get_test_client,
+    utils::slowlog_tail,
+    Result,
+};
+use futures_util::StreamExt;
+use serial_test::serial;
+use std::{collections::HashMap, time::Duration};
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn slowlog_tail_yields_new_entries() -> Result<()> {
+    let client = get_test_client().await?;
+    let tail_client = get_test_client().await?;
+
+    let config: HashMap<String, String> = client.config_get("slowlog-log-slower-than").await?;
+    client.config_set(("slowlog-log-slower-than", 0)).await?;
+
+    client.set("slowlog_tail:before", "value").await?;
+
+    let mut entries = Box::pin(slowlog_tail(&tail_client, Duration::from_millis(10)));
+
+    // the first poll only records the entries already in the slow log
+    let writer = client.clone();
+    spawn(async move {
+        sleep(Duration::from_millis(100)).await;
+        writer.set("slowlog_tail:after", "value").await.unwrap();
+    });
+
+    let mut found = false;
+    while let Some(entry) = entries.next().await {
+        let entry = entry?;
+        assert!(!entry.command.contains(&"slowlog_tail:before".to_owned()));
+        if entry.command.contains(&"slowlog_tail:after".to_owned()) {
+            found = true;
+            break;
+        }
+    }
+    assert!(found);
+
+    client
+        .config_set((
+            "slowlog-log-slower-than",
+            config["slowlog-log-slower-than"].as_str(),
+        ))
+        .await?;
+
+    Ok(())
+}
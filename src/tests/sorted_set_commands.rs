@@ -1,9 +1,10 @@
 use crate::{
     commands::{
-        BZpopMinMaxResult, BlockingCommands, FlushingMode, GenericCommands, ServerCommands,
-        SortedSetCommands, ZAddOptions, ZRangeOptions, ZRangeSortBy, ZScanOptions, ZScanResult,
-        ZWhere,
+        BZpopMinMaxResult, BlockingCommands, FlushingMode, GenericCommands, LexBound, ScoreBound,
+        ServerCommands, SortedSetCommands, ZAddComparison, ZAddCondition, ZAddOptions,
+        ZRangeOptions, ZRangeSortBy, ZScanOptions, ZScanResult, ZWhere,
     },
+    resp::CommandArgs,
     sleep, spawn,
     tests::get_test_client,
     Result,
@@ -279,6 +280,82 @@ async fn zadd() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn zadd_incr() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del("key").await?;
+
+    let score = client
+        .zadd_incr(
+            "key",
+            ZAddCondition::None,
+            ZAddComparison::None,
+            false,
+            1.5,
+            "one",
+        )
+        .await?;
+    assert_eq!(Some(1.5), score);
+
+    // NX: the member already exists
+    let score = client
+        .zadd_incr(
+            "key",
+            ZAddCondition::NX,
+            ZAddComparison::None,
+            false,
+            1.,
+            "one",
+        )
+        .await?;
+    assert_eq!(None, score);
+
+    // XX: the member does not exist
+    let score = client
+        .zadd_incr(
+            "key",
+            ZAddCondition::XX,
+            ZAddComparison::None,
+            false,
+            1.,
+            "two",
+        )
+        .await?;
+    assert_eq!(None, score);
+
+    // GT: the new score would be lower
+    let score = client
+        .zadd_incr(
+            "key",
+            ZAddCondition::None,
+            ZAddComparison::GT,
+            false,
+            -1.,
+            "one",
+        )
+        .await?;
+    assert_eq!(None, score);
+
+    // LT: the new score is lower
+    let score = client
+        .zadd_incr(
+            "key",
+            ZAddCondition::None,
+            ZAddComparison::LT,
+            false,
+            -1.,
+            "one",
+        )
+        .await?;
+    assert_eq!(Some(0.5), score);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -321,6 +398,16 @@ async fn zcount() -> Result<()> {
     let len = client.zcount("key", "(1", 3).await?;
     assert_eq!(2, len);
 
+    let len = client
+        .zcount("key", ScoreBound::NegInfinity, ScoreBound::PosInfinity)
+        .await?;
+    assert_eq!(3, len);
+
+    let len = client
+        .zcount("key", ScoreBound::Exclusive(1.), ScoreBound::Inclusive(3.))
+        .await?;
+    assert_eq!(2, len);
+
     Ok(())
 }
 
@@ -521,9 +608,50 @@ async fn zlexcount() -> Result<()> {
     let len = client.zlexcount("key", "[b", "[f").await?;
     assert_eq!(5, len);
 
+    let len = client
+        .zlexcount("key", LexBound::NegInfinity, LexBound::PosInfinity)
+        .await?;
+    assert_eq!(7, len);
+
+    let len = client
+        .zlexcount("key", LexBound::inclusive("b"), LexBound::exclusive("f"))
+        .await?;
+    assert_eq!(4, len);
+
     Ok(())
 }
 
+#[test]
+fn score_and_lex_bounds() {
+    let args = CommandArgs::default()
+        .arg(ScoreBound::Inclusive(1.5))
+        .arg(ScoreBound::Exclusive(2.))
+        .arg(ScoreBound::Exclusive(f64::NEG_INFINITY))
+        .arg(ScoreBound::NegInfinity)
+        .arg(ScoreBound::PosInfinity)
+        .arg(LexBound::inclusive("a"))
+        .arg(LexBound::exclusive("b"))
+        .arg(LexBound::NegInfinity)
+        .arg(LexBound::PosInfinity)
+        .build();
+
+    let args: Vec<&[u8]> = args.into_iter().collect();
+    assert_eq!(
+        vec![
+            b"1.5".as_slice(),
+            b"(2",
+            b"(-inf",
+            b"-inf",
+            b"+inf",
+            b"[a",
+            b"(b",
+            b"-",
+            b"+"
+        ],
+        args
+    );
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
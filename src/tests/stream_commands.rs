@@ -1,9 +1,10 @@
 use crate::{
     commands::{
-        FlushingMode, ServerCommands, StreamCommands, StreamEntry, XAddOptions, XAutoClaimOptions,
-        XAutoClaimResult, XGroupCreateOptions, XInfoStreamOptions, XPendingOptions,
-        XReadGroupOptions, XReadOptions, XTrimOperator, XTrimOptions,
+        FlushingMode, ServerCommands, StreamCommands, StreamEntry, StreamId, StreamPosition,
+        XAddOptions, XAutoClaimOptions, XAutoClaimResult, XGroupCreateOptions, XInfoStreamOptions,
+        XPendingOptions, XReadGroupOptions, XReadOptions, XTrimOperator, XTrimOptions,
     },
+    resp::CommandArgs,
     tests::get_test_client,
     Result,
 };
@@ -137,6 +138,89 @@ async fn xgroup() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn xinfo_stream_full() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client
+        .xgroup_create(
+            "mystream",
+            "mygroup",
+            "$",
+            XGroupCreateOptions::default().mk_stream(),
+        )
+        .await?;
+
+    let id1: String = client
+        .xadd(
+            "mystream",
+            "*",
+            ("message", "apple"),
+            XAddOptions::default(),
+        )
+        .await?;
+    let id2: String = client
+        .xadd(
+            "mystream",
+            "*",
+            ("message", "orange"),
+            XAddOptions::default(),
+        )
+        .await?;
+
+    let _results: Vec<(String, Vec<StreamEntry<String>>)> = client
+        .xreadgroup(
+            "mygroup",
+            "Bob",
+            XReadGroupOptions::default().count(1),
+            "mystream",
+            ">",
+        )
+        .await?;
+
+    let result = client.xinfo_stream_full("mystream", None).await?;
+    assert_eq!(2, result.length);
+    assert_eq!(id2, result.last_generated_id);
+    assert_eq!(Some(2), result.entries_added);
+    assert_eq!(2, result.entries.len());
+    assert_eq!(id1, result.entries[0].stream_id);
+    assert_eq!(
+        Some(&"apple".to_owned()),
+        result.entries[0].items.get("message")
+    );
+    assert_eq!(1, result.groups.len());
+
+    let group = &result.groups[0];
+    assert_eq!("mygroup", group.name);
+    assert_eq!(id1, group.last_delivered_id);
+    assert_eq!(Some(1), group.entries_read);
+    assert_eq!(Some(1), group.lag);
+    assert_eq!(1, group.pel_count);
+    assert_eq!(1, group.pel.len());
+    assert_eq!(id1, group.pel[0].id);
+    assert_eq!("Bob", group.pel[0].consumer);
+    assert_eq!(1, group.pel[0].delivery_count);
+
+    assert_eq!(1, group.consumers.len());
+    let consumer = &group.consumers[0];
+    assert_eq!("Bob", consumer.name);
+    assert!(consumer.seen_time_millis > 0);
+    assert_eq!(1, consumer.pel_count);
+    assert_eq!(id1, consumer.pel[0].id);
+    assert_eq!(
+        group.pel[0].delivery_time_millis,
+        consumer.pel[0].delivery_time_millis
+    );
+
+    let result = client.xinfo_stream_full("mystream", Some(1)).await?;
+    assert_eq!(1, result.entries.len());
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -847,3 +931,74 @@ async fn xadd_ignore_result() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn stream_id() -> Result<()> {
+    let id: StreamId = "1526919030474-55".parse()?;
+    assert_eq!(StreamId::new(1526919030474, 55), id);
+    assert_eq!("1526919030474-55", id.to_string());
+    assert_eq!(StreamId::new(1526919030474, 0), "1526919030474".parse()?);
+    assert!("1526919030474-".parse::<StreamId>().is_err());
+    assert!("*".parse::<StreamId>().is_err());
+
+    assert!(StreamId::new(1, 2) < StreamId::new(1, 3));
+    assert!(StreamId::new(1, u64::MAX) < StreamId::new(2, 0));
+
+    assert_eq!(Some(StreamId::new(1, 3)), StreamId::new(1, 2).next());
+    assert_eq!(Some(StreamId::new(2, 0)), StreamId::new(1, u64::MAX).next());
+    assert_eq!(None, StreamId::MAX.next());
+    assert_eq!(Some(StreamId::new(1, 1)), StreamId::new(1, 2).prev());
+    assert_eq!(Some(StreamId::new(0, u64::MAX)), StreamId::new(1, 0).prev());
+    assert_eq!(None, StreamId::MIN.prev());
+
+    let args = CommandArgs::default()
+        .arg(StreamPosition::Min)
+        .arg(StreamPosition::Max)
+        .arg(StreamPosition::Last)
+        .arg(StreamPosition::New)
+        .arg(StreamPosition::from(id))
+        .build();
+    assert_eq!(
+        vec![
+            b"-".to_vec(),
+            b"+".to_vec(),
+            b"$".to_vec(),
+            b">".to_vec(),
+            b"1526919030474-55".to_vec()
+        ],
+        args.iter().cloned().collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn xadd_stream_id() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let id1: StreamId = client
+        .xadd(
+            "mystream",
+            StreamId::new(123456, 0),
+            [("name", "John")],
+            XAddOptions::default(),
+        )
+        .await?;
+    assert_eq!(StreamId::new(123456, 0), id1);
+
+    let id2: StreamId = client
+        .xadd("mystream", "*", [("name", "Jane")], XAddOptions::default())
+        .await?;
+    assert!(id1 < id2);
+
+    let entries: Vec<StreamEntry<String>> = client
+        .xrange("mystream", id1.next().unwrap(), StreamPosition::Max, None)
+        .await?;
+    assert_eq!(1, entries.len());
+    assert_eq!(id2, entries[0].stream_id.parse()?);
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::{
+    client::Client,
+    commands::{FlushingMode, ServerCommands, StreamCommands, StreamEntry, XAddOptions},
+    tests::{get_default_addr, get_test_client},
+    utils::StreamConsumer,
+    Result,
+};
+use futures_util::StreamExt;
+use serial_test::serial;
+use std::time::Duration;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn stream_consumer() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+    let consumer_client = Client::connect(get_default_addr()).await?;
+
+    let consumer = StreamConsumer::new(&consumer_client, "mystream", "mygroup", "consumer1")
+        .block(Duration::from_millis(100));
+    consumer.create_group().await?;
+    // creating the group twice is not an error
+    consumer.create_group().await?;
+
+    let id: String = client
+        .xadd("mystream", "*", ("field", "value"), XAddOptions::default())
+        .await?;
+
+    let mut messages = Box::pin(consumer.into_stream());
+    let message = messages.next().await.unwrap()?;
+    assert_eq!(id, message.stream_id);
+    assert_eq!(Some(&"value".to_owned()), message.items.get("field"));
+
+    message.ack().await?;
+    let pending = client.xpending("mystream", "mygroup").await?;
+    assert_eq!(0, pending.num_pending_messages);
+
+    consumer_client.close().await?;
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn stream_consumer_reclaim_and_dead_letter() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+    let consumer_client = Client::connect(get_default_addr()).await?;
+
+    let new_consumer = |name: &str| {
+        StreamConsumer::new(&consumer_client, "mystream", "mygroup", name)
+            .block(Duration::from_millis(100))
+            .min_idle_time(Duration::ZERO)
+            .dead_letter(2, "mystream:dead")
+    };
+    new_consumer("consumer1").create_group().await?;
+
+    let id: String = client
+        .xadd("mystream", "*", ("field", "value"), XAddOptions::default())
+        .await?;
+
+    // 1st delivery, never acknowledged
+    let mut messages = Box::pin(new_consumer("consumer1").into_stream());
+    let message = messages.next().await.unwrap()?;
+    assert_eq!(id, message.stream_id);
+
+    // 2nd delivery, reclaimed by another consumer
+    let mut messages = Box::pin(new_consumer("consumer2").into_stream());
+    let message = messages.next().await.unwrap()?;
+    assert_eq!(id, message.stream_id);
+
+    // 3rd delivery would exceed the max, the entry is moved to the dead-letter stream
+    let mut messages = Box::pin(new_consumer("consumer3").into_stream());
+    client
+        .xadd::<_, _, _, _, _, String>("mystream", "*", ("field", "value2"), XAddOptions::default())
+        .await?;
+    let message = messages.next().await.unwrap()?;
+    assert_eq!(Some(&"value2".to_owned()), message.items.get("field"));
+
+    let dead: Vec<StreamEntry<String>> = client.xrange("mystream:dead", "-", "+", None).await?;
+    assert_eq!(1, dead.len());
+    assert_eq!(Some(&"value".to_owned()), dead[0].items.get("field"));
+
+    consumer_client.close().await?;
+    client.close().await?;
+
+    Ok(())
+}
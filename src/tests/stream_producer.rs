@@ -0,0 +1,113 @@
+use crate::{
+    commands::{FlushingMode, ServerCommands, StreamCommands, StreamEntry},
+    network::timeout,
+    tests::get_test_client,
+    utils::{StreamProducer, StreamProducerConfig},
+    Result,
+};
+use futures_util::future::try_join_all;
+use serial_test::serial;
+use std::time::Duration;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn stream_producer() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let producer = StreamProducer::new(
+        &client,
+        "mystream",
+        StreamProducerConfig {
+            batch_size: 3,
+            linger: Duration::from_millis(50),
+            capacity: 10,
+        },
+    );
+
+    let mut ids = Vec::new();
+    for i in 0..5 {
+        ids.push(producer.send([("field", i)]).await?);
+    }
+    let ids = try_join_all(ids).await?;
+    assert_eq!(5, ids.len());
+
+    producer.close().await?;
+
+    let entries: Vec<StreamEntry<String>> = client.xrange("mystream", "-", "+", None).await?;
+    assert_eq!(5, entries.len());
+    for (i, (entry, id)) in entries.iter().zip(ids).enumerate() {
+        assert_eq!(id, entry.stream_id);
+        assert_eq!(Some(&i.to_string()), entry.items.get("field"));
+    }
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn stream_producer_close() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let producer = StreamProducer::new(
+        &client,
+        "mystream",
+        StreamProducerConfig {
+            linger: Duration::from_secs(60),
+            ..Default::default()
+        },
+    );
+
+    let id = producer.send([("field", "value")]).await?;
+    // closing flushes the pending batch without waiting for the linger time
+    producer.close().await?;
+    id.await?;
+
+    assert_eq!(1, client.xlen("mystream").await?);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn stream_producer_capacity() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let producer = StreamProducer::new(
+        &client,
+        "mystream",
+        StreamProducerConfig {
+            batch_size: 10,
+            linger: Duration::from_secs(60),
+            capacity: 2,
+        },
+    );
+
+    let id1 = producer.send([("field", 1)]).await?;
+    let id2 = producer.send([("field", 2)]).await?;
+    // the buffer is full until the pending batch is sent
+    assert!(
+        timeout(Duration::from_millis(100), producer.send([("field", 3)]))
+            .await
+            .is_err()
+    );
+
+    producer.close().await?;
+    id1.await?;
+    id2.await?;
+
+    assert_eq!(2, client.xlen("mystream").await?);
+
+    client.close().await?;
+
+    Ok(())
+}
@@ -1,13 +1,18 @@
 use crate::{
     commands::{
-        GenericCommands, GetExOptions, LcsMatch, SetCondition, SetExpiration, StringCommands,
+        ConnectionCommands, GenericCommands, GetExOptions, HelloOptions, LcsMatch, SetCondition,
+        SetExpiration, StringCommands,
     },
-    resp::Value,
+    resp::{BulkString, Value},
     tests::get_test_client,
     Error, RedisError, RedisErrorKind, Result,
 };
+use bytes::Bytes;
 use serial_test::serial;
-use std::time::{Duration, SystemTime};
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
@@ -243,6 +248,7 @@ async fn getrange() -> Result<()> {
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
+#[allow(deprecated)]
 async fn getset() -> Result<()> {
     let client = get_test_client().await?;
 
@@ -354,6 +360,31 @@ async fn incrbyfloat() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn incr_by_float() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del("key").await?;
+
+    client.set("key", "10.50").await?;
+
+    // RESP2: bulk string reply
+    let value = client.incr_by_float("key", 0.1).await?;
+    assert_eq!(10.6, value);
+
+    // RESP3
+    client.hello(HelloOptions::new(3)).await?;
+    let value = client.incr_by_float("key", -5f64).await?;
+    assert_eq!(5.6, value);
+
+    client.close().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -389,6 +420,9 @@ async fn lcs() -> Result<()> {
     assert_eq!(2, result.matches.len());
     assert_eq!(LcsMatch((4, 7), (5, 8), Some(4)), result.matches[0]);
     assert_eq!(LcsMatch((2, 3), (0, 1), Some(2)), result.matches[1]);
+    assert_eq!((2, 3), result.matches[1].first_range());
+    assert_eq!((0, 1), result.matches[1].second_range());
+    assert_eq!(Some(2), result.matches[1].match_len());
 
     client.close().await?;
 
@@ -420,6 +454,37 @@ async fn mget_mset() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn mget_map_mset_map() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del(["key1", "key2", "key3", "key4"]).await?;
+
+    client
+        .mset_map(HashMap::from([
+            ("key1", "value1"),
+            ("key2", "value2"),
+            ("key3", "value3"),
+        ]))
+        .await?;
+
+    let values: HashMap<String, Option<String>> = client
+        .mget_map(["key3", "key1", "key4", "key2"].map(String::from))
+        .await?;
+    assert_eq!(4, values.len());
+    assert_eq!(Some("value1"), values["key1"].as_deref());
+    assert_eq!(Some("value2"), values["key2"].as_deref());
+    assert_eq!(Some("value3"), values["key3"].as_deref());
+    assert_eq!(None, values["key4"]);
+
+    client.close().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -459,6 +524,7 @@ async fn msetnx() -> Result<()> {
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
+#[allow(deprecated)]
 async fn psetex() -> Result<()> {
     let client = get_test_client().await?;
 
@@ -615,6 +681,32 @@ async fn set_with_options() -> Result<()> {
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
+async fn set_with_expiry() -> Result<()> {
+    let client = get_test_client().await?;
+
+    client
+        .set_with_expiry("key", "value", Duration::from_secs(10))
+        .await?;
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+    let ttl = client.ttl("key").await?;
+    assert!(ttl > 0 && ttl <= 10);
+
+    client
+        .set_with_expiry("key", "value", Duration::from_micros(1_500_500))
+        .await?;
+    let ttl = client.pttl("key").await?;
+    assert!(ttl > 0 && ttl <= 1501);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+#[allow(deprecated)]
 async fn setex() -> Result<()> {
     let client = get_test_client().await?;
 
@@ -633,6 +725,7 @@ async fn setex() -> Result<()> {
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
+#[allow(deprecated)]
 async fn setnx() -> Result<()> {
     let client = get_test_client().await?;
 
@@ -676,6 +769,46 @@ async fn setrange() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn binary_values() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // not valid UTF-8
+    let value: &[u8] = &[0xff, 0x00, 0xfe, 0x01, 0x80];
+
+    client.set("key", value).await?;
+    let result: Vec<u8> = client.get("key").await?;
+    assert_eq!(value, &result[..]);
+
+    client.set("key", Bytes::from_static(value)).await?;
+    let result: Bytes = client.get("key").await?;
+    assert_eq!(value, &result[..]);
+
+    let result: BulkString = client.getrange("key", 1, 3).await?;
+    assert_eq!(&value[1..4], result.as_bytes());
+
+    let new_len = client.setrange("key", 5, [0xc3, 0x28]).await?;
+    assert_eq!(7, new_len);
+
+    let new_len = client.append("key", vec![0xa0, 0xa1]).await?;
+    assert_eq!(9, new_len);
+
+    let result: Vec<u8> = client.get("key").await?;
+    assert_eq!(
+        vec![0xff, 0x00, 0xfe, 0x01, 0x80, 0xc3, 0x28, 0xa0, 0xa1],
+        result
+    );
+
+    let result: Result<String> = client.get("key").await;
+    assert!(result.is_err());
+
+    client.close().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
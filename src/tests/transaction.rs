@@ -6,6 +6,10 @@ use crate::{
     Error, RedisError, RedisErrorKind, Result,
 };
 use serial_test::serial;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
@@ -109,6 +113,61 @@ async fn watch() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn transaction_with_retries() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+    client.set("key", 1).await?;
+
+    // the key is modified by another client during the first 2 attempts
+    let client2 = get_test_client().await?;
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let value: i64 = client
+        .transaction("key", 5, |mut transaction| {
+            let client2 = client2.clone();
+            let attempts = attempts.clone();
+            async move {
+                let value: i64 = transaction.client().get("key").await?;
+                if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                    client2.incr("key").await?;
+                }
+                transaction.set("key", value * 10).forget();
+                transaction.get::<_, i64>("key").queue();
+                Ok(transaction)
+            }
+        })
+        .await?;
+    assert_eq!(30, value);
+    assert_eq!(3, attempts.load(Ordering::SeqCst));
+
+    // aborted after max attempts
+    let result: Result<()> = client
+        .transaction("key", 2, |mut transaction| {
+            let client2 = client2.clone();
+            async move {
+                client2.incr("key").await?;
+                transaction.set("key", 0).queue();
+                Ok(transaction)
+            }
+        })
+        .await;
+    assert!(matches!(result, Err(Error::Aborted)));
+    let value: i64 = client.get("key").await?;
+    assert_eq!(32, value);
+
+    // errors of the closure are returned
+    let result: Result<()> = client
+        .transaction(["key", "key2"], 2, |_transaction| async move {
+            Err(Error::Client("error".to_owned()))
+        })
+        .await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
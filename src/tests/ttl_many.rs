@@ -0,0 +1,48 @@
+use crate::{
+    commands::{FlushingMode, ServerCommands, StringCommands},
+    tests::get_test_client,
+    utils::{ttl_many, Ttl},
+    Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+#[test]
+fn ttl_from_replies() {
+    assert_eq!(Ttl::NoKey, Ttl::from_millis(-2));
+    assert_eq!(Ttl::NoExpiry, Ttl::from_millis(-1));
+    assert_eq!(
+        Ttl::Remaining(Duration::from_millis(1500)),
+        Ttl::from_millis(1500)
+    );
+    assert_eq!(Ttl::Remaining(Duration::from_secs(10)), Ttl::from_secs(10));
+    assert_eq!(None, Ttl::NoExpiry.remaining());
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn ttl_many_keys() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client
+        .set_with_expiry("key1", "value", Duration::from_secs(60))
+        .await?;
+    client.set("key2", "value").await?;
+
+    let ttls = ttl_many(&client, ["key1", "key2", "key3"]).await?;
+    assert_eq!(3, ttls.len());
+    assert_eq!("key1", ttls[0].0);
+    assert!(matches!(ttls[0].1.remaining(), Some(ttl) if ttl <= Duration::from_secs(60)));
+    assert_eq!(("key2", Ttl::NoExpiry), ttls[1]);
+    assert_eq!(("key3", Ttl::NoKey), ttls[2]);
+
+    let ttls = ttl_many(&client, vec!["key2".to_owned()]).await?;
+    assert_eq!(vec![("key2".to_owned(), Ttl::NoExpiry)], ttls);
+
+    let ttls = ttl_many::<String, _>(&client, Vec::new()).await?;
+    assert!(ttls.is_empty());
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
 #[cfg(feature = "tls")]
 use crate::client::IntoConfig;
 use crate::{client::Client, Result};
+use log::{Log, Metadata, Record};
 #[cfg(feature = "tls")]
 use native_tls::Certificate;
+use std::sync::Mutex;
 
 /// copy-paste of the root certificate located at crt/certs/ca.crt
 #[cfg(feature = "tls")]
@@ -132,13 +134,54 @@ pub(crate) async fn get_redis_stack_test_client() -> Result<Client> {
     Client::connect(format!("redis://{}:{}", get_default_host(), 8000)).await
 }
 
+/// Records captured between [`start_log_capture`] and [`stop_log_capture`]
+static CAPTURED_LOGS: Mutex<Option<Vec<String>>> = Mutex::new(None);
+
+/// Forwards the records to `env_logger` and keeps them while a capture is in progress
+struct CapturingLogger(env_logger::Logger);
+
+impl Log for CapturingLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.0.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if self.0.matches(record) {
+            if let Some(logs) = CAPTURED_LOGS.lock().unwrap().as_mut() {
+                logs.push(record.args().to_string());
+            }
+        }
+        self.0.log(record);
+    }
+
+    fn flush(&self) {
+        self.0.flush();
+    }
+}
+
 pub fn log_try_init() {
-    let _ = env_logger::builder()
+    let logger = env_logger::builder()
         .format_target(false)
         .format_timestamp(None)
         .filter_level(log::LevelFilter::Debug)
         .target(env_logger::Target::Stdout)
         .is_test(true)
         .parse_default_env()
-        .try_init();
+        .build();
+    let max_level = logger.filter();
+
+    if log::set_boxed_logger(Box::new(CapturingLogger(logger))).is_ok() {
+        log::set_max_level(max_level);
+    }
+}
+
+/// Starts recording the log messages, see [`stop_log_capture`]
+pub fn start_log_capture() {
+    log_try_init();
+    *CAPTURED_LOGS.lock().unwrap() = Some(Vec::new());
+}
+
+/// Stops recording the log messages and returns the ones recorded since [`start_log_capture`]
+pub fn stop_log_capture() -> Vec<String> {
+    CAPTURED_LOGS.lock().unwrap().take().unwrap_or_default()
 }
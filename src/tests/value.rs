@@ -102,3 +102,44 @@ fn display() {
         ])
     );
 }
+
+#[test]
+fn pretty() {
+    let value = Value::Array(vec![
+        Value::Integer(12),
+        Value::Double(12.5),
+        Value::SimpleString("OK".to_owned()),
+        Value::BulkString(b"my \"string\"\r\n\x00\xff".to_vec()),
+        Value::Boolean(true),
+        Value::Error(RedisError {
+            kind: RedisErrorKind::Err,
+            description: "MyError".to_owned(),
+        }),
+        Value::Nil,
+        Value::Array(vec![]),
+        Value::Set(vec![Value::BulkString(b"a".to_vec()), Value::Integer(1)]),
+        Value::Map(HashMap::from([(
+            Value::BulkString(b"field".to_vec()),
+            Value::Array(vec![Value::Integer(1), Value::Integer(2)]),
+        )])),
+    ]);
+
+    assert_eq!(
+        [
+            r#" 1) (integer) 12"#,
+            r#" 2) (double) 12.5"#,
+            r#" 3) OK"#,
+            r#" 4) "my \"string\"\r\n\x00\xff""#,
+            r#" 5) (true)"#,
+            r#" 6) (error) ERR MyError"#,
+            r#" 7) (nil)"#,
+            r#" 8) (empty array)"#,
+            r#" 9) 1~ "a""#,
+            r#"    2~ (integer) 1"#,
+            r#"10) 1# "field" => 1) (integer) 1"#,
+            r#"       2) (integer) 2"#,
+        ]
+        .join("\n"),
+        value.pretty()
+    );
+}
@@ -0,0 +1,101 @@
+use crate::{
+    commands::{FlushingMode, GenericCommands, ServerCommands, StringCommands},
+    sleep, spawn,
+    tests::get_test_client,
+    utils::{wait_for_key, KeyCondition},
+    Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+async fn check_conditions() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    // timeout
+    let result = wait_for_key(
+        &client,
+        "key",
+        KeyCondition::Exists,
+        Duration::from_millis(300),
+    )
+    .await?;
+    assert!(!result);
+
+    // already deleted
+    let result = wait_for_key(
+        &client,
+        "key",
+        KeyCondition::Deleted,
+        Duration::from_millis(300),
+    )
+    .await?;
+    assert!(result);
+
+    // created by another client
+    let setter = client.clone();
+    spawn(async move {
+        sleep(Duration::from_millis(200)).await;
+        let _result = setter.set("key", "value").await;
+    });
+    let result = wait_for_key(&client, "key", KeyCondition::Exists, Duration::from_secs(5)).await?;
+    assert!(result);
+
+    // changed
+    let setter = client.clone();
+    spawn(async move {
+        sleep(Duration::from_millis(200)).await;
+        let _result = setter.set("key", "new_value").await;
+    });
+    let result = wait_for_key(
+        &client,
+        "key",
+        KeyCondition::Changed,
+        Duration::from_secs(5),
+    )
+    .await?;
+    assert!(result);
+
+    // deleted
+    let setter = client.clone();
+    spawn(async move {
+        sleep(Duration::from_millis(200)).await;
+        let _result = setter.del("key").await;
+    });
+    let result = wait_for_key(
+        &client,
+        "key",
+        KeyCondition::Deleted,
+        Duration::from_secs(5),
+    )
+    .await?;
+    assert!(result);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn polling() -> Result<()> {
+    let client = get_test_client().await?;
+    client.config_set(("notify-keyspace-events", "")).await?;
+
+    check_conditions().await
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn keyspace_notifications() -> Result<()> {
+    let client = get_test_client().await?;
+    client.config_set(("notify-keyspace-events", "KA")).await?;
+
+    let result = check_conditions().await;
+
+    client.config_set(("notify-keyspace-events", "")).await?;
+
+    result
+}
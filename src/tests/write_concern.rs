@@ -0,0 +1,77 @@
+use crate::{
+    client::BatchPreparedCommand,
+    commands::{FlushingMode, ServerCommands, StringCommands},
+    resp::cmd,
+    tests::get_test_client,
+    Error, Result,
+};
+use serial_test::serial;
+use std::time::Duration;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn write_concern() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    // the test server has no replica
+    let satisfied = client
+        .clone()
+        .with_write_concern(0, Duration::from_millis(10));
+    satisfied.set("key", "value").await?;
+
+    let unsatisfied = client.with_write_concern(1, Duration::from_millis(10));
+    let result = unsatisfied.set("key", "value2").await;
+    assert!(matches!(
+        result,
+        Err(Error::WriteConcern {
+            num_replicas: 1,
+            acknowledged: 0
+        })
+    ));
+
+    // the write has been applied anyway, and reads are not concerned
+    let value: String = unsatisfied.get("key").await?;
+    assert_eq!("value2", value);
+
+    // a failed write reports its own error
+    let result = unsatisfied.incr("key").await;
+    assert!(matches!(result, Err(Error::Redis(_))));
+
+    let mut pipeline = unsatisfied.create_pipeline();
+    pipeline.get::<_, String>("key").queue();
+    pipeline.set("key2", "value").queue();
+    let result: Result<(String, ())> = pipeline.execute().await;
+    assert!(matches!(result, Err(Error::WriteConcern { .. })));
+
+    // only the failed write is reported, even if a read has succeeded
+    let mut pipeline = unsatisfied.create_pipeline();
+    pipeline.get::<_, String>("key").queue();
+    pipeline.incr("key").queue();
+    let result: Result<(String, i64)> = pipeline.execute().await;
+    assert!(matches!(result, Err(Error::Redis(_))));
+
+    // an aborted transaction has applied nothing
+    let mut transaction = unsatisfied.create_transaction();
+    transaction.set("key3", "value").queue();
+    transaction.queue(cmd("SET").arg("key3"));
+    let result: Result<((), ())> = transaction.execute().await;
+    assert!(matches!(result, Err(Error::Redis(_))));
+
+    let mut transaction = unsatisfied.create_transaction();
+    transaction.set("key3", "value").queue();
+    let result: Result<()> = transaction.execute().await;
+    assert!(matches!(result, Err(Error::WriteConcern { .. })));
+
+    let mut pipeline = unsatisfied.create_pipeline();
+    pipeline.get::<_, String>("key").queue();
+    pipeline.get::<_, String>("key2").queue();
+    let (value1, value2): (String, String) = pipeline.execute().await?;
+    assert_eq!("value2", value1);
+    assert_eq!("value", value2);
+
+    satisfied.flushall(FlushingMode::Sync).await?;
+
+    Ok(())
+}
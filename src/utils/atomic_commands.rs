@@ -0,0 +1,249 @@
+use crate::{
+    client::{Client, PreparedCommand},
+    resp::{CommandArgs, KeyValueArgsCollection, PrimitiveResponse, SingleArg},
+    utils::Script,
+};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use std::sync::OnceLock;
+
+const SET_IF_EQUAL: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    redis.call('SET', KEYS[1], ARGV[2])
+    return 1
+end
+return 0
+"#;
+
+const GET_AND_EXPIRE: &str = r#"
+local value = redis.call('GET', KEYS[1])
+if value then
+    redis.call('PEXPIRE', KEYS[1], ARGV[1])
+end
+return value
+"#;
+
+const COMPARE_AND_DELETE: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+const CHECK_AND_SET: &str = r#"
+local current = tonumber(redis.call('GET', KEYS[1])) or 0
+if current ~= tonumber(ARGV[1]) then
+    return {0, current}
+end
+for i = 2, #KEYS do
+    redis.call('SET', KEYS[i], ARGV[i])
+end
+return {1, redis.call('INCR', KEYS[1])}
+"#;
+
+static SET_IF_EQUAL_SCRIPT: OnceLock<Script> = OnceLock::new();
+static GET_AND_EXPIRE_SCRIPT: OnceLock<Script> = OnceLock::new();
+static COMPARE_AND_DELETE_SCRIPT: OnceLock<Script> = OnceLock::new();
+static CHECK_AND_SET_SCRIPT: OnceLock<Script> = OnceLock::new();
+
+/// Result of the [`check_and_set`](AtomicCommands::check_and_set) operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckAndSetResult {
+    /// The keys have been updated and the version has been incremented
+    Applied {
+        /// New version
+        version: u64,
+    },
+    /// The version did not match the expected one, no key has been updated
+    Conflict {
+        /// Version currently stored on the server
+        current_version: u64,
+    },
+}
+
+impl<'de> Deserialize<'de> for CheckAndSetResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (applied, version) = <(bool, u64)>::deserialize(deserializer)?;
+        Ok(if applied {
+            CheckAndSetResult::Applied { version }
+        } else {
+            CheckAndSetResult::Conflict {
+                current_version: version,
+            }
+        })
+    }
+}
+
+/// A group of atomic one-shot operations, implemented as bundled Lua scripts.
+///
+/// These operations are the usual building blocks of distributed locks.
+/// Scripts are invoked through [`Script`](crate::utils::Script)
+/// and are thus loaded automatically on the server when needed.
+pub trait AtomicCommands<'a> {
+    /// Set `key` to hold `value` only if its current value is equal to `expected`.
+    ///
+    /// # Return
+    /// `true` if the value has been set, `false` otherwise
+    /// (including when the key does not exist).
+    #[must_use]
+    fn set_if_equal<K, E, V>(
+        self,
+        key: K,
+        expected: E,
+        value: V,
+    ) -> PreparedCommand<'a, Self, bool>
+    where
+        Self: Sized,
+        K: SingleArg,
+        E: SingleArg,
+        V: SingleArg;
+
+    /// Get the value of `key` and, if the key exists, set its time to live in milliseconds.
+    ///
+    /// # Return
+    /// the value of `key`, or `nil` when `key` does not exist.
+    #[must_use]
+    fn get_and_expire<K, V>(self, key: K, ttl: u64) -> PreparedCommand<'a, Self, V>
+    where
+        Self: Sized,
+        K: SingleArg,
+        V: PrimitiveResponse + DeserializeOwned + Send + 'a;
+
+    /// Delete `key` only if its current value is equal to `expected`.
+    ///
+    /// # Return
+    /// `true` if the key has been deleted, `false` otherwise.
+    #[must_use]
+    fn compare_and_delete<K, E>(self, key: K, expected: E) -> PreparedCommand<'a, Self, bool>
+    where
+        Self: Sized,
+        K: SingleArg,
+        E: SingleArg;
+
+    /// Set several keys at once only if the version stored at `version_key` is equal to `expected_version`,
+    /// then increment the version.
+    ///
+    /// This is an optimistic concurrency control: read the version along with the keys,
+    /// compute the new values, then write them back only if nobody else did in the meantime.
+    /// A missing version key stands for the version `0`.
+    ///
+    /// In a cluster, the version key and all the keys must belong to the same hash slot.
+    ///
+    /// # Return
+    /// [`CheckAndSetResult::Applied`] with the new version,
+    /// or [`CheckAndSetResult::Conflict`] with the current version if it did not match.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{
+    ///     client::Client,
+    ///     commands::GenericCommands,
+    ///     utils::{AtomicCommands, CheckAndSetResult},
+    ///     Result,
+    /// };
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let client = Client::connect("127.0.0.1:6379").await?;
+    ///     client.del(["{user}:version", "{user}:name", "{user}:email"]).await?;
+    ///
+    ///     let result = client
+    ///         .check_and_set(
+    ///             "{user}:version",
+    ///             0,
+    ///             [("{user}:name", "John"), ("{user}:email", "john@example.com")],
+    ///         )
+    ///         .await?;
+    ///     assert_eq!(CheckAndSetResult::Applied { version: 1 }, result);
+    ///
+    ///     // stale version
+    ///     let result = client
+    ///         .check_and_set("{user}:version", 0, [("{user}:name", "Jane")])
+    ///         .await?;
+    ///     assert_eq!(CheckAndSetResult::Conflict { current_version: 1 }, result);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    fn check_and_set<VK, K, V, C>(
+        self,
+        version_key: VK,
+        expected_version: u64,
+        items: C,
+    ) -> PreparedCommand<'a, Self, CheckAndSetResult>
+    where
+        Self: Sized,
+        VK: SingleArg,
+        K: SingleArg,
+        V: SingleArg,
+        C: KeyValueArgsCollection<K, V>;
+}
+
+impl<'a> AtomicCommands<'a> for &'a Client {
+    fn set_if_equal<K, E, V>(self, key: K, expected: E, value: V) -> PreparedCommand<'a, Self, bool>
+    where
+        K: SingleArg,
+        E: SingleArg,
+        V: SingleArg,
+    {
+        SET_IF_EQUAL_SCRIPT
+            .get_or_init(|| Script::new(SET_IF_EQUAL))
+            .call(
+                self,
+                key,
+                CommandArgs::default().arg(expected).arg(value).build(),
+            )
+    }
+
+    fn get_and_expire<K, V>(self, key: K, ttl: u64) -> PreparedCommand<'a, Self, V>
+    where
+        K: SingleArg,
+        V: PrimitiveResponse + DeserializeOwned + Send + 'a,
+    {
+        GET_AND_EXPIRE_SCRIPT
+            .get_or_init(|| Script::new(GET_AND_EXPIRE))
+            .call(self, key, ttl)
+    }
+
+    fn compare_and_delete<K, E>(self, key: K, expected: E) -> PreparedCommand<'a, Self, bool>
+    where
+        K: SingleArg,
+        E: SingleArg,
+    {
+        COMPARE_AND_DELETE_SCRIPT
+            .get_or_init(|| Script::new(COMPARE_AND_DELETE))
+            .call(self, key, expected)
+    }
+
+    fn check_and_set<VK, K, V, C>(
+        self,
+        version_key: VK,
+        expected_version: u64,
+        items: C,
+    ) -> PreparedCommand<'a, Self, CheckAndSetResult>
+    where
+        VK: SingleArg,
+        K: SingleArg,
+        V: SingleArg,
+        C: KeyValueArgsCollection<K, V>,
+    {
+        let items = CommandArgs::default().arg(items).build();
+        let mut keys = CommandArgs::default().arg(version_key).build();
+        let mut args = CommandArgs::default().arg(expected_version).build();
+        for (index, arg) in items.iter().enumerate() {
+            if index % 2 == 0 {
+                keys.write_arg(arg.as_slice());
+            } else {
+                args.write_arg(arg.as_slice());
+            }
+        }
+
+        CHECK_AND_SET_SCRIPT
+            .get_or_init(|| Script::new(CHECK_AND_SET))
+            .call(self, keys, args)
+    }
+}
@@ -0,0 +1,270 @@
+use crate::{
+    client::{BatchPreparedCommand, Client},
+    commands::{GenericCommands, MemoryUsageOptions, ScanOptions, ServerCommands},
+    resp::{cmd, Command, Value},
+    sleep, Result,
+};
+use futures_util::{stream, Stream};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// Key whose memory usage exceeds the threshold given to [`find_big_keys`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigKey {
+    /// Name of the key
+    pub key: String,
+    /// Type of the value, as returned by [`TYPE`](https://redis.io/commands/type/)
+    pub key_type: String,
+    /// Number of bytes used by the key and its value, as estimated by [`MEMORY USAGE`](https://redis.io/commands/memory-usage/)
+    pub size: usize,
+    /// Number of elements of a list, set, sorted set, hash or stream,
+    /// `None` for the other types
+    pub elements: Option<usize>,
+}
+
+/// Options for the [`find_big_keys`] function
+#[derive(Debug, Clone)]
+pub struct BigKeysOptions {
+    match_pattern: Option<String>,
+    scan_count: usize,
+    max_keys_per_second: Option<usize>,
+    memory_samples: Option<usize>,
+}
+
+impl Default for BigKeysOptions {
+    fn default() -> Self {
+        Self {
+            match_pattern: None,
+            scan_count: 100,
+            max_keys_per_second: None,
+            memory_samples: None,
+        }
+    }
+}
+
+impl BigKeysOptions {
+    /// Only inspect the keys matching this glob-style pattern
+    #[must_use]
+    pub fn match_pattern(mut self, match_pattern: impl Into<String>) -> Self {
+        self.match_pattern = Some(match_pattern.into());
+        self
+    }
+
+    /// Number of keys hinted to the server for each `SCAN` iteration.
+    ///
+    /// The default is `100`
+    #[must_use]
+    pub fn scan_count(mut self, scan_count: usize) -> Self {
+        self.scan_count = scan_count.max(1);
+        self
+    }
+
+    /// Maximum number of keys inspected per second, to limit the load put on the server.
+    ///
+    /// By default, keys are inspected without pause.
+    #[must_use]
+    pub fn max_keys_per_second(mut self, max_keys_per_second: usize) -> Self {
+        self.max_keys_per_second = Some(max_keys_per_second.max(1));
+        self
+    }
+
+    /// Number of nested values sampled by `MEMORY USAGE` (server default `5`, `0` for all)
+    #[must_use]
+    pub fn memory_samples(mut self, memory_samples: usize) -> Self {
+        self.memory_samples = Some(memory_samples);
+        self
+    }
+}
+
+struct BigKeysState {
+    /// `None` once the server has returned the final cursor
+    cursor: Option<u64>,
+    big_keys: VecDeque<BigKey>,
+    /// Start of the current rate limiting window and number of keys inspected since then
+    window: Option<(Instant, usize)>,
+}
+
+/// Returns a [`Stream`] of the keys whose memory usage is greater than or equal to `threshold_bytes`.
+///
+/// The keyspace is iterated with [`SCAN`](https://redis.io/commands/scan/).
+/// For each batch of scanned keys, `TYPE` and `MEMORY USAGE` are sent in a single pipeline,
+/// then the number of elements of the big keys is retrieved with
+/// `LLEN`, `SCARD`, `ZCARD`, `HLEN` or `XLEN`, depending on their type.
+///
+/// See [`KeyspaceAnalyzer`](crate::utils::KeyspaceAnalyzer) for statistics on the whole keyspace.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::Client,
+///     utils::{find_big_keys, BigKeysOptions},
+///     Result,
+/// };
+/// use futures_util::TryStreamExt;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let options = BigKeysOptions::default().max_keys_per_second(1000);
+///     let mut big_keys = Box::pin(find_big_keys(&client, 1024 * 1024, options));
+///     while let Some(big_key) = big_keys.try_next().await? {
+///         println!(
+///             "{} ({}): {} bytes, {:?} elements",
+///             big_key.key, big_key.key_type, big_key.size, big_key.elements
+///         );
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn find_big_keys(
+    client: &Client,
+    threshold_bytes: usize,
+    options: BigKeysOptions,
+) -> impl Stream<Item = Result<BigKey>> + '_ {
+    let state = BigKeysState {
+        cursor: Some(0),
+        big_keys: VecDeque::new(),
+        window: None,
+    };
+
+    stream::try_unfold(state, move |mut state| {
+        let options = options.clone();
+        async move {
+            loop {
+                if let Some(big_key) = state.big_keys.pop_front() {
+                    return Ok(Some((big_key, state)));
+                }
+
+                let Some(cursor) = state.cursor else {
+                    return Ok(None);
+                };
+
+                let mut scan_options = ScanOptions::default().count(options.scan_count);
+                if let Some(match_pattern) = &options.match_pattern {
+                    scan_options = scan_options.match_pattern(match_pattern.as_str());
+                }
+                let (next_cursor, keys): (u64, Vec<String>) =
+                    client.scan(cursor, scan_options).await?;
+
+                state.cursor = if next_cursor == 0 {
+                    None
+                } else {
+                    Some(next_cursor)
+                };
+
+                if let Some(max_keys_per_second) = options.max_keys_per_second {
+                    throttle(&mut state.window, keys.len(), max_keys_per_second).await;
+                }
+
+                state.big_keys.extend(
+                    inspect_keys(client, keys, threshold_bytes, options.memory_samples).await?,
+                );
+            }
+        }
+    })
+}
+
+/// Wait, if needed, so that no more than `max_keys_per_second` keys are inspected per second
+async fn throttle(
+    window: &mut Option<(Instant, usize)>,
+    num_keys: usize,
+    max_keys_per_second: usize,
+) {
+    let (start, inspected) = window.get_or_insert_with(|| (Instant::now(), 0));
+    *inspected += num_keys;
+
+    let expected = Duration::from_secs_f64(*inspected as f64 / max_keys_per_second as f64);
+    let elapsed = start.elapsed();
+    if expected > elapsed {
+        sleep(expected - elapsed).await;
+    }
+}
+
+async fn inspect_keys(
+    client: &Client,
+    keys: Vec<String>,
+    threshold_bytes: usize,
+    memory_samples: Option<usize>,
+) -> Result<Vec<BigKey>> {
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut pipeline = client.create_pipeline();
+    for key in &keys {
+        let memory_usage_options = match memory_samples {
+            Some(samples) => MemoryUsageOptions::default().samples(samples),
+            None => MemoryUsageOptions::default(),
+        };
+        pipeline.type_(key).queue();
+        pipeline.memory_usage(key, memory_usage_options).queue();
+    }
+    let values: Vec<Value> = pipeline.execute().await?;
+
+    let mut big_keys = Vec::new();
+    let mut values = values.into_iter();
+    for key in keys {
+        let (Some(key_type), Some(size)) = (values.next(), values.next()) else {
+            break;
+        };
+
+        // the key has been deleted or has expired since it was scanned
+        let Some(size) = size.into::<Option<usize>>()? else {
+            continue;
+        };
+
+        if size >= threshold_bytes {
+            big_keys.push(BigKey {
+                key,
+                key_type: key_type.into()?,
+                size,
+                elements: None,
+            });
+        }
+    }
+
+    let commands: Vec<(usize, Command)> = big_keys
+        .iter()
+        .enumerate()
+        .filter_map(|(i, big_key)| {
+            cardinality_command(&big_key.key_type, &big_key.key).map(|command| (i, command))
+        })
+        .collect();
+
+    let elements: Vec<usize> = match commands.len() {
+        0 => Vec::new(),
+        // a pipeline of a single command does not reply with an array
+        1 => vec![client.send(commands[0].1.clone(), None).await?.to()?],
+        _ => {
+            let mut pipeline = client.create_pipeline();
+            for (_, command) in &commands {
+                pipeline.queue(command.clone());
+            }
+            pipeline.execute().await?
+        }
+    };
+
+    for ((i, _), elements) in commands.into_iter().zip(elements) {
+        big_keys[i].elements = Some(elements);
+    }
+
+    Ok(big_keys)
+}
+
+fn cardinality_command(key_type: &str, key: &str) -> Option<Command> {
+    let name = match key_type {
+        "list" => "LLEN",
+        "set" => "SCARD",
+        "zset" => "ZCARD",
+        "hash" => "HLEN",
+        "stream" => "XLEN",
+        _ => return None,
+    };
+
+    Some(cmd(name).arg(key))
+}
@@ -0,0 +1,442 @@
+#[cfg(any(
+    feature = "cache-json",
+    feature = "cache-msgpack",
+    feature = "cache-bincode",
+    feature = "cache-compression"
+))]
+use crate::Error;
+use crate::{
+    client::Client,
+    commands::{GenericCommands, SetCondition, SetExpiration, StringCommands},
+    sleep,
+    utils::AtomicCommands,
+    Result,
+};
+use rand::Rng;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{future::Future, marker::PhantomData, time::Duration};
+
+/// Header byte of an uncompressed value, when compression is enabled
+#[cfg(feature = "cache-compression")]
+const RAW_HEADER: u8 = 0;
+/// Header byte of a zlib compressed value, when compression is enabled
+#[cfg(feature = "cache-compression")]
+const ZLIB_HEADER: u8 = 1;
+
+/// Serialization format of the values stored by a [`Cache`]
+///
+/// Implementations are provided for JSON ([`JsonSerializer`], feature `cache-json`),
+/// MessagePack ([`MessagePackSerializer`], feature `cache-msgpack`)
+/// and bincode ([`BincodeSerializer`], feature `cache-bincode`).
+pub trait CacheSerializer: Send + Sync {
+    /// Serialize `value` to bytes
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>>;
+
+    /// Deserialize a value from `bytes`
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T>;
+}
+
+/// [JSON](https://www.json.org/) serialization, based on [`serde_json`](https://docs.rs/serde_json)
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::{Cache, JsonSerializer}, Result};
+/// use serde::{Deserialize, Serialize};
+/// use std::time::Duration;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     let cache = Cache::<User, _>::new(&client, JsonSerializer).with_prefix("users:");
+///
+///     let user = cache
+///         .get_or_compute("1", Some(Duration::from_secs(60)), || async {
+///             // expensive loading from the database
+///             Ok(User { name: "John".to_owned(), age: 42 })
+///         })
+///         .await?;
+///     assert_eq!("John", user.name);
+///
+///     let user = cache.get("1").await?;
+///     assert!(user.is_some());
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "cache-json")))]
+#[cfg(feature = "cache-json")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonSerializer;
+
+#[cfg(feature = "cache-json")]
+impl CacheSerializer for JsonSerializer {
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>> {
+        serde_json::to_vec(value)
+            .map_err(|e| Error::Client(format!("Cannot serialize to JSON: {e}")))
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T> {
+        serde_json::from_slice(bytes)
+            .map_err(|e| Error::Client(format!("Cannot deserialize from JSON: {e}")))
+    }
+}
+
+/// [MessagePack](https://msgpack.org/) serialization, based on [`rmp_serde`](https://docs.rs/rmp-serde)
+///
+/// Structs are serialized as maps, so that fields can be added or reordered.
+#[cfg_attr(docsrs, doc(cfg(feature = "cache-msgpack")))]
+#[cfg(feature = "cache-msgpack")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MessagePackSerializer;
+
+#[cfg(feature = "cache-msgpack")]
+impl CacheSerializer for MessagePackSerializer {
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>> {
+        rmp_serde::to_vec_named(value)
+            .map_err(|e| Error::Client(format!("Cannot serialize to MessagePack: {e}")))
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T> {
+        rmp_serde::from_slice(bytes)
+            .map_err(|e| Error::Client(format!("Cannot deserialize from MessagePack: {e}")))
+    }
+}
+
+/// [bincode](https://docs.rs/bincode) serialization
+///
+/// This is the most compact format, but values cannot be read back
+/// once the layout of the serialized type changes.
+#[cfg_attr(docsrs, doc(cfg(feature = "cache-bincode")))]
+#[cfg(feature = "cache-bincode")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BincodeSerializer;
+
+#[cfg(feature = "cache-bincode")]
+impl CacheSerializer for BincodeSerializer {
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>> {
+        bincode::serialize(value)
+            .map_err(|e| Error::Client(format!("Cannot serialize to bincode: {e}")))
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T> {
+        bincode::deserialize(bytes)
+            .map_err(|e| Error::Client(format!("Cannot deserialize from bincode: {e}")))
+    }
+}
+
+/// Options of the lock protecting the loading of a missing value,
+/// see [`Cache::get_or_set_locked`]
+#[derive(Debug, Clone)]
+pub struct CacheLockOptions {
+    lock_timeout: Duration,
+    retry_interval: Duration,
+    jitter: Duration,
+}
+
+impl Default for CacheLockOptions {
+    fn default() -> Self {
+        Self {
+            lock_timeout: Duration::from_secs(10),
+            retry_interval: Duration::from_millis(50),
+            jitter: Duration::from_millis(25),
+        }
+    }
+}
+
+impl CacheLockOptions {
+    /// Time to live of the lock key.
+    ///
+    /// If the process loading the value does not store it in time, for instance because it crashed,
+    /// the lock expires and another process takes over.
+    ///
+    /// The default is 10 seconds
+    #[must_use]
+    pub fn lock_timeout(mut self, lock_timeout: Duration) -> Self {
+        self.lock_timeout = lock_timeout;
+        self
+    }
+
+    /// Interval between two checks of the value while another process holds the lock.
+    ///
+    /// The default is 50ms
+    #[must_use]
+    pub fn retry_interval(mut self, retry_interval: Duration) -> Self {
+        self.retry_interval = retry_interval;
+        self
+    }
+
+    /// Maximum random duration added to each retry interval,
+    /// so that waiting processes do not check the value all at once.
+    ///
+    /// The default is 25ms
+    #[must_use]
+    pub fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    fn next_retry_delay(&self) -> Duration {
+        let jitter_millis = self.jitter.as_millis() as u64;
+        if jitter_millis == 0 {
+            self.retry_interval
+        } else {
+            self.retry_interval
+                + Duration::from_millis(rand::thread_rng().gen_range(0..=jitter_millis))
+        }
+    }
+}
+
+/// Typed cache of values of type `T`, stored as Redis strings
+///
+/// Values are serialized with a pluggable [`CacheSerializer`]
+/// and optionally compressed (feature `cache-compression`).
+///
+/// [`get_or_set_locked`](Cache::get_or_set_locked) protects the loading of a missing value
+/// with a lock key set with [`SET NX`](https://redis.io/commands/set/),
+/// so that concurrent callers wait for the value instead of loading it all at once.
+///
+/// # Example
+/// See [`JsonSerializer`].
+pub struct Cache<T, S> {
+    client: Client,
+    serializer: S,
+    prefix: String,
+    lock_options: CacheLockOptions,
+    #[cfg(feature = "cache-compression")]
+    compression_threshold: Option<usize>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, S> Cache<T, S>
+where
+    T: Serialize + DeserializeOwned,
+    S: CacheSerializer,
+{
+    /// Create a new cache storing its values with `client`
+    #[must_use]
+    pub fn new(client: &Client, serializer: S) -> Self {
+        Self {
+            client: client.clone(),
+            serializer,
+            prefix: String::new(),
+            lock_options: CacheLockOptions::default(),
+            #[cfg(feature = "cache-compression")]
+            compression_threshold: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Prefix prepended to the keys of the cache
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Options of the lock used by [`get_or_set_locked`](Cache::get_or_set_locked)
+    #[must_use]
+    pub fn with_lock_options(mut self, lock_options: CacheLockOptions) -> Self {
+        self.lock_options = lock_options;
+        self
+    }
+
+    /// Compress with zlib the serialized values of at least `min_size` bytes.
+    ///
+    /// A header byte is prepended to each value when compression is enabled:
+    /// values written by a cache with compression can only be read by a cache with compression.
+    #[cfg_attr(docsrs, doc(cfg(feature = "cache-compression")))]
+    #[cfg(feature = "cache-compression")]
+    #[must_use]
+    pub fn with_compression(mut self, min_size: usize) -> Self {
+        self.compression_threshold = Some(min_size);
+        self
+    }
+
+    /// Get the value of `key`
+    ///
+    /// # Return
+    /// The value, or `None` if the key does not exist.
+    pub async fn get(&self, key: &str) -> Result<Option<T>> {
+        let bytes: Option<Vec<u8>> = self.client.get(self.full_key(key)).await?;
+        match bytes {
+            Some(bytes) => Ok(Some(self.decode(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Set the value of `key`, with an optional time to live
+    pub async fn set(&self, key: &str, value: &T, ttl: Option<Duration>) -> Result<()> {
+        let bytes = self.encode(value)?;
+        match ttl {
+            Some(ttl) => {
+                self.client
+                    .set_with_expiry(self.full_key(key), bytes, ttl)
+                    .await
+            }
+            None => self.client.set(self.full_key(key), bytes).await,
+        }
+    }
+
+    /// Delete the value of `key`
+    ///
+    /// # Return
+    /// `true` if the key has been deleted, `false` if it did not exist.
+    pub async fn delete(&self, key: &str) -> Result<bool> {
+        Ok(self.client.del(self.full_key(key)).await? > 0)
+    }
+
+    /// Get the value of `key`, or compute it with `compute` and store it with an optional time to live
+    /// if the key does not exist.
+    ///
+    /// Same as [`get_or_set_locked`](Cache::get_or_set_locked).
+    pub async fn get_or_compute<F, Fut>(
+        &self,
+        key: &str,
+        ttl: Option<Duration>,
+        compute: F,
+    ) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.get_or_set_locked(key, ttl, compute).await
+    }
+
+    /// Get the value of `key`, or load it with `loader` and store it with an optional time to live
+    /// if the key does not exist.
+    ///
+    /// Only one process loads a missing value at a time: it holds a short-lived lock key
+    /// (`<key>:lock`) while the others wait for the value to be stored,
+    /// checking it at regular intervals (see [`CacheLockOptions`]).
+    /// If the lock expires before the value is stored, one of the waiting processes takes over.
+    pub async fn get_or_set_locked<F, Fut>(
+        &self,
+        key: &str,
+        ttl: Option<Duration>,
+        loader: F,
+    ) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        if let Some(value) = self.get(key).await? {
+            return Ok(value);
+        }
+
+        let lock_key = format!("{}:lock", self.full_key(key));
+        let token = rand::thread_rng().gen::<u64>().to_string();
+
+        loop {
+            let locked = self
+                .client
+                .set_with_options(
+                    lock_key.as_str(),
+                    token.as_str(),
+                    SetCondition::NX,
+                    SetExpiration::Px(self.lock_options.lock_timeout.as_millis() as u64),
+                    false,
+                )
+                .await?;
+
+            if locked {
+                break;
+            }
+
+            sleep(self.lock_options.next_retry_delay()).await;
+
+            if let Some(value) = self.get(key).await? {
+                return Ok(value);
+            }
+        }
+
+        // the value may have been stored just before the lock was acquired
+        let result = match self.get(key).await {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => self.compute_and_set(key, ttl, loader).await,
+            Err(e) => Err(e),
+        };
+
+        self.client.compare_and_delete(lock_key, token).await?;
+
+        result
+    }
+
+    async fn compute_and_set<F, Fut>(
+        &self,
+        key: &str,
+        ttl: Option<Duration>,
+        compute: F,
+    ) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let value = compute().await?;
+        self.set(key, &value, ttl).await?;
+        Ok(value)
+    }
+
+    fn full_key(&self, key: &str) -> String {
+        format!("{}{key}", self.prefix)
+    }
+
+    fn encode(&self, value: &T) -> Result<Vec<u8>> {
+        let bytes = self.serializer.serialize(value)?;
+
+        #[cfg(feature = "cache-compression")]
+        if let Some(threshold) = self.compression_threshold {
+            return compress(bytes, threshold);
+        }
+
+        Ok(bytes)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<T> {
+        #[cfg(feature = "cache-compression")]
+        if self.compression_threshold.is_some() {
+            return self.serializer.deserialize(&decompress(bytes)?);
+        }
+
+        self.serializer.deserialize(bytes)
+    }
+}
+
+#[cfg(feature = "cache-compression")]
+fn compress(bytes: Vec<u8>, threshold: usize) -> Result<Vec<u8>> {
+    use flate2::{write::ZlibEncoder, Compression};
+    use std::io::Write;
+
+    if bytes.len() < threshold {
+        let mut buf = Vec::with_capacity(bytes.len() + 1);
+        buf.push(RAW_HEADER);
+        buf.extend_from_slice(&bytes);
+        return Ok(buf);
+    }
+
+    let mut encoder = ZlibEncoder::new(vec![ZLIB_HEADER], Compression::default());
+    encoder.write_all(&bytes)?;
+    Ok(encoder.finish()?)
+}
+
+#[cfg(feature = "cache-compression")]
+fn decompress(bytes: &[u8]) -> Result<std::borrow::Cow<'_, [u8]>> {
+    use flate2::read::ZlibDecoder;
+    use std::{borrow::Cow, io::Read};
+
+    match bytes.split_first() {
+        Some((&RAW_HEADER, payload)) => Ok(Cow::Borrowed(payload)),
+        Some((&ZLIB_HEADER, payload)) => {
+            let mut buf = Vec::new();
+            ZlibDecoder::new(payload).read_to_end(&mut buf)?;
+            Ok(Cow::Owned(buf))
+        }
+        _ => Err(Error::Client("Unknown cache value header".to_owned())),
+    }
+}
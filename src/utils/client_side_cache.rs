@@ -0,0 +1,302 @@
+use crate::{
+    client::{BatchPreparedCommand, Client},
+    commands::{
+        ClientCachingMode, ClientTrackingOptions, ClientTrackingStatus, ConnectionCommands,
+        StringCommands,
+    },
+    spawn, Result,
+};
+use futures_util::StreamExt;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+};
+
+/// Tracking mode of a [`ClientSideCache`]
+///
+/// See [`CLIENT TRACKING`](https://redis.io/commands/client-tracking/)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TrackingMode {
+    /// The server remembers the keys read by the client
+    /// and sends invalidation messages for these keys only.
+    #[default]
+    Default,
+    /// Keys are only tracked when read with [`get`](ClientSideCache::get),
+    /// which sends `CLIENT CACHING YES` just before the read.
+    OptIn,
+    /// Keys are tracked unless read with [`get_uncached`](ClientSideCache::get_uncached),
+    /// which sends `CLIENT CACHING NO` just before the read.
+    OptOut,
+    /// The server does not remember any key and sends invalidation messages
+    /// for all the keys starting with one of the prefixes, or for all the keys if none is given.
+    Broadcast {
+        /// Key prefixes to receive invalidation messages for
+        prefixes: Vec<String>,
+    },
+}
+
+/// Options of a [`ClientSideCache`]
+#[derive(Debug, Clone, Default)]
+pub struct ClientSideCacheOptions {
+    mode: TrackingMode,
+    no_loop: bool,
+}
+
+impl ClientSideCacheOptions {
+    /// Tracking mode
+    #[must_use]
+    pub fn mode(mut self, mode: TrackingMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Enable the [`OptIn`](TrackingMode::OptIn) tracking mode
+    #[must_use]
+    pub fn optin(self) -> Self {
+        self.mode(TrackingMode::OptIn)
+    }
+
+    /// Enable the [`OptOut`](TrackingMode::OptOut) tracking mode
+    #[must_use]
+    pub fn optout(self) -> Self {
+        self.mode(TrackingMode::OptOut)
+    }
+
+    /// Enable the [`Broadcast`](TrackingMode::Broadcast) tracking mode for the given key prefixes
+    #[must_use]
+    pub fn broadcast<P: Into<String>>(self, prefixes: impl IntoIterator<Item = P>) -> Self {
+        self.mode(TrackingMode::Broadcast {
+            prefixes: prefixes.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// Don't receive invalidation messages for the keys modified by the caching client itself
+    #[must_use]
+    pub fn no_loop(mut self) -> Self {
+        self.no_loop = true;
+        self
+    }
+}
+
+enum CacheEntry {
+    /// A read is in flight, the value can be stored if no invalidation occurs meanwhile
+    Pending,
+    Value(Option<String>),
+}
+
+/// Client-side cache of string values, kept up to date with
+/// [server-assisted client side caching](https://redis.io/docs/manual/client-side-caching/)
+///
+/// Values are read through a first client, on which tracking is enabled.
+/// Invalidation messages are redirected to a second, dedicated client,
+/// and applied to the local cache by a background task.
+///
+/// Tracking is a property of a connection: after a reconnection of the caching client,
+/// the cache must be recreated.
+/// If the invalidation stream ends, the cache is cleared and disabled.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::{ClientSideCache, ClientSideCacheOptions}, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     let invalidation_client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let cache = ClientSideCache::new(
+///         &client,
+///         &invalidation_client,
+///         ClientSideCacheOptions::default().broadcast(["user:"]),
+///     )
+///     .await?;
+///
+///     // read from Redis the first time, from the local cache afterwards
+///     let _name = cache.get("user:1:name").await?;
+///     let _name = cache.get("user:1:name").await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct ClientSideCache {
+    client: Client,
+    mode: TrackingMode,
+    entries: Arc<Mutex<HashMap<String, CacheEntry>>>,
+    enabled: Arc<AtomicBool>,
+}
+
+impl ClientSideCache {
+    /// Enable tracking on `client`, redirecting invalidation messages to `invalidation_client`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn new(
+        client: &Client,
+        invalidation_client: &Client,
+        options: ClientSideCacheOptions,
+    ) -> Result<Self> {
+        let entries: Arc<Mutex<HashMap<String, CacheEntry>>> = Default::default();
+        let enabled = Arc::new(AtomicBool::new(true));
+
+        let invalidation_id = match invalidation_client.cached_client_id() {
+            Some(client_id) => client_id,
+            None => invalidation_client.client_id().await?,
+        };
+        let mut invalidations = invalidation_client.create_client_tracking_invalidation_stream()?;
+
+        let mut tracking_options = ClientTrackingOptions::default().redirect(invalidation_id);
+        match &options.mode {
+            TrackingMode::Default => (),
+            TrackingMode::OptIn => tracking_options = tracking_options.optin(),
+            TrackingMode::OptOut => tracking_options = tracking_options.optout(),
+            TrackingMode::Broadcast { prefixes } => {
+                tracking_options = tracking_options.broadcasting();
+                for prefix in prefixes {
+                    tracking_options = tracking_options.prefix(prefix);
+                }
+            }
+        }
+        if options.no_loop {
+            tracking_options = tracking_options.no_loop();
+        }
+
+        client
+            .client_tracking(ClientTrackingStatus::On, tracking_options)
+            .await?;
+
+        let task_entries = entries.clone();
+        let task_enabled = enabled.clone();
+        spawn(async move {
+            while let Some(keys) = invalidations.next().await {
+                let mut entries = lock(&task_entries);
+                for key in keys {
+                    entries.remove(&key);
+                }
+            }
+
+            task_enabled.store(false, Ordering::SeqCst);
+            lock(&task_entries).clear();
+        });
+
+        Ok(Self {
+            client: client.clone(),
+            mode: options.mode,
+            entries,
+            enabled,
+        })
+    }
+
+    /// Tracking mode of the cache
+    #[must_use]
+    pub fn mode(&self) -> &TrackingMode {
+        &self.mode
+    }
+
+    /// Get the value of `key`, from the local cache if possible.
+    ///
+    /// In [`Broadcast`](TrackingMode::Broadcast) mode, keys which do not start with
+    /// one of the prefixes are always read from Redis.
+    /// In [`OptIn`](TrackingMode::OptIn) mode, the read is preceded by `CLIENT CACHING YES`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn get(&self, key: &str) -> Result<Option<String>> {
+        if !self.enabled.load(Ordering::SeqCst) || !self.is_tracked(key) {
+            return self.client.get(key).await;
+        }
+
+        {
+            let mut entries = lock(&self.entries);
+            match entries.get(key) {
+                Some(CacheEntry::Value(value)) => return Ok(value.clone()),
+                Some(CacheEntry::Pending) => (),
+                None => {
+                    entries.insert(key.to_owned(), CacheEntry::Pending);
+                }
+            }
+        }
+
+        let value: Option<String> = if self.mode == TrackingMode::OptIn {
+            self.get_with_caching_hint(key, ClientCachingMode::Yes)
+                .await?
+        } else {
+            self.client.get(key).await?
+        };
+
+        // an invalidation received meanwhile has removed the pending entry
+        if let Some(entry @ CacheEntry::Pending) = lock(&self.entries).get_mut(key) {
+            *entry = CacheEntry::Value(value.clone());
+        }
+
+        Ok(value)
+    }
+
+    /// Get the value of `key` from Redis, without caching it.
+    ///
+    /// In [`OptOut`](TrackingMode::OptOut) mode, the read is preceded by `CLIENT CACHING NO`
+    /// so that the key is not tracked.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn get_uncached(&self, key: &str) -> Result<Option<String>> {
+        if self.mode == TrackingMode::OptOut {
+            self.get_with_caching_hint(key, ClientCachingMode::No).await
+        } else {
+            self.client.get(key).await
+        }
+    }
+
+    /// Number of values in the local cache
+    #[must_use]
+    pub fn len(&self) -> usize {
+        lock(&self.entries)
+            .values()
+            .filter(|entry| matches!(entry, CacheEntry::Value(_)))
+            .count()
+    }
+
+    /// Returns `true` if the local cache holds no value
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all the values from the local cache
+    pub fn clear(&self) {
+        lock(&self.entries).clear();
+    }
+
+    /// In broadcasting mode, keys outside of the prefixes are not cached
+    /// since no invalidation message would be received for them
+    fn is_tracked(&self, key: &str) -> bool {
+        match &self.mode {
+            TrackingMode::Broadcast { prefixes } if !prefixes.is_empty() => prefixes
+                .iter()
+                .any(|prefix| key.starts_with(prefix.as_str())),
+            _ => true,
+        }
+    }
+
+    /// Send `CLIENT CACHING` and `GET` in the same batch,
+    /// so that no other command can be sent in between
+    async fn get_with_caching_hint(
+        &self,
+        key: &str,
+        mode: ClientCachingMode,
+    ) -> Result<Option<String>> {
+        let mut pipeline = self.client.create_pipeline();
+        pipeline.client_caching(mode).forget();
+        pipeline.get::<_, Option<String>>(key).queue();
+        pipeline.execute().await
+    }
+}
+
+fn lock(
+    entries: &Mutex<HashMap<String, CacheEntry>>,
+) -> MutexGuard<'_, HashMap<String, CacheEntry>> {
+    entries.lock().unwrap_or_else(|e| e.into_inner())
+}
@@ -0,0 +1,159 @@
+use crate::{client::Client, commands::ServerCommands, Result};
+use std::collections::HashMap;
+
+/// A configuration parameter whose value on the server deviates from the expected one
+///
+/// See [`config_diff`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigDifference {
+    /// Name of the parameter, in lowercase
+    pub parameter: String,
+    /// Expected value, as given to [`config_diff`]
+    pub expected: String,
+    /// Value on the server, as returned by [`CONFIG GET`](https://redis.io/commands/config-get/),
+    /// `None` if the parameter is unknown to the server
+    pub actual: Option<String>,
+}
+
+/// Compares the configuration of the server with the `expected` parameters
+/// and returns the parameters which deviate, sorted by name.
+///
+/// Values are compared after normalization, so that a value written in a `redis.conf` file
+/// matches the value returned by [`CONFIG GET`](https://redis.io/commands/config-get/):
+/// * parameter names are case insensitive,
+/// * memory sizes are converted to bytes (`1gb`, `512mb`, `100k`),
+/// * booleans are compared as `yes`/`no` (`true`/`false` and `on`/`off` are accepted),
+/// * multiple whitespaces are collapsed (e.g. for `save` or `client-output-buffer-limit`).
+///
+/// The expected parameters can be read from a configuration file with [`parse_redis_conf`].
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::config_diff, Result};
+/// use std::collections::HashMap;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let expected = HashMap::from([("maxmemory-policy".to_owned(), "noeviction".to_owned())]);
+///     for difference in config_diff(&client, &expected).await? {
+///         println!(
+///             "{}: expected {}, found {:?}",
+///             difference.parameter, difference.expected, difference.actual
+///         );
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn config_diff(
+    client: &Client,
+    expected: &HashMap<String, String>,
+) -> Result<Vec<ConfigDifference>> {
+    let actual: HashMap<String, String> = client.config_get("*").await?;
+
+    let mut differences: Vec<ConfigDifference> = expected
+        .iter()
+        .filter_map(|(parameter, expected)| {
+            let parameter = parameter.to_lowercase();
+            let actual = actual.get(&parameter);
+            let deviates = match actual {
+                Some(actual) => normalize_config_value(expected) != normalize_config_value(actual),
+                None => true,
+            };
+
+            deviates.then(|| ConfigDifference {
+                parameter,
+                expected: expected.clone(),
+                actual: actual.cloned(),
+            })
+        })
+        .collect();
+
+    differences.sort_by(|d1, d2| d1.parameter.cmp(&d2.parameter));
+    Ok(differences)
+}
+
+/// Parses the directives of a `redis.conf` file into parameters and values.
+///
+/// Comments and empty lines are ignored, parameter names are converted to lowercase,
+/// double-quoted arguments are unquoted, and the arguments of a directive repeated on several lines
+/// (e.g. `save`) are joined with a whitespace, as [`CONFIG GET`](https://redis.io/commands/config-get/) returns them.
+#[must_use]
+pub fn parse_redis_conf(content: &str) -> HashMap<String, String> {
+    let mut parameters: HashMap<String, String> = HashMap::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (name, args) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let value = args
+            .split_whitespace()
+            .map(|arg| arg.trim_matches('"'))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        parameters
+            .entry(name.to_lowercase())
+            .and_modify(|existing| {
+                if !value.is_empty() {
+                    existing.push(' ');
+                    existing.push_str(&value);
+                }
+            })
+            .or_insert(value);
+    }
+
+    parameters
+}
+
+/// Normalizes a configuration value token by token
+fn normalize_config_value(value: &str) -> String {
+    value
+        .split_whitespace()
+        .map(normalize_config_token)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn normalize_config_token(token: &str) -> String {
+    let lowercase = token.to_lowercase();
+    match lowercase.as_str() {
+        "yes" | "true" | "on" => return "yes".to_owned(),
+        "no" | "false" | "off" => return "no".to_owned(),
+        _ => (),
+    }
+
+    parse_memory_size(&lowercase)
+        .map(|bytes| bytes.to_string())
+        .unwrap_or_else(|| token.to_owned())
+}
+
+/// Parses a memory size with the units of `redis.conf`
+fn parse_memory_size(value: &str) -> Option<u64> {
+    let digits_end = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    if digits_end == 0 {
+        return None;
+    }
+
+    let (number, unit) = value.split_at(digits_end);
+    let multiplier: u64 = match unit {
+        "" | "b" => 1,
+        "k" => 1_000,
+        "kb" => 1_024,
+        "m" => 1_000_000,
+        "mb" => 1_024 * 1_024,
+        "g" => 1_000_000_000,
+        "gb" => 1_024 * 1_024 * 1_024,
+        _ => return None,
+    };
+
+    number.parse::<u64>().ok()?.checked_mul(multiplier)
+}
@@ -0,0 +1,122 @@
+use crate::{
+    client::{BatchPreparedCommand, Client},
+    commands::{DumpResult, GenericCommands, RestoreOptions},
+    resp::SingleArg,
+    Result,
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Options for the [`copy_key_between`] function
+#[derive(Default)]
+pub struct CopyKeyOptions {
+    replace: bool,
+    abs_ttl: bool,
+    delete_source: bool,
+}
+
+impl CopyKeyOptions {
+    /// Replace the key in the destination instance if it already exists.
+    #[must_use]
+    pub fn replace(mut self) -> Self {
+        self.replace = true;
+        self
+    }
+
+    /// Restore the key with an absolute Unix timestamp (`ABSTTL`) computed
+    /// when the key is read from the source instance, instead of a relative time to live.
+    ///
+    /// The key then expires at the same time in both instances,
+    /// regardless of the duration of the transfer.
+    /// Clocks of the client and of the destination instance must be synchronized.
+    #[must_use]
+    pub fn abs_ttl(mut self) -> Self {
+        self.abs_ttl = true;
+        self
+    }
+
+    /// Delete the key from the source instance once restored in the destination instance.
+    #[must_use]
+    pub fn delete_source(mut self) -> Self {
+        self.delete_source = true;
+        self
+    }
+}
+
+/// Copy a key between two unrelated Redis instances
+/// with [`DUMP`](https://redis.io/commands/dump/), [`PTTL`](https://redis.io/commands/pttl/)
+/// and [`RESTORE`](https://redis.io/commands/restore/).
+///
+/// Unlike [`migrate`](crate::commands::GenericCommands::migrate), the source instance does not need
+/// to connect to the destination instance: the serialized value transits through the client.
+///
+/// # Return
+/// `true` if the key has been copied, `false` if the key does not exist in the source instance.
+///
+/// # Errors
+/// Any Redis driver [`Error`](crate::Error) that occurs during the operation,
+/// for instance a `BUSYKEY` error if the key already exists in the destination instance
+/// and [`replace`](CopyKeyOptions::replace) is not set.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::{copy_key_between, CopyKeyOptions}, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let src_client = Client::connect("127.0.0.1:6379").await?;
+///     let dst_client = Client::connect("redis://127.0.0.1:6379/1").await?;
+///
+///     copy_key_between(&src_client, &dst_client, "key", CopyKeyOptions::default().replace()).await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn copy_key_between<K>(
+    src_client: &Client,
+    dst_client: &Client,
+    key: K,
+    options: CopyKeyOptions,
+) -> Result<bool>
+where
+    K: SingleArg + Clone,
+{
+    let mut pipeline = src_client.create_pipeline();
+    pipeline.dump(key.clone()).queue();
+    pipeline.pttl(key.clone()).queue();
+    let (dump, pttl): (Option<DumpResult>, i64) = pipeline.execute().await?;
+
+    // -2: the key does not exist
+    let (Some(DumpResult(serialized_value)), false) = (dump, pttl == -2) else {
+        return Ok(false);
+    };
+
+    let mut restore_options = RestoreOptions::default();
+    if options.replace {
+        restore_options = restore_options.replace();
+    }
+
+    // -1: the key has no associated expire
+    let ttl = if pttl < 0 {
+        0
+    } else if options.abs_ttl {
+        restore_options = restore_options.abs_ttl();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        now + pttl as u64
+    } else {
+        pttl as u64
+    };
+
+    dst_client
+        .restore(key.clone(), ttl, serialized_value, restore_options)
+        .await?;
+
+    if options.delete_source {
+        src_client.del(key).await?;
+    }
+
+    Ok(true)
+}
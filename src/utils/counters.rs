@@ -0,0 +1,263 @@
+use crate::{
+    client::{BatchPreparedCommand, Client},
+    commands::{ExpireOption, GenericCommands, StringCommands},
+    Error, Result,
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Maximum number of keys fetched by a single `MGET`
+const MGET_BATCH_SIZE: usize = 1000;
+
+/// Time window of the buckets of a [`Counters`]
+///
+/// Buckets are aligned on the Unix epoch, so day buckets are UTC days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Granularity {
+    /// One bucket per minute
+    Minute,
+    /// One bucket per hour
+    Hour,
+    /// One bucket per day
+    Day,
+}
+
+impl Granularity {
+    /// Duration of a bucket
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs(self.secs())
+    }
+
+    /// Default time during which the buckets are kept:
+    /// 2 hours of minutes, 2 days of hours and 60 days of days.
+    #[must_use]
+    pub fn default_retention(&self) -> Duration {
+        match self {
+            Granularity::Minute => Duration::from_secs(2 * 3600),
+            Granularity::Hour => Duration::from_secs(2 * 86400),
+            Granularity::Day => Duration::from_secs(60 * 86400),
+        }
+    }
+
+    fn secs(&self) -> u64 {
+        match self {
+            Granularity::Minute => 60,
+            Granularity::Hour => 3600,
+            Granularity::Day => 86400,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Granularity::Minute => "minute",
+            Granularity::Hour => "hour",
+            Granularity::Day => "day",
+        }
+    }
+
+    /// Start of the bucket containing `time`, in seconds since the Unix epoch
+    fn bucket(&self, time: SystemTime) -> u64 {
+        let secs = unix_secs(time);
+        secs - secs % self.secs()
+    }
+}
+
+/// Counters bucketed by time window
+///
+/// Each increment is applied to one bucket per configured [`Granularity`],
+/// stored at `<prefix>:<name>:<granularity>:<bucket start>`, the bucket start being
+/// a number of seconds since the Unix epoch.
+/// Buckets expire after the retention of their granularity.
+///
+/// Ranges of buckets are read back with pipelined [`MGET`](https://redis.io/commands/mget/) commands.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::{Counters, Granularity}, Result};
+/// use std::time::{Duration, SystemTime};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let counters = Counters::new(&client, "stats");
+///     counters.incr("page_views", 1).await?;
+///
+///     let now = SystemTime::now();
+///     let last_hour = counters
+///         .total(
+///             "page_views",
+///             Granularity::Minute,
+///             now - Duration::from_secs(3600),
+///             now,
+///         )
+///         .await?;
+///     assert!(last_hour >= 1);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Counters {
+    client: Client,
+    prefix: String,
+    granularities: Vec<(Granularity, Duration)>,
+}
+
+impl Counters {
+    /// Create new counters with keys prefixed by `prefix`,
+    /// bucketed by minute, hour and day with their default retentions.
+    #[must_use]
+    pub fn new(client: &Client, prefix: impl Into<String>) -> Self {
+        Self {
+            client: client.clone(),
+            prefix: prefix.into(),
+            granularities: [Granularity::Minute, Granularity::Hour, Granularity::Day]
+                .into_iter()
+                .map(|granularity| (granularity, granularity.default_retention()))
+                .collect(),
+        }
+    }
+
+    /// Granularities of the buckets, with their default retentions
+    #[must_use]
+    pub fn granularities(mut self, granularities: &[Granularity]) -> Self {
+        self.granularities = granularities
+            .iter()
+            .map(|granularity| (*granularity, granularity.default_retention()))
+            .collect();
+        self
+    }
+
+    /// Time during which the buckets of `granularity` are kept.
+    ///
+    /// The granularity is added if it is not configured yet.
+    #[must_use]
+    pub fn retention(mut self, granularity: Granularity, retention: Duration) -> Self {
+        match self
+            .granularities
+            .iter_mut()
+            .find(|(g, _)| *g == granularity)
+        {
+            Some((_, r)) => *r = retention,
+            None => self.granularities.push((granularity, retention)),
+        }
+        self
+    }
+
+    /// Increment the counter `name` by `increment` in the current bucket of each granularity.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn incr(&self, name: &str, increment: i64) -> Result<()> {
+        self.incr_at(name, increment, SystemTime::now()).await
+    }
+
+    /// Increment the counter `name` by `increment` in the buckets containing `time`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn incr_at(&self, name: &str, increment: i64, time: SystemTime) -> Result<()> {
+        if self.granularities.is_empty() {
+            return Ok(());
+        }
+
+        let now = unix_secs(SystemTime::now());
+        let mut pipeline = self.client.create_pipeline();
+        for (granularity, retention) in &self.granularities {
+            let bucket = granularity.bucket(time);
+            let key = self.key(name, *granularity, bucket);
+            // the retention starts at the end of the bucket
+            let expires_at = bucket + granularity.secs() + retention.as_secs();
+            let ttl = expires_at.saturating_sub(now).max(1);
+            pipeline.incrby(key.clone(), increment).forget();
+            pipeline.expire(key, ttl, ExpireOption::None).forget();
+        }
+        pipeline.execute::<()>().await
+    }
+
+    /// Value of the counter `name` in each bucket of `granularity` between `from` and `to`, inclusive.
+    ///
+    /// # Return
+    /// The start time of each bucket with its value, `0` for missing or expired buckets
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn range(
+        &self,
+        name: &str,
+        granularity: Granularity,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<Vec<(SystemTime, i64)>> {
+        let from = granularity.bucket(from);
+        let to = granularity.bucket(to);
+        if from > to {
+            return Err(Error::Client(
+                "The start of the range must not be after its end".to_owned(),
+            ));
+        }
+
+        let buckets: Vec<u64> = (from..=to).step_by(granularity.secs() as usize).collect();
+        let keys: Vec<String> = buckets
+            .iter()
+            .map(|bucket| self.key(name, granularity, *bucket))
+            .collect();
+
+        let chunks: Vec<&[String]> = keys.chunks(MGET_BATCH_SIZE).collect();
+        let values: Vec<Option<i64>> = if chunks.len() == 1 {
+            // a pipeline of a single command does not reply with an array
+            self.client.mget(chunks[0]).await?
+        } else {
+            let mut pipeline = self.client.create_pipeline();
+            for chunk in &chunks {
+                pipeline
+                    .mget::<_, _, Option<i64>, Vec<Option<i64>>>(*chunk)
+                    .queue();
+            }
+            let values: Vec<Vec<Option<i64>>> = pipeline.execute().await?;
+            values.into_iter().flatten().collect()
+        };
+
+        Ok(buckets
+            .into_iter()
+            .zip(values)
+            .map(|(bucket, value)| {
+                (
+                    UNIX_EPOCH + Duration::from_secs(bucket),
+                    value.unwrap_or_default(),
+                )
+            })
+            .collect())
+    }
+
+    /// Sum of the buckets of `granularity` of the counter `name` between `from` and `to`, inclusive.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn total(
+        &self,
+        name: &str,
+        granularity: Granularity,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<i64> {
+        Ok(self
+            .range(name, granularity, from, to)
+            .await?
+            .into_iter()
+            .map(|(_, value)| value)
+            .sum())
+    }
+
+    fn key(&self, name: &str, granularity: Granularity, bucket: u64) -> String {
+        format!("{}:{name}:{}:{bucket}", self.prefix, granularity.name())
+    }
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
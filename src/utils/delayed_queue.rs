@@ -0,0 +1,312 @@
+use crate::{
+    client::Client,
+    resp::{CommandArgs, SingleArg},
+    sleep,
+    utils::Script,
+    Result,
+};
+use futures_util::{stream, Stream};
+use rand::Rng;
+use std::{
+    collections::VecDeque,
+    fmt::Write,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const SCHEDULE: &str = r#"
+redis.call('HSET', KEYS[2], ARGV[1], ARGV[3])
+redis.call('ZADD', KEYS[1], ARGV[2], ARGV[1])
+"#;
+
+const CLAIM: &str = r#"
+local ids = redis.call('ZRANGEBYSCORE', KEYS[1], '-inf', ARGV[1], 'LIMIT', 0, ARGV[3])
+local jobs = {}
+for _, id in ipairs(ids) do
+    local payload = redis.call('HGET', KEYS[2], id)
+    if payload then
+        redis.call('ZADD', KEYS[1], ARGV[1] + ARGV[2], id)
+        local attempts = redis.call('HINCRBY', KEYS[3], id, 1)
+        table.insert(jobs, {id, payload, attempts})
+    else
+        redis.call('ZREM', KEYS[1], id)
+    end
+end
+return jobs
+"#;
+
+const ACK: &str = r#"
+redis.call('HDEL', KEYS[2], ARGV[1])
+redis.call('HDEL', KEYS[3], ARGV[1])
+return redis.call('ZREM', KEYS[1], ARGV[1])
+"#;
+
+const RETRY: &str = r#"
+if redis.call('ZSCORE', KEYS[1], ARGV[1]) then
+    redis.call('ZADD', KEYS[1], ARGV[2], ARGV[1])
+    return 1
+end
+return 0
+"#;
+
+/// Job delivered by a [`DelayedQueue`]
+///
+/// The job is delivered again after the visibility timeout of the queue
+/// until it is [acknowledged](DelayedJob::ack).
+pub struct DelayedJob {
+    /// Id of the job, generated when the job is scheduled
+    pub id: String,
+    /// Payload of the job
+    pub payload: String,
+    /// Number of times the job has been delivered, including this delivery
+    pub attempts: usize,
+    queue: DelayedQueue,
+}
+
+impl DelayedJob {
+    /// Acknowledge the job, removing it from the queue.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn ack(self) -> Result<()> {
+        self.queue.ack(&self.id).await?;
+        Ok(())
+    }
+
+    /// Deliver the job again after `delay`, instead of waiting for the visibility timeout.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn retry(self, delay: Duration) -> Result<()> {
+        self.queue.retry(&self.id, delay).await?;
+        Ok(())
+    }
+}
+
+/// Delayed job scheduler built on a sorted set
+///
+/// Jobs are stored in the sorted set `<queue>`, scored by the Unix time in milliseconds
+/// at which they are due, and their payloads in the hash `<queue>:jobs`.
+///
+/// Due jobs are claimed atomically by a Lua script which pushes back their score
+/// by the visibility timeout: a job which is not [acknowledged](DelayedQueue::ack) in time,
+/// for example because its worker has crashed, is delivered again.
+/// The number of deliveries of each job is kept in the hash `<queue>:attempts`.
+///
+/// Due times are computed from the clocks of the application instances,
+/// which are expected to be synchronized.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::DelayedQueue, Result};
+/// use futures_util::StreamExt;
+/// use std::time::Duration;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let queue = DelayedQueue::new(&client, "emails");
+///     queue.schedule_in("welcome:42", Duration::from_secs(60)).await?;
+///
+///     let mut jobs = Box::pin(queue.into_stream());
+///     # return Ok(());
+///     while let Some(job) = jobs.next().await {
+///         let job = job?;
+///         // process job.payload...
+///         job.ack().await?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DelayedQueue {
+    client: Client,
+    key: String,
+    visibility_timeout: Duration,
+    poll_interval: Duration,
+    batch_size: usize,
+}
+
+impl DelayedQueue {
+    /// Default time after which a claimed job which has not been acknowledged is delivered again
+    pub const DEFAULT_VISIBILITY_TIMEOUT: Duration = Duration::from_secs(60);
+    /// Default interval between two polls when no job is due
+    pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+    /// Default maximum number of jobs claimed at once
+    pub const DEFAULT_BATCH_SIZE: usize = 10;
+
+    /// Create a new queue stored at `key`
+    #[must_use]
+    pub fn new(client: &Client, key: impl Into<String>) -> Self {
+        Self {
+            client: client.clone(),
+            key: key.into(),
+            visibility_timeout: Self::DEFAULT_VISIBILITY_TIMEOUT,
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    /// Time after which a claimed job which has not been acknowledged is delivered again
+    #[must_use]
+    pub fn visibility_timeout(mut self, visibility_timeout: Duration) -> Self {
+        self.visibility_timeout = visibility_timeout;
+        self
+    }
+
+    /// Interval between two polls of the [stream](DelayedQueue::into_stream) when no job is due
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Maximum number of jobs claimed at once
+    #[must_use]
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Schedule a job to run at `run_at`.
+    ///
+    /// # Return
+    /// The generated id of the job
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn schedule<P: SingleArg>(&self, payload: P, run_at: SystemTime) -> Result<String> {
+        let id = generate_job_id();
+        let mut args = CommandArgs::default();
+        args.arg(&id).arg(unix_millis(run_at)).arg(payload);
+
+        Script::new(SCHEDULE)
+            .call::<_, _, _, _, ()>(&self.client, [self.key.clone(), self.jobs_key()], args)
+            .await?;
+
+        Ok(id)
+    }
+
+    /// Schedule a job to run after `delay`.
+    ///
+    /// # Return
+    /// The generated id of the job
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn schedule_in<P: SingleArg>(&self, payload: P, delay: Duration) -> Result<String> {
+        self.schedule(payload, SystemTime::now() + delay).await
+    }
+
+    /// Claim up to `batch_size` due jobs.
+    ///
+    /// Claimed jobs are delivered again after the visibility timeout,
+    /// unless they are acknowledged.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn claim(&self) -> Result<Vec<DelayedJob>> {
+        let mut args = CommandArgs::default();
+        args.arg(unix_millis(SystemTime::now()))
+            .arg(self.visibility_timeout.as_millis() as u64)
+            .arg(self.batch_size);
+
+        let jobs: Vec<(String, String, usize)> = Script::new(CLAIM)
+            .call(
+                &self.client,
+                [self.key.clone(), self.jobs_key(), self.attempts_key()],
+                args,
+            )
+            .await?;
+
+        Ok(jobs
+            .into_iter()
+            .map(|(id, payload, attempts)| DelayedJob {
+                id,
+                payload,
+                attempts,
+                queue: self.clone(),
+            })
+            .collect())
+    }
+
+    /// Acknowledge the job `id`, removing it from the queue.
+    ///
+    /// # Return
+    /// `false` if the job was not found
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn ack(&self, id: &str) -> Result<bool> {
+        Script::new(ACK)
+            .call(
+                &self.client,
+                [self.key.clone(), self.jobs_key(), self.attempts_key()],
+                id,
+            )
+            .await
+    }
+
+    /// Deliver the job `id` again after `delay`.
+    ///
+    /// # Return
+    /// `false` if the job was not found
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn retry(&self, id: &str, delay: Duration) -> Result<bool> {
+        let mut args = CommandArgs::default();
+        args.arg(id).arg(unix_millis(SystemTime::now() + delay));
+
+        Script::new(RETRY)
+            .call(&self.client, self.key.clone(), args)
+            .await
+    }
+
+    /// Convert the queue into an endless stream of due jobs,
+    /// polling the sorted set when no job is due.
+    pub fn into_stream(self) -> impl Stream<Item = Result<DelayedJob>> {
+        let state = (self, VecDeque::new());
+
+        stream::unfold(state, |(queue, mut buffer)| async move {
+            loop {
+                if let Some(job) = buffer.pop_front() {
+                    return Some((Ok(job), (queue, buffer)));
+                }
+
+                match queue.claim().await {
+                    Ok(jobs) if jobs.is_empty() => sleep(queue.poll_interval).await,
+                    Ok(jobs) => buffer.extend(jobs),
+                    Err(e) => return Some((Err(e), (queue, buffer))),
+                }
+            }
+        })
+    }
+
+    fn jobs_key(&self) -> String {
+        format!("{}:jobs", self.key)
+    }
+
+    fn attempts_key(&self) -> String {
+        format!("{}:attempts", self.key)
+    }
+}
+
+fn unix_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Random 128-bit identifier, hex encoded
+fn generate_job_id() -> String {
+    let bytes: [u8; 16] = rand::thread_rng().gen();
+    bytes
+        .iter()
+        .fold(String::with_capacity(32), |mut id, byte| {
+            let _ = write!(id, "{byte:02x}");
+            id
+        })
+}
@@ -0,0 +1,135 @@
+use crate::{
+    client::Client,
+    commands::{GenericCommands, ScanOptions},
+    sleep, Result,
+};
+use std::time::Duration;
+
+/// Options for the [`delete_matching`] function
+#[derive(Debug, Clone)]
+pub struct DeleteMatchingOptions {
+    batch_size: usize,
+    pause: Option<Duration>,
+}
+
+impl Default for DeleteMatchingOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 1000,
+            pause: None,
+        }
+    }
+}
+
+impl DeleteMatchingOptions {
+    /// Maximum number of keys deleted by a single [`UNLINK`](https://redis.io/commands/unlink/).
+    ///
+    /// The default is `1000`
+    #[must_use]
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Pause between two batches, to limit the load put on the server.
+    ///
+    /// By default, batches are sent without pause.
+    #[must_use]
+    pub fn pause(mut self, pause: Duration) -> Self {
+        self.pause = Some(pause);
+        self
+    }
+}
+
+/// Delete all keys matching `pattern`, without blocking the server.
+///
+/// Keys are iterated with [`SCAN`](https://redis.io/commands/scan/) and `MATCH`,
+/// and deleted in batches with [`UNLINK`](https://redis.io/commands/unlink/),
+/// which reclaims memory in a background thread of the server.
+///
+/// Keys created while the deletion is in progress may or may not be deleted.
+///
+/// # Return
+/// The number of deleted keys
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::Client,
+///     commands::StringCommands,
+///     utils::{delete_matching, DeleteMatchingOptions},
+///     Result,
+/// };
+/// use std::time::Duration;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     client.set("session:1", "John").await?;
+///
+///     let options = DeleteMatchingOptions::default()
+///         .batch_size(500)
+///         .pause(Duration::from_millis(10));
+///     let deleted = delete_matching(&client, "session:*", options).await?;
+///     assert!(deleted >= 1);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn delete_matching(
+    client: &Client,
+    pattern: impl Into<String>,
+    options: DeleteMatchingOptions,
+) -> Result<usize> {
+    let pattern = pattern.into();
+    let mut cursor = 0;
+    let mut batch = Vec::<String>::with_capacity(options.batch_size);
+    let mut num_deleted = 0;
+    let mut is_first_batch = true;
+
+    loop {
+        let (next_cursor, keys): (u64, Vec<String>) = client
+            .scan(
+                cursor,
+                ScanOptions::default()
+                    .match_pattern(pattern.as_str())
+                    .count(options.batch_size),
+            )
+            .await?;
+        cursor = next_cursor;
+
+        for key in keys {
+            batch.push(key);
+            if batch.len() == options.batch_size {
+                num_deleted += unlink_batch(client, &mut batch, &options, is_first_batch).await?;
+                is_first_batch = false;
+            }
+        }
+
+        if cursor == 0 {
+            break;
+        }
+    }
+
+    if !batch.is_empty() {
+        num_deleted += unlink_batch(client, &mut batch, &options, is_first_batch).await?;
+    }
+
+    Ok(num_deleted)
+}
+
+async fn unlink_batch(
+    client: &Client,
+    batch: &mut Vec<String>,
+    options: &DeleteMatchingOptions,
+    is_first_batch: bool,
+) -> Result<usize> {
+    if let (Some(pause), false) = (options.pause, is_first_batch) {
+        sleep(pause).await;
+    }
+
+    // keys returned several times by SCAN are only counted once by UNLINK
+    let num_deleted = client.unlink(std::mem::take(batch)).await?;
+    Ok(num_deleted)
+}
@@ -0,0 +1,491 @@
+use crate::{
+    client::{BatchPreparedCommand, Client},
+    commands::{GenericCommands, ScanOptions},
+    network::base64_encode,
+    resp::{cmd, Command, Value},
+    Result,
+};
+use futures_util::{stream, Stream, StreamExt};
+use serde_json::{json, Map, Number};
+use std::{collections::VecDeque, io::Write, time::Duration};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Output format of the [`export_keys`] and [`export_keys_async`] functions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One JSON object per line (`{"key":…,"type":…,"ttl":…,"value":…}`)
+    #[default]
+    JsonLines,
+    /// CSV with a `key,type,ttl,value` header,
+    /// where non-string values are encoded in JSON
+    Csv,
+}
+
+/// Options for the [`export_stream`], [`export_keys`] and [`export_keys_async`] functions
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    match_pattern: Option<String>,
+    key_type: Option<String>,
+    scan_count: usize,
+    format: ExportFormat,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            match_pattern: None,
+            key_type: None,
+            scan_count: 100,
+            format: ExportFormat::default(),
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Only export the keys matching this glob-style pattern
+    #[must_use]
+    pub fn match_pattern(mut self, match_pattern: impl Into<String>) -> Self {
+        self.match_pattern = Some(match_pattern.into());
+        self
+    }
+
+    /// Only export the keys of this type (`string`, `list`, `set`, `zset`, `hash` or `stream`)
+    #[must_use]
+    pub fn key_type(mut self, key_type: impl Into<String>) -> Self {
+        self.key_type = Some(key_type.into());
+        self
+    }
+
+    /// Number of keys hinted to the server for each `SCAN` iteration.
+    ///
+    /// The default is `100`
+    #[must_use]
+    pub fn scan_count(mut self, scan_count: usize) -> Self {
+        self.scan_count = scan_count.max(1);
+        self
+    }
+
+    /// Output format, newline-delimited JSON by default
+    #[must_use]
+    pub fn format(mut self, format: ExportFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// Prefix of the binary strings, encoded in base64, in the exported keys and values
+pub const EXPORT_BASE64_PREFIX: &str = "base64:";
+
+/// Key exported by [`export_stream`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedKey {
+    /// Name of the key
+    pub key: Vec<u8>,
+    /// Type of the value, as returned by [`TYPE`](https://redis.io/commands/type/)
+    pub key_type: String,
+    /// Remaining time to live of the key, `None` if the key has no expire
+    pub ttl: Option<Duration>,
+    /// Value of the key:
+    /// * a string for a `string`,
+    /// * an array of strings for a `list` or a `set`,
+    /// * an array of `[member, score]` pairs for a `zset`,
+    /// * an object for a `hash`,
+    /// * an array of `{"id": …, "fields": {…}}` objects for a `stream`,
+    /// * `null` for the other types.
+    ///
+    /// Strings which are not valid UTF-8 are encoded in base64 and prefixed with
+    /// [`EXPORT_BASE64_PREFIX`], like the strings which already start with this prefix,
+    /// so that binary keys and values are exported losslessly.
+    pub value: serde_json::Value,
+}
+
+impl ExportedKey {
+    /// Serializes the key as a single line JSON object, without the trailing newline
+    #[must_use]
+    pub fn to_json_line(&self) -> String {
+        json!({
+            "key": encode_string(&self.key),
+            "type": self.key_type,
+            "ttl": self.ttl.map(|ttl| ttl.as_millis() as u64),
+            "value": self.value,
+        })
+        .to_string()
+    }
+
+    /// Serializes the key as a CSV record, without the trailing newline
+    #[must_use]
+    pub fn to_csv_record(&self) -> String {
+        let ttl = self
+            .ttl
+            .map(|ttl| ttl.as_millis().to_string())
+            .unwrap_or_default();
+        let value = match &self.value {
+            serde_json::Value::String(value) => value.clone(),
+            value => value.to_string(),
+        };
+
+        format!(
+            "{},{},{},{}",
+            csv_field(&encode_string(&self.key)),
+            csv_field(&self.key_type),
+            ttl,
+            csv_field(&value)
+        )
+    }
+
+    fn to_line(&self, format: ExportFormat) -> String {
+        let mut line = match format {
+            ExportFormat::JsonLines => self.to_json_line(),
+            ExportFormat::Csv => self.to_csv_record(),
+        };
+        line.push('\n');
+        line
+    }
+}
+
+/// Header line of the CSV format
+const CSV_HEADER: &str = "key,type,ttl,value\n";
+
+struct ExportState {
+    /// `None` once the server has returned the final cursor
+    cursor: Option<u64>,
+    keys: VecDeque<ExportedKey>,
+}
+
+/// Returns a [`Stream`] of the keys of the database, with their value and their time to live.
+///
+/// The keyspace is iterated with [`SCAN`](https://redis.io/commands/scan/).
+/// For each batch of scanned keys, `TYPE` and `PTTL` are sent in a single pipeline,
+/// then the values are fetched in a second pipeline with `GET`, `LRANGE`, `SMEMBERS`,
+/// `ZRANGE`, `HGETALL` or `XRANGE`, depending on their type.
+///
+/// Keys modified during the export may or may not be exported with their latest value.
+pub fn export_stream(
+    client: &Client,
+    options: ExportOptions,
+) -> impl Stream<Item = Result<ExportedKey>> + '_ {
+    let state = ExportState {
+        cursor: Some(0),
+        keys: VecDeque::new(),
+    };
+
+    stream::try_unfold(state, move |mut state| {
+        let options = options.clone();
+        async move {
+            loop {
+                if let Some(key) = state.keys.pop_front() {
+                    return Ok(Some((key, state)));
+                }
+
+                let Some(cursor) = state.cursor else {
+                    return Ok(None);
+                };
+
+                let mut scan_options = ScanOptions::default().count(options.scan_count);
+                if let Some(match_pattern) = &options.match_pattern {
+                    scan_options = scan_options.match_pattern(match_pattern.as_str());
+                }
+                if let Some(key_type) = &options.key_type {
+                    scan_options = scan_options.type_(key_type.as_str());
+                }
+                let (next_cursor, keys): (u64, Vec<Vec<u8>>) =
+                    client.scan(cursor, scan_options).await?;
+
+                state.cursor = if next_cursor == 0 {
+                    None
+                } else {
+                    Some(next_cursor)
+                };
+
+                state.keys.extend(export_batch(client, keys).await?);
+            }
+        }
+    })
+}
+
+/// Exports the keys of the database to `writer`, in the format given by the `options`.
+///
+/// See [`export_stream`] for the details of the export.
+///
+/// # Return
+/// The number of exported keys
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::Client,
+///     utils::{export_keys, ExportFormat, ExportOptions},
+///     Result,
+/// };
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let mut csv = Vec::new();
+///     let options = ExportOptions::default()
+///         .match_pattern("user:*")
+///         .format(ExportFormat::Csv);
+///     let num_keys = export_keys(&client, options, &mut csv).await?;
+///     println!("{num_keys} keys exported:\n{}", String::from_utf8_lossy(&csv));
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn export_keys<W: Write>(
+    client: &Client,
+    options: ExportOptions,
+    mut writer: W,
+) -> Result<usize> {
+    let format = options.format;
+    if format == ExportFormat::Csv {
+        writer.write_all(CSV_HEADER.as_bytes())?;
+    }
+
+    let mut num_keys = 0;
+    let mut keys = Box::pin(export_stream(client, options));
+    while let Some(key) = keys.next().await {
+        writer.write_all(key?.to_line(format).as_bytes())?;
+        num_keys += 1;
+    }
+
+    writer.flush()?;
+    Ok(num_keys)
+}
+
+/// Asynchronous variant of [`export_keys`], writing to an [`AsyncWrite`] sink
+///
+/// # Return
+/// The number of exported keys
+pub async fn export_keys_async<W: AsyncWrite + Unpin>(
+    client: &Client,
+    options: ExportOptions,
+    mut writer: W,
+) -> Result<usize> {
+    let format = options.format;
+    if format == ExportFormat::Csv {
+        writer.write_all(CSV_HEADER.as_bytes()).await?;
+    }
+
+    let mut num_keys = 0;
+    let mut keys = Box::pin(export_stream(client, options));
+    while let Some(key) = keys.next().await {
+        writer.write_all(key?.to_line(format).as_bytes()).await?;
+        num_keys += 1;
+    }
+
+    writer.flush().await?;
+    Ok(num_keys)
+}
+
+async fn export_batch(client: &Client, keys: Vec<Vec<u8>>) -> Result<Vec<ExportedKey>> {
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut pipeline = client.create_pipeline();
+    for key in &keys {
+        pipeline.type_(key.as_slice()).queue();
+        pipeline.pttl(key.as_slice()).queue();
+    }
+    let values: Vec<Value> = pipeline.execute().await?;
+
+    let mut exported_keys = Vec::new();
+    let mut values = values.into_iter();
+    for key in keys {
+        let (Some(key_type), Some(ttl)) = (values.next(), values.next()) else {
+            break;
+        };
+
+        let key_type: String = key_type.into()?;
+        // the key has been deleted or has expired since it was scanned
+        if key_type == "none" {
+            continue;
+        }
+
+        let ttl: i64 = ttl.into()?;
+        exported_keys.push(ExportedKey {
+            key,
+            key_type,
+            ttl: u64::try_from(ttl).ok().map(Duration::from_millis),
+            value: serde_json::Value::Null,
+        });
+    }
+
+    let commands: Vec<(usize, Command)> = exported_keys
+        .iter()
+        .enumerate()
+        .filter_map(|(i, exported_key)| {
+            value_command(&exported_key.key_type, &exported_key.key).map(|command| (i, command))
+        })
+        .collect();
+
+    let values: Vec<Value> = match commands.len() {
+        0 => Vec::new(),
+        // a pipeline of a single command does not reply with an array
+        1 => vec![client.send(commands[0].1.clone(), None).await?.to()?],
+        _ => {
+            let mut pipeline = client.create_pipeline();
+            for (_, command) in &commands {
+                pipeline.queue(command.clone());
+            }
+            pipeline.execute().await?
+        }
+    };
+
+    for ((i, _), value) in commands.into_iter().zip(values) {
+        let exported_key = &mut exported_keys[i];
+        exported_key.value = json_value(&exported_key.key_type, value);
+    }
+
+    Ok(exported_keys)
+}
+
+fn value_command(key_type: &str, key: &[u8]) -> Option<Command> {
+    let command = match key_type {
+        "string" => cmd("GET").arg(key),
+        "list" => cmd("LRANGE").arg(key).arg(0).arg(-1),
+        "set" => cmd("SMEMBERS").arg(key),
+        "zset" => cmd("ZRANGE").arg(key).arg(0).arg(-1).arg("WITHSCORES"),
+        "hash" => cmd("HGETALL").arg(key),
+        "stream" => cmd("XRANGE").arg(key).arg("-").arg("+"),
+        _ => return None,
+    };
+
+    Some(command)
+}
+
+/// Converts the reply of the command returned by [`value_command`] to JSON,
+/// for both RESP2 and RESP3
+pub(crate) fn json_value(key_type: &str, value: Value) -> serde_json::Value {
+    match key_type {
+        "zset" => serde_json::Value::Array(
+            pairs(value)
+                .into_iter()
+                .map(|(member, score)| json!([to_json(member), to_json_number(score)]))
+                .collect(),
+        ),
+        "hash" => serde_json::Value::Object(to_json_object(value)),
+        "stream" => serde_json::Value::Array(
+            elements(value)
+                .into_iter()
+                .map(|entry| {
+                    let mut entry = elements(entry).into_iter();
+                    let id = entry.next().map(to_json).unwrap_or_default();
+                    let fields = entry.next().map(to_json_object).unwrap_or_default();
+                    json!({ "id": id, "fields": fields })
+                })
+                .collect(),
+        ),
+        _ => to_json(value),
+    }
+}
+
+fn elements(value: Value) -> Vec<Value> {
+    match value {
+        Value::Array(elements) | Value::Set(elements) | Value::Push(elements) => elements,
+        Value::Map(map) => map.into_iter().flat_map(|(k, v)| [k, v]).collect(),
+        Value::Nil => Vec::new(),
+        value => vec![value],
+    }
+}
+
+/// Pairs of a flat array (RESP2) or of an array of pairs or a map (RESP3)
+fn pairs(value: Value) -> Vec<(Value, Value)> {
+    let elements = elements(value);
+    if !elements.is_empty()
+        && elements
+            .iter()
+            .all(|element| matches!(element, Value::Array(pair) if pair.len() == 2))
+    {
+        return elements
+            .into_iter()
+            .filter_map(|element| {
+                let mut pair = elements_of_pair(element)?;
+                Some((pair.next()?, pair.next()?))
+            })
+            .collect();
+    }
+
+    let mut elements = elements.into_iter();
+    let mut pairs = Vec::new();
+    while let (Some(first), Some(second)) = (elements.next(), elements.next()) {
+        pairs.push((first, second));
+    }
+    pairs
+}
+
+fn elements_of_pair(value: Value) -> Option<std::vec::IntoIter<Value>> {
+    match value {
+        Value::Array(pair) => Some(pair.into_iter()),
+        _ => None,
+    }
+}
+
+fn to_json_object(value: Value) -> Map<String, serde_json::Value> {
+    pairs(value)
+        .into_iter()
+        .map(|(field, value)| (json_key(field), to_json(value)))
+        .collect()
+}
+
+fn json_key(value: Value) -> String {
+    match to_json(value) {
+        serde_json::Value::String(key) => key,
+        key => key.to_string(),
+    }
+}
+
+/// Sorted set scores are bulk strings in RESP2 and doubles in RESP3
+fn to_json_number(value: Value) -> serde_json::Value {
+    match value {
+        Value::BulkString(score) => std::str::from_utf8(&score)
+            .ok()
+            .and_then(|score| score.parse::<f64>().ok())
+            .and_then(Number::from_f64)
+            .map(serde_json::Value::Number)
+            .unwrap_or_else(|| serde_json::Value::String(encode_string(&score))),
+        value => to_json(value),
+    }
+}
+
+fn to_json(value: Value) -> serde_json::Value {
+    match value {
+        Value::SimpleString(s) => serde_json::Value::String(encode_string(s.as_bytes())),
+        Value::Integer(i) => serde_json::Value::Number(i.into()),
+        Value::Double(d) => Number::from_f64(d)
+            .map(serde_json::Value::Number)
+            .unwrap_or_else(|| serde_json::Value::String(d.to_string())),
+        Value::BulkString(bytes) => serde_json::Value::String(encode_string(&bytes)),
+        Value::Boolean(b) => serde_json::Value::Bool(b),
+        Value::Array(elements) | Value::Set(elements) | Value::Push(elements) => {
+            serde_json::Value::Array(elements.into_iter().map(to_json).collect())
+        }
+        Value::Map(map) => serde_json::Value::Object(
+            map.into_iter()
+                .map(|(k, v)| (json_key(k), to_json(v)))
+                .collect(),
+        ),
+        Value::Error(e) => serde_json::Value::String(e.to_string()),
+        Value::Nil => serde_json::Value::Null,
+    }
+}
+
+/// Strings which are not valid UTF-8, or which start with [`EXPORT_BASE64_PREFIX`],
+/// are encoded in base64 after this prefix
+pub(crate) fn encode_string(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(s) if !s.starts_with(EXPORT_BASE64_PREFIX) => s.to_owned(),
+        _ => format!("{EXPORT_BASE64_PREFIX}{}", base64_encode(bytes)),
+    }
+}
+
+/// Quotes a CSV field if needed, as specified by RFC 4180
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{
+    client::{prepare_command, Client, PreparedCommand},
+    commands::{CallBuilder, FunctionListOptions, ScriptingCommands},
+    resp::{cmd, Response, SingleArg, SingleArgCollection},
+    Result,
+};
+use std::fmt::Write;
+
+#[derive(Debug, Clone)]
+struct LibraryFunction {
+    name: String,
+    body: String,
+    read_only: bool,
+}
+
+/// A library of [Redis Functions](https://redis.io/docs/manual/programmability/functions-intro/)
+/// packaged from Lua function bodies.
+///
+/// The library source is generated with one
+/// [`redis.register_function`](https://redis.io/docs/manual/programmability/lua-api/#redis.register_function)
+/// call per declared function, read-only functions being registered with the `no-writes` flag.
+///
+/// [`register`](FunctionLibrary::register) loads the library only when the version on the server
+/// differs from the local one, so it can be called at each application startup.
+/// Functions are then invoked with [`call`](FunctionLibrary::call), which sends
+/// [`FCALL_RO`](https://redis.io/commands/fcall_ro/) for read-only functions,
+/// allowing them to run on replicas, and [`FCALL`](https://redis.io/commands/fcall/) otherwise.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::FunctionLibrary, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let library = FunctionLibrary::new("counters")
+///         .function(
+///             "incr_capped",
+///             r#"function(keys, args)
+///                 local value = redis.call('INCR', keys[1])
+///                 if value > tonumber(args[1]) then
+///                     redis.call('SET', keys[1], args[1])
+///                     return tonumber(args[1])
+///                 end
+///                 return value
+///             end"#,
+///         )
+///         .read_only_function(
+///             "get_or_zero",
+///             "function(keys, args) return tonumber(redis.call('GET', keys[1]) or 0) end",
+///         );
+///     library.register(&client).await?;
+///
+///     let value: i64 = library.call(&client, "incr_capped", "counter", 10).await?;
+///     assert!(value <= 10);
+///     let value: i64 = library.call(&client, "get_or_zero", "counter", Vec::<String>::new()).await?;
+///     assert!(value <= 10);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FunctionLibrary {
+    name: String,
+    functions: Vec<LibraryFunction>,
+}
+
+impl FunctionLibrary {
+    /// Create a new empty library
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            functions: Vec::new(),
+        }
+    }
+
+    /// Declare a function of the library.
+    ///
+    /// `body` is a Lua function expression taking the keys and the arguments,
+    /// e.g. `function(keys, args) return redis.call('GET', keys[1]) end`
+    #[must_use]
+    pub fn function(mut self, name: impl Into<String>, body: impl Into<String>) -> Self {
+        self.functions.push(LibraryFunction {
+            name: name.into(),
+            body: body.into(),
+            read_only: false,
+        });
+        self
+    }
+
+    /// Declare a function of the library which does not write,
+    /// registered with the `no-writes` flag and invoked with `FCALL_RO`.
+    #[must_use]
+    pub fn read_only_function(mut self, name: impl Into<String>, body: impl Into<String>) -> Self {
+        self.functions.push(LibraryFunction {
+            name: name.into(),
+            body: body.into(),
+            read_only: true,
+        });
+        self
+    }
+
+    /// Name of the library
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Generated Lua source code of the library
+    #[must_use]
+    pub fn code(&self) -> String {
+        let mut code = format!("#!lua name={}\n", self.name);
+
+        for function in &self.functions {
+            let _ = write!(
+                code,
+                "\nredis.register_function{{\n  function_name='{}',\n  callback={},\n  flags={{{}}}\n}}\n",
+                function.name,
+                function.body,
+                if function.read_only { "'no-writes'" } else { "" }
+            );
+        }
+
+        code
+    }
+
+    /// Hexadecimal SHA1 digest of the generated source code
+    #[must_use]
+    pub fn checksum(&self) -> String {
+        sha1_smol::Sha1::from(self.code()).digest().to_string()
+    }
+
+    /// Load the library on the server, unless the loaded version is identical.
+    ///
+    /// The source code of the loaded version is retrieved with
+    /// [`FUNCTION LIST`](https://redis.io/commands/function-list/) and compared by checksum.
+    ///
+    /// # Return
+    /// `true` if the library has been loaded, `false` if it was up to date
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation,
+    /// including compilation errors of the library
+    pub async fn register(&self, client: &Client) -> Result<bool> {
+        let code = self.code();
+        let checksum = sha1_smol::Sha1::from(&code).digest().to_string();
+
+        let libraries = client
+            .function_list(
+                FunctionListOptions::default()
+                    .library_name_pattern(self.name.as_str())
+                    .with_code(),
+            )
+            .await?;
+
+        let up_to_date = libraries.iter().any(|library| {
+            library.library_name == self.name
+                && library.library_code.as_ref().is_some_and(|code| {
+                    sha1_smol::Sha1::from(code).digest().to_string() == checksum
+                })
+        });
+
+        if up_to_date {
+            return Ok(false);
+        }
+
+        client.function_load::<_, String>(true, code).await?;
+        Ok(true)
+    }
+
+    /// Invoke a function of the library with the given keys and arguments.
+    ///
+    /// Functions declared with [`read_only_function`](FunctionLibrary::read_only_function)
+    /// are invoked with `FCALL_RO`, the other ones with `FCALL`.
+    ///
+    /// # Return
+    /// The return value of the function
+    #[must_use]
+    pub fn call<'a, K, KK, A, AA, R>(
+        &self,
+        client: &'a Client,
+        function: &str,
+        keys: KK,
+        args: AA,
+    ) -> PreparedCommand<'a, &'a Client, R>
+    where
+        K: SingleArg,
+        KK: SingleArgCollection<K>,
+        A: SingleArg,
+        AA: SingleArgCollection<A>,
+        R: Response,
+    {
+        let read_only = self
+            .functions
+            .iter()
+            .any(|f| f.read_only && f.name == function);
+
+        prepare_command(
+            client,
+            cmd(if read_only { "FCALL_RO" } else { "FCALL" })
+                .arg(CallBuilder::function(function).keys(keys).args(args)),
+        )
+    }
+}
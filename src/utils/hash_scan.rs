@@ -0,0 +1,135 @@
+use crate::{
+    client::Client,
+    commands::{HScanOptions, HashCommands},
+    resp::PrimitiveResponse,
+    Result,
+};
+use futures_util::{stream, Stream};
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+
+/// Number of fields hinted to the server for each `HSCAN` iteration
+const HSCAN_COUNT: usize = 1000;
+
+struct HashScanState<T> {
+    /// `None` once the server has returned the final cursor
+    cursor: Option<u64>,
+    items: VecDeque<T>,
+}
+
+impl<T> HashScanState<T> {
+    fn new() -> Self {
+        Self {
+            cursor: Some(0),
+            items: VecDeque::new(),
+        }
+    }
+}
+
+/// Returns a [`Stream`] of the fields of the hash stored at `key`, fetched incrementally with
+/// [`HSCAN`](https://redis.io/commands/hscan/) and `NOVALUES`.
+///
+/// Unlike [`hkeys`](HashCommands::hkeys), huge hashes are traversed without a single enormous reply
+/// and other clients are served between two iterations.
+/// As a counterpart, fields added or removed during the iteration may or may not be returned,
+/// and a field may be returned more than once.
+///
+/// Requires Redis 7.4 or later.
+///
+/// # Example
+/// ```
+/// use futures_util::TryStreamExt;
+/// use rustis::{client::Client, commands::HashCommands, utils::hash_fields_stream, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     client.hset("user:1", [("name", "John"), ("surname", "Doe")]).await?;
+///
+///     let mut fields: Vec<String> = hash_fields_stream(&client, "user:1").try_collect().await?;
+///     fields.sort();
+///     assert_eq!(vec!["name".to_owned(), "surname".to_owned()], fields);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn hash_fields_stream<F>(
+    client: &Client,
+    key: impl Into<String>,
+) -> impl Stream<Item = Result<F>> + '_
+where
+    F: PrimitiveResponse + DeserializeOwned + Send + 'static,
+{
+    let key = key.into();
+
+    stream::try_unfold(HashScanState::new(), move |mut state| {
+        let key = key.clone();
+        async move {
+            loop {
+                if let Some(field) = state.items.pop_front() {
+                    return Ok(Some((field, state)));
+                }
+
+                let Some(cursor) = state.cursor else {
+                    return Ok(None);
+                };
+
+                let (next_cursor, fields): (u64, Vec<F>) = client
+                    .hscan_novalues(
+                        key.as_str(),
+                        cursor,
+                        HScanOptions::default().count(HSCAN_COUNT),
+                    )
+                    .await?;
+
+                state.cursor = (next_cursor != 0).then_some(next_cursor);
+                state.items.extend(fields);
+            }
+        }
+    })
+}
+
+/// Returns a [`Stream`] of the fields and values of the hash stored at `key`, fetched incrementally with
+/// [`HSCAN`](https://redis.io/commands/hscan/).
+///
+/// Unlike [`hgetall`](HashCommands::hgetall), huge hashes are traversed without a single enormous reply
+/// and other clients are served between two iterations.
+/// As a counterpart, fields added or removed during the iteration may or may not be returned,
+/// and a field may be returned more than once.
+pub fn hash_entries_stream<F, V>(
+    client: &Client,
+    key: impl Into<String>,
+) -> impl Stream<Item = Result<(F, V)>> + '_
+where
+    F: PrimitiveResponse + DeserializeOwned + Send + 'static,
+    V: PrimitiveResponse + DeserializeOwned + Send + 'static,
+{
+    let key = key.into();
+
+    stream::try_unfold(HashScanState::new(), move |mut state| {
+        let key = key.clone();
+        async move {
+            loop {
+                if let Some(entry) = state.items.pop_front() {
+                    return Ok(Some((entry, state)));
+                }
+
+                let Some(cursor) = state.cursor else {
+                    return Ok(None);
+                };
+
+                let result = client
+                    .hscan::<_, F, V>(
+                        key.as_str(),
+                        cursor,
+                        HScanOptions::default().count(HSCAN_COUNT),
+                    )
+                    .await?;
+
+                state.cursor = (result.cursor != 0).then_some(result.cursor);
+                state.items.extend(result.elements);
+            }
+        }
+    })
+}
@@ -0,0 +1,361 @@
+use crate::{
+    client::Client,
+    resp::{Command, Value},
+    Error, Result,
+};
+use std::io::{BufRead, Read};
+
+/// Input format of the [`import_commands`] function
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImportFormat {
+    /// One command per line, in the inline protocol of `redis-cli`
+    /// (e.g. `SET "hello world" 12`).
+    ///
+    /// Empty lines and lines starting with `#` are ignored.
+    #[default]
+    Inline,
+    /// One JSON array of strings or numbers per line (e.g. `["SET", "hello world", 12]`).
+    ///
+    /// Empty lines are ignored.
+    JsonLines,
+    /// Commands encoded as RESP arrays of bulk strings,
+    /// like in an append-only file (`appendonly.aof`)
+    Resp,
+}
+
+/// Behavior of the [`import_commands`] function when a command cannot be parsed
+/// or is rejected by the server
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImportErrorPolicy {
+    /// Stop at the first error and return it
+    #[default]
+    Abort,
+    /// Count the errors and go on with the next commands
+    Skip,
+    /// Collect the errors in the [`ImportReport`] and go on with the next commands
+    Collect,
+}
+
+/// Options for the [`import_commands`] function
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    format: ImportFormat,
+    batch_size: usize,
+    error_policy: ImportErrorPolicy,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            format: ImportFormat::default(),
+            batch_size: 100,
+            error_policy: ImportErrorPolicy::default(),
+        }
+    }
+}
+
+impl ImportOptions {
+    /// Input format, the inline protocol by default
+    #[must_use]
+    pub fn format(mut self, format: ImportFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Maximum number of commands sent in a single pipeline.
+    ///
+    /// The default is `100`
+    #[must_use]
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Behavior on parsing or command errors, [`Abort`](ImportErrorPolicy::Abort) by default
+    #[must_use]
+    pub fn error_policy(mut self, error_policy: ImportErrorPolicy) -> Self {
+        self.error_policy = error_policy;
+        self
+    }
+}
+
+/// Error collected by [`import_commands`] with the [`Collect`](ImportErrorPolicy::Collect) policy
+#[derive(Debug)]
+pub struct ImportError {
+    /// Line of the input where the command starts, starting at 1
+    pub line: usize,
+    /// Name of the command, `None` if the command could not be parsed
+    pub command_name: Option<&'static str>,
+    /// Parsing error or error returned by the server
+    pub error: Error,
+}
+
+/// Outcome of [`import_commands`]
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    /// Number of commands successfully executed
+    pub num_commands: usize,
+    /// Number of commands which could not be parsed or were rejected by the server
+    pub num_errors: usize,
+    /// Errors collected with the [`Collect`](ImportErrorPolicy::Collect) policy
+    pub errors: Vec<ImportError>,
+}
+
+impl ImportReport {
+    fn record_error(&mut self, error: ImportError, policy: ImportErrorPolicy) -> Result<()> {
+        self.num_errors += 1;
+        match policy {
+            ImportErrorPolicy::Abort => Err(error.error),
+            ImportErrorPolicy::Skip => Ok(()),
+            ImportErrorPolicy::Collect => {
+                self.errors.push(error);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Parses a single line of a command file in the [`Inline`](ImportFormat::Inline)
+/// or the [`JsonLines`](ImportFormat::JsonLines) format.
+///
+/// # Return
+/// `None` for an empty line or a comment
+///
+/// # Errors
+/// A [`Client`](crate::Error::Client) error if the line is malformed
+/// or if `format` is [`Resp`](ImportFormat::Resp)
+pub fn parse_command_line(line: &str, format: ImportFormat) -> Result<Option<Command>> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Ok(None);
+    }
+
+    match format {
+        ImportFormat::Inline if line.starts_with('#') => Ok(None),
+        ImportFormat::Inline => Command::parse_inline(line).map(Some),
+        ImportFormat::JsonLines => {
+            let args: Vec<serde_json::Value> = serde_json::from_str(line)
+                .map_err(|e| Error::Client(format!("Invalid JSON command: {e}")))?;
+            let args = args
+                .into_iter()
+                .map(|arg| match arg {
+                    serde_json::Value::String(arg) => Ok(arg),
+                    serde_json::Value::Number(arg) => Ok(arg.to_string()),
+                    arg => Err(Error::Client(format!(
+                        "Invalid JSON command argument: {arg}"
+                    ))),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Command::parse_cli(args).map(Some)
+        }
+        ImportFormat::Resp => Err(Error::Client(
+            "The RESP format cannot be parsed line by line".to_owned(),
+        )),
+    }
+}
+
+/// Replays the commands read from `reader` against the server, in the format given by the `options`.
+///
+/// Commands are sent in pipelines of up to [`batch_size`](ImportOptions::batch_size) commands,
+/// and their errors are handled according to the [`error_policy`](ImportOptions::error_policy).
+/// With the [`Abort`](ImportErrorPolicy::Abort) policy, the commands of the pipeline
+/// of the failing command have already been executed when the error is returned.
+///
+/// A framing error of a RESP input (an invalid header or bulk string) cannot be resynchronized:
+/// it always aborts the import, whatever the error policy,
+/// once the commands read before it have been executed.
+/// A well-framed RESP command which is invalid (e.g. an empty array)
+/// is handled according to the error policy.
+///
+/// The commands are sent on the multiplexed connection of `client`:
+/// a `SELECT` read from the input also changes the database of the other users of the client.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::Client,
+///     utils::{import_commands, ImportErrorPolicy, ImportOptions},
+///     Result,
+/// };
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let seed = "\
+///         # users\n\
+///         HSET user:1 name \"John Doe\" age 42\n\
+///         SADD users 1\n";
+///     let options = ImportOptions::default().error_policy(ImportErrorPolicy::Collect);
+///     let report = import_commands(&client, options, seed.as_bytes()).await?;
+///     assert_eq!(2, report.num_commands);
+///     assert!(report.errors.is_empty());
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn import_commands<R: BufRead>(
+    client: &Client,
+    options: ImportOptions,
+    mut reader: R,
+) -> Result<ImportReport> {
+    let mut report = ImportReport::default();
+    let mut batch: Vec<(usize, Command)> = Vec::with_capacity(options.batch_size);
+    let mut line_number = 0;
+    let mut buf = String::new();
+
+    loop {
+        let (command_line, command) = if options.format == ImportFormat::Resp {
+            match read_resp_command(&mut reader, &mut line_number) {
+                Ok(Some(command)) => command,
+                Ok(None) => break,
+                Err(error) => {
+                    // the input cannot be resynchronized, only the previous commands are executed
+                    execute_batch(client, &mut batch, &mut report, options.error_policy).await?;
+                    return Err(error);
+                }
+            }
+        } else {
+            buf.clear();
+            if reader.read_line(&mut buf)? == 0 {
+                break;
+            }
+            line_number += 1;
+
+            match parse_command_line(&buf, options.format).transpose() {
+                Some(command) => (line_number, command),
+                None => continue,
+            }
+        };
+
+        let command = match command {
+            Ok(command) => command,
+            Err(error) => {
+                // report the errors of the previous commands first
+                if options.error_policy == ImportErrorPolicy::Abort {
+                    execute_batch(client, &mut batch, &mut report, options.error_policy).await?;
+                }
+                let error = ImportError {
+                    line: command_line,
+                    command_name: None,
+                    error,
+                };
+                report.record_error(error, options.error_policy)?;
+                continue;
+            }
+        };
+
+        batch.push((command_line, command));
+        if batch.len() >= options.batch_size {
+            execute_batch(client, &mut batch, &mut report, options.error_policy).await?;
+        }
+    }
+
+    execute_batch(client, &mut batch, &mut report, options.error_policy).await?;
+    report.errors.sort_by_key(|error| error.line);
+    Ok(report)
+}
+
+async fn execute_batch(
+    client: &Client,
+    batch: &mut Vec<(usize, Command)>,
+    report: &mut ImportReport,
+    error_policy: ImportErrorPolicy,
+) -> Result<()> {
+    if batch.is_empty() {
+        return Ok(());
+    }
+
+    let (lines, commands): (Vec<_>, Vec<_>) = batch.drain(..).unzip();
+    let names: Vec<&'static str> = commands.iter().map(|command| command.name).collect();
+    let replies = client.send_batch(commands, None).await?;
+
+    for ((line, name), reply) in lines.into_iter().zip(names).zip(replies) {
+        let error = match reply.to::<Value>() {
+            Ok(Value::Error(e)) => Error::Redis(e),
+            Ok(_) => {
+                report.num_commands += 1;
+                continue;
+            }
+            Err(e) => e,
+        };
+
+        let error = ImportError {
+            line,
+            command_name: Some(name),
+            error,
+        };
+        report.record_error(error, error_policy)?;
+    }
+
+    Ok(())
+}
+
+/// Maximum length of a bulk string, the default `proto-max-bulk-len` of the server
+const MAX_BULK_LEN: usize = 512 * 1024 * 1024;
+/// Arguments are not preallocated beyond this count, the header of the array is not trusted
+const MAX_PREALLOCATED_ARGS: usize = 1024;
+
+/// Reads a RESP array of bulk strings with the line where it starts, `None` at the end of the input
+///
+/// The outer error is a framing error, after which the input cannot be read any further,
+/// the inner error is an invalid command.
+pub(crate) fn read_resp_command<R: BufRead>(
+    reader: &mut R,
+    line_number: &mut usize,
+) -> Result<Option<(usize, Result<Command>)>> {
+    let mut header = String::new();
+    loop {
+        header.clear();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        *line_number += 1;
+        if !header.trim().is_empty() {
+            break;
+        }
+    }
+
+    let command_line = *line_number;
+    let num_args = parse_resp_header(&header, '*', command_line)?;
+    let mut args = Vec::with_capacity(num_args.min(MAX_PREALLOCATED_ARGS));
+    for _ in 0..num_args {
+        header.clear();
+        reader.read_line(&mut header)?;
+        *line_number += 1;
+        let len = parse_resp_header(&header, '$', *line_number)?;
+        if len > MAX_BULK_LEN {
+            return Err(Error::Client(format!(
+                "RESP bulk string of {len} bytes at line {line_number} exceeds {MAX_BULK_LEN} bytes"
+            )));
+        }
+
+        // the input is read as it comes, a truncated input does not allocate the announced length
+        let mut arg = Vec::new();
+        reader.take(len as u64 + 2).read_to_end(&mut arg)?;
+        if arg.len() != len + 2 || !arg.ends_with(b"\r\n") {
+            return Err(Error::Client(format!(
+                "Invalid RESP bulk string at line {line_number}"
+            )));
+        }
+        arg.truncate(len);
+        *line_number += arg.iter().filter(|b| **b == b'\n').count() + 1;
+        args.push(arg);
+    }
+
+    Ok(Some((command_line, Command::parse_cli(args))))
+}
+
+fn parse_resp_header(header: &str, tag: char, line_number: usize) -> Result<usize> {
+    header
+        .trim_end()
+        .strip_prefix(tag)
+        .and_then(|len| len.parse().ok())
+        .ok_or_else(|| {
+            Error::Client(format!(
+                "Invalid RESP header `{}` at line {line_number}, expected `{tag}<len>`",
+                header.trim_end()
+            ))
+        })
+}
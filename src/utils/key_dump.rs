@@ -0,0 +1,190 @@
+use crate::{
+    client::{BatchPreparedCommand, Client},
+    commands::{DumpResult, GenericCommands, RestoreOptions},
+    resp::{
+        rdb::{crc64, inspect_dump, DumpInfo, FOOTER_LEN},
+        SingleArg,
+    },
+    Error, Result,
+};
+use std::time::Duration;
+
+/// Serialized value of a key, with its time to live, as returned by [`backup_key`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyDump {
+    /// Payload returned by [`DUMP`](https://redis.io/commands/dump/)
+    pub payload: Vec<u8>,
+    /// Remaining time to live of the key when it was dumped, `None` if the key has no expire
+    pub ttl: Option<Duration>,
+}
+
+impl KeyDump {
+    /// Version of the RDB format the payload has been serialized with
+    ///
+    /// # Errors
+    /// An [`InvalidDump`](crate::Error::InvalidDump) error if the payload is too short
+    pub fn rdb_version(&self) -> Result<u16> {
+        let Some(footer_start) = self.payload.len().checked_sub(FOOTER_LEN) else {
+            return Err(Error::InvalidDump(format!(
+                "payload of {} bytes is too short",
+                self.payload.len()
+            )));
+        };
+
+        Ok(u16::from_le_bytes([
+            self.payload[footer_start],
+            self.payload[footer_start + 1],
+        ]))
+    }
+
+    /// Check the RDB version and the CRC64 checksum of the payload,
+    /// like the Redis server does before restoring it.
+    ///
+    /// # Errors
+    /// An [`InvalidDump`](crate::Error::InvalidDump) error if the payload is too short
+    /// or if its checksum does not match
+    pub fn validate(&self) -> Result<()> {
+        self.rdb_version()?;
+
+        let (data, checksum) = self.payload.split_at(self.payload.len() - 8);
+        let mut expected = [0u8; 8];
+        expected.copy_from_slice(checksum);
+        let expected = u64::from_le_bytes(expected);
+        let actual = crc64(data);
+
+        if actual != expected {
+            return Err(Error::InvalidDump(format!(
+                "checksum mismatch: expected {expected:#018x}, computed {actual:#018x}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validate the payload and report the type, encoding and number of elements of its value
+    ///
+    /// See [`inspect_dump`](crate::resp::rdb::inspect_dump)
+    ///
+    /// # Errors
+    /// An [`InvalidDump`](crate::Error::InvalidDump) error if the payload is corrupted
+    pub fn inspect(&self) -> Result<DumpInfo> {
+        self.validate()?;
+        inspect_dump(&self.payload)
+    }
+}
+
+/// Serialize a key with [`DUMP`](https://redis.io/commands/dump/),
+/// along with its time to live retrieved with [`PTTL`](https://redis.io/commands/pttl/).
+///
+/// Both commands are sent in a single pipeline.
+///
+/// # Return
+/// The dump of the key, or `None` if the key does not exist
+///
+/// # Errors
+/// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::Client,
+///     commands::{RestoreOptions, StringCommands},
+///     utils::{backup_key, restore_key},
+///     Result,
+/// };
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     client.set("key", "value").await?;
+///
+///     let dump = backup_key(&client, "key").await?.unwrap();
+///     restore_key(&client, "key_copy", &dump, RestoreOptions::default().replace()).await?;
+///
+///     let value: String = client.get("key_copy").await?;
+///     assert_eq!("value", value);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn backup_key<K>(client: &Client, key: K) -> Result<Option<KeyDump>>
+where
+    K: SingleArg + Clone,
+{
+    let mut pipeline = client.create_pipeline();
+    pipeline.dump(key.clone()).queue();
+    pipeline.pttl(key).queue();
+    let (dump, pttl): (Option<DumpResult>, i64) = pipeline.execute().await?;
+
+    // -2: the key does not exist
+    let (Some(DumpResult(payload)), false) = (dump, pttl == -2) else {
+        return Ok(None);
+    };
+
+    Ok(Some(KeyDump {
+        payload,
+        // -1: the key has no associated expire
+        ttl: u64::try_from(pttl).ok().map(Duration::from_millis),
+    }))
+}
+
+/// Restore a key from a [`KeyDump`] with [`RESTORE`](https://redis.io/commands/restore/).
+///
+/// The payload is validated client-side before being sent:
+/// its checksum must match, and its RDB version must be supported by the server
+/// when the server version is known.
+/// The key expires after the time to live recorded in the dump.
+///
+/// # Errors
+/// * An [`InvalidDump`](crate::Error::InvalidDump) error if the payload is corrupted,
+///   or serialized with a RDB version unknown to the server
+/// * Any Redis driver [`Error`](crate::Error) that occurs during the operation,
+///   for instance a `BUSYKEY` error if the key already exists
+///   and [`replace`](RestoreOptions::replace) is not set.
+pub async fn restore_key<K>(
+    client: &Client,
+    key: K,
+    dump: &KeyDump,
+    options: RestoreOptions,
+) -> Result<()>
+where
+    K: SingleArg,
+{
+    dump.validate()?;
+
+    let rdb_version = dump.rdb_version()?;
+    if let Some(max_rdb_version) = client
+        .server_features()
+        .and_then(|f| max_rdb_version(f.major, f.minor))
+    {
+        if rdb_version > max_rdb_version {
+            return Err(Error::InvalidDump(format!(
+                "RDB version {rdb_version} is not supported by the server (maximum {max_rdb_version})"
+            )));
+        }
+    }
+
+    let ttl = dump
+        .ttl
+        .map(|ttl| u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX).max(1))
+        .unwrap_or(0);
+
+    client
+        .restore(key, ttl, dump.payload.clone(), options)
+        .await
+}
+
+/// Highest RDB version supported by a Redis version, `None` if the version is unknown
+fn max_rdb_version(major: u8, minor: u8) -> Option<u16> {
+    match (major, minor) {
+        (0, _) => None,
+        (1..=3, _) => Some(7),
+        (4, _) => Some(8),
+        (5 | 6, _) => Some(9),
+        (7, 0 | 1) => Some(10),
+        (7, 2 | 3) => Some(11),
+        (7, _) | (8, _) => Some(12),
+        _ => None,
+    }
+}
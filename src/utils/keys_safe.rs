@@ -0,0 +1,53 @@
+use crate::{client::Client, commands::ScanOptions, utils::scan_stream, Result};
+use futures_util::{future, Stream, TryStreamExt};
+use std::collections::HashSet;
+
+/// Number of keys hinted to the server for each `SCAN` iteration
+const SCAN_COUNT: usize = 1000;
+
+/// Returns all keys matching `pattern`, like [`keys`](crate::commands::GenericCommands::keys),
+/// without blocking the server.
+///
+/// The keys are fetched incrementally with [`SCAN`](https://redis.io/commands/scan/) and `MATCH`,
+/// so other clients are served between two iterations.
+/// As a counterpart, keys added or removed during the iteration may or may not be returned.
+/// Each key is returned only once.
+///
+/// See [`keys_safe_stream`] to process the keys as they are received.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, commands::StringCommands, utils::keys_safe, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     client.set("user:1", "John").await?;
+///
+///     let keys = keys_safe(&client, "user:*").await?;
+///     assert!(keys.contains(&"user:1".to_owned()));
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn keys_safe(client: &Client, pattern: impl Into<String>) -> Result<Vec<String>> {
+    keys_safe_stream(client, pattern).try_collect().await
+}
+
+/// Returns a [`Stream`] of all keys matching `pattern`, fetched incrementally with
+/// [`SCAN`](https://redis.io/commands/scan/) and `MATCH`.
+///
+/// Each key is yielded only once, even if `SCAN` returns it several times.
+/// In cluster mode, all the master nodes are scanned, see [`scan_stream`].
+pub fn keys_safe_stream(
+    client: &Client,
+    pattern: impl Into<String>,
+) -> impl Stream<Item = Result<String>> + '_ {
+    let options = ScanOptions::default()
+        .match_pattern(pattern.into())
+        .count(SCAN_COUNT);
+    let mut seen = HashSet::new();
+
+    scan_stream(client, options).try_filter(move |key| future::ready(seen.insert(key.clone())))
+}
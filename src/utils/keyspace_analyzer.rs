@@ -0,0 +1,394 @@
+use crate::{
+    client::{BatchPreparedCommand, Client},
+    commands::{GenericCommands, MemoryUsageOptions, ScanOptions, ServerCommands},
+    resp::Value,
+    utils::Ttl,
+    Error, Result,
+};
+use rand::Rng;
+use std::{cmp::Reverse, collections::HashMap};
+
+/// Number of commands sent for each inspected key
+const COMMANDS_PER_KEY: usize = 4;
+
+/// Type, encoding, memory usage and time to live of a key
+///
+/// See [`KeyspaceAnalyzer::inspect`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyInfo {
+    /// Name of the key
+    pub key: String,
+    /// Type of the value, as returned by [`TYPE`](https://redis.io/commands/type/)
+    pub key_type: String,
+    /// Internal encoding of the value, as returned by [`OBJECT ENCODING`](https://redis.io/commands/object-encoding/)
+    pub encoding: String,
+    /// Number of bytes used by the key and its value, as estimated by [`MEMORY USAGE`](https://redis.io/commands/memory-usage/)
+    pub memory_usage: usize,
+    /// Remaining time to live of the key
+    pub ttl: Ttl,
+}
+
+/// Statistics of the sampled keys sharing the same prefix
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrefixStats {
+    /// Prefix of the keys, e.g. `user:*`
+    pub prefix: String,
+    /// Number of sampled keys
+    pub keys: usize,
+    /// Total memory usage of the sampled keys, in bytes
+    pub memory_usage: usize,
+    /// Sampled key with the largest memory usage
+    pub biggest_key: String,
+    /// Memory usage of [`biggest_key`](PrefixStats::biggest_key), in bytes
+    pub biggest_key_memory_usage: usize,
+    /// Number of sampled keys with an associated expire
+    pub keys_with_ttl: usize,
+    /// Number of sampled keys by type
+    pub types: HashMap<String, usize>,
+    /// Number of sampled keys by encoding
+    pub encodings: HashMap<String, usize>,
+}
+
+impl PrefixStats {
+    /// Average memory usage of the sampled keys, in bytes
+    #[must_use]
+    pub fn average_memory_usage(&self) -> usize {
+        self.memory_usage.checked_div(self.keys).unwrap_or_default()
+    }
+
+    fn add(&mut self, info: &KeyInfo) {
+        self.keys += 1;
+        self.memory_usage += info.memory_usage;
+        if info.memory_usage >= self.biggest_key_memory_usage {
+            self.biggest_key = info.key.clone();
+            self.biggest_key_memory_usage = info.memory_usage;
+        }
+        if matches!(info.ttl, Ttl::Remaining(_)) {
+            self.keys_with_ttl += 1;
+        }
+        *self.types.entry(info.key_type.clone()).or_default() += 1;
+        *self.encodings.entry(info.encoding.clone()).or_default() += 1;
+    }
+}
+
+/// Result of [`KeyspaceAnalyzer::analyze`]
+#[derive(Debug, Clone, Default)]
+pub struct KeyspaceReport {
+    /// Number of keys returned by `SCAN`
+    pub scanned_keys: usize,
+    /// Number of keys inspected, depending on the sample rate
+    pub sampled_keys: usize,
+    /// Total memory usage of the sampled keys, in bytes
+    pub memory_usage: usize,
+    /// Statistics by key prefix, the prefixes using the most memory first
+    pub prefixes: Vec<PrefixStats>,
+    /// Sampled keys with the largest memory usage, the biggest first
+    pub biggest_keys: Vec<KeyInfo>,
+}
+
+impl KeyspaceReport {
+    /// Estimated total memory usage of the scanned keys, extrapolated from the sampled keys
+    #[must_use]
+    pub fn estimated_memory_usage(&self) -> usize {
+        if self.sampled_keys == 0 {
+            0
+        } else {
+            (self.memory_usage as f64 * self.scanned_keys as f64 / self.sampled_keys as f64)
+                as usize
+        }
+    }
+}
+
+/// Programmatic equivalent of `redis-cli --bigkeys` and `redis-cli --memkeys`
+///
+/// The keyspace is iterated with [`SCAN`](https://redis.io/commands/scan/),
+/// so the server is not blocked. A random sample of the scanned keys is inspected
+/// with pipelined `TYPE`, `OBJECT ENCODING`, `MEMORY USAGE` and `PTTL` commands,
+/// and the results are aggregated by key prefix.
+///
+/// The prefix of a key is made of its first segments, split by a separator:
+/// with the default separator `:` and depth `1`, the prefix of `user:42:profile` is `user:*`.
+/// Keys without separator are their own prefix.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, commands::StringCommands, utils::KeyspaceAnalyzer, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     client.set("user:1:name", "John").await?;
+///
+///     let report = KeyspaceAnalyzer::new(&client)
+///         .match_pattern("user:*")
+///         .sample_rate(0.5)
+///         .analyze()
+///         .await?;
+///
+///     for prefix in &report.prefixes {
+///         println!(
+///             "{}: {} keys, {} bytes",
+///             prefix.prefix, prefix.keys, prefix.memory_usage
+///         );
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct KeyspaceAnalyzer {
+    client: Client,
+    match_pattern: Option<String>,
+    scan_count: usize,
+    sample_rate: f64,
+    max_keys: Option<usize>,
+    separator: char,
+    prefix_depth: usize,
+    memory_samples: Option<usize>,
+    top_keys: usize,
+}
+
+impl KeyspaceAnalyzer {
+    /// Default number of keys hinted to the server for each `SCAN` iteration
+    pub const DEFAULT_SCAN_COUNT: usize = 100;
+    /// Default number of keys reported in [`KeyspaceReport::biggest_keys`]
+    pub const DEFAULT_TOP_KEYS: usize = 10;
+
+    /// Create a new analyzer of the keyspace of the database of `client`
+    #[must_use]
+    pub fn new(client: &Client) -> Self {
+        Self {
+            client: client.clone(),
+            match_pattern: None,
+            scan_count: Self::DEFAULT_SCAN_COUNT,
+            sample_rate: 1.,
+            max_keys: None,
+            separator: ':',
+            prefix_depth: 1,
+            memory_samples: None,
+            top_keys: Self::DEFAULT_TOP_KEYS,
+        }
+    }
+
+    /// Only analyze the keys matching this glob-style pattern
+    #[must_use]
+    pub fn match_pattern(mut self, match_pattern: impl Into<String>) -> Self {
+        self.match_pattern = Some(match_pattern.into());
+        self
+    }
+
+    /// Number of keys hinted to the server for each `SCAN` iteration
+    #[must_use]
+    pub fn scan_count(mut self, scan_count: usize) -> Self {
+        self.scan_count = scan_count;
+        self
+    }
+
+    /// Probability, between `0` and `1`, for a scanned key to be inspected (default `1`)
+    #[must_use]
+    pub fn sample_rate(mut self, sample_rate: f64) -> Self {
+        self.sample_rate = sample_rate.clamp(0., 1.);
+        self
+    }
+
+    /// Stop the analysis after this number of scanned keys
+    #[must_use]
+    pub fn max_keys(mut self, max_keys: usize) -> Self {
+        self.max_keys = Some(max_keys);
+        self
+    }
+
+    /// Separator of the segments of the keys (default `:`)
+    #[must_use]
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Number of segments of the keys which make their prefix (default `1`)
+    #[must_use]
+    pub fn prefix_depth(mut self, prefix_depth: usize) -> Self {
+        self.prefix_depth = prefix_depth.max(1);
+        self
+    }
+
+    /// Number of nested values sampled by `MEMORY USAGE` (server default `5`, `0` for all)
+    #[must_use]
+    pub fn memory_samples(mut self, memory_samples: usize) -> Self {
+        self.memory_samples = Some(memory_samples);
+        self
+    }
+
+    /// Number of keys reported in [`KeyspaceReport::biggest_keys`]
+    #[must_use]
+    pub fn top_keys(mut self, top_keys: usize) -> Self {
+        self.top_keys = top_keys;
+        self
+    }
+
+    /// Scan the keyspace and aggregate the statistics of the sampled keys.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn analyze(&self) -> Result<KeyspaceReport> {
+        let mut report = KeyspaceReport::default();
+        let mut prefixes: HashMap<String, PrefixStats> = HashMap::new();
+        let mut cursor = 0;
+
+        loop {
+            let mut options = ScanOptions::default().count(self.scan_count);
+            if let Some(match_pattern) = &self.match_pattern {
+                options = options.match_pattern(match_pattern.clone());
+            }
+            let (next_cursor, mut keys): (u64, Vec<String>) =
+                self.client.scan(cursor, options).await?;
+
+            if let Some(max_keys) = self.max_keys {
+                keys.truncate(max_keys - report.scanned_keys);
+            }
+            report.scanned_keys += keys.len();
+
+            let sampled_keys = self.sample(keys);
+            for info in self.inspect(&sampled_keys).await? {
+                report.sampled_keys += 1;
+                report.memory_usage += info.memory_usage;
+
+                let prefix = self.prefix(&info.key);
+                prefixes
+                    .entry(prefix.clone())
+                    .or_insert_with(|| PrefixStats {
+                        prefix,
+                        ..Default::default()
+                    })
+                    .add(&info);
+
+                self.add_biggest_key(&mut report.biggest_keys, info);
+            }
+
+            cursor = next_cursor;
+            if cursor == 0 || self.max_keys.is_some_and(|max| report.scanned_keys >= max) {
+                break;
+            }
+        }
+
+        report.prefixes = prefixes.into_values().collect();
+        report
+            .prefixes
+            .sort_by_key(|prefix| Reverse(prefix.memory_usage));
+
+        Ok(report)
+    }
+
+    /// Type, encoding, memory usage and time to live of each key of `keys`,
+    /// retrieved in a single pipeline.
+    ///
+    /// # Return
+    /// The information of the keys, in the order of `keys`, without the keys which do not exist
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn inspect(&self, keys: &[String]) -> Result<Vec<KeyInfo>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut pipeline = self.client.create_pipeline();
+        for key in keys {
+            let memory_usage_options = match self.memory_samples {
+                Some(samples) => MemoryUsageOptions::default().samples(samples),
+                None => MemoryUsageOptions::default(),
+            };
+            pipeline.type_(key).queue();
+            pipeline.object_encoding::<_, Value>(key).queue();
+            pipeline.memory_usage(key, memory_usage_options).queue();
+            pipeline.pttl(key).queue();
+        }
+        let values: Vec<Value> = pipeline.execute().await?;
+
+        if values.len() != keys.len() * COMMANDS_PER_KEY {
+            return Err(Error::Client(format!(
+                "Unexpected number of replies: {}",
+                values.len()
+            )));
+        }
+
+        let mut infos = Vec::with_capacity(keys.len());
+        let mut values = values.into_iter();
+        for key in keys {
+            let (Some(key_type), Some(encoding), Some(memory_usage), Some(pttl)) =
+                (values.next(), values.next(), values.next(), values.next())
+            else {
+                break;
+            };
+
+            let key_type: String = key_type.into()?;
+            let encoding: Option<String> = encoding.into()?;
+            let memory_usage: Option<usize> = memory_usage.into()?;
+            let ttl = Ttl::from_millis(pttl.into()?);
+
+            // the key has been deleted or has expired since it was scanned
+            let (Some(encoding), Some(memory_usage)) = (encoding, memory_usage) else {
+                continue;
+            };
+            if key_type == "none" {
+                continue;
+            }
+
+            infos.push(KeyInfo {
+                key: key.clone(),
+                key_type,
+                encoding,
+                memory_usage,
+                ttl,
+            });
+        }
+
+        Ok(infos)
+    }
+
+    /// Prefix of `key`, made of its first `prefix_depth` segments
+    fn prefix(&self, key: &str) -> String {
+        let mut segments = key.splitn(self.prefix_depth + 1, self.separator);
+        let prefix: Vec<&str> = segments.by_ref().take(self.prefix_depth).collect();
+
+        if segments.next().is_some() {
+            format!(
+                "{}{}*",
+                prefix.join(&self.separator.to_string()),
+                self.separator
+            )
+        } else {
+            key.to_owned()
+        }
+    }
+
+    fn sample(&self, keys: Vec<String>) -> Vec<String> {
+        if self.sample_rate >= 1. {
+            return keys;
+        }
+
+        let mut rng = rand::thread_rng();
+        keys.into_iter()
+            .filter(|_| rng.gen_bool(self.sample_rate))
+            .collect()
+    }
+
+    fn add_biggest_key(&self, biggest_keys: &mut Vec<KeyInfo>, info: KeyInfo) {
+        if self.top_keys == 0 {
+            return;
+        }
+
+        if biggest_keys.len() == self.top_keys {
+            match biggest_keys.last() {
+                Some(smallest) if smallest.memory_usage < info.memory_usage => {
+                    biggest_keys.pop();
+                }
+                _ => return,
+            }
+        }
+
+        let index = biggest_keys.partition_point(|k| k.memory_usage >= info.memory_usage);
+        biggest_keys.insert(index, info);
+    }
+}
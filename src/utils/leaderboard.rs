@@ -0,0 +1,223 @@
+use crate::{
+    client::{BatchPreparedCommand, Client},
+    commands::{SortedSetCommands, ZAddOptions, ZRangeOptions},
+    resp::SingleArg,
+    Result,
+};
+
+/// Entry of a [`Leaderboard`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaderboardEntry {
+    /// Member name
+    pub member: String,
+    /// Score of the member
+    pub score: f64,
+    /// 1-based rank of the member, `1` being the best
+    pub rank: usize,
+}
+
+/// Leaderboard built on a sorted set
+///
+/// By default, higher scores rank first.
+/// Ranks exposed by this type are 1-based, unlike the 0-based ranks of Redis.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::Leaderboard, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let leaderboard = Leaderboard::new(&client, "highscores");
+///     leaderboard.set_score("alice", 150.).await?;
+///     leaderboard.set_score("bob", 100.).await?;
+///     leaderboard.incr_score("bob", 100.).await?;
+///
+///     let top = leaderboard.top(10).await?;
+///     assert_eq!("bob", top[0].member);
+///     assert_eq!(Some(2), leaderboard.rank("alice").await?);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Leaderboard {
+    client: Client,
+    key: String,
+    lower_is_better: bool,
+}
+
+impl Leaderboard {
+    /// Create a new leaderboard stored at `key`
+    #[must_use]
+    pub fn new(client: &Client, key: impl Into<String>) -> Self {
+        Self {
+            client: client.clone(),
+            key: key.into(),
+            lower_is_better: false,
+        }
+    }
+
+    /// Rank lower scores first, for example for completion times
+    #[must_use]
+    pub fn lower_is_better(mut self) -> Self {
+        self.lower_is_better = true;
+        self
+    }
+
+    /// Set the score of a member, adding the member if needed
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn set_score<M: SingleArg>(&self, member: M, score: f64) -> Result<()> {
+        self.client
+            .zadd(&self.key, (score, member), ZAddOptions::default())
+            .await?;
+        Ok(())
+    }
+
+    /// Increment the score of a member, adding the member if needed
+    ///
+    /// # Return
+    /// The new score of the member
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn incr_score<M: SingleArg>(&self, member: M, increment: f64) -> Result<f64> {
+        self.client.zincrby(&self.key, increment, member).await
+    }
+
+    /// Remove a member from the leaderboard
+    ///
+    /// # Return
+    /// `false` if the member was not found
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn remove<M: SingleArg>(&self, member: M) -> Result<bool> {
+        Ok(self.client.zrem(&self.key, member).await? > 0)
+    }
+
+    /// Number of members in the leaderboard
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn len(&self) -> Result<usize> {
+        self.client.zcard(&self.key).await
+    }
+
+    /// Score of a member, or `None` if the member was not found
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn score<M: SingleArg>(&self, member: M) -> Result<Option<f64>> {
+        self.client.zscore(&self.key, member).await
+    }
+
+    /// 1-based rank of a member, or `None` if the member was not found
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn rank<M: SingleArg>(&self, member: M) -> Result<Option<usize>> {
+        let rank = if self.lower_is_better {
+            self.client.zrank(&self.key, member).await?
+        } else {
+            self.client.zrevrank(&self.key, member).await?
+        };
+        Ok(rank.map(|rank| rank + 1))
+    }
+
+    /// Score and rank of a member, or `None` if the member was not found
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn entry(&self, member: &str) -> Result<Option<LeaderboardEntry>> {
+        let mut pipeline = self.client.create_pipeline();
+        pipeline.zscore(&self.key, member).queue();
+        if self.lower_is_better {
+            pipeline.zrank(&self.key, member).queue();
+        } else {
+            pipeline.zrevrank(&self.key, member).queue();
+        }
+        let (score, rank): (Option<f64>, Option<usize>) = pipeline.execute().await?;
+
+        Ok(match (score, rank) {
+            (Some(score), Some(rank)) => Some(LeaderboardEntry {
+                member: member.to_owned(),
+                score,
+                rank: rank + 1,
+            }),
+            _ => None,
+        })
+    }
+
+    /// The `count` best members
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn top(&self, count: usize) -> Result<Vec<LeaderboardEntry>> {
+        self.range(0, count).await
+    }
+
+    /// Page `page` (0-based) of the leaderboard, with `page_size` members per page
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn page(&self, page: usize, page_size: usize) -> Result<Vec<LeaderboardEntry>> {
+        self.range(page * page_size, page_size).await
+    }
+
+    /// A member with up to `count` members ranked just before and just after it
+    ///
+    /// # Return
+    /// An empty collection if the member was not found
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn around_member<M: SingleArg>(
+        &self,
+        member: M,
+        count: usize,
+    ) -> Result<Vec<LeaderboardEntry>> {
+        let Some(rank) = self.rank(member).await? else {
+            return Ok(Vec::new());
+        };
+        let start = (rank - 1).saturating_sub(count);
+        let end = rank + count;
+        self.range(start, end - start).await
+    }
+
+    /// `count` members starting at the 0-based offset `start`
+    async fn range(&self, start: usize, count: usize) -> Result<Vec<LeaderboardEntry>> {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+
+        let options = if self.lower_is_better {
+            ZRangeOptions::default()
+        } else {
+            ZRangeOptions::default().reverse()
+        };
+        let entries: Vec<(String, f64)> = self
+            .client
+            .zrange_with_scores(
+                &self.key,
+                start as isize,
+                (start + count - 1) as isize,
+                options,
+            )
+            .await?;
+
+        Ok(entries
+            .into_iter()
+            .enumerate()
+            .map(|(i, (member, score))| LeaderboardEntry {
+                member,
+                score,
+                rank: start + i + 1,
+            })
+            .collect())
+    }
+}
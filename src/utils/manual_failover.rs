@@ -0,0 +1,271 @@
+use crate::{
+    client::Client,
+    commands::{
+        ClientPauseMode, ConnectionCommands, FailOverOptions, ReplicaOfOptions, RoleResult,
+        ServerCommands,
+    },
+    sleep, Error, Result,
+};
+use log::{debug, warn};
+use std::time::{Duration, Instant};
+
+/// Interval between two checks of the replication offsets and roles
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How the roles of the master and of the replica are switched by a [`ManualFailover`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FailoverStrategy {
+    /// The client pauses the writes on the master, waits for the replica to catch up,
+    /// and switches the roles with [`REPLICAOF`](https://redis.io/commands/replicaof/).
+    ///
+    /// Works with any version of Redis.
+    #[default]
+    ReplicaOf,
+    /// The master coordinates the failover itself with
+    /// [`FAILOVER`](https://redis.io/commands/failover/), available since Redis 6.2.
+    Failover,
+}
+
+/// Promote a replica to master, without losing writes, for deployments running without Sentinel.
+///
+/// With the default [`ReplicaOf`](FailoverStrategy::ReplicaOf) strategy, the failover runs as follows:
+/// 1. the writes are paused on the master with [`CLIENT PAUSE WRITE`](https://redis.io/commands/client-pause/),
+/// 2. the replication offset of the replica is polled with [`ROLE`](https://redis.io/commands/role/)
+///    until it reaches the offset of the master,
+/// 3. the replica is promoted with `REPLICAOF NO ONE`,
+/// 4. the former master becomes a replica of the promoted replica,
+/// 5. the writes are resumed on the former master with [`CLIENT UNPAUSE`](https://redis.io/commands/client-unpause/),
+///    its clients now receiving `READONLY` errors.
+///
+/// If the replica does not catch up within the [`sync_timeout`](ManualFailover::sync_timeout),
+/// or if a step fails, the previous steps are rolled back: the replica is attached to the
+/// master again, and the writes are resumed on the master.
+///
+/// With the [`Failover`](FailoverStrategy::Failover) strategy, the same sequence is executed by the master
+/// and the failover is aborted with `FAILOVER ABORT` if the roles have not been switched within the timeout.
+///
+/// # Example
+/// ```no_run
+/// use rustis::{client::Client, utils::ManualFailover, Result};
+/// use std::time::Duration;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let master = Client::connect("127.0.0.1:6379").await?;
+///     let replica = Client::connect("127.0.0.1:6380").await?;
+///
+///     ManualFailover::new(&master, &replica, "127.0.0.1", 6380)
+///         .sync_timeout(Duration::from_secs(5))
+///         .execute()
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct ManualFailover {
+    master: Client,
+    replica: Client,
+    replica_host: String,
+    replica_port: u16,
+    strategy: FailoverStrategy,
+    sync_timeout: Duration,
+    pause_timeout: Duration,
+}
+
+impl ManualFailover {
+    /// Prepare the failover from `master` to `replica`.
+    ///
+    /// `replica_host` and `replica_port` are the address of the replica,
+    /// as reachable from the master.
+    #[must_use]
+    pub fn new(
+        master: &Client,
+        replica: &Client,
+        replica_host: impl Into<String>,
+        replica_port: u16,
+    ) -> Self {
+        Self {
+            master: master.clone(),
+            replica: replica.clone(),
+            replica_host: replica_host.into(),
+            replica_port,
+            strategy: FailoverStrategy::default(),
+            sync_timeout: Duration::from_secs(5),
+            pause_timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Strategy used to switch the roles, [`ReplicaOf`](FailoverStrategy::ReplicaOf) by default
+    #[must_use]
+    pub fn strategy(mut self, strategy: FailoverStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Maximum time to wait for the replica to catch up with the master, 5 seconds by default
+    #[must_use]
+    pub fn sync_timeout(mut self, sync_timeout: Duration) -> Self {
+        self.sync_timeout = sync_timeout;
+        self
+    }
+
+    /// Duration of the `CLIENT PAUSE` sent to the master, 10 seconds by default.
+    ///
+    /// The writes are resumed automatically by the server after this duration,
+    /// even if the client is disconnected in the middle of the failover.
+    /// It must be longer than the [`sync_timeout`](ManualFailover::sync_timeout).
+    #[must_use]
+    pub fn pause_timeout(mut self, pause_timeout: Duration) -> Self {
+        self.pause_timeout = pause_timeout;
+        self
+    }
+
+    /// Run the failover
+    ///
+    /// # Errors
+    /// * [`Error::Client`] if the replica is not a replica
+    /// * [`Error::Timeout`] if the replica has not caught up with the master within the
+    ///   [`sync_timeout`](ManualFailover::sync_timeout), after the failover has been rolled back
+    /// * Any Redis driver [`Error`](crate::Error) that occurs during the operation,
+    ///   after the failover has been rolled back
+    pub async fn execute(&self) -> Result<()> {
+        let RoleResult::Replica {
+            master_ip,
+            master_port,
+            ..
+        } = self.replica.role().await?
+        else {
+            return Err(Error::Client(
+                "Cannot failover to an instance which is not a replica".to_owned(),
+            ));
+        };
+
+        match self.strategy {
+            FailoverStrategy::ReplicaOf => self.execute_replicaof(master_ip, master_port).await,
+            FailoverStrategy::Failover => self.execute_failover().await,
+        }
+    }
+
+    async fn execute_replicaof(&self, master_ip: String, master_port: u16) -> Result<()> {
+        debug!("[ManualFailover] pausing writes on the master");
+        self.master
+            .client_pause(
+                self.pause_timeout.as_millis() as u64,
+                ClientPauseMode::Write,
+            )
+            .await?;
+
+        if let Err(e) = self.wait_for_sync().await {
+            self.resume_master().await;
+            return Err(e);
+        }
+
+        debug!("[ManualFailover] promoting the replica");
+        if let Err(e) = self.replica.replicaof(ReplicaOfOptions::no_one()).await {
+            self.resume_master().await;
+            return Err(e);
+        }
+
+        debug!("[ManualFailover] demoting the master");
+        if let Err(e) = self
+            .master
+            .replicaof(ReplicaOfOptions::master(
+                self.replica_host.as_str(),
+                self.replica_port,
+            ))
+            .await
+        {
+            if let Err(rollback_error) = self
+                .replica
+                .replicaof(ReplicaOfOptions::master(master_ip, master_port))
+                .await
+            {
+                warn!("[ManualFailover] cannot attach the replica to the master again: {rollback_error}");
+            }
+            self.resume_master().await;
+            return Err(e);
+        }
+
+        self.resume_master().await;
+        Ok(())
+    }
+
+    /// Wait until the replica has received all the writes of the master
+    async fn wait_for_sync(&self) -> Result<()> {
+        let deadline = Instant::now() + self.sync_timeout;
+
+        let RoleResult::Master {
+            master_replication_offset,
+            ..
+        } = self.master.role().await?
+        else {
+            return Err(Error::Client(
+                "Cannot failover from an instance which is not a master".to_owned(),
+            ));
+        };
+
+        loop {
+            if let RoleResult::Replica {
+                amount_data_received,
+                ..
+            } = self.replica.role().await?
+            {
+                if amount_data_received >= 0
+                    && amount_data_received as usize >= master_replication_offset
+                {
+                    return Ok(());
+                }
+            }
+
+            if Instant::now() >= deadline {
+                return Err(Error::Timeout(
+                    "The replica has not caught up with the master".to_owned(),
+                ));
+            }
+
+            sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    async fn resume_master(&self) {
+        debug!("[ManualFailover] resuming writes on the master");
+        if let Err(e) = self.master.client_unpause().await {
+            warn!("[ManualFailover] cannot resume writes on the master: {e}");
+        }
+    }
+
+    async fn execute_failover(&self) -> Result<()> {
+        let timeout_millis = self.sync_timeout.as_millis() as u64;
+        self.master
+            .failover(
+                FailOverOptions::default()
+                    .to(self.replica_host.as_str(), self.replica_port)
+                    .timeout(timeout_millis),
+            )
+            .await?;
+
+        // the failover is asynchronous: wait for the master to become a replica
+        let deadline = Instant::now() + self.sync_timeout * 2;
+        loop {
+            if let RoleResult::Replica { .. } = self.master.role().await? {
+                return Ok(());
+            }
+
+            if Instant::now() >= deadline {
+                if let Err(e) = self
+                    .master
+                    .failover(FailOverOptions::default().abort())
+                    .await
+                {
+                    warn!("[ManualFailover] cannot abort the failover: {e}");
+                }
+                return Err(Error::Timeout(
+                    "The master has not switched roles with the replica".to_owned(),
+                ));
+            }
+
+            sleep(POLL_INTERVAL).await;
+        }
+    }
+}
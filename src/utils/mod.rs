@@ -0,0 +1,225 @@
+/*!
+Higher level helpers built on top of Redis commands
+
+Unlike the traits of the [`commands`](crate::commands) module which map one-to-one
+with Redis built-in commands, the helpers of this module combine several commands
+or bundle Lua scripts to offer common patterns out of the box.
+
+# Scripts
+
+The [`Script`] struct wraps a Lua script and its SHA1 digest.
+Scripts are invoked with [`EVALSHA`](https://redis.io/commands/evalsha/)
+and automatically loaded with [`SCRIPT LOAD`](https://redis.io/commands/script-load/)
+the first time Redis answers with a `NOSCRIPT` error.
+
+The [`FunctionLibrary`] struct packages Lua functions into a
+[Redis Functions](https://redis.io/docs/manual/programmability/functions-intro/) library,
+loads it only when the version on the server differs,
+and invokes read-only functions with [`FCALL_RO`](https://redis.io/commands/fcall_ro/).
+
+# Atomic commands
+
+The [`AtomicCommands`] trait exposes a few one-shot operations,
+implemented as bundled scripts, that are often used as distributed locking primitives.
+
+# Cache
+
+The [`Cache`] struct stores typed values with a pluggable serialization format
+and optional compression, and protects the computation of missing values against cache stampedes.
+
+The [`ClientSideCache`] struct keeps values in the application memory and relies on
+[server-assisted client side caching](https://redis.io/docs/manual/client-side-caching/)
+to invalidate them, in the default, opt-in, opt-out or broadcasting [tracking mode](TrackingMode).
+
+# Key migration
+
+The [`copy_key_between`] function copies a key between unrelated Redis instances,
+when [`MIGRATE`](https://redis.io/commands/migrate/) cannot be used.
+
+# Key backup
+
+The [`backup_key`] and [`restore_key`] functions pair [`DUMP`](https://redis.io/commands/dump/)
+with [`PTTL`](https://redis.io/commands/pttl/) into a [`KeyDump`], and validate its checksum
+and RDB version before sending [`RESTORE`](https://redis.io/commands/restore/).
+
+# Key iteration
+
+The [`keys_safe`] and [`keys_safe_stream`] functions are non-blocking alternatives
+to [`KEYS`](https://redis.io/commands/keys/), based on [`scan_stream`], which iterates
+all the master nodes of a cluster transparently with [`SCAN`](https://redis.io/commands/scan/).
+The [`delete_matching`] function deletes the keys matching a pattern in batches.
+The [`hash_fields_stream`] and [`hash_entries_stream`] functions traverse huge hashes
+incrementally with [`HSCAN`](https://redis.io/commands/hscan/).
+
+# Export
+
+With the `export` feature, the [`export_keys`] and [`export_keys_async`] functions scan the keyspace
+and write the keys, with their value and time to live, as newline-delimited JSON or CSV,
+for lightweight backups and data inspection.
+
+Conversely, with the `import` feature, the [`import_commands`] function replays a file of commands,
+in the inline protocol, as JSON lines or in RESP like an append-only file, through pipelines,
+for seeding test environments.
+
+# Keyspace analysis
+
+The [`KeyspaceAnalyzer`] struct samples the keyspace with `SCAN`, inspects the type, encoding,
+memory usage and time to live of the sampled keys, and aggregates them by key prefix,
+like `redis-cli --bigkeys` and `redis-cli --memkeys` do.
+The [`find_big_keys`] function streams the keys whose memory usage exceeds a threshold,
+with their number of elements, at a configurable pace.
+
+# Configuration drift
+
+The [`config_diff`] function compares the configuration of a server with the expected parameters,
+possibly read from a `redis.conf` file with [`parse_redis_conf`], and returns the deviating ones.
+
+# Slow log
+
+The [`slowlog_tail`] function polls the slow log of the server and streams the new entries,
+to surface slow commands in near real time.
+
+# Failover
+
+The [`ManualFailover`] struct promotes a replica to master without losing writes,
+for deployments running without Sentinel: the writes are paused on the master
+until the replica has caught up, then the roles are switched, and rolled back on failure.
+
+# Pub/Sub routing
+
+The [`PubSubRouter`] struct dispatches pub/sub messages to async handlers
+registered per channel or per pattern.
+The [`PubSubBridge`] struct appends the pub/sub messages to a stream,
+so that the consumers which cannot afford to miss messages during their restarts read them from there.
+
+# Streams
+
+The [`StreamConsumer`] struct wraps consumer group reads, acknowledgements,
+reclaims of stale pending entries and dead-lettering into a [`Stream`](futures_util::Stream) of messages.
+On the producing side, the [`StreamProducer`] struct buffers entries and sends them in pipelined batches.
+
+# Queues
+
+The [`ReliableQueue`] struct implements the reliable queue pattern over lists:
+popped items are moved to a per-consumer processing list until they are acknowledged,
+and the items of consumers which stopped sending heartbeats are requeued.
+The [`DelayedQueue`] struct schedules jobs to run at a given time with a sorted set,
+and delivers them again when they are not acknowledged within a visibility timeout.
+
+# Leaderboards
+
+The [`Leaderboard`] struct wraps a sorted set to rank members by score,
+with paging and rank queries around a given member.
+
+# Counters
+
+The [`Counters`] struct increments counters bucketed by minute, hour and day,
+with automatic expiration of old buckets, and aggregates ranges of buckets.
+
+# Rate limiting
+
+The [`RateLimiter`] struct provides fixed window, sliding window log and token bucket
+rate limiting, each check being executed atomically on the server.
+
+# Expirations
+
+The [`ttl_many`] function returns the time to live of many keys in a single round trip,
+as [`Ttl`] values instead of `-2`/`-1` sentinels.
+
+# Waiting for keys
+
+The [`wait_for_key`] function waits until a key appears, changes or is deleted,
+relying on keyspace notifications when they are enabled and on polling otherwise.
+It is handy for simple job-completion signaling between services.
+
+# Example
+```
+use rustis::{
+    client::Client,
+    commands::StringCommands,
+    utils::AtomicCommands,
+    Result,
+};
+
+#[cfg_attr(feature = "tokio-runtime", tokio::main)]
+#[cfg_attr(feature = "async-std-runtime", async_std::main)]
+async fn main() -> Result<()> {
+    let client = Client::connect("127.0.0.1:6379").await?;
+    client.set("lock", "owner1").await?;
+
+    // only the owner of the lock can release it
+    let deleted = client.compare_and_delete("lock", "owner2").await?;
+    assert!(!deleted);
+    let deleted = client.compare_and_delete("lock", "owner1").await?;
+    assert!(deleted);
+
+    Ok(())
+}
+```
+*/
+
+mod atomic_commands;
+mod big_keys;
+mod cache;
+mod client_side_cache;
+mod config_diff;
+mod copy_key;
+mod counters;
+mod delayed_queue;
+mod delete_matching;
+#[cfg(feature = "export")]
+mod export;
+mod function_library;
+mod hash_scan;
+#[cfg(feature = "import")]
+mod import;
+mod key_dump;
+mod keys_safe;
+mod keyspace_analyzer;
+mod leaderboard;
+mod manual_failover;
+mod pub_sub_bridge;
+mod pub_sub_router;
+mod rate_limiter;
+mod reliable_queue;
+mod scan_stream;
+mod script;
+mod slowlog_tail;
+mod stream_consumer;
+mod stream_producer;
+mod ttl_many;
+mod wait_for_key;
+
+pub use atomic_commands::*;
+pub use big_keys::*;
+pub use cache::*;
+pub use client_side_cache::*;
+pub use config_diff::*;
+pub use copy_key::*;
+pub use counters::*;
+pub use delayed_queue::*;
+pub use delete_matching::*;
+#[cfg_attr(docsrs, doc(cfg(feature = "export")))]
+#[cfg(feature = "export")]
+pub use export::*;
+pub use function_library::*;
+pub use hash_scan::*;
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+#[cfg(feature = "import")]
+pub use import::*;
+pub use key_dump::*;
+pub use keys_safe::*;
+pub use keyspace_analyzer::*;
+pub use leaderboard::*;
+pub use manual_failover::*;
+pub use pub_sub_bridge::*;
+pub use pub_sub_router::*;
+pub use rate_limiter::*;
+pub use reliable_queue::*;
+pub use scan_stream::*;
+pub use script::*;
+pub use slowlog_tail::*;
+pub use stream_consumer::*;
+pub use stream_producer::*;
+pub use ttl_many::*;
+pub use wait_for_key::*;
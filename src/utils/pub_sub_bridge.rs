@@ -0,0 +1,218 @@
+use crate::{
+    client::{Client, PubSubEvent, PubSubMessage, RetryPolicy},
+    resp::{cmd, Command},
+    sleep, Result,
+};
+use futures_util::StreamExt;
+use log::{debug, warn};
+use std::time::Duration;
+
+/// Maximum number of messages appended to the stream in a single pipeline
+const MAX_BATCH_SIZE: usize = 100;
+const MIN_RETRY_DELAY: Duration = Duration::from_millis(100);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Bridge which subscribes to pub/sub channels & patterns and appends each received message
+/// to a stream with [`XADD`](https://redis.io/commands/xadd/).
+///
+/// Pub/sub is fire-and-forget: a subscriber which is not connected when a message is published
+/// never receives it. Bridging the messages into a stream lets the consumers which cannot afford
+/// to miss messages, during their restarts for instance, read them later from the stream,
+/// e.g. with a [`StreamConsumer`](crate::utils::StreamConsumer).
+///
+/// Each entry of the stream has the following fields:
+/// * `channel`: the channel of the message,
+/// * `pattern`: the pattern of the subscription, only for messages received through a pattern,
+/// * `payload`: the payload of the message.
+///
+/// Messages are appended at least once: the `XADD` commands which fail with a transient error
+/// (see [`RetryPolicy::is_transient`]) are sent again, with an exponential backoff,
+/// until they succeed. Any other error, e.g. `WRONGTYPE` if the key does not hold a stream,
+/// stops the bridge. When the connection is lost while
+/// a pipeline is in flight, its messages are sent again, which may duplicate some entries.
+/// Messages published while the bridge itself is disconnected are still lost,
+/// a warning is logged when the subscriptions are restored after a reconnection.
+///
+/// Because a multiplexed client must be dedicated to pub/sub once subscribed,
+/// the bridge needs a second client to append the messages to the stream.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::PubSubBridge, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let subscribing_client = Client::connect("127.0.0.1:6379").await?;
+///     let regular_client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let bridge = PubSubBridge::new(&subscribing_client, &regular_client, "events")
+///         .channel("orders")
+///         .pattern("notifications.*")
+///         .max_len(10_000, true);
+///
+///     // `run` only returns when the subscriptions are closed,
+///     // so it is usually spawned in a dedicated task
+///     # #[cfg(feature = "tokio-runtime")]
+///     tokio::spawn(bridge.run());
+///
+///     Ok(())
+/// }
+/// ```
+pub struct PubSubBridge {
+    subscribing_client: Client,
+    regular_client: Client,
+    stream_key: String,
+    channels: Vec<Vec<u8>>,
+    patterns: Vec<Vec<u8>>,
+    max_len: Option<(usize, bool)>,
+}
+
+impl PubSubBridge {
+    /// Create a new bridge to the stream `stream_key`, without any subscription.
+    ///
+    /// `subscribing_client` is dedicated to the subscriptions of the bridge,
+    /// `regular_client` sends the `XADD` commands.
+    #[must_use]
+    pub fn new(
+        subscribing_client: &Client,
+        regular_client: &Client,
+        stream_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            subscribing_client: subscribing_client.clone(),
+            regular_client: regular_client.clone(),
+            stream_key: stream_key.into(),
+            channels: Vec::new(),
+            patterns: Vec::new(),
+            max_len: None,
+        }
+    }
+
+    /// Subscribe to a channel
+    #[must_use]
+    pub fn channel(mut self, channel: impl Into<Vec<u8>>) -> Self {
+        self.channels.push(channel.into());
+        self
+    }
+
+    /// Subscribe to a pattern
+    #[must_use]
+    pub fn pattern(mut self, pattern: impl Into<Vec<u8>>) -> Self {
+        self.patterns.push(pattern.into());
+        self
+    }
+
+    /// Trim the stream to `max_len` entries on each `XADD` (`MAXLEN`).
+    ///
+    /// With `approximate`, the stream is trimmed with `~`, which is much more efficient
+    /// but can leave a few tens more entries than `max_len`.
+    ///
+    /// The stream is not trimmed by default
+    #[must_use]
+    pub fn max_len(mut self, max_len: usize, approximate: bool) -> Self {
+        self.max_len = Some((max_len, approximate));
+        self
+    }
+
+    /// Subscribe to all the registered channels & patterns and append incoming messages
+    /// to the stream until the subscriptions are closed.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the subscriptions,
+    /// or which is not transient while appending the messages to the stream
+    pub async fn run(self) -> Result<()> {
+        let mut pub_sub_stream = self.subscribing_client.create_pub_sub();
+
+        if !self.channels.is_empty() {
+            pub_sub_stream.subscribe(self.channels.clone()).await?;
+        }
+
+        if !self.patterns.is_empty() {
+            pub_sub_stream.psubscribe(self.patterns.clone()).await?;
+        }
+
+        // the sink must be kept alive to keep the subscriptions
+        let (_sink, stream) = pub_sub_stream.split();
+        let mut events = stream.into_events().ready_chunks(MAX_BATCH_SIZE);
+
+        while let Some(events) = events.next().await {
+            let mut commands = Vec::with_capacity(events.len());
+            for event in events {
+                match event {
+                    Ok(PubSubEvent::Message(message)) => commands.push(self.xadd(message)),
+                    Ok(PubSubEvent::Reconnected) => warn!(
+                        "[{}] pub/sub subscriptions restored, messages published during the disconnection are lost",
+                        self.stream_key
+                    ),
+                    Err(e) => warn!("Cannot read pub/sub message: {e}"),
+                }
+            }
+
+            self.append(commands).await?;
+        }
+
+        debug!("[{}] end of pub/sub bridge", self.stream_key);
+        Ok(())
+    }
+
+    fn xadd(&self, message: PubSubMessage) -> Command {
+        let mut command = cmd("XADD").arg(&self.stream_key);
+        if let Some((max_len, approximate)) = self.max_len {
+            command = command
+                .arg("MAXLEN")
+                .arg(if approximate { "~" } else { "=" })
+                .arg(max_len);
+        }
+
+        command = command.arg("*").arg("channel").arg(message.channel);
+        if !message.pattern.is_empty() {
+            command = command.arg("pattern").arg(message.pattern);
+        }
+        command.arg("payload").arg(message.payload)
+    }
+
+    /// Send the `XADD` commands, retrying the failed ones until they all succeed
+    /// or one of them fails with an error which is not transient
+    async fn append(&self, mut commands: Vec<Command>) -> Result<()> {
+        let retry_policy = RetryPolicy::default();
+        let mut retry_delay = MIN_RETRY_DELAY;
+        while !commands.is_empty() {
+            let error = match self.regular_client.send_batch(commands.clone(), None).await {
+                Ok(results) => {
+                    let mut error = None;
+                    let mut results = results.into_iter();
+                    commands.retain(|_| match results.next() {
+                        Some(result) if result.is_error() => {
+                            error = result.to::<()>().err();
+                            true
+                        }
+                        _ => false,
+                    });
+                    error
+                }
+                Err(e) => Some(e),
+            };
+
+            if commands.is_empty() {
+                break;
+            }
+
+            if let Some(e) = error {
+                if !retry_policy.is_transient(&e) {
+                    return Err(e);
+                }
+
+                warn!(
+                    "[{}] cannot append {} pub/sub messages to the stream, retrying in {retry_delay:?}: {e}",
+                    self.stream_key,
+                    commands.len()
+                );
+            }
+            sleep(retry_delay).await;
+            retry_delay = (retry_delay * 2).min(MAX_RETRY_DELAY);
+        }
+
+        Ok(())
+    }
+}
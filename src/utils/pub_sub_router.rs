@@ -0,0 +1,147 @@
+use crate::{
+    client::{Client, PubSubMessage},
+    spawn, Result,
+};
+use futures_util::{future::BoxFuture, Future, FutureExt, StreamExt};
+use log::{debug, warn};
+use std::{collections::HashMap, sync::Arc};
+
+type Handler = Arc<dyn Fn(PubSubMessage) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// Dispatch pub/sub messages to async handlers registered per channel or per pattern.
+///
+/// Each message is handled in its own task, so a slow handler does not delay the others.
+/// Messages received through a pattern subscription are routed to the handler of the pattern,
+/// other messages to the handler of their channel.
+///
+/// Subscriptions are restored after a reconnection as long as
+/// [`Config::auto_resubscribe`](crate::client::Config::auto_resubscribe) is `true` (default).
+///
+/// # Example
+/// ```
+/// use rustis::{client::{Client, PubSubMessage}, utils::PubSubRouter, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let router = PubSubRouter::new(&client)
+///         .channel("orders", |message: PubSubMessage| async move {
+///             println!("new order: {}", String::from_utf8_lossy(&message.payload));
+///         })
+///         .pattern("events.*", |message: PubSubMessage| async move {
+///             println!("event on {}", String::from_utf8_lossy(&message.channel));
+///         });
+///
+///     // `run` only returns when the subscriptions are closed,
+///     // so it is usually spawned in a dedicated task
+///     # #[cfg(feature = "tokio-runtime")]
+///     tokio::spawn(router.run());
+///
+///     Ok(())
+/// }
+/// ```
+pub struct PubSubRouter {
+    client: Client,
+    channels: HashMap<Vec<u8>, Handler>,
+    patterns: HashMap<Vec<u8>, Handler>,
+}
+
+impl PubSubRouter {
+    /// Create a new router without any route
+    #[must_use]
+    pub fn new(client: &Client) -> Self {
+        Self {
+            client: client.clone(),
+            channels: HashMap::new(),
+            patterns: HashMap::new(),
+        }
+    }
+
+    /// Register the handler of a channel.
+    ///
+    /// Registering a handler for an already registered channel replaces the previous handler.
+    #[must_use]
+    pub fn channel<C, F, Fut>(mut self, channel: C, handler: F) -> Self
+    where
+        C: Into<Vec<u8>>,
+        F: Fn(PubSubMessage) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.channels.insert(channel.into(), to_handler(handler));
+        self
+    }
+
+    /// Register the handler of a pattern
+    ///
+    /// Registering a handler for an already registered pattern replaces the previous handler.
+    #[must_use]
+    pub fn pattern<P, F, Fut>(mut self, pattern: P, handler: F) -> Self
+    where
+        P: Into<Vec<u8>>,
+        F: Fn(PubSubMessage) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.patterns.insert(pattern.into(), to_handler(handler));
+        self
+    }
+
+    /// Subscribe to all the registered channels & patterns and dispatch incoming messages
+    /// until the subscriptions are closed.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the subscriptions
+    pub async fn run(self) -> Result<()> {
+        let mut pub_sub_stream = self.client.create_pub_sub();
+
+        if !self.channels.is_empty() {
+            pub_sub_stream
+                .subscribe(self.channels.keys().cloned().collect::<Vec<_>>())
+                .await?;
+        }
+
+        if !self.patterns.is_empty() {
+            pub_sub_stream
+                .psubscribe(self.patterns.keys().cloned().collect::<Vec<_>>())
+                .await?;
+        }
+
+        while let Some(message) = pub_sub_stream.next().await {
+            let message = match message {
+                Ok(message) => message,
+                Err(e) => {
+                    warn!("Cannot read pub/sub message: {e}");
+                    continue;
+                }
+            };
+
+            let handler = if message.pattern.is_empty() {
+                self.channels.get(&message.channel)
+            } else {
+                self.patterns.get(&message.pattern)
+            };
+
+            match handler {
+                Some(handler) => {
+                    // detach the handler task
+                    let _join_handle = spawn(handler(message));
+                }
+                None => debug!(
+                    "No route for pub/sub message on channel `{}`",
+                    String::from_utf8_lossy(&message.channel)
+                ),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn to_handler<F, Fut>(handler: F) -> Handler
+where
+    F: Fn(PubSubMessage) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    Arc::new(move |message| handler(message).boxed())
+}
@@ -0,0 +1,223 @@
+use crate::{
+    client::Client,
+    resp::{CommandArgs, SingleArg},
+    utils::Script,
+    Error, Result,
+};
+use rand::Rng;
+use std::{sync::OnceLock, time::Duration};
+
+const FIXED_WINDOW: &str = r#"
+local limit = tonumber(ARGV[1])
+local current = redis.call('INCR', KEYS[1])
+if current == 1 then
+    redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+local ttl = redis.call('PTTL', KEYS[1])
+if current > limit then
+    return {0, 0, ttl}
+end
+return {1, limit - current, ttl}
+"#;
+
+const SLIDING_WINDOW_LOG: &str = r#"
+local limit = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now - window)
+local count = redis.call('ZCARD', KEYS[1])
+if count < limit then
+    redis.call('ZADD', KEYS[1], now, now .. '-' .. ARGV[3])
+    redis.call('PEXPIRE', KEYS[1], window)
+    return {1, limit - count - 1, window}
+end
+local oldest = redis.call('ZRANGE', KEYS[1], 0, 0, 'WITHSCORES')
+return {0, 0, tonumber(oldest[2]) + window - now}
+"#;
+
+const TOKEN_BUCKET: &str = r#"
+local capacity = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local rate = capacity / window
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local bucket = redis.call('HMGET', KEYS[1], 'tokens', 'ts')
+local tokens = tonumber(bucket[1])
+local ts = tonumber(bucket[2])
+if tokens == nil or ts == nil then
+    tokens = capacity
+    ts = now
+end
+tokens = math.min(capacity, tokens + (now - ts) * rate)
+local allowed = 0
+local reset_after
+if tokens >= 1 then
+    tokens = tokens - 1
+    allowed = 1
+    reset_after = math.ceil((capacity - tokens) / rate)
+else
+    reset_after = math.ceil((1 - tokens) / rate)
+end
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'ts', now)
+redis.call('PEXPIRE', KEYS[1], window)
+return {allowed, math.floor(tokens), reset_after}
+"#;
+
+static FIXED_WINDOW_SCRIPT: OnceLock<Script> = OnceLock::new();
+static SLIDING_WINDOW_LOG_SCRIPT: OnceLock<Script> = OnceLock::new();
+static TOKEN_BUCKET_SCRIPT: OnceLock<Script> = OnceLock::new();
+
+/// Algorithm used by a [`RateLimiter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitAlgorithm {
+    /// Count requests in consecutive fixed windows with `INCR` & `PEXPIRE`.
+    ///
+    /// Cheap, but allows bursts of up to twice the limit around window boundaries.
+    FixedWindow,
+    /// Log each request timestamp in a sorted set and count the ones in the last window.
+    ///
+    /// Exact, at the cost of one sorted set entry per allowed request.
+    SlidingWindowLog,
+    /// Bucket of `limit` tokens, continuously refilled at a rate of `limit` tokens per window.
+    ///
+    /// Allows bursts up to `limit` requests while enforcing the average rate.
+    TokenBucket,
+}
+
+/// Outcome of a [`RateLimiter::check`] call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decision {
+    /// `true` if the request is allowed
+    pub allowed: bool,
+    /// Number of requests that can still be done before being limited
+    pub remaining: u64,
+    /// When the request is denied, time to wait before a new request may be allowed.
+    /// Otherwise, time after which the full quota is available again.
+    pub reset_after: Duration,
+}
+
+/// Rate limiter which keeps its state in Redis.
+///
+/// Each check is executed atomically on the server with a Lua script,
+/// so a same limit can be safely shared by several application instances.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::RateLimiter, Result};
+/// use std::time::Duration;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     // 10 requests per second
+///     let rate_limiter = RateLimiter::token_bucket(&client, 10, Duration::from_secs(1));
+///
+///     let decision = rate_limiter.check("rate:user:123").await?;
+///     if !decision.allowed {
+///         println!("retry in {:?}", decision.reset_after);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct RateLimiter {
+    client: Client,
+    algorithm: RateLimitAlgorithm,
+    limit: u64,
+    window: Duration,
+}
+
+impl RateLimiter {
+    /// Create a new rate limiter allowing `limit` requests per `window`.
+    ///
+    /// A `limit` of zero or a `window` shorter than a millisecond cannot be enforced:
+    /// [`check`](RateLimiter::check) rejects them
+    /// with an [`Error::Client`](crate::Error::Client) without querying the server.
+    #[must_use]
+    pub fn new(
+        client: &Client,
+        algorithm: RateLimitAlgorithm,
+        limit: u64,
+        window: Duration,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            algorithm,
+            limit,
+            window,
+        }
+    }
+
+    /// Create a new rate limiter with the [`FixedWindow`](RateLimitAlgorithm::FixedWindow) algorithm
+    #[must_use]
+    pub fn fixed_window(client: &Client, limit: u64, window: Duration) -> Self {
+        Self::new(client, RateLimitAlgorithm::FixedWindow, limit, window)
+    }
+
+    /// Create a new rate limiter with the [`SlidingWindowLog`](RateLimitAlgorithm::SlidingWindowLog) algorithm
+    #[must_use]
+    pub fn sliding_window_log(client: &Client, limit: u64, window: Duration) -> Self {
+        Self::new(client, RateLimitAlgorithm::SlidingWindowLog, limit, window)
+    }
+
+    /// Create a new rate limiter with the [`TokenBucket`](RateLimitAlgorithm::TokenBucket) algorithm
+    #[must_use]
+    pub fn token_bucket(client: &Client, limit: u64, window: Duration) -> Self {
+        Self::new(client, RateLimitAlgorithm::TokenBucket, limit, window)
+    }
+
+    /// Algorithm of the rate limiter
+    #[must_use]
+    pub fn algorithm(&self) -> RateLimitAlgorithm {
+        self.algorithm
+    }
+
+    /// Count a new request for `key` and decide if it is allowed.
+    ///
+    /// # Errors
+    /// * [`Error::Client`](crate::Error::Client) if the limit is zero
+    ///   or if the window is shorter than a millisecond
+    /// * Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn check<K: SingleArg>(&self, key: K) -> Result<Decision> {
+        if self.limit == 0 {
+            return Err(Error::Client("Rate limit must be positive".to_owned()));
+        }
+        if self.window < Duration::from_millis(1) {
+            return Err(Error::Client(
+                "Rate limit window must be at least one millisecond".to_owned(),
+            ));
+        }
+
+        let window = self.window.as_millis() as u64;
+        let mut args = CommandArgs::default();
+        args.arg(self.limit).arg(window);
+
+        let script = match self.algorithm {
+            RateLimitAlgorithm::FixedWindow => {
+                FIXED_WINDOW_SCRIPT.get_or_init(|| Script::new(FIXED_WINDOW))
+            }
+            RateLimitAlgorithm::SlidingWindowLog => {
+                // distinguishes requests logged in the same millisecond
+                let nonce: u64 = rand::thread_rng().gen();
+                args.arg(nonce);
+                SLIDING_WINDOW_LOG_SCRIPT.get_or_init(|| Script::new(SLIDING_WINDOW_LOG))
+            }
+            RateLimitAlgorithm::TokenBucket => {
+                TOKEN_BUCKET_SCRIPT.get_or_init(|| Script::new(TOKEN_BUCKET))
+            }
+        };
+
+        let (allowed, remaining, reset_after): (bool, i64, i64) =
+            script.call(&self.client, key, args).await?;
+
+        Ok(Decision {
+            allowed,
+            remaining: remaining.max(0) as u64,
+            reset_after: Duration::from_millis(reset_after.max(0) as u64),
+        })
+    }
+}
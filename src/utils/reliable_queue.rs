@@ -0,0 +1,280 @@
+use crate::{
+    client::{BatchPreparedCommand, Client},
+    commands::{BlockingCommands, LMoveWhere, ListCommands, SetCommands, StringCommands},
+    resp::{PrimitiveResponse, SingleArg},
+    utils::Script,
+    Result,
+};
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+
+const REQUEUE_STALE: &str = r#"
+local requeued = 0
+for _, consumer in ipairs(redis.call('SMEMBERS', KEYS[2])) do
+    if redis.call('EXISTS', ARGV[1] .. ':heartbeat:' .. consumer) == 0 then
+        local processing = ARGV[1] .. ':processing:' .. consumer
+        while redis.call('LMOVE', processing, KEYS[1], 'LEFT', 'RIGHT') do
+            requeued = requeued + 1
+        end
+        redis.call('SREM', KEYS[2], consumer)
+    end
+end
+return requeued
+"#;
+
+/// Work queue implementing the [reliable queue pattern](https://redis.io/commands/lmove/#pattern-reliable-queue)
+///
+/// Items are pushed on the left of the list `<queue>` and popped from its right.
+/// A popped item is atomically moved with [`BLMOVE`](https://redis.io/commands/blmove/)
+/// to the processing list of the consumer, `<queue>:processing:<consumer>`,
+/// where it stays until it is [acknowledged](ReliableQueue::ack).
+///
+/// Each consumer maintains a heartbeat key, `<queue>:heartbeat:<consumer>`,
+/// which expires after the visibility timeout.
+/// [`requeue_stale`](ReliableQueue::requeue_stale) moves back the items
+/// of the consumers whose heartbeat has expired to the queue,
+/// so that the items of a crashed consumer are eventually processed by another one.
+///
+/// Since `BLMOVE` blocks the connection, the consumer should be given
+/// its own [`Client`] instead of a client shared with other parts of the application.
+///
+/// The keys of a queue are built at runtime by the requeue script,
+/// so a queue cannot be used with a cluster.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::ReliableQueue, Result};
+/// use std::time::Duration;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let queue = ReliableQueue::new(&client, "jobs", "worker1");
+///     queue.push("job1").await?;
+///
+///     let job: Option<String> = queue.pop(Duration::from_secs(1)).await?;
+///     if let Some(job) = job {
+///         // process job...
+///         queue.ack(job).await?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ReliableQueue {
+    client: Client,
+    queue: String,
+    consumer: String,
+    visibility_timeout: Duration,
+}
+
+impl ReliableQueue {
+    /// Default time after which the items of a consumer without heartbeat can be requeued
+    pub const DEFAULT_VISIBILITY_TIMEOUT: Duration = Duration::from_secs(60);
+
+    /// Create a new queue handle for the consumer `consumer` of the queue `queue`
+    #[must_use]
+    pub fn new(client: &Client, queue: impl Into<String>, consumer: impl Into<String>) -> Self {
+        Self {
+            client: client.clone(),
+            queue: queue.into(),
+            consumer: consumer.into(),
+            visibility_timeout: Self::DEFAULT_VISIBILITY_TIMEOUT,
+        }
+    }
+
+    /// Time after which the items of the consumer can be requeued if it stops sending heartbeats
+    #[must_use]
+    pub fn visibility_timeout(mut self, visibility_timeout: Duration) -> Self {
+        self.visibility_timeout = visibility_timeout;
+        self
+    }
+
+    /// Name of the queue
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.queue
+    }
+
+    /// Name of the consumer
+    #[must_use]
+    pub fn consumer(&self) -> &str {
+        &self.consumer
+    }
+
+    /// Key of the processing list of the consumer
+    #[must_use]
+    pub fn processing_key(&self) -> String {
+        format!("{}:processing:{}", self.queue, self.consumer)
+    }
+
+    /// Push an item to the queue.
+    ///
+    /// # Return
+    /// The length of the queue after the push
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn push<E: SingleArg>(&self, item: E) -> Result<usize> {
+        self.client.lpush(&self.queue, item).await
+    }
+
+    /// Wait up to `timeout` for an item and move it to the processing list of the consumer.
+    ///
+    /// A zero `timeout` waits indefinitely.
+    /// The heartbeat of the consumer is refreshed before waiting.
+    ///
+    /// # Return
+    /// The item, or `None` if the timeout has been reached
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn pop<E>(&self, timeout: Duration) -> Result<Option<E>>
+    where
+        E: PrimitiveResponse + DeserializeOwned + Send,
+    {
+        self.send_heartbeat(timeout).await?;
+        self.client
+            .blmove(
+                &self.queue,
+                self.processing_key(),
+                LMoveWhere::Right,
+                LMoveWhere::Left,
+                timeout.as_secs_f64(),
+            )
+            .await
+    }
+
+    /// Move an item to the processing list of the consumer, without waiting.
+    ///
+    /// # Return
+    /// The item, or `None` if the queue is empty
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn try_pop<E>(&self) -> Result<Option<E>>
+    where
+        E: PrimitiveResponse + DeserializeOwned + Send,
+    {
+        self.send_heartbeat(Duration::ZERO).await?;
+        self.client
+            .lmove(
+                &self.queue,
+                self.processing_key(),
+                LMoveWhere::Right,
+                LMoveWhere::Left,
+            )
+            .await
+    }
+
+    /// Acknowledge a processed item, removing it from the processing list of the consumer.
+    ///
+    /// # Return
+    /// `false` if the item was not found in the processing list,
+    /// for example because it has been requeued after the visibility timeout.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn ack<E: SingleArg>(&self, item: E) -> Result<bool> {
+        let removed = self.client.lrem(self.processing_key(), -1, item).await?;
+        Ok(removed > 0)
+    }
+
+    /// Refresh the heartbeat of the consumer.
+    ///
+    /// Consumers processing items for longer than the visibility timeout
+    /// must call this method periodically to keep their items.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn heartbeat(&self) -> Result<()> {
+        self.send_heartbeat(Duration::ZERO).await
+    }
+
+    /// Move back to the queue the items of all the consumers whose heartbeat has expired.
+    ///
+    /// Requeued items are popped before the other items of the queue.
+    ///
+    /// # Return
+    /// The number of requeued items
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn requeue_stale(&self) -> Result<usize> {
+        Script::new(REQUEUE_STALE)
+            .call(
+                &self.client,
+                [self.queue.clone(), self.consumers_key()],
+                self.queue.clone(),
+            )
+            .await
+    }
+
+    /// Number of items waiting in the queue
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn len(&self) -> Result<usize> {
+        self.client.llen(&self.queue).await
+    }
+
+    /// Number of items in the processing list of the consumer
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn processing_len(&self) -> Result<usize> {
+        self.client.llen(self.processing_key()).await
+    }
+
+    /// The next `count` items of the queue, in pop order, without removing them
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn peek<E>(&self, count: usize) -> Result<Vec<E>>
+    where
+        E: PrimitiveResponse + DeserializeOwned + Send,
+    {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        let mut items: Vec<E> = self
+            .client
+            .lrange(&self.queue, -(count as isize), -1)
+            .await?;
+        items.reverse();
+        Ok(items)
+    }
+
+    /// Items in the processing list of the consumer, oldest first
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn processing_items<E>(&self) -> Result<Vec<E>>
+    where
+        E: PrimitiveResponse + DeserializeOwned + Send,
+    {
+        let mut items: Vec<E> = self.client.lrange(self.processing_key(), 0, -1).await?;
+        items.reverse();
+        Ok(items)
+    }
+
+    fn consumers_key(&self) -> String {
+        format!("{}:consumers", self.queue)
+    }
+
+    /// Register the consumer and refresh its heartbeat,
+    /// which must outlive a pending `BLMOVE` of `block` duration
+    async fn send_heartbeat(&self, block: Duration) -> Result<()> {
+        let heartbeat_key = format!("{}:heartbeat:{}", self.queue, self.consumer);
+        let ttl = (self.visibility_timeout + block).max(Duration::from_millis(1));
+
+        let mut pipeline = self.client.create_pipeline();
+        pipeline.sadd(self.consumers_key(), &self.consumer).queue();
+        pipeline.set_with_expiry(heartbeat_key, 1, ttl).queue();
+        let _: (usize, ()) = pipeline.execute().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    client::Client,
+    commands::{ClusterCommands, ClusterShardResult, LegacyClusterShardResult, ScanOptions},
+    resp::cmd,
+    Error, Result,
+};
+use futures_util::{stream, Stream};
+use std::collections::{HashSet, VecDeque};
+
+struct ScanStreamState {
+    /// Hash slots of the master nodes left to scan, `None` for a standalone server.
+    ///
+    /// Resolved on the first iteration
+    targets: Option<VecDeque<Option<u16>>>,
+    cursor: u64,
+    keys: VecDeque<String>,
+}
+
+/// Returns a [`Stream`] of the keys of the database, fetched incrementally with
+/// [`SCAN`](https://redis.io/commands/scan/) and the given `options`.
+///
+/// In cluster mode, the master nodes are scanned one after the other,
+/// each with its own cursor, so that the keys of the whole cluster are returned
+/// without enumerating the nodes. Keys migrated between two nodes during the iteration
+/// may or may not be returned.
+///
+/// Like `SCAN`, a key may be returned several times, see [`keys_safe_stream`](crate::utils::keys_safe_stream)
+/// to get each key only once.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::Client,
+///     commands::{ScanOptions, StringCommands},
+///     utils::scan_stream,
+///     Result,
+/// };
+/// use futures_util::TryStreamExt;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     client.set("user:1", "John").await?;
+///
+///     let keys: Vec<String> =
+///         scan_stream(&client, ScanOptions::default().match_pattern("user:*"))
+///             .try_collect()
+///             .await?;
+///     assert!(keys.contains(&"user:1".to_owned()));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn scan_stream(
+    client: &Client,
+    options: ScanOptions,
+) -> impl Stream<Item = Result<String>> + '_ {
+    let state = ScanStreamState {
+        targets: None,
+        cursor: 0,
+        keys: VecDeque::new(),
+    };
+
+    stream::try_unfold(state, move |mut state| {
+        let options = options.clone();
+        async move {
+            loop {
+                if let Some(key) = state.keys.pop_front() {
+                    return Ok(Some((key, state)));
+                }
+
+                let targets = match &mut state.targets {
+                    Some(targets) => targets,
+                    None => state.targets.insert(scan_targets(client).await?),
+                };
+                let Some(target) = targets.front().copied() else {
+                    return Ok(None);
+                };
+
+                let mut command = cmd("SCAN").arg(state.cursor).arg(options.clone());
+                if let Some(hash_slot) = target {
+                    command = command.hash_slot(hash_slot);
+                }
+                let (next_cursor, keys): (u64, Vec<String>) =
+                    client.send(command, None).await?.to()?;
+
+                if next_cursor == 0 {
+                    targets.pop_front();
+                }
+                state.cursor = next_cursor;
+                state.keys.extend(keys);
+            }
+        }
+    })
+}
+
+/// One hash slot served by each master node in cluster mode, `None` for a standalone server
+async fn scan_targets(client: &Client) -> Result<VecDeque<Option<u16>>> {
+    // From Redis 7.x CLUSTER SLOTS is deprecated in favor of CLUSTER SHARDS
+    let use_shards = client
+        .server_features()
+        .is_some_and(|server_features| server_features.is_at_least(7, 0));
+
+    let hash_slots = if use_shards {
+        let shards: Result<Vec<ClusterShardResult>> = client.cluster_shards().await;
+        shards.map(|shards| {
+            shards
+                .into_iter()
+                .filter_map(|shard| shard.slots.first().map(|(start, _end)| Some(*start)))
+                .collect()
+        })
+    } else {
+        let slot_ranges: Result<Vec<LegacyClusterShardResult>> = client.cluster_slots().await;
+        slot_ranges.map(|slot_ranges| {
+            let mut master_ids = HashSet::new();
+            slot_ranges
+                .into_iter()
+                .filter(|slot_range| {
+                    slot_range
+                        .nodes
+                        .first()
+                        .is_some_and(|master| master_ids.insert(master.id.clone()))
+                })
+                .map(|slot_range| Some(slot_range.slot.0))
+                .collect()
+        })
+    };
+
+    match hash_slots {
+        Ok(hash_slots) => Ok(hash_slots),
+        Err(e) if is_cluster_support_disabled(&e) => Ok(VecDeque::from([None])),
+        Err(e) => Err(e),
+    }
+}
+
+/// `ERR This instance has cluster support disabled`, returned by a standalone server
+pub(crate) fn is_cluster_support_disabled(error: &Error) -> bool {
+    matches!(error, Error::Redis(e) if e.description.contains("cluster support disabled"))
+}
@@ -0,0 +1,109 @@
+use crate::{
+    client::{prepare_command, Client, PreparedCommand},
+    commands::{CallBuilder, ScriptingCommands},
+    resp::{cmd, Response, SingleArg, SingleArgCollection},
+    Error, RedisError, RedisErrorKind,
+};
+use serde::de::DeserializeOwned;
+
+/// A Lua script which can be invoked by its SHA1 digest.
+///
+/// The digest is computed client side, so the script is invoked directly with
+/// [`EVALSHA`](https://redis.io/commands/evalsha/).
+/// If the script is not in the server's cache yet, it is loaded with
+/// [`SCRIPT LOAD`](https://redis.io/commands/script-load/)
+/// and the `EVALSHA` command is sent again.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::Script, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let script = Script::new("return KEYS[1] .. ARGV[1]");
+///     let value: String = script.call(&client, "hello", " world").await?;
+///     assert_eq!("hello world", value);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Script {
+    code: String,
+    sha1: String,
+}
+
+impl Script {
+    /// Create a new script from its Lua source code
+    #[must_use]
+    pub fn new<C: Into<String>>(code: C) -> Self {
+        let code = code.into();
+        let sha1 = sha1_smol::Sha1::from(&code).digest().to_string();
+        Self { code, sha1 }
+    }
+
+    /// Lua source code of the script
+    #[must_use]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Hexadecimal SHA1 digest of the script
+    #[must_use]
+    pub fn sha1(&self) -> &str {
+        &self.sha1
+    }
+
+    /// Invoke the script with the given keys and arguments.
+    ///
+    /// The script is loaded on the server if needed.
+    /// Since the automatic loading is driven by the response,
+    /// it only happens when the prepared command is awaited.
+    ///
+    /// # Return
+    /// The return value of the script
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/evalsha/>](https://redis.io/commands/evalsha/)
+    #[must_use]
+    pub fn call<'a, K, KK, A, AA, R>(
+        &self,
+        client: &'a Client,
+        keys: KK,
+        args: AA,
+    ) -> PreparedCommand<'a, &'a Client, R>
+    where
+        K: SingleArg,
+        KK: SingleArgCollection<K>,
+        A: SingleArg,
+        AA: SingleArgCollection<A>,
+        R: Response + DeserializeOwned + Send + 'a,
+    {
+        let code = self.code.clone();
+
+        prepare_command(
+            client,
+            cmd("EVALSHA").arg(CallBuilder::sha1(self.sha1.clone()).keys(keys).args(args)),
+        )
+        .custom_converter(Box::new(move |resp_buffer, command, client| {
+            let code = code.clone();
+            Box::pin(async move {
+                if resp_buffer.is_error() {
+                    if let Err(Error::Redis(RedisError {
+                        kind: RedisErrorKind::NoScript,
+                        ..
+                    })) = resp_buffer.to::<()>()
+                    {
+                        client.script_load::<_, String>(code).await?;
+                        return client.send(command, None).await?.to();
+                    }
+                }
+
+                resp_buffer.to()
+            })
+        }))
+    }
+}
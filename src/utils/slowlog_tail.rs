@@ -0,0 +1,93 @@
+use crate::{
+    client::Client,
+    commands::{ServerCommands, SlowLogEntry, SlowLogOptions},
+    sleep, Result,
+};
+use futures_util::{stream, Stream};
+use std::{collections::VecDeque, time::Duration};
+
+/// Maximum number of entries fetched by each `SLOWLOG GET`
+const SLOWLOG_GET_COUNT: usize = 128;
+
+struct SlowLogTailState {
+    /// Greatest entry id already seen, `None` before the first poll
+    last_id: Option<i64>,
+    entries: VecDeque<SlowLogEntry>,
+}
+
+/// Returns a [`Stream`] of the entries added to the slow log of the server,
+/// fetched every `poll_interval` with [`SLOWLOG GET`](https://redis.io/commands/slowlog-get/).
+///
+/// Entries already in the slow log when the stream starts are skipped,
+/// entries are de-duplicated by id and yielded from the oldest to the newest.
+/// At most 128 entries are fetched at each poll: entries added faster than that are missed.
+/// If the ids go backward, the server is considered restarted and all its entries are yielded.
+///
+/// The stream never ends, it can be stopped by dropping it.
+///
+/// # Example
+/// ```
+/// use futures_util::StreamExt;
+/// use rustis::{client::Client, utils::slowlog_tail, Result};
+/// use std::time::Duration;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let slow_commands = slowlog_tail(&client, Duration::from_secs(1));
+///     // display the next 10 slow commands
+///     let mut slow_commands = Box::pin(slow_commands.take(10));
+///     while let Some(entry) = slow_commands.next().await {
+///         let entry = entry?;
+///         println!("{}us: {}", entry.execution_time_micros, entry.command.join(" "));
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn slowlog_tail(
+    client: &Client,
+    poll_interval: Duration,
+) -> impl Stream<Item = Result<SlowLogEntry>> + '_ {
+    let state = SlowLogTailState {
+        last_id: None,
+        entries: VecDeque::new(),
+    };
+
+    stream::try_unfold(state, move |mut state| async move {
+        loop {
+            if let Some(entry) = state.entries.pop_front() {
+                return Ok(Some((entry, state)));
+            }
+
+            if state.last_id.is_some() {
+                sleep(poll_interval).await;
+            }
+
+            // newest entries first
+            let entries = client
+                .slowlog_get(SlowLogOptions::default().count(SLOWLOG_GET_COUNT))
+                .await?;
+            let Some(max_id) = entries.iter().map(|entry| entry.id).max() else {
+                state.last_id.get_or_insert(-1);
+                continue;
+            };
+
+            match state.last_id {
+                None => (),
+                Some(last_id) if max_id < last_id => {
+                    state.entries.extend(entries.into_iter().rev());
+                }
+                Some(last_id) => {
+                    state
+                        .entries
+                        .extend(entries.into_iter().rev().filter(|entry| entry.id > last_id));
+                }
+            }
+
+            state.last_id = Some(max_id);
+        }
+    })
+}
@@ -0,0 +1,340 @@
+use crate::{
+    client::Client,
+    commands::{
+        StreamCommands, StreamEntry, XAddOptions, XAutoClaimOptions, XGroupCreateOptions,
+        XPendingOptions, XReadGroupOptions,
+    },
+    Error, RedisError, RedisErrorKind, Result,
+};
+use futures_util::{stream, Stream};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+/// Message delivered by a [`StreamConsumer`]
+///
+/// The message stays in the pending entries list of the consumer group
+/// until it is [acknowledged](StreamMessage::ack).
+pub struct StreamMessage {
+    /// The stream Id
+    pub stream_id: String,
+    /// Fields and values of the entry
+    pub items: HashMap<String, String>,
+    client: Client,
+    key: String,
+    group: String,
+}
+
+impl StreamMessage {
+    /// Acknowledge the message, removing it from the pending entries list of the consumer group.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn ack(self) -> Result<()> {
+        self.client
+            .xack(&self.key, &self.group, &self.stream_id)
+            .await?;
+        Ok(())
+    }
+}
+
+/// Consumer of a stream, as a member of a consumer group.
+///
+/// The consumer reads new entries with [`XREADGROUP`](https://redis.io/commands/xreadgroup/)
+/// and periodically reclaims entries left pending by other consumers for too long
+/// with [`XAUTOCLAIM`](https://redis.io/commands/xautoclaim/).
+/// Entries which have been delivered too many times can be moved to a dead-letter stream.
+///
+/// Since `XREADGROUP` is called with the `BLOCK` option, the consumer should be given
+/// its own [`Client`] instead of a client shared with other parts of the application.
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::StreamConsumer, Result};
+/// use futures_util::StreamExt;
+/// use std::time::Duration;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let consumer = StreamConsumer::new(&client, "events", "workers", "worker1")
+///         .block(Duration::from_millis(100))
+///         .dead_letter(5, "events:dead");
+///     consumer.create_group().await?;
+///
+///     let mut messages = Box::pin(consumer.into_stream());
+///     # return Ok(());
+///     while let Some(message) = messages.next().await {
+///         let message = message?;
+///         // process message.items...
+///         message.ack().await?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct StreamConsumer {
+    client: Client,
+    key: String,
+    group: String,
+    consumer: String,
+    block: Duration,
+    batch_size: usize,
+    claim_interval: Duration,
+    min_idle_time: Duration,
+    max_deliveries: Option<usize>,
+    dead_letter_key: Option<String>,
+}
+
+impl StreamConsumer {
+    /// Default block time of `XREADGROUP`
+    pub const DEFAULT_BLOCK: Duration = Duration::from_secs(1);
+    /// Default maximum number of entries read at once
+    pub const DEFAULT_BATCH_SIZE: usize = 10;
+    /// Default interval between two reclaims of pending entries
+    pub const DEFAULT_CLAIM_INTERVAL: Duration = Duration::from_secs(30);
+    /// Default idle time after which a pending entry can be reclaimed
+    pub const DEFAULT_MIN_IDLE_TIME: Duration = Duration::from_secs(60);
+
+    /// Create a new consumer named `consumer`, member of the group `group` of the stream `key`
+    #[must_use]
+    pub fn new(
+        client: &Client,
+        key: impl Into<String>,
+        group: impl Into<String>,
+        consumer: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            key: key.into(),
+            group: group.into(),
+            consumer: consumer.into(),
+            block: Self::DEFAULT_BLOCK,
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            claim_interval: Self::DEFAULT_CLAIM_INTERVAL,
+            min_idle_time: Self::DEFAULT_MIN_IDLE_TIME,
+            max_deliveries: None,
+            dead_letter_key: None,
+        }
+    }
+
+    /// Maximum time to block waiting for new entries
+    #[must_use]
+    pub fn block(mut self, block: Duration) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Maximum number of entries read or reclaimed at once
+    #[must_use]
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Interval between two reclaims of pending entries
+    #[must_use]
+    pub fn claim_interval(mut self, claim_interval: Duration) -> Self {
+        self.claim_interval = claim_interval;
+        self
+    }
+
+    /// Idle time after which an entry pending for another consumer can be reclaimed
+    #[must_use]
+    pub fn min_idle_time(mut self, min_idle_time: Duration) -> Self {
+        self.min_idle_time = min_idle_time;
+        self
+    }
+
+    /// Move entries delivered at least `max_deliveries` times to the stream `dead_letter_key`
+    /// instead of reclaiming them once more.
+    ///
+    /// Moved entries are acknowledged in the consumer group.
+    #[must_use]
+    pub fn dead_letter(
+        mut self,
+        max_deliveries: usize,
+        dead_letter_key: impl Into<String>,
+    ) -> Self {
+        self.max_deliveries = Some(max_deliveries);
+        self.dead_letter_key = Some(dead_letter_key.into());
+        self
+    }
+
+    /// Create the consumer group, and the stream if needed.
+    ///
+    /// The group is created to read only new entries.
+    /// If the group already exists, nothing is done.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the operation
+    pub async fn create_group(&self) -> Result<()> {
+        match self
+            .client
+            .xgroup_create(
+                &self.key,
+                &self.group,
+                "$",
+                XGroupCreateOptions::default().mk_stream(),
+            )
+            .await
+        {
+            Ok(_)
+            | Err(Error::Redis(RedisError {
+                kind: RedisErrorKind::BusyGroup,
+                ..
+            })) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Convert the consumer into an endless stream of messages
+    pub fn into_stream(self) -> impl Stream<Item = Result<StreamMessage>> {
+        let state = ConsumerState {
+            consumer: self,
+            buffer: VecDeque::new(),
+            last_claim: None,
+            claim_cursor: "0-0".to_owned(),
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(entry) = state.buffer.pop_front() {
+                    let message = state.consumer.to_message(entry);
+                    return Some((Ok(message), state));
+                }
+
+                if let Err(e) = state.fill_buffer().await {
+                    return Some((Err(e), state));
+                }
+            }
+        })
+    }
+
+    fn to_message(&self, entry: StreamEntry<String>) -> StreamMessage {
+        StreamMessage {
+            stream_id: entry.stream_id,
+            items: entry.items,
+            client: self.client.clone(),
+            key: self.key.clone(),
+            group: self.group.clone(),
+        }
+    }
+
+    async fn move_to_dead_letter(&self) -> Result<()> {
+        let (Some(max_deliveries), Some(dead_letter_key)) =
+            (self.max_deliveries, &self.dead_letter_key)
+        else {
+            return Ok(());
+        };
+
+        let pending = self
+            .client
+            .xpending_with_options(
+                &self.key,
+                &self.group,
+                XPendingOptions::default()
+                    .idle(self.min_idle_time.as_millis() as u64)
+                    .start("-")
+                    .end("+")
+                    .count(self.batch_size),
+            )
+            .await?;
+
+        for message in pending {
+            if message.times_delivered < max_deliveries {
+                continue;
+            }
+
+            let entries: Vec<StreamEntry<String>> = self
+                .client
+                .xrange(&self.key, &message.message_id, &message.message_id, None)
+                .await?;
+
+            if let Some(entry) = entries.into_iter().next() {
+                self.client
+                    .xadd::<_, _, _, _, _, String>(
+                        dead_letter_key,
+                        "*",
+                        entry.items,
+                        XAddOptions::default(),
+                    )
+                    .await?;
+            }
+
+            self.client
+                .xack(&self.key, &self.group, &message.message_id)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+struct ConsumerState {
+    consumer: StreamConsumer,
+    buffer: VecDeque<StreamEntry<String>>,
+    last_claim: Option<Instant>,
+    claim_cursor: String,
+}
+
+impl ConsumerState {
+    async fn fill_buffer(&mut self) -> Result<()> {
+        let consumer = &self.consumer;
+
+        let claim_due = match self.last_claim {
+            Some(last_claim) => last_claim.elapsed() >= consumer.claim_interval,
+            None => true,
+        };
+
+        if claim_due {
+            consumer.move_to_dead_letter().await?;
+
+            let result = consumer
+                .client
+                .xautoclaim::<_, _, _, _, String>(
+                    &consumer.key,
+                    &consumer.group,
+                    &consumer.consumer,
+                    consumer.min_idle_time.as_millis() as u64,
+                    &self.claim_cursor,
+                    XAutoClaimOptions::default().count(consumer.batch_size),
+                )
+                .await?;
+
+            // a full scan of the pending entries list has been done when the cursor is back to 0-0
+            if result.start_stream_id == "0-0" {
+                self.last_claim = Some(Instant::now());
+            }
+            self.claim_cursor = result.start_stream_id;
+            self.buffer.extend(result.entries);
+
+            if !self.buffer.is_empty() {
+                return Ok(());
+            }
+        }
+
+        let results: Vec<(String, Vec<StreamEntry<String>>)> = consumer
+            .client
+            .xreadgroup(
+                &consumer.group,
+                &consumer.consumer,
+                XReadGroupOptions::default()
+                    .count(consumer.batch_size)
+                    .block(consumer.block.as_millis() as u64),
+                &consumer.key,
+                ">",
+            )
+            .await?;
+
+        for (_key, entries) in results {
+            self.buffer.extend(entries);
+        }
+
+        Ok(())
+    }
+}
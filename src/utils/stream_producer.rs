@@ -0,0 +1,189 @@
+use crate::{
+    client::Client,
+    resp::{cmd, CommandArgs, KeyValueArgsCollection, SingleArg},
+    sleep, spawn, Error, Future, JoinHandle, Result,
+};
+use futures_channel::{mpsc, oneshot};
+use futures_util::{select, FutureExt, StreamExt};
+use log::debug;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// The permit is released once the batch of the entry has been sent
+type Entry = (
+    CommandArgs,
+    oneshot::Sender<Result<String>>,
+    OwnedSemaphorePermit,
+);
+
+/// Configuration of a [`StreamProducer`]
+#[derive(Debug, Clone)]
+pub struct StreamProducerConfig {
+    /// Maximum number of entries sent in a single batch.
+    ///
+    /// The default is `100`
+    pub batch_size: usize,
+    /// Maximum time an entry waits in the buffer for a batch to be filled.
+    ///
+    /// The default is 10ms
+    pub linger: Duration,
+    /// Maximum number of entries buffered and not yet sent.
+    /// When reached, [`send`](StreamProducer::send) waits until some room is available.
+    ///
+    /// The default is `10000`
+    pub capacity: usize,
+}
+
+impl Default for StreamProducerConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 100,
+            linger: Duration::from_millis(10),
+            capacity: 10_000,
+        }
+    }
+}
+
+/// Producer which buffers [`XADD`](https://redis.io/commands/xadd/) entries
+/// and sends them to the stream in pipelined batches.
+///
+/// A batch is sent as soon as it contains [`batch_size`](StreamProducerConfig::batch_size) entries,
+/// or when the oldest entry has waited for [`linger`](StreamProducerConfig::linger).
+///
+/// # Example
+/// ```
+/// use rustis::{client::Client, utils::{StreamProducer, StreamProducerConfig}, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     let producer = StreamProducer::new(&client, "telemetry", StreamProducerConfig::default());
+///
+///     // the id will be known once the batch has been sent
+///     let id = producer.send([("temperature", "21.5")]).await?;
+///     println!("entry sent with id {}", id.await?);
+///
+///     producer.close().await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct StreamProducer {
+    sender: mpsc::UnboundedSender<Entry>,
+    capacity: Arc<Semaphore>,
+    join_handle: JoinHandle<()>,
+}
+
+impl StreamProducer {
+    /// Create a new producer for the stream `key` and start its background flushing task
+    #[must_use]
+    pub fn new(client: &Client, key: impl Into<String>, config: StreamProducerConfig) -> Self {
+        let (sender, receiver) = mpsc::unbounded();
+        let capacity = Arc::new(Semaphore::new(
+            config.capacity.clamp(1, Semaphore::MAX_PERMITS),
+        ));
+        let join_handle = spawn(producer_loop(client.clone(), key.into(), config, receiver));
+
+        Self {
+            sender,
+            capacity,
+            join_handle,
+        }
+    }
+
+    /// Buffer a new entry.
+    ///
+    /// Waits if the buffer is full.
+    ///
+    /// # Return
+    /// A future resolved with the id assigned to the entry, once its batch has been sent.
+    ///
+    /// # Errors
+    /// An error is returned if the producer is closed
+    pub async fn send<F, V, FFVV>(&self, items: FFVV) -> Result<Future<'static, String>>
+    where
+        F: SingleArg,
+        V: SingleArg,
+        FFVV: KeyValueArgsCollection<F, V>,
+    {
+        let (id_sender, id_receiver) = oneshot::channel();
+
+        let permit = self
+            .capacity
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|_| Error::Client("Stream producer is closed".to_owned()))?;
+
+        self.sender
+            .unbounded_send((CommandArgs::default().arg(items).build(), id_sender, permit))
+            .map_err(|_| Error::Client("Stream producer is closed".to_owned()))?;
+
+        Ok(Box::pin(async move {
+            id_receiver
+                .await
+                .map_err(|_| Error::Client("Stream producer is closed".to_owned()))?
+        }))
+    }
+
+    /// Send the buffered entries and stop the producer
+    ///
+    /// # Errors
+    /// Error raised by the background task
+    pub async fn close(self) -> Result<()> {
+        self.sender.close_channel();
+        self.join_handle.await
+    }
+}
+
+async fn producer_loop(
+    client: Client,
+    key: String,
+    config: StreamProducerConfig,
+    mut receiver: mpsc::UnboundedReceiver<Entry>,
+) {
+    while let Some(entry) = receiver.next().await {
+        let mut batch = vec![entry];
+        let mut linger = Box::pin(sleep(config.linger).fuse());
+
+        while batch.len() < config.batch_size {
+            select! {
+                entry = receiver.next() => match entry {
+                    Some(entry) => batch.push(entry),
+                    None => break,
+                },
+                _ = linger => break,
+            }
+        }
+
+        flush(&client, &key, batch).await;
+    }
+
+    debug!("[{key}] end of stream producer loop");
+}
+
+async fn flush(client: &Client, key: &str, batch: Vec<Entry>) {
+    let (commands, id_senders): (Vec<_>, Vec<_>) = batch
+        .into_iter()
+        .map(|(items, id_sender, permit)| {
+            (
+                cmd("XADD").arg(key).arg("*").arg(items),
+                (id_sender, permit),
+            )
+        })
+        .unzip();
+
+    match client.send_batch(commands, None).await {
+        Ok(results) => {
+            for (result, (id_sender, _permit)) in results.into_iter().zip(id_senders) {
+                let _result = id_sender.send(result.to());
+            }
+        }
+        Err(e) => {
+            for (id_sender, _permit) in id_senders {
+                let _result = id_sender.send(Err(e.clone()));
+            }
+        }
+    }
+}
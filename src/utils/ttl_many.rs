@@ -0,0 +1,109 @@
+use crate::{
+    client::{BatchPreparedCommand, Client},
+    commands::GenericCommands,
+    resp::SingleArg,
+    Result,
+};
+use std::time::Duration;
+
+/// Remaining time to live of a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ttl {
+    /// The key does not exist
+    NoKey,
+    /// The key exists but has no associated expire
+    NoExpiry,
+    /// The key expires after this duration
+    Remaining(Duration),
+}
+
+impl Ttl {
+    /// Converts a reply of [`PTTL`](https://redis.io/commands/pttl/), where
+    /// `-2` means that the key does not exist and `-1` that it has no associated expire.
+    #[must_use]
+    pub fn from_millis(pttl: i64) -> Self {
+        match pttl {
+            -2 => Ttl::NoKey,
+            millis if millis < 0 => Ttl::NoExpiry,
+            millis => Ttl::Remaining(Duration::from_millis(millis as u64)),
+        }
+    }
+
+    /// Converts a reply of [`TTL`](https://redis.io/commands/ttl/), where
+    /// `-2` means that the key does not exist and `-1` that it has no associated expire.
+    #[must_use]
+    pub fn from_secs(ttl: i64) -> Self {
+        match ttl {
+            -2 => Ttl::NoKey,
+            secs if secs < 0 => Ttl::NoExpiry,
+            secs => Ttl::Remaining(Duration::from_secs(secs as u64)),
+        }
+    }
+
+    /// Remaining duration, if the key exists and has an associated expire
+    #[must_use]
+    pub fn remaining(&self) -> Option<Duration> {
+        match self {
+            Ttl::Remaining(duration) => Some(*duration),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the remaining time to live of each key of `keys`,
+/// with [`PTTL`](https://redis.io/commands/pttl/) commands sent in a single pipeline.
+///
+/// # Return
+/// The keys with their time to live, in the order of `keys`.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::Client,
+///     commands::StringCommands,
+///     utils::{ttl_many, Ttl},
+///     Result,
+/// };
+/// use std::time::Duration;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     client.set_with_expiry("session", "John", Duration::from_secs(60)).await?;
+///     client.set("settings", "dark").await?;
+///
+///     let ttls = ttl_many(&client, ["session", "settings", "unknown"]).await?;
+///     assert!(matches!(ttls[0], ("session", Ttl::Remaining(_))));
+///     assert_eq!(("settings", Ttl::NoExpiry), ttls[1]);
+///     assert_eq!(("unknown", Ttl::NoKey), ttls[2]);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn ttl_many<K, I>(client: &Client, keys: I) -> Result<Vec<(K, Ttl)>>
+where
+    K: SingleArg + Clone,
+    I: IntoIterator<Item = K>,
+{
+    let keys: Vec<K> = keys.into_iter().collect();
+
+    let pttls: Vec<i64> = match keys.len() {
+        0 => return Ok(Vec::new()),
+        // a pipeline of a single command does not reply with an array
+        1 => vec![client.pttl(keys[0].clone()).await?],
+        _ => {
+            let mut pipeline = client.create_pipeline();
+            for key in &keys {
+                pipeline.pttl(key.clone()).queue();
+            }
+            pipeline.execute().await?
+        }
+    };
+
+    Ok(keys
+        .into_iter()
+        .zip(pttls)
+        .map(|(key, pttl)| (key, Ttl::from_millis(pttl)))
+        .collect())
+}
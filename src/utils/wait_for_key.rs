@@ -0,0 +1,150 @@
+use crate::{
+    client::{Client, PubSubStream},
+    commands::{GenericCommands, PubSubCommands, ServerCommands},
+    sleep, timeout, Error, Result,
+};
+use futures_util::StreamExt;
+use std::{collections::HashMap, time::Duration};
+
+/// Interval between two checks of the key when keyspace notifications are disabled
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Interval between two checks of the key when keyspace notifications are enabled,
+/// in case some notification classes are not enabled on the server
+const NOTIFIED_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Condition awaited by [`wait_for_key`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCondition {
+    /// The key exists
+    Exists,
+    /// The value of the key has changed since the wait began,
+    /// including when the key is created or deleted
+    Changed,
+    /// The key does not exist
+    Deleted,
+}
+
+/// Wait until `key` meets `condition`, or until `timeout_duration` expires.
+///
+/// When [keyspace notifications](https://redis.io/docs/manual/keyspace-notifications/) are enabled
+/// on the server (`K` flag of the `notify-keyspace-events` configuration parameter),
+/// the key is checked each time a notification is received for it.
+/// Otherwise, the key is polled with [`EXISTS`](https://redis.io/commands/exists/),
+/// or [`DUMP`](https://redis.io/commands/dump/) for [`KeyCondition::Changed`].
+///
+/// # Return
+/// `true` if the condition has been met, `false` if the timeout has expired.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::Client,
+///     commands::StringCommands,
+///     utils::{wait_for_key, KeyCondition},
+///     Result,
+/// };
+/// use std::time::Duration;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///     client.set("job:42:done", 1).await?;
+///
+///     let done = wait_for_key(&client, "job:42:done", KeyCondition::Exists, Duration::from_secs(1)).await?;
+///     assert!(done);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn wait_for_key(
+    client: &Client,
+    key: impl Into<String>,
+    condition: KeyCondition,
+    timeout_duration: Duration,
+) -> Result<bool> {
+    let key = key.into();
+
+    // subscribe before the first check so that no change can be missed in between
+    let mut notifications = if keyspace_notifications_enabled(client).await {
+        client
+            .psubscribe(format!("__keyspace@*__:{}", escape_pattern(&key)))
+            .await
+            .ok()
+    } else {
+        None
+    };
+
+    let initial_value = if condition == KeyCondition::Changed {
+        client.dump(key.as_str()).await?.map(|dump| dump.0)
+    } else {
+        None
+    };
+
+    let wait = async {
+        loop {
+            let is_met = match condition {
+                KeyCondition::Exists => client.exists(key.as_str()).await? > 0,
+                KeyCondition::Deleted => client.exists(key.as_str()).await? == 0,
+                KeyCondition::Changed => {
+                    client.dump(key.as_str()).await?.map(|dump| dump.0) != initial_value
+                }
+            };
+
+            if is_met {
+                return Ok::<_, Error>(true);
+            }
+
+            wait_next_check(&mut notifications).await;
+        }
+    };
+
+    let result = match timeout(timeout_duration, wait).await {
+        Ok(result) => result,
+        Err(Error::Timeout(_)) => Ok(false),
+        Err(e) => Err(e),
+    };
+
+    if let Some(notifications) = notifications {
+        notifications.close().await?;
+    }
+
+    result
+}
+
+async fn keyspace_notifications_enabled(client: &Client) -> bool {
+    // CONFIG GET may be forbidden by ACLs: fall back to polling
+    let config: Result<HashMap<String, String>> = client.config_get("notify-keyspace-events").await;
+    match config {
+        Ok(config) => config
+            .get("notify-keyspace-events")
+            .map(|flags| flags.contains('K'))
+            .unwrap_or(false),
+        Err(_) => false,
+    }
+}
+
+async fn wait_next_check(notifications: &mut Option<PubSubStream>) {
+    match notifications {
+        Some(stream) => match timeout(NOTIFIED_POLL_INTERVAL, stream.next()).await {
+            Ok(Some(_)) | Err(_) => (),
+            Ok(None) => {
+                // subscription closed: keep on polling
+                *notifications = None;
+            }
+        },
+        None => sleep(POLL_INTERVAL).await,
+    }
+}
+
+/// Escape glob-style special characters, so that the key is matched literally
+fn escape_pattern(key: &str) -> String {
+    let mut pattern = String::with_capacity(key.len());
+    for c in key.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern
+}